@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Prints lines of files having a pattern, like `grep`.
+//!
+//! Usage: `cargo run --example stlgrep -- PATTERN FILE...`
+//!
+//! Each matching line is printed as `FILE:LINE:COLUMN:TEXT`.
+
+use std::process::ExitCode;
+
+use stl::*;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let (Some(pattern), files) = (args.next(), args.collect::<Vec<_>>()) else {
+        eprintln!("usage: stlgrep PATTERN FILE...");
+        return ExitCode::from(2);
+    };
+
+    let pattern = pattern.into_bytes();
+    let mut found = false;
+    for file in &files {
+        let text = match std::fs::read(file) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("stlgrep: {file}: {e}");
+                continue;
+            }
+        };
+        for m in textsearch::parallel_grep(&text, &pattern) {
+            found = true;
+            let rest = text.suffix_from(m.line_start);
+            let line = textsearch::lines(&rest).next().unwrap();
+            println!(
+                "{}:{}:{}:{}",
+                file,
+                m.line_number,
+                m.match_start - m.line_start + 1,
+                String::from_utf8_lossy(
+                    &line.iter().copied().collect::<Vec<_>>()
+                )
+            );
+        }
+    }
+
+    if found {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
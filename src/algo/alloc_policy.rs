@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+/// Policy limiting memory buffered algorithms are allowed to allocate.
+///
+/// Buffered algorithms (like `stable_sort_by`, `merge_inplace_by` and
+/// `stable_partition_with`) use a buffer when policy allows it, and
+/// otherwise degrade to a slower variant doing no allocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AllocPolicy {
+    /// Algorithms can allocate as much memory as they need.
+    #[default]
+    Unlimited,
+
+    /// Algorithms can allocate buffer of at most given number of bytes.
+    Bounded(usize),
+
+    /// Algorithms are not allowed to allocate.
+    Forbidden,
+}
+
+impl AllocPolicy {
+    /// Returns true if a buffer of `count` elements of type `T` can be
+    /// allocated wrt policy.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// assert!(AllocPolicy::Unlimited.allows::<u64>(1000));
+    /// assert!(AllocPolicy::Bounded(64).allows::<u64>(8));
+    /// assert!(!AllocPolicy::Bounded(64).allows::<u64>(9));
+    /// assert!(!AllocPolicy::Forbidden.allows::<u64>(1));
+    /// ```
+    pub fn allows<T>(&self, count: usize) -> bool {
+        self.allows_bytes(count.saturating_mul(std::mem::size_of::<T>()))
+    }
+
+    /// Returns true if a buffer of `bytes` bytes can be allocated wrt policy.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let bytes = estimate_stable_sort_scratch_bytes(1000, 8);
+    /// assert!(AllocPolicy::Bounded(4096).allows_bytes(bytes));
+    /// ```
+    pub fn allows_bytes(&self, bytes: usize) -> bool {
+        match self {
+            AllocPolicy::Unlimited => true,
+            AllocPolicy::Bounded(limit) => bytes <= *limit,
+            AllocPolicy::Forbidden => false,
+        }
+    }
+
+    /// Returns policy for each of `tasks` tasks running concurrently, such
+    /// that their buffers together stay within `self`.
+    ///
+    /// Bound is split evenly among tasks; other policies are unchanged.
+    ///
+    /// # Precondition
+    ///   - `tasks > 0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// assert_eq!(AllocPolicy::Bounded(1000).split_among(4), AllocPolicy::Bounded(250));
+    /// assert_eq!(AllocPolicy::Unlimited.split_among(4), AllocPolicy::Unlimited);
+    /// ```
+    pub fn split_among(&self, tasks: usize) -> AllocPolicy {
+        match self {
+            AllocPolicy::Bounded(limit) => AllocPolicy::Bounded(limit / tasks),
+            policy => *policy,
+        }
+    }
+}
+
+/// Returns number of bytes of buffer `stable_sort_by` allocates for sorting
+/// `count` elements of `element_size` bytes each, if policy allows it.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// assert_eq!(estimate_stable_sort_scratch_bytes(1000, 8), 4000);
+/// ```
+pub fn estimate_stable_sort_scratch_bytes(
+    count: usize,
+    element_size: usize,
+) -> usize {
+    (count / 2).saturating_mul(element_size)
+}
+
+/// Returns number of bytes of buffer `merge_inplace_by` allocates for
+/// merging, when first sorted part has `first_count` elements of
+/// `element_size` bytes each, if policy allows it.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// assert_eq!(estimate_merge_inplace_scratch_bytes(300, 8), 2400);
+/// ```
+pub fn estimate_merge_inplace_scratch_bytes(
+    first_count: usize,
+    element_size: usize,
+) -> usize {
+    first_count.saturating_mul(element_size)
+}
+
+/// Returns maximum number of bytes of buffer `stable_partition_with`
+/// allocates for partitioning `count` elements of `element_size` bytes each,
+/// if policy allows it.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// assert_eq!(estimate_stable_partition_scratch_bytes(1000, 8), 8000);
+/// ```
+pub fn estimate_stable_partition_scratch_bytes(
+    count: usize,
+    element_size: usize,
+) -> usize {
+    count.saturating_mul(element_size)
+}
+
+/// Returns maximum number of bytes `partitioned` allocates for partitioning
+/// `count` elements of `element_size` bytes each.
+///
+/// Elements are copied to a buffer first, and second partition is split off
+/// into its own buffer.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// assert_eq!(estimate_partitioned_scratch_bytes(1000, 8), 16000);
+/// ```
+pub fn estimate_partitioned_scratch_bytes(
+    count: usize,
+    element_size: usize,
+) -> usize {
+    count.saturating_mul(element_size).saturating_mul(2)
+}
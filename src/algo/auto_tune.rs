@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    estimate_stable_sort_scratch_bytes, AllocPolicy, Collection, CollectionExt,
+    MutableCollection, MutableCollectionExt, ParallelCollectionExt,
+    RandomAccessCollection, RandomAccessCollectionExt,
+};
+
+/// Policy for automatically choosing between variants of an algorithm based
+/// on size of input, available parallelism and allowed allocation.
+///
+/// Top-level functions like `sort` and `find` use `AutoTune::default()`, and
+/// their `_with` variants accept a custom policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AutoTune {
+    /// Maximum number of threads considered available, 0 means all hardware
+    /// threads. Setting it to 1 disables parallel execution.
+    pub max_threads: usize,
+
+    /// Minimum size of input in bytes worth splitting across threads.
+    pub min_parallel_bytes: usize,
+
+    /// Memory algorithms are allowed to allocate for buffers. Defaults to
+    /// `AllocPolicy::Forbidden`, so that algorithms work in place unless
+    /// policy asks for buffers.
+    pub alloc_policy: AllocPolicy,
+}
+
+impl Default for AutoTune {
+    fn default() -> Self {
+        AutoTune {
+            max_threads: 0,
+            min_parallel_bytes: 64 * 1024,
+            alloc_policy: AllocPolicy::Forbidden,
+        }
+    }
+}
+
+/// Variant of sorting algorithm chosen by `AutoTune::sort_strategy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortStrategy {
+    /// Sequential introsort, doing no allocation.
+    SequentialInPlace,
+
+    /// Sequential merge sort with a buffer of half the elements.
+    SequentialBuffered,
+
+    /// Parallel introsort of parts, merging sorted parts in place, doing no
+    /// allocation.
+    ParallelInPlace,
+
+    /// Parallel merge sort with buffers for sorting and merging parts.
+    ParallelBuffered,
+}
+
+impl AutoTune {
+    /// Returns a policy that never executes in parallel.
+    pub fn sequential() -> Self {
+        AutoTune {
+            max_threads: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Returns number of threads available for execution wrt policy.
+    pub fn available_threads(&self) -> usize {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        match self.max_threads {
+            0 => hardware_concurrency,
+            n => usize::min(n, hardware_concurrency),
+        }
+    }
+
+    /// Returns true if processing `count` elements of type `T` should be
+    /// executed in parallel.
+    pub fn should_parallelize<T>(&self, count: usize) -> bool {
+        let element_size = usize::max(std::mem::size_of::<T>(), 1);
+        self.available_threads() > 1
+            && count.saturating_mul(element_size) >= self.min_parallel_bytes
+    }
+
+    /// Returns the variant of sorting algorithm to use for sorting `count`
+    /// elements of type `T`.
+    ///
+    /// Sorting is done in parallel if `should_parallelize` allows it, and
+    /// uses buffers if `alloc_policy` allows the scratch memory of merge sort.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let policy = AutoTune {
+    ///     alloc_policy: AllocPolicy::Forbidden,
+    ///     ..AutoTune::sequential()
+    /// };
+    /// assert_eq!(
+    ///     policy.sort_strategy::<u64>(1000),
+    ///     SortStrategy::SequentialInPlace
+    /// );
+    /// ```
+    pub fn sort_strategy<T>(&self, count: usize) -> SortStrategy {
+        let scratch_bytes =
+            estimate_stable_sort_scratch_bytes(count, std::mem::size_of::<T>());
+        let use_buffer = self.alloc_policy.allows_bytes(scratch_bytes);
+        match (self.should_parallelize::<T>(count), use_buffer) {
+            (false, false) => SortStrategy::SequentialInPlace,
+            (false, true) => SortStrategy::SequentialBuffered,
+            (true, false) => SortStrategy::ParallelInPlace,
+            (true, true) => SortStrategy::ParallelBuffered,
+        }
+    }
+}
+
+/// Sorts `collection` in place, choosing algorithm as per default policy.
+///
+/// # Postcondition
+///   - Relative ordering of equivalent elements are NOT guaranteed to be
+///     presevered.
+///   - No allocation is done.
+///
+/// # Complexity
+///   - O(n * log(n)) worst case if sorted sequentially, otherwise
+///     O(n * log(n)^2), where `n == collection.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut arr = [3, 1, 2];
+/// stl::sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn sort<C>(collection: &mut C)
+where
+    C: RandomAccessCollection + MutableCollection + ?Sized,
+    C::Whole: RandomAccessCollection + MutableCollection + Send,
+    C::Element: Ord,
+{
+    sort_with(&AutoTune::default(), collection)
+}
+
+/// Sorts `collection` in place, sequentially or in parallel as per
+/// `policy.should_parallelize`.
+///
+/// Sorting is always done in place, i.e., `policy.alloc_policy` is not used;
+/// use `sort_buffered_with` for sorting with buffers policy allows.
+///
+/// # Postcondition
+///   - Relative ordering of equivalent elements are NOT guaranteed to be
+///     presevered.
+///   - No allocation is done.
+///
+/// # Complexity
+///   - O(n * log(n)) worst case if sorted sequentially, otherwise
+///     O(n * log(n)^2), where `n == collection.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut arr = [3, 1, 2];
+/// stl::sort_with(&AutoTune::sequential(), &mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn sort_with<C>(policy: &AutoTune, collection: &mut C)
+where
+    C: RandomAccessCollection + MutableCollection + ?Sized,
+    C::Whole: RandomAccessCollection + MutableCollection + Send,
+    C::Element: Ord,
+{
+    let n = collection.count();
+    if policy.should_parallelize::<C::Element>(n) {
+        collection.parallel_sort_unstable()
+    } else {
+        sequential_sort_unstable(collection)
+    }
+}
+
+/// Sorts `collection` in place, choosing algorithm as per
+/// `policy.sort_strategy`, i.e., using buffers only if `policy.alloc_policy`
+/// allows them.
+///
+/// # Postcondition
+///   - Relative ordering of equivalent elements are NOT guaranteed to be
+///     presevered.
+///   - Buffers are allocated only if `policy.alloc_policy` allows it.
+///
+/// # Complexity
+///   - O(n * log(n)) worst case, except parallel sorting without buffers
+///     which is O(n * log(n)^2), where `n == collection.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let policy = AutoTune {
+///     alloc_policy: AllocPolicy::Unlimited,
+///     ..AutoTune::sequential()
+/// };
+/// let mut arr = [3, 1, 2];
+/// stl::sort_buffered_with(&policy, &mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn sort_buffered_with<C>(policy: &AutoTune, collection: &mut C)
+where
+    C: RandomAccessCollection + MutableCollection + ?Sized,
+    C::Whole: RandomAccessCollection + MutableCollection + Send,
+    C::Element: Clone + Ord,
+{
+    let n = collection.count();
+    match policy.sort_strategy::<C::Element>(n) {
+        SortStrategy::SequentialInPlace => sequential_sort_unstable(collection),
+        SortStrategy::SequentialBuffered => {
+            collection.stable_sort(policy.alloc_policy)
+        }
+        SortStrategy::ParallelInPlace => collection.parallel_sort_unstable(),
+        SortStrategy::ParallelBuffered => {
+            collection.parallel_stable_sort(policy.alloc_policy)
+        }
+    }
+}
+
+/// Sorts `collection` with introsort, or as a Rust slice if it is contiguous
+/// (see `MutableCollection::contiguous_slice_mut`).
+fn sequential_sort_unstable<C>(collection: &mut C)
+where
+    C: RandomAccessCollection + MutableCollection + ?Sized,
+    C::Whole: RandomAccessCollection + MutableCollection,
+    C::Element: Ord,
+{
+    let (start, end) = (collection.start(), collection.end());
+    match collection.contiguous_slice_mut(start, end) {
+        Some(slice) => slice.sort_unstable(),
+        None => collection.sort_unstable(),
+    }
+}
+
+/// Finds position of first element in `collection` equal to `e`, choosing
+/// sequential or parallel search as per default policy.
+///
+/// # Complexity
+///   - O(n) where `n == collection.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let arr = [1, 2, 3, 2];
+/// assert_eq!(stl::find(&arr, &2), Some(1));
+/// assert_eq!(stl::find(&arr, &5), None);
+/// ```
+pub fn find<C>(collection: &C, e: &C::Element) -> Option<C::Position>
+where
+    C: Collection + ?Sized,
+    C::Whole: Send,
+    C::Element: Eq + Sync,
+{
+    find_with(&AutoTune::default(), collection, e)
+}
+
+/// Finds position of first element in `collection` equal to `e`, choosing
+/// sequential or parallel search as per `policy`.
+///
+/// # Complexity
+///   - O(n) where `n == collection.count()`.
+pub fn find_with<C>(
+    policy: &AutoTune,
+    collection: &C,
+    e: &C::Element,
+) -> Option<C::Position>
+where
+    C: Collection + ?Sized,
+    C::Whole: Send,
+    C::Element: Eq + Sync,
+{
+    if policy
+        .should_parallelize::<C::Element>(collection.underestimated_count())
+    {
+        collection.parallel_first_position_of(e)
+    } else {
+        collection.first_position_of(e)
+    }
+}
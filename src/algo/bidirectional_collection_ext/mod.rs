@@ -3,6 +3,7 @@
 
 use crate::algo::collection_ext::CollectionExt;
 use crate::collections::ReversedCollection;
+use crate::iterators::{RSplitWhereIterator, RSplitWhereIteratorMut};
 use crate::BidirectionalCollection;
 use crate::Collection;
 use crate::ReorderableCollection;
@@ -41,11 +42,26 @@ where
 
     /// Returns a collection whose elements are in reversed order of given collection.
     ///
+    /// Returned collection is lazy, reorderable or mutable if given collection
+    /// is, so that, e.g., sorting it sorts given collection in descending order.
+    ///
     /// # Postcondition:
     ///   - No allocations are done for forming collection.
     ///
     /// # Complexity:
     ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 1, 4, 2];
+    /// arr.full_mut().reversed().sort_unstable();
+    /// assert_eq!(arr, [4, 3, 2, 1]);
+    ///
+    /// let squares = (1..4).lazy_map(|x| x * x).reversed();
+    /// assert_eq!(squares.lazy_first(), Some(9));
+    /// ```
     fn reversed(self) -> ReversedCollection<Self>
     where
         Self: Sized,
@@ -53,6 +69,203 @@ where
         ReversedCollection::new(self)
     }
 
+    /*-----------------Iterator Algorithms-----------------*/
+
+    /// Returns an iterator of slices which are separated by elements that
+    /// match `pred`, starting from the back of `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 5, 2, 2, 3, 4, 5, 5];
+    ///
+    /// // Store sum of each split.
+    /// let mut res = vec![];
+    /// arr.rsplitting_where(|x| x % 2 == 0)
+    ///     .for_each(|s| res.push(s.iter().sum::<i32>()));
+    /// assert_eq!(res, vec![10, 3, 0, 9]);
+    /// ```
+    fn rsplitting_where<Pred>(
+        &self,
+        pred: Pred,
+    ) -> RSplitWhereIterator<'_, Self::Whole, Pred>
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        self.full().rsplit_where(pred)
+    }
+
+    /// Returns an iterator of mutable slices which are separated by elements
+    /// that match `pred`, starting from the back of `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 3, 5, 2, 3, 4, 5, 7];
+    /// // Reverse each split, except the last one.
+    /// arr.rsplitting_where_mut(|x| x % 2 == 0)
+    ///     .skip(1)
+    ///     .for_each(|mut s| s.reverse());
+    /// assert_eq!(arr, [5, 3, 1, 2, 3, 4, 5, 7]);
+    /// ```
+    fn rsplitting_where_mut<Pred>(
+        &mut self,
+        pred: Pred,
+    ) -> RSplitWhereIteratorMut<'_, Self::Whole, Pred>
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        self.full_mut().rsplit_where(pred)
+    }
+
+    /*-----------------Permutation Algorithms-----------------*/
+
+    /// Transforms `self` into the next lexicographically greater permutation
+    /// with respect to `are_in_increasing_order` and returns true; if no such
+    /// permutation exists, transforms `self` into the lexicographically
+    /// smallest permutation and returns false.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// assert!(arr.next_permutation_by(|x, y| x > y));
+    /// assert_eq!(arr, [3, 1, 2]);
+    /// ```
+    fn next_permutation_by<Compare>(
+        &mut self,
+        mut are_in_increasing_order: Compare,
+    ) -> bool
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        let start = self.start();
+        let end = self.end();
+        if start == end || self.next(start.clone()) == end {
+            return false;
+        }
+
+        let mut i = self.prior(end.clone());
+        loop {
+            let i1 = i.clone();
+            self.form_prior(&mut i);
+            if are_in_increasing_order(&self.at(&i), &self.at(&i1)) {
+                let mut j = self.prior(end.clone());
+                while !are_in_increasing_order(&self.at(&i), &self.at(&j)) {
+                    self.form_prior(&mut j);
+                }
+                self.swap_at(&i, &j);
+                self.suffix_from_mut(i1).reverse();
+                return true;
+            }
+            if i == start {
+                self.reverse();
+                return false;
+            }
+        }
+    }
+
+    /// Transforms `self` into the next lexicographically greater permutation
+    /// and returns true; if no such permutation exists, transforms `self` into
+    /// the lexicographically smallest permutation and returns false.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// assert!(arr.next_permutation());
+    /// assert_eq!(arr, [1, 3, 2]);
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// assert!(!arr.next_permutation());
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    fn next_permutation(&mut self) -> bool
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.next_permutation_by(|x, y| x < y)
+    }
+
+    /// Transforms `self` into the previous lexicographically smaller
+    /// permutation with respect to `are_in_increasing_order` and returns true;
+    /// if no such permutation exists, transforms `self` into the
+    /// lexicographically greatest permutation and returns false.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 1, 2];
+    /// assert!(arr.prev_permutation_by(|x, y| x > y));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    fn prev_permutation_by<Compare>(
+        &mut self,
+        mut are_in_increasing_order: Compare,
+    ) -> bool
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        self.next_permutation_by(|x, y| are_in_increasing_order(y, x))
+    }
+
+    /// Transforms `self` into the previous lexicographically smaller
+    /// permutation and returns true; if no such permutation exists, transforms
+    /// `self` into the lexicographically greatest permutation and returns
+    /// false.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 3, 2];
+    /// assert!(arr.prev_permutation());
+    /// assert_eq!(arr, [1, 2, 3]);
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// assert!(!arr.prev_permutation());
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    fn prev_permutation(&mut self) -> bool
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.prev_permutation_by(|x, y| x < y)
+    }
+
     /*-----------------Numeric Algorithms-----------------*/
 
     /// Returns the result of combining elements of given collection using given
@@ -1,10 +1,28 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::{
+    collections::HashSet,
+    hash::Hash,
+    iter::{Product, Sum},
+    ops::{ControlFlow, Range},
+};
+
 use crate::{
-    collections::MappedCollection,
-    iterators::{CollectionIter, SplitEvenlyIterator, SplitWhereIterator},
-    Collection, Slice,
+    buffer::TempBuffer,
+    collections::{
+        AdjacentPairsCollection, BoundedTopK, CompactedCollection,
+        CycleCollection, FlatMappedCollection, IndexedMappedCollection,
+        InterleavedCollection, JoinedCollection, MappedCollection,
+        MergedCollection, PaddedCollection, ProjectedCollection,
+        RotatedCollection, StridedCollection,
+    },
+    iterators::{
+        ChunkByIterator, CollectionIter, GroupRunsByKeyIterator,
+        SplitEvenlyIterator, SplitWhereIterator, SplitWithSeparatorsIterator,
+    },
+    Collection, Fallible, MutableCollection, RandomAccessCollectionExt, Slice,
+    SlidingView,
 };
 
 /// Algorithms for `Collection`.
@@ -28,6 +46,88 @@ pub trait CollectionExt: Collection {
         }
     }
 
+    /// Returns the position having `offset` elements before it in `self`.
+    ///
+    /// # Precondition
+    ///   - `offset <= self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(offset) otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let s = arr.suffix(3);
+    /// assert_eq!(s.position_at(1), 3);
+    /// ```
+    fn position_at(&self, offset: usize) -> Self::Position {
+        self.next_n(self.start(), offset)
+    }
+
+    /// Returns number of elements before `position` in `self`, i.e., the
+    /// offset of `position`.
+    ///
+    /// # Precondition
+    ///   - `position` is a valid position in `self`.
+    ///
+    /// # Postcondition
+    ///   - `self.position_at(self.offset_of(&position)) == position`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(offset) otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let s = arr.suffix(3);
+    /// let i = s.first_position_of(&4).unwrap();
+    /// assert_eq!(i, 3);
+    /// assert_eq!(s.offset_of(&i), 1);
+    /// ```
+    fn offset_of(&self, position: &Self::Position) -> usize {
+        self.distance(self.start(), position.clone())
+    }
+
+    /// Returns a lazy collection of offsets of elements of `self`, i.e.,
+    /// `0..self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise, where
+    ///     `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// assert!("abc".offsets().equals(&[0, 1, 2]));
+    /// ```
+    fn offsets(&self) -> Range<usize> {
+        0..self.count()
+    }
+
+    /// Returns the element having `offset` elements before it in `self`.
+    ///
+    /// # Precondition
+    ///   - `offset < self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(offset) otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// assert_eq!(*arr.suffix(3).element_at(1), 4);
+    /// ```
+    fn element_at(&self, offset: usize) -> Self::ElementRef<'_> {
+        self.at(&self.position_at(offset))
+    }
+
     /*-----------------Slice Algorithms-----------------*/
 
     /// Returns slice of the collection covering full collection.
@@ -47,6 +147,27 @@ pub trait CollectionExt: Collection {
         self.slice(self.start(), self.end())
     }
 
+    /// Returns a sliding view with an empty window at start of collection,
+    /// which can slide till end of collection.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// let mut view = arr.sliding_view();
+    /// view.advance_back();
+    /// view.advance_back();
+    /// view.advance_front();
+    /// assert!(view.window().equals(&[2]));
+    /// ```
+    fn sliding_view(&self) -> SlidingView<'_, Self::Whole> {
+        SlidingView::new(self.full())
+    }
+
     /// Returns a slice, upto specified maximum length, containing the initial elements of
     /// collection.
     ///
@@ -256,6 +377,28 @@ pub trait CollectionExt: Collection {
         self.slice(from, self.end())
     }
 
+    /// Returns slice of `self` covering elements with offsets in `range`.
+    ///
+    /// # Precondition
+    ///   - `range.start <= range.end <= self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(range.end) otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let s = arr.slice_at_offsets(1..3);
+    /// assert!(s.equals(&[2, 3]));
+    /// ```
+    fn slice_at_offsets(&self, range: Range<usize>) -> Slice<'_, Self::Whole> {
+        let from = self.position_at(range.start);
+        let to = self.next_n(from.clone(), range.end - range.start);
+        self.slice(from, to)
+    }
+
     /// Returns two disjoint slices of `self` split at the given `position`.
     ///
     /// # Examples
@@ -329,6 +472,48 @@ pub trait CollectionExt: Collection {
         }
     }
 
+    /// Applies `f` to each element of collection from left to right, stopping
+    /// at first element for which `f` returns a residual, e.g., `Err`.
+    ///
+    /// # Postcondition
+    ///   - Returns first residual returned by `f` if any, otherwise the
+    ///     successful output, e.g., `Ok(())`.
+    ///
+    /// # Complexity:
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// let mut seen = vec![];
+    /// let res = arr.try_for_each(|x| {
+    ///     if *x == 3 {
+    ///         return Err(*x);
+    ///     }
+    ///     seen.push(*x);
+    ///     Ok(())
+    /// });
+    /// assert_eq!(res, Err(3));
+    /// assert_eq!(seen, [1, 2]);
+    /// ```
+    fn try_for_each<F, Out>(&self, mut f: F) -> Out
+    where
+        F: FnMut(&Self::Element) -> Out,
+        Out: Fallible<Output = ()>,
+    {
+        let mut start = self.start();
+        let end = self.end();
+        while start != end {
+            if let ControlFlow::Break(r) = f(&self.at(&start)).branch() {
+                return Out::from_residual(r);
+            }
+            start = self.next(start);
+        }
+        Out::from_output(())
+    }
+
     /// Returns an iterator of slices which are separated by elements that match `pred`.
     ///
     /// # Example
@@ -354,6 +539,227 @@ pub trait CollectionExt: Collection {
         self.full().split_where(pred)
     }
 
+    /// Returns an iterator of slices which are separated by elements
+    /// equivalent to `separator` by `are_equivalent`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, -1, 2, 1, 3];
+    /// let splits: Vec<_> = arr
+    ///     .splitting_on_by(&1, |x: &i32, y: &i32| x.abs() == y.abs())
+    ///     .map(|s| s.to_vec())
+    ///     .collect();
+    /// assert_eq!(splits, vec![vec![], vec![], vec![2], vec![3]]);
+    /// ```
+    fn splitting_on_by<'a, F>(
+        &'a self,
+        separator: &'a Self::Element,
+        mut are_equivalent: F,
+    ) -> SplitWhereIterator<
+        'a,
+        Self::Whole,
+        impl FnMut(&Self::Element) -> bool + Clone + 'a,
+    >
+    where
+        F: FnMut(&Self::Element, &Self::Element) -> bool + Clone + 'a,
+        Self: Sized,
+    {
+        self.splitting_where(move |x| are_equivalent(x, separator))
+    }
+
+    /// Returns an iterator of slices which are separated by elements equal to
+    /// `separator`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 0, 2, 3, 0, 4];
+    /// let splits: Vec<_> = arr.splitting_on(&0).map(|s| s.to_vec()).collect();
+    /// assert_eq!(splits, vec![vec![1], vec![2, 3], vec![4]]);
+    /// ```
+    fn splitting_on<'a>(
+        &'a self,
+        separator: &'a Self::Element,
+    ) -> SplitWhereIterator<
+        'a,
+        Self::Whole,
+        impl FnMut(&Self::Element) -> bool + Clone + 'a,
+    >
+    where
+        Self::Element: Eq,
+        Self: Sized,
+    {
+        self.splitting_on_by(separator, |x, y| x == y)
+    }
+
+    /// Returns an iterator of slices which are separated by elements that match
+    /// `pred`, also yielding the separating elements with their positions.
+    ///
+    /// # Postcondition
+    ///   - Yielded items alternate between segments and separators, starting
+    ///     and ending with a segment.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::SplitItem;
+    ///
+    /// let arr = [1, 0, 2, 3, 0];
+    /// let mut res = vec![];
+    /// for item in arr.splitting_with_separators(|x| *x == 0) {
+    ///     match item {
+    ///         SplitItem::Segment(s) => res.extend(s.iter().copied()),
+    ///         SplitItem::Separator(_, e) => res.push(*e),
+    ///     }
+    /// }
+    /// assert_eq!(res, arr);
+    /// ```
+    fn splitting_with_separators<Pred>(
+        &self,
+        pred: Pred,
+    ) -> SplitWithSeparatorsIterator<'_, Self::Whole, Pred>
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        self.full().split_with_separators(pred)
+    }
+
+    /// Returns an iterator of maximal runs of consecutive elements of `self`
+    /// having equal key, along with the key of each run.
+    ///
+    /// Runs are yielded in order of their occurrence in `self`. Unlike
+    /// hash-based grouping, elements with equal key that are not adjacent
+    /// form separate runs. Thus, for `self` sorted by key, every key is
+    /// yielded exactly once.
+    ///
+    /// # Complexity
+    ///   - O(n) for complete iteration where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 2, 4, 6, 5];
+    /// let v: Vec<_> = arr
+    ///     .group_runs_by_key(|x| x % 2)
+    ///     .map(|(k, s)| (k, s.to_vec()))
+    ///     .collect();
+    /// assert_eq!(v, vec![(1, vec![1, 3]), (0, vec![2, 4, 6]), (1, vec![5])]);
+    /// ```
+    fn group_runs_by_key<F, K>(
+        &self,
+        key_fn: F,
+    ) -> GroupRunsByKeyIterator<'_, Self::Whole, F, K>
+    where
+        F: FnMut(&Self::Element) -> K,
+        K: PartialEq,
+    {
+        GroupRunsByKeyIterator::new(self.full(), key_fn)
+    }
+
+    /// Returns an iterator of maximal slices of `self` where every pair of
+    /// adjacent elements `(a, b)` in a slice satisfies `bi_pred(a, b)`.
+    ///
+    /// Slices are yielded lazily in order of their occurrence in `self`,
+    /// without any allocation.
+    ///
+    /// # Postcondition
+    ///   - Every yielded slice is non-empty, and yielded slices together form
+    ///     `self`.
+    ///
+    /// # Complexity
+    ///   - O(n) for complete iteration where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 4, 3, 5, 6, 1];
+    /// let v: Vec<_> = arr.chunk_by(|x, y| x < y).map(|s| s.to_vec()).collect();
+    /// assert_eq!(v, vec![vec![1, 2, 4], vec![3, 5, 6], vec![1]]);
+    /// ```
+    fn chunk_by<F>(&self, bi_pred: F) -> ChunkByIterator<'_, Self::Whole, F>
+    where
+        F: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        ChunkByIterator::new(self.full(), bi_pred)
+    }
+
+    /// Returns an iterator of run-length encoding of `self`, i.e., of
+    /// `(element, count)` for every maximal run of `count` consecutive equal
+    /// elements.
+    ///
+    /// # Postcondition
+    ///   - Every yielded count is non-zero, and elements of consecutive yielded
+    ///     pairs are different.
+    ///
+    /// # Complexity
+    ///   - O(n) for complete iteration where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = ['a', 'a', 'a', 'b', 'c', 'c'];
+    /// let rle: Vec<_> = arr.rle_encode().collect();
+    /// assert_eq!(rle, vec![('a', 3), ('b', 1), ('c', 2)]);
+    /// ```
+    fn rle_encode(&self) -> impl Iterator<Item = (Self::Element, usize)> + '_
+    where
+        Self::Element: Eq + Clone,
+    {
+        self.chunk_by(|x, y| x == y)
+            .map(|run| (run.at(&run.start()).clone(), run.count()))
+    }
+
+    /// Writes elements run-length encoded by `self` to the start of `dest`,
+    /// and returns the position in `dest` just after the last written element.
+    ///
+    /// `self` is collection of `(element, count)` as produced by
+    /// `rle_encode`.
+    ///
+    /// # Precondition
+    ///   - `dest.count()` is atleast sum of counts in `self`.
+    ///
+    /// # Postcondition
+    ///   - `element` is written `count` times for every `(element, count)` in
+    ///     `self`, in order.
+    ///
+    /// # Complexity
+    ///   - O(m) where `m` is sum of counts in `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let rle = [('a', 3), ('b', 1), ('c', 2)];
+    /// let mut dest = [' '; 7];
+    /// let i = rle.rle_decode_into(&mut dest);
+    /// assert_eq!(i, 6);
+    /// assert_eq!(dest, ['a', 'a', 'a', 'b', 'c', 'c', ' ']);
+    /// ```
+    fn rle_decode_into<T, D>(&self, dest: &mut D) -> D::Position
+    where
+        Self: Collection<Element = (T, usize)>,
+        D: MutableCollection<Element = T> + ?Sized,
+        D::Whole: MutableCollection,
+        T: Clone,
+    {
+        let mut out = dest.start();
+        let mut rest = self.full();
+        while let Some(run) = rest.pop_first() {
+            let (e, n) = &*run;
+            for _ in 0..*n {
+                *dest.at_mut(&out) = e.clone();
+                dest.form_next(&mut out);
+            }
+        }
+        out
+    }
+
     /// Returns an iterator that iterates through evenly sized consecutive at
     /// max `max_slices` slices of `self` with every slice being atleast of
     /// size `min_size`.
@@ -443,461 +849,2517 @@ pub trait CollectionExt: Collection {
         MappedCollection::new(self, map_fn)
     }
 
-    /*-----------------Equality algorithms-----------------*/
+    /// Returns a lazy collection projecting elements of mapping the given
+    /// closure over positions and elements.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [10, 20, 30].map_indexed(|i, x| i * x);
+    /// assert!(arr.equals(&[0, 20, 60]));
+    ///
+    /// let s: Vec<String> = ["a", "b"]
+    ///     .map_indexed(|i, x| format!("{i}:{x}"))
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(s, ["0:a", "1:b"]);
+    /// ```
+    fn map_indexed<MapFn, MappedType>(
+        self,
+        map_fn: MapFn,
+    ) -> IndexedMappedCollection<Self, MapFn, MappedType>
+    where
+        Self: Sized,
+        MapFn: Fn(&Self::Position, &Self::Element) -> MappedType,
+    {
+        IndexedMappedCollection::new(self, map_fn)
+    }
 
-    /// Returns true if elements of self is equivalent to elements of other by given relation bi_pred.
+    /// Returns a collection presenting the field projected by `proj` of each
+    /// element.
     ///
-    /// # Postcondition
-    ///   - Returns true if elements of self is equivalent to other by given relation bi_pred.
-    ///   - If self and other have different number of elements, then return false.
+    /// Unlike `map`, elements are referred rather than computed, and
+    /// reordering returned collection reorders whole elements of `self`, so
+    /// that, e.g., a collection of structs can be sorted by a field.
     ///
     /// # Complexity
-    ///   - `O(min(m, n))`
-    ///     where
-    ///     - `m == self.count()`
-    ///     - `n == other.count()`
+    ///   - O(1).
     ///
-    /// # Examples
+    /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr1 = [1, 2, 3];
-    /// let arr2 = [2, 3, 4];
-    /// assert!(arr1.equals_by(&arr2, |x, y| *y == x + 1));
+    /// struct Person {
+    ///     name: &'static str,
+    ///     age: u32,
+    /// }
+    ///
+    /// let mut people = vec![
+    ///     Person { name: "A", age: 30 },
+    ///     Person { name: "B", age: 20 },
+    /// ];
+    /// people.full_mut().project(|p| &p.age).sort_unstable();
+    /// assert_eq!(people[0].name, "B");
     /// ```
-    fn equals_by<OtherCollection, F>(
-        &self,
-        other: &OtherCollection,
-        mut bi_pred: F,
-    ) -> bool
+    fn project<Proj, Field>(
+        self,
+        proj: Proj,
+    ) -> ProjectedCollection<Self, Proj, Field>
     where
-        OtherCollection: Collection,
-        F: FnMut(&Self::Element, &OtherCollection::Element) -> bool,
+        Self: Sized,
+        Proj: Fn(&Self::Element) -> &Field,
     {
-        let mut self1 = self.full();
-        let mut other1 = other.full();
-        loop {
-            match (self1.pop_first(), other1.pop_first()) {
-                (Some(x), Some(y)) if bi_pred(&x, &y) => {}
-                (None, None) => return true,
-                _ => return false,
-            }
-        }
+        ProjectedCollection::new(self, proj)
     }
 
-    /// Returns true if elements of self is equal to elements of other.
+    /// Returns a lazy collection presenting elements of collections obtained by
+    /// mapping the given closure over elements, one collection after another.
     ///
     /// # Postcondition
-    ///   - Returns true if elements of self is equal to elements of other.
-    ///   - If self and other have different number of elements, then return false.
+    ///   - No allocations are done for forming collection.
+    ///   - Mapped collections are not stored and are computed again on every
+    ///     access.
     ///
     /// # Complexity
-    ///   - `O(min(m, n))`
-    ///     where
-    ///     - `m == self.count()`
-    ///     - `n == other.count()`
+    ///   - O(1) for forming the collection.
+    ///   - Every position step and element access calls the closure once, so
+    ///     a full traversal calls it O(m) times per element, where `m` is
+    ///     length of collection obtained from that element. Prefer closures
+    ///     cheap to call, e.g., returning slices or lazy collections.
     ///
-    /// # Examples
+    /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr1 = [1, 2, 3];
-    /// let arr2 = [1, 2, 3];
-    /// assert!(arr1.equals(&arr2));
+    /// let arr = [1, 2, 3].flat_map(|x| 0..*x);
+    /// assert!(arr.equals(&[0, 0, 1, 0, 1, 2]));
     /// ```
-    fn equals<OtherCollection>(&self, other: &OtherCollection) -> bool
+    fn flat_map<MapFn, Inner>(
+        self,
+        map_fn: MapFn,
+    ) -> FlatMappedCollection<Self, MapFn, Inner>
     where
-        OtherCollection: Collection<Element = Self::Element>,
-        Self::Element: Eq,
+        Self: Sized,
+        MapFn: Fn(&Self::Element) -> Inner,
+        Inner: Collection,
+        Inner::Element: Clone,
     {
-        self.equals_by(other, |x, y| x == y)
+        FlatMappedCollection::new(self, map_fn)
     }
 
-    /*-----------------Find Algorithms-----------------*/
-
-    /// Finds position of first element in `self` satisfying `pred`. If no such
-    /// element exists, returns `self.end()`.
+    /// Returns a collection presenting elements of `self` starting from
+    /// `pivot`, followed by elements before `pivot`.
+    ///
+    /// # Precondition
+    ///   - `pivot` is a valid position in `self`.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 2, 3];
-    /// let i = arr.first_position_where(|x| *x == 3);
-    /// assert_eq!(i, Some(2));
+    /// let arr = [1, 2, 3, 4, 5].rotated(2);
+    /// assert!(arr.equals(&[3, 4, 5, 1, 2]));
     /// ```
-    fn first_position_where<Pred>(
-        &self,
-        mut pred: Pred,
-    ) -> Option<Self::Position>
+    fn rotated(self, pivot: Self::Position) -> RotatedCollection<Self>
     where
-        Pred: FnMut(&Self::Element) -> bool,
+        Self: Sized,
     {
-        let mut rest = self.full();
-        let mut p = self.start();
-        while let Some(e) = rest.pop_first() {
-            if pred(&e) {
-                return Some(p);
-            }
-            p = rest.start()
-        }
-        None
+        RotatedCollection::new(self, pivot)
     }
 
-    /// Finds position of first element in `self` equals `e`. If no such element
-    /// exists, returns `self.end()`.
+    /// Returns a collection presenting elements of `self` repeated `times`
+    /// times.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 2, 3, 3];
-    /// let i = arr.first_position_of(&3);
-    /// assert_eq!(i, Some(2));
+    /// let arr = [1, 2, 3].cycled(2);
+    /// assert!(arr.equals(&[1, 2, 3, 1, 2, 3]));
+    ///
+    /// // For endless cycling, use iterator of collection.
+    /// let v: Vec<_> = [1, 2].iter().cycle().take(5).copied().collect();
+    /// assert_eq!(v, vec![1, 2, 1, 2, 1]);
     /// ```
-    fn first_position_of(&self, e: &Self::Element) -> Option<Self::Position>
+    fn cycled(self, times: usize) -> CycleCollection<Self>
     where
-        Self::Element: Eq,
+        Self: Sized,
     {
-        self.first_position_where(|x| x == e)
+        CycleCollection::new(self, times)
     }
 
-    /// Finds position of last element in `self` satisfying `pred`. If no such
-    /// element exists, returns `self.end()`.
+    /// Returns a collection presenting every `step`-th element of `self`,
+    /// starting from the first element.
+    ///
+    /// # Precondition
+    ///   - `step > 0`.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - Positions of returned collection are positions of `self`.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 2, 3, 4];
-    /// let i = arr.last_position_where(|x| x % 2 == 1);
-    /// assert_eq!(i, Some(2));
+    /// // Interleaved real and imaginary parts.
+    /// let data = [1, -1, 2, -2, 3, -3];
+    /// assert!(data.stride(2).equals(&[1, 2, 3]));
+    /// assert!(data.suffix(5).stride(2).equals(&[-1, -2, -3]));
     /// ```
-    fn last_position_where<Pred>(
-        &self,
-        mut pred: Pred,
-    ) -> Option<Self::Position>
+    fn stride(self, step: usize) -> StridedCollection<Self>
     where
-        Pred: FnMut(&Self::Element) -> bool,
+        Self: Sized,
     {
-        let mut rest = self.full();
-        let mut res = None;
-        let mut p = self.start();
-        while let Some(e) = rest.pop_first() {
-            if pred(&e) {
-                res = Some(p);
-            }
-            p = rest.start();
-        }
-        res
+        StridedCollection::new(self, step)
     }
 
-    /// Finds position of `last` element equals `e`. If no such element exist,
-    /// return `self.end()`.
+    /// Returns a collection presenting elements of all inner collections of
+    /// `self`, one inner collection after another.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - Empty inner collections are skipped while traversal.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 3, 3];
-    /// let i = arr.last_position_of(&3);
-    /// assert_eq!(i, Some(2));
+    /// let v = vec![vec![1, 2], vec![], vec![3]];
+    /// assert!(v.joined().equals(&[1, 2, 3]));
     /// ```
-    fn last_position_of(&self, e: &Self::Element) -> Option<Self::Position>
+    fn joined<'a>(&'a self) -> JoinedCollection<'a, Self>
     where
-        Self::Element: Eq,
+        Self: Collection<ElementRef<'a> = &'a <Self as Collection>::Element>,
+        Self::Element: Collection,
     {
-        self.last_position_where(|x| x == e)
+        JoinedCollection::new(self)
     }
 
-    /*-----------------Predicate Test Algorithms-----------------*/
-
-    /// Returns true if all element in `self` satisfies `pred`.
+    /// Returns a lazy collection presenting elements of sorted `self` and
+    /// sorted `other` as a single collection sorted wrt
+    /// `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - Merging is stable, i.e., equivalent elements of `self` are presented
+    ///     before those of `other`.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
-    ///
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 3, 5];
-    /// assert!(arr.all_satisfy(|x| x % 2 == 1));
+    /// let arr = [5, 3, 1].merged_by([6, 4], |x, y| x > y);
+    /// assert!(arr.equals(&[6, 5, 4, 3, 1]));
     /// ```
-    fn all_satisfy<Pred>(&self, mut pred: Pred) -> bool
+    fn merged_by<Other, Compare>(
+        self,
+        other: Other,
+        are_in_increasing_order: Compare,
+    ) -> MergedCollection<Self, Other, Compare>
     where
-        Pred: FnMut(&Self::Element) -> bool,
+        Self: Sized,
+        Self::Element: Clone,
+        Other: Collection<Element = Self::Element>,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
     {
-        let mut cur = self.full();
-        while let Some(e) = cur.pop_first() {
-            if !pred(&e) {
-                return false;
-            }
-        }
-        true
+        MergedCollection::new(self, other, are_in_increasing_order)
     }
 
-    /// Returns true if atleast one element in `self` satisfies `pred`.
+    /// Returns a lazy collection presenting elements of sorted `self` and
+    /// sorted `other` as a single sorted collection.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - Merging is stable, i.e., equivalent elements of `self` are presented
+    ///     before those of `other`.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
-    ///
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 2, 5];
-    /// assert!(arr.any_satisfy(|x| x % 2 == 1));
+    /// let arr = [1, 4, 7].merged([2, 5]).merged([3, 6]);
+    /// assert!(arr.equals(&[1, 2, 3, 4, 5, 6, 7]));
     /// ```
-    fn any_satisfy<Pred>(&self, mut pred: Pred) -> bool
+    #[allow(clippy::type_complexity)]
+    fn merged<Other>(
+        self,
+        other: Other,
+    ) -> MergedCollection<Self, Other, fn(&Self::Element, &Self::Element) -> bool>
     where
-        Pred: FnMut(&Self::Element) -> bool,
+        Self: Sized,
+        Self::Element: Ord + Clone,
+        Other: Collection<Element = Self::Element>,
     {
-        let mut cur = self.full();
-        while let Some(e) = cur.pop_first() {
-            if pred(&e) {
-                return true;
-            }
-        }
-        false
+        MergedCollection::new(self, other, |x, y| x < y)
     }
 
-    /// Returns true if none of elements in `self` satisfy `pred`.
+    /// Returns a lazy collection presenting pairs of neighboring elements of
+    /// `self`, i.e., `(e1, e2), (e2, e3), ..., (e(n-1), en)`.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - Collection is empty if `self` has less than 2 elements.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1).
     ///
     /// # Example
-    ///
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [2, 4, 6];
-    /// assert!(arr.none_satisfy(|x| x % 2 == 1));
+    /// let arr = [1, 4, 9, 16].adjacent_pairs();
+    /// assert!(arr.equals(&[(1, 4), (4, 9), (9, 16)]));
     /// ```
-    fn none_satisfy<Pred>(&self, mut pred: Pred) -> bool
+    fn adjacent_pairs(self) -> AdjacentPairsCollection<Self>
     where
-        Pred: FnMut(&Self::Element) -> bool,
+        Self: Sized,
+        Self::Element: Clone,
     {
-        let mut cur = self.full();
-        while let Some(e) = cur.pop_first() {
-            if pred(&e) {
-                return false;
-            }
-        }
-        true
+        AdjacentPairsCollection::new(self)
     }
 
-    /*-----------------Count Algorithms-----------------*/
-
-    /// Returns number of elements in `self` satisfying `pred`.
+    /// Returns a lazy collection presenting elements of `self` followed by
+    /// copies of `value`, so that it has at least `len` elements.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - If `self` has at least `len` elements, returned collection has
+    ///     exactly elements of `self`.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise where
+    ///     `n == self.count()`.
     ///
     /// # Example
     /// ```rust
     /// use stl::*;
     ///
     /// let arr = [1, 2, 3];
-    /// let n = arr.count_where(|x| x % 2 == 1);
-    /// assert_eq!(n, 2);
+    /// assert!(arr.padded_to(5, 0).equals(&[1, 2, 3, 0, 0]));
+    /// assert!(arr.padded_to(2, 0).equals(&[1, 2, 3]));
     /// ```
-    fn count_where<Pred>(&self, mut pred: Pred) -> usize
+    fn padded_to(
+        self,
+        len: usize,
+        value: Self::Element,
+    ) -> PaddedCollection<Self>
     where
-        Pred: FnMut(&Self::Element) -> bool,
+        Self: Sized,
+        Self::Element: Clone,
     {
-        let mut cur = self.full();
-        let mut count = 0;
-        while let Some(e) = cur.pop_first() {
-            if pred(&e) {
-                count += 1;
-            }
-        }
-        count
+        PaddedCollection::new(self, len, value)
     }
 
-    /// Returns number of elements in `self` equals `e`.
+    /// Returns a lazy collection presenting elements of `self` and `other`
+    /// alternately, starting with `self`.
+    ///
+    /// # Postcondition
+    ///   - Once the shorter collection is exhausted, remaining elements of the
+    ///     longer collection are presented in order.
+    ///   - No allocations are done for forming collection.
+    ///   - Returned collection is random access if both collections are.
     ///
     /// # Complexity
-    ///   - O(n) where `n == self.count()`.
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise where `n` is total
+    ///     number of elements.
     ///
     /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [3, 0, 3];
-    /// let n = arr.count_of(&3);
-    /// assert_eq!(n, 2);
+    /// let arr = [1, 3, 5, 7].interleaved_with([2, 4]);
+    /// assert!(arr.equals(&[1, 2, 3, 4, 5, 7]));
     /// ```
-    fn count_of(&self, e: &Self::Element) -> usize
+    fn interleaved_with<Other>(
+        self,
+        other: Other,
+    ) -> InterleavedCollection<Self, Other>
     where
-        Self::Element: Eq,
+        Self: Sized,
+        Self::Element: Clone,
+        Other: Collection<Element = Self::Element>,
     {
-        self.count_where(|x| x == e)
+        InterleavedCollection::new(self, other)
     }
 
-    /*-----------------Partition Algorithms-----------------*/
+    /*-----------------Equality algorithms-----------------*/
 
-    /// Returns position of first element of collection for which predicate returns false.
+    /// Returns true if elements of self is equivalent to elements of other by given relation bi_pred.
     ///
-    /// # Precondition
-    ///   - The collection should be already partitioned wrt predicate i.e,
-    ///     there exist a position `i` such that predicate is true for every
-    ///     element of `self.prefix_upto(i)` and predicate is false for every
-    ///     element of `self.suffix_from(i)`.
+    /// # Postcondition
+    ///   - Returns true if elements of self is equivalent to other by given relation bi_pred.
+    ///   - If self and other have different number of elements, then return false.
     ///
     /// # Complexity
-    ///   - O(log n) for RandomAccessCollection, O(n) otherwise; where `n == self.count()`.
-    ///   - O(log n) application of `belongs_in_second_half`.
+    ///   - `O(min(m, n))`
+    ///     where
+    ///     - `m == self.count()`
+    ///     - `n == other.count()`
     ///
-    /// # Example
+    /// # Examples
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 3, 5, 2, 4];
-    /// let i = arr.partition_point(|x| x % 2 == 0);
-    /// assert_eq!(i, 3);
+    /// let arr1 = [1, 2, 3];
+    /// let arr2 = [2, 3, 4];
+    /// assert!(arr1.equals_by(&arr2, |x, y| *y == x + 1));
     /// ```
-    fn partition_point<F>(
+    fn equals_by<OtherCollection, F>(
         &self,
-        mut belongs_in_second_half: F,
-    ) -> Self::Position
+        other: &OtherCollection,
+        mut bi_pred: F,
+    ) -> bool
     where
-        F: FnMut(&Self::Element) -> bool,
+        OtherCollection: Collection,
+        F: FnMut(&Self::Element, &OtherCollection::Element) -> bool,
     {
-        let mut f = self.start();
-        let mut n = self.count();
-        while n > 0 {
-            let half = n / 2;
-            let m = self.next_n(f.clone(), half);
-            if belongs_in_second_half(&self.at(&m)) {
-                n = half;
-            } else {
-                f = self.next(m);
-                n -= half + 1;
+        let mut self1 = self.full();
+        let mut other1 = other.full();
+        loop {
+            match (self1.pop_first(), other1.pop_first()) {
+                (Some(x), Some(y)) if bi_pred(&x, &y) => {}
+                (None, None) => return true,
+                _ => return false,
             }
         }
-        f
     }
 
-    /// Returns two Vec containing the elements of the collection that
-    /// don’t and do satisfy the given predicate, respectively.
+    /// Returns true if elements of self is equal to elements of other.
     ///
     /// # Postcondition
-    ///   - Returns `(falseVec, trueVec)` where `falseVec` contains all elements
-    ///     that don't satisfy predicate and `trueVec` contains all elements
-    ///     that do satisfy predicate.
-    ///   - Relative ordering of elements is preserved in both Vec.
+    ///   - Returns true if elements of self is equal to elements of other.
+    ///   - If self and other have different number of elements, then return false.
+    ///
+    /// If both collections are contiguous (see `Collection::contiguous_slice`),
+    /// elements are compared as slices, e.g., with `memcmp` for primitive
+    /// elements.
+    ///
+    /// # Complexity
+    ///   - `O(min(m, n))`
+    ///     where
+    ///     - `m == self.count()`
+    ///     - `n == other.count()`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr1 = [1, 2, 3];
+    /// let arr2 = [1, 2, 3];
+    /// assert!(arr1.equals(&arr2));
+    /// ```
+    fn equals<OtherCollection>(&self, other: &OtherCollection) -> bool
+    where
+        OtherCollection: Collection<Element = Self::Element>,
+        Self::Element: Eq,
+    {
+        if let (Some(x), Some(y)) = (
+            self.contiguous_slice(self.start(), self.end()),
+            other.contiguous_slice(other.start(), other.end()),
+        ) {
+            return x == y;
+        }
+        self.equals_by(other, |x, y| x == y)
+    }
+
+    /// Returns true if `other` is equivalent to a prefix of `self` by given
+    /// relation bi_pred.
+    ///
+    /// # Complexity
+    ///   - `O(n)` where `n == other.count()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// assert!(arr.starts_with_by(&[2, 3], |x, y| *y == x + 1));
+    /// ```
+    fn starts_with_by<OtherCollection, F>(
+        &self,
+        other: &OtherCollection,
+        mut bi_pred: F,
+    ) -> bool
+    where
+        OtherCollection: Collection + ?Sized,
+        F: FnMut(&Self::Element, &OtherCollection::Element) -> bool,
+    {
+        let mut self1 = self.full();
+        let mut other1 = other.full();
+        loop {
+            match (self1.pop_first(), other1.pop_first()) {
+                (_, None) => return true,
+                (Some(x), Some(y)) if bi_pred(&x, &y) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns true if `other` is equal to a prefix of `self`.
+    ///
+    /// # Complexity
+    ///   - `O(n)` where `n == other.count()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// assert!(arr.starts_with(&[1, 2]));
+    /// assert!(!arr.starts_with(&[2]));
+    /// ```
+    fn starts_with<OtherCollection>(&self, other: &OtherCollection) -> bool
+    where
+        OtherCollection: Collection<Element = Self::Element> + ?Sized,
+        Self::Element: Eq,
+    {
+        self.starts_with_by(other, |x, y| x == y)
+    }
+
+    /*-----------------Find Algorithms-----------------*/
+
+    /// Finds position of first element in `self` satisfying `pred`. If no such
+    /// element exists, returns `self.end()`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// let i = arr.first_position_where(|x| *x == 3);
+    /// assert_eq!(i, Some(2));
+    /// ```
+    fn first_position_where<Pred>(
+        &self,
+        mut pred: Pred,
+    ) -> Option<Self::Position>
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut rest = self.full();
+        let mut p = self.start();
+        while let Some(e) = rest.pop_first() {
+            if pred(&e) {
+                return Some(p);
+            }
+            p = rest.start()
+        }
+        None
+    }
+
+    /// Finds position of first element in `self` equals `e`. If no such element
+    /// exists, returns `self.end()`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 3];
+    /// let i = arr.first_position_of(&3);
+    /// assert_eq!(i, Some(2));
+    /// ```
+    fn first_position_of(&self, e: &Self::Element) -> Option<Self::Position>
+    where
+        Self::Element: Eq,
+    {
+        self.first_position_of_by(e, |x, y| x == y)
+    }
+
+    /// Finds position of first element in `self` equivalent to `e` by
+    /// `are_equivalent`. If no such element exists, returns `None`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = ["a", "B", "b"];
+    /// let i = arr.first_position_of_by(&"b", |x, y| x.eq_ignore_ascii_case(y));
+    /// assert_eq!(i, Some(1));
+    /// ```
+    fn first_position_of_by<F>(
+        &self,
+        e: &Self::Element,
+        mut are_equivalent: F,
+    ) -> Option<Self::Position>
+    where
+        F: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        self.first_position_where(|x| are_equivalent(x, e))
+    }
+
+    /// Finds position of last element in `self` satisfying `pred`. If no such
+    /// element exists, returns `self.end()`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// let i = arr.last_position_where(|x| x % 2 == 1);
+    /// assert_eq!(i, Some(2));
+    /// ```
+    fn last_position_where<Pred>(
+        &self,
+        mut pred: Pred,
+    ) -> Option<Self::Position>
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut rest = self.full();
+        let mut res = None;
+        let mut p = self.start();
+        while let Some(e) = rest.pop_first() {
+            if pred(&e) {
+                res = Some(p);
+            }
+            p = rest.start();
+        }
+        res
+    }
+
+    /// Finds position of `last` element equals `e`. If no such element exist,
+    /// return `self.end()`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 3];
+    /// let i = arr.last_position_of(&3);
+    /// assert_eq!(i, Some(2));
+    /// ```
+    fn last_position_of(&self, e: &Self::Element) -> Option<Self::Position>
+    where
+        Self::Element: Eq,
+    {
+        self.last_position_of_by(e, |x, y| x == y)
+    }
+
+    /// Finds position of last element in `self` equivalent to `e` by
+    /// `are_equivalent`. If no such element exists, returns `None`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 13, 3, 5];
+    /// let i = arr.last_position_of_by(&23, |x, y| x % 10 == y % 10);
+    /// assert_eq!(i, Some(2));
+    /// ```
+    fn last_position_of_by<F>(
+        &self,
+        e: &Self::Element,
+        mut are_equivalent: F,
+    ) -> Option<Self::Position>
+    where
+        F: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        self.last_position_where(|x| are_equivalent(x, e))
+    }
+
+    /// Returns first element in `self` satisfying `pred`, or `None` if no
+    /// such element exists.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// assert_eq!(arr.first_where(|x| x % 2 == 0), Some(&2));
+    /// assert_eq!(arr.first_where(|x| *x > 4), None);
+    /// ```
+    fn first_where<Pred>(&self, pred: Pred) -> Option<Self::ElementRef<'_>>
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        self.first_position_where(pred).map(|p| self.at(&p))
+    }
+
+    /// Returns last element in `self` satisfying `pred`, or `None` if no such
+    /// element exists.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// assert_eq!(arr.last_where(|x| x % 2 == 1), Some(&3));
+    /// assert_eq!(arr.last_where(|x| *x > 4), None);
+    /// ```
+    fn last_where<Pred>(&self, pred: Pred) -> Option<Self::ElementRef<'_>>
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        self.last_position_where(pred).map(|p| self.at(&p))
+    }
+
+    /*-----------------Predicate Test Algorithms-----------------*/
+
+    /// Returns true if all element in `self` satisfies `pred`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 5];
+    /// assert!(arr.all_satisfy(|x| x % 2 == 1));
+    /// ```
+    fn all_satisfy<Pred>(&self, mut pred: Pred) -> bool
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut cur = self.full();
+        while let Some(e) = cur.pop_first() {
+            if !pred(&e) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns true if atleast one element in `self` satisfies `pred`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 5];
+    /// assert!(arr.any_satisfy(|x| x % 2 == 1));
+    /// ```
+    fn any_satisfy<Pred>(&self, mut pred: Pred) -> bool
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut cur = self.full();
+        while let Some(e) = cur.pop_first() {
+            if pred(&e) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns true if none of elements in `self` satisfy `pred`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [2, 4, 6];
+    /// assert!(arr.none_satisfy(|x| x % 2 == 1));
+    /// ```
+    fn none_satisfy<Pred>(&self, mut pred: Pred) -> bool
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut cur = self.full();
+        while let Some(e) = cur.pop_first() {
+            if pred(&e) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /*-----------------Count Algorithms-----------------*/
+
+    /// Returns number of elements in `self` satisfying `pred`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// let n = arr.count_where(|x| x % 2 == 1);
+    /// assert_eq!(n, 2);
+    /// ```
+    fn count_where<Pred>(&self, mut pred: Pred) -> usize
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut cur = self.full();
+        let mut count = 0;
+        while let Some(e) = cur.pop_first() {
+            if pred(&e) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Returns number of elements in `self` equals `e`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [3, 0, 3];
+    /// let n = arr.count_of(&3);
+    /// assert_eq!(n, 2);
+    /// ```
+    fn count_of(&self, e: &Self::Element) -> usize
+    where
+        Self::Element: Eq,
+    {
+        self.count_of_by(e, |x, y| x == y)
+    }
+
+    /// Returns number of elements in `self` equivalent to `e` by
+    /// `are_equivalent`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, -1, 2, 1];
+    /// let n = arr.count_of_by(&1, |x: &i32, y: &i32| x.abs() == y.abs());
+    /// assert_eq!(n, 3);
+    /// ```
+    fn count_of_by<F>(&self, e: &Self::Element, mut are_equivalent: F) -> usize
+    where
+        F: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        self.count_where(|x| are_equivalent(x, e))
+    }
+
+    /*-----------------Partition Algorithms-----------------*/
+
+    /// Returns position of first element of collection for which predicate returns false.
+    ///
+    /// # Precondition
+    ///   - The collection should be already partitioned wrt predicate i.e,
+    ///     there exist a position `i` such that predicate is true for every
+    ///     element of `self.prefix_upto(i)` and predicate is false for every
+    ///     element of `self.suffix_from(i)`.
+    ///
+    /// # Complexity
+    ///   - O(log n) for RandomAccessCollection, O(n) otherwise; where `n == self.count()`.
+    ///   - O(log n) application of `belongs_in_second_half`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 5, 2, 4];
+    /// let i = arr.partition_point(|x| x % 2 == 0);
+    /// assert_eq!(i, 3);
+    /// ```
+    fn partition_point<F>(
+        &self,
+        mut belongs_in_second_half: F,
+    ) -> Self::Position
+    where
+        F: FnMut(&Self::Element) -> bool,
+    {
+        let mut f = self.start();
+        let mut n = self.count();
+        while n > 0 {
+            let half = n / 2;
+            let m = self.next_n(f.clone(), half);
+            if belongs_in_second_half(&self.at(&m)) {
+                n = half;
+            } else {
+                f = self.next(m);
+                n -= half + 1;
+            }
+        }
+        f
+    }
+
+    /// Returns position of first element of collection for which predicate
+    /// returns true, searching by galloping from `hint`.
+    ///
+    /// Probes positions at distance 1, 2, 4, ... after `hint` till predicate
+    /// holds, and then binary searches the last step. This is much faster than
+    /// `partition_point` when result is known to be near `hint`, e.g., when
+    /// repeatedly searching for clustered keys while merging sorted
+    /// collections.
+    ///
+    /// # Precondition
+    ///   - The collection should be already partitioned wrt predicate, as for
+    ///     `partition_point`.
+    ///   - `hint` is a valid position in collection.
+    ///
+    /// # Complexity
+    ///   - If result is at distance `d` after `hint`: O(log d) applications of
+    ///     `belongs_in_second_half`, and O(log d) for RandomAccessCollection,
+    ///     O(d) otherwise.
+    ///   - If result is at or before `hint`: same as `partition_point` over
+    ///     `self.prefix_upto(hint)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// assert_eq!(arr.partition_point_hinted(2, |x| *x > 4), 4);
+    /// assert_eq!(arr.partition_point_hinted(6, |x| *x > 4), 4);
+    /// assert_eq!(arr.partition_point_hinted(9, |x| *x > 9), 9);
+    /// ```
+    fn partition_point_hinted<F>(
+        &self,
+        hint: Self::Position,
+        mut belongs_in_second_half: F,
+    ) -> Self::Position
+    where
+        F: FnMut(&Self::Element) -> bool,
+    {
+        let end = self.end();
+        if hint == end || belongs_in_second_half(&self.at(&hint)) {
+            return self
+                .slice(self.start(), hint)
+                .partition_point(belongs_in_second_half);
+        }
+
+        // Every element before `lo` doesn't belong in second half.
+        let mut lo = self.next(hint);
+        let mut step = 1;
+        loop {
+            match self.next_n_limited_by(lo.clone(), step - 1, end.clone()) {
+                Some(p) if p != end => {
+                    if belongs_in_second_half(&self.at(&p)) {
+                        return self
+                            .slice(lo, p)
+                            .partition_point(belongs_in_second_half);
+                    }
+                    lo = self.next(p);
+                    step *= 2;
+                }
+                _ => {
+                    return self
+                        .slice(lo, end)
+                        .partition_point(belongs_in_second_half);
+                }
+            }
+        }
+    }
+
+    /// Returns true if collection is partitioned wrt predicate, i.e., no
+    /// element satisfying predicate is followed by an element not satisfying
+    /// predicate.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 5, 2, 4];
+    /// assert!(arr.is_partitioned(|x| x % 2 == 0));
+    /// assert!(!arr.is_partitioned(|x| x % 2 == 1));
+    /// ```
+    fn is_partitioned<Pred>(&self, mut pred: Pred) -> bool
+    where
+        Pred: FnMut(&Self::Element) -> bool,
+    {
+        let mut rest = self.full();
+        while let Some(e) = rest.pop_first() {
+            if pred(&e) {
+                return rest.all_satisfy(|e| pred(e));
+            }
+        }
+        true
+    }
+
+    /// Returns two Vec containing the elements of the collection that
+    /// don’t and do satisfy the given predicate, respectively.
+    ///
+    /// # Postcondition
+    ///   - Returns `(falseVec, trueVec)` where `falseVec` contains all elements
+    ///     that don't satisfy predicate and `trueVec` contains all elements
+    ///     that do satisfy predicate.
+    ///   - Relative ordering of elements is preserved in both Vec.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let (evens, odds) = arr.partitioned(|x| x % 2 == 1);
+    /// assert_eq!(evens, [2, 4]);
+    /// assert_eq!(odds, [1, 3, 5]);
+    /// ```
+    fn partitioned<F>(
+        &self,
+        mut belongs_in_second_half: F,
+    ) -> (Vec<Self::Element>, Vec<Self::Element>)
+    where
+        Self::Element: Clone,
+        F: FnMut(&Self::Element) -> bool,
+    {
+        let mut buffer = TempBuffer::with_capacity(self.count());
+        let mut rest = self.full();
+        while let Some(e) = rest.pop_first() {
+            if belongs_in_second_half(&e) {
+                buffer.push_second((*e).clone());
+            } else {
+                buffer.push((*e).clone());
+            }
+        }
+        buffer.into_parts()
+    }
+
+    /// Returns two Vec containing positions of the elements of the collection
+    /// that don’t and do satisfy the given predicate, respectively.
+    ///
+    /// Elements are not cloned, so this is useful for collections of non-Clone
+    /// or heavy elements.
+    ///
+    /// # Postcondition
+    ///   - Returns `(falseVec, trueVec)` where `falseVec` contains positions of
+    ///     all elements that don't satisfy predicate and `trueVec` contains
+    ///     positions of all elements that do satisfy predicate.
+    ///   - Positions are in increasing order in both Vec.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [String::from("a"), String::from("bb"), String::from("c")];
+    /// let (short, long) = arr.partition_positions(|s| s.len() > 1);
+    /// assert_eq!(short, [0, 2]);
+    /// assert_eq!(long, [1]);
+    /// ```
+    fn partition_positions<F>(
+        &self,
+        mut belongs_in_second_half: F,
+    ) -> (Vec<Self::Position>, Vec<Self::Position>)
+    where
+        F: FnMut(&Self::Element) -> bool,
+    {
+        let mut left = vec![];
+        let mut right = vec![];
+        let mut rest = self.full();
+        let mut p = rest.start();
+        while let Some(e) = rest.pop_first() {
+            if belongs_in_second_half(&e) {
+                right.push(p);
+            } else {
+                left.push(p);
+            }
+            p = rest.start();
+        }
+        (left, right)
+    }
+
+    /*-----------------Binary Search Algorithms-----------------*/
+
+    /// Searches sorted collection for an element equivalent to `element`, using
+    /// the given predicate as comparision between elements.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///   - The collection should be sorted wrt `are_in_increasing_order`.
+    ///
+    /// # Postcondition
+    ///   - Returns `Ok(p)` if an equivalent element exists, where `p` is
+    ///     position of first such element.
+    ///   - Otherwise returns `Err(p)` where `p` is position at which `element`
+    ///     could be inserted keeping collection sorted.
+    ///
+    /// # Complexity
+    ///   - O(log n) for RandomAccessCollection, O(n) otherwise; where `n == self.count()`.
+    ///   - O(log n) applications of `are_in_increasing_order`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [(1, 'a'), (3, 'b'), (3, 'c'), (5, 'd')];
+    /// let cmp = |x: &(i32, char), y: &(i32, char)| x.0 < y.0;
+    /// assert_eq!(arr.binary_search_position_by(&(3, 'z'), cmp), Ok(1));
+    /// assert_eq!(arr.binary_search_position_by(&(4, 'z'), cmp), Err(3));
+    /// ```
+    fn binary_search_position_by<Compare>(
+        &self,
+        element: &Self::Element,
+        mut are_in_increasing_order: Compare,
+    ) -> Result<Self::Position, Self::Position>
+    where
+        Compare: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        let p = self.partition_point(|e| !are_in_increasing_order(e, element));
+        if p != self.end() && !are_in_increasing_order(element, &self.at(&p)) {
+            Ok(p)
+        } else {
+            Err(p)
+        }
+    }
+
+    /// Searches sorted collection for an element equal to `element`.
+    ///
+    /// # Precondition
+    ///   - The collection should be sorted.
+    ///
+    /// # Postcondition
+    ///   - Returns `Ok(p)` if an equal element exists, where `p` is position
+    ///     of first such element.
+    ///   - Otherwise returns `Err(p)` where `p` is position at which `element`
+    ///     could be inserted keeping collection sorted.
+    ///
+    /// # Complexity
+    ///   - O(log n) for RandomAccessCollection, O(n) otherwise; where `n == self.count()`.
+    ///   - O(log n) comparisions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 3, 5];
+    /// assert_eq!(arr.binary_search_position(&3), Ok(1));
+    /// assert_eq!(arr.binary_search_position(&4), Err(3));
+    /// assert_eq!(arr.binary_search_position(&6), Err(4));
+    /// ```
+    fn binary_search_position(
+        &self,
+        element: &Self::Element,
+    ) -> Result<Self::Position, Self::Position>
+    where
+        Self::Element: Ord,
+    {
+        self.binary_search_position_by(element, |x, y| x < y)
+    }
+
+    /*-----------------Numeric Algorithms-----------------*/
+
+    /// Returns the result of combining elements of given collection using given
+    /// accumulation operation from left to right.
+    ///
+    /// # Postcondition
+    ///   - Result is `(((init + e1) + e2) + ... + en)`.
+    ///     where e1, e2, ..., en are the references to collection elements,
+    ///     where (a + b) represents op(a, b).
+    ///
+    /// # Complexity:
+    ///   - O(`count`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// assert_eq!(arr.fold_left(0, |x, y| x + y), 6);
+    /// ```
+    fn fold_left<R, F>(&self, init: R, mut op: F) -> R
+    where
+        F: FnMut(R, &Self::Element) -> R,
+    {
+        let mut res = init;
+        let mut rest = self.full();
+        while let Some(e) = rest.pop_first() {
+            res = op(res, &e)
+        }
+        res
+    }
+
+    /// Returns the result of combining elements of given collection using given
+    /// fallible accumulation operation from left to right, stopping at first
+    /// residual returned by `op`, e.g., `Err`.
+    ///
+    /// # Postcondition
+    ///   - Returns first residual returned by `op` if any, otherwise the
+    ///     successful result of `(((init + e1) + e2) + ... + en)`, where
+    ///     (a + b) represents op(a, b).
+    ///
+    /// # Complexity:
+    ///   - O(`count`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [100u8, 100, 100];
+    /// assert_eq!(arr.try_fold_left(0u8, |x, y| x.checked_add(*y)), None);
+    /// let sum = arr.try_fold_left(0u32, |x, y| x.checked_add(*y as u32));
+    /// assert_eq!(sum, Some(300));
+    ///
+    /// let words = ["1", "2", "x", "4"];
+    /// let sum = words.try_fold_left(0, |x, w| w.parse::<i32>().map(|y| x + y));
+    /// assert!(sum.is_err());
+    /// ```
+    fn try_fold_left<R, F, Out>(&self, init: R, mut op: F) -> Out
+    where
+        F: FnMut(R, &Self::Element) -> Out,
+        Out: Fallible<Output = R>,
+    {
+        let mut res = init;
+        let mut rest = self.full();
+        while let Some(e) = rest.pop_first() {
+            match op(res, &e).branch() {
+                ControlFlow::Continue(x) => res = x,
+                ControlFlow::Break(r) => return Out::from_residual(r),
+            }
+        }
+        Out::from_output(res)
+    }
+
+    /// Returns result of folding each window of `window` consecutive elements
+    /// of `self` from left to right with `op` starting from `init`.
+    ///
+    /// # Precondition
+    ///   - `window > 0`.
+    ///
+    /// # Postcondition
+    ///   - Returns `n - window + 1` results in order of windows, or no result
+    ///     if `n < window`, where `n == self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(n * window) where `n == self.count()`. Use
+    ///     `windowed_fold_with_inverse` for O(n) if `op` is invertible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 2, 5, 4];
+    /// let maxes = arr.windowed_fold(3, i32::MIN, |x, y| x.max(*y));
+    /// assert_eq!(maxes, vec![3, 5, 5]);
+    /// ```
+    fn windowed_fold<R, F>(&self, window: usize, init: R, mut op: F) -> Vec<R>
+    where
+        R: Clone,
+        F: FnMut(R, &Self::Element) -> R,
+    {
+        assert!(window > 0, "window should be non-empty");
+        let mut res = vec![];
+        let mut view = self.sliding_view();
+        while view.advance_back() {
+            if view.len() == window {
+                res.push(view.window().fold_left(init.clone(), &mut op));
+                view.advance_front();
+            }
+        }
+        res
+    }
+
+    /// Returns result of folding each window of `window` consecutive elements
+    /// of `self` from left to right with `op` starting from `init`, updating
+    /// result of previous window in O(1) by removing its first element with
+    /// `inverse_op`.
+    ///
+    /// # Precondition
+    ///   - `window > 0`.
+    ///   - `inverse_op(op(r, e), e)` is equivalent to `r`, and `op` is
+    ///     associative and commutative, e.g., addition and subtraction.
+    ///
+    /// # Postcondition
+    ///   - Returns `n - window + 1` results in order of windows, or no result
+    ///     if `n < window`, where `n == self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let prices = [10.0, 12.0, 11.0, 15.0];
+    /// let averages: Vec<f64> = prices
+    ///     .windowed_fold_with_inverse(2, 0.0, |x, y| x + y, |x, y| x - y)
+    ///     .into_iter()
+    ///     .map(|sum| sum / 2.0)
+    ///     .collect();
+    /// assert_eq!(averages, vec![11.0, 11.5, 13.0]);
+    /// ```
+    fn windowed_fold_with_inverse<R, F, InverseF>(
+        &self,
+        window: usize,
+        init: R,
+        mut op: F,
+        mut inverse_op: InverseF,
+    ) -> Vec<R>
+    where
+        R: Clone,
+        F: FnMut(R, &Self::Element) -> R,
+        InverseF: FnMut(R, &Self::Element) -> R,
+    {
+        assert!(window > 0, "window should be non-empty");
+        let mut res = vec![];
+        let mut acc = init;
+        let mut view = self.sliding_view();
+        while view.advance_back() {
+            acc = op(acc, &view.back().unwrap());
+            if view.len() == window {
+                res.push(acc.clone());
+                acc = inverse_op(acc, &view.front().unwrap());
+                view.advance_front();
+            }
+        }
+        res
+    }
+
+    /// Returns sum of all elements of `self`, or zero if `self` is empty.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// assert_eq!(arr.sum(), 10);
+    /// ```
+    fn sum(&self) -> Self::Element
+    where
+        Self::Element: Sum + Clone,
+    {
+        self.iter().map(|e| e.clone()).sum()
+    }
+
+    /// Returns product of all elements of `self`, or one if `self` is empty.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// assert_eq!(arr.product(), 24);
+    /// ```
+    fn product(&self) -> Self::Element
+    where
+        Self::Element: Product + Clone,
+    {
+        self.iter().map(|e| e.clone()).product()
+    }
+
+    /// Writes running accumulation of elements of `self` by `op` to the start
+    /// of `dest`, and returns the position in `dest` just after the last
+    /// written element.
+    ///
+    /// # Precondition
+    ///   - `dest.count() >= self.count()`.
+    ///
+    /// # Postcondition
+    ///   - i-th written element is `(((e1 + e2) + e3) + ... + ei)`, where
+    ///     e1, e2, ..., en are elements of `self` and (a + b) represents
+    ///     op(a, b).
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// let mut dest = [0; 4];
+    /// arr.inclusive_scan_into(&mut dest, |x, y| x + y);
+    /// assert_eq!(dest, [1, 3, 6, 10]);
+    /// ```
+    fn inclusive_scan_into<D, F>(&self, dest: &mut D, mut op: F) -> D::Position
+    where
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+        F: FnMut(&Self::Element, &Self::Element) -> Self::Element,
+    {
+        let mut out = dest.start();
+        let mut rest = self.full();
+        let mut acc = match rest.pop_first() {
+            Some(e) => e.clone(),
+            None => return out,
+        };
+        loop {
+            *dest.at_mut(&out) = acc.clone();
+            dest.form_next(&mut out);
+            match rest.pop_first() {
+                Some(e) => acc = op(&acc, &e),
+                None => return out,
+            }
+        }
+    }
+
+    /// Writes running accumulation of elements of `self` by `op` starting
+    /// from `init` to the start of `dest`, excluding the element at same
+    /// offset, and returns the position in `dest` just after the last written
+    /// element.
+    ///
+    /// # Precondition
+    ///   - `dest.count() >= self.count()`.
+    ///
+    /// # Postcondition
+    ///   - i-th written element is `((init + e1) + ... + e(i-1))`, where
+    ///     e1, e2, ..., en are elements of `self` and (a + b) represents
+    ///     op(a, b).
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// let mut dest = [0; 4];
+    /// arr.exclusive_scan_into(0, &mut dest, |x, y| x + y);
+    /// assert_eq!(dest, [0, 1, 3, 6]);
+    /// ```
+    fn exclusive_scan_into<D, F>(
+        &self,
+        init: Self::Element,
+        dest: &mut D,
+        mut op: F,
+    ) -> D::Position
+    where
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+        F: FnMut(&Self::Element, &Self::Element) -> Self::Element,
+    {
+        let mut out = dest.start();
+        let mut acc = init;
+        let mut rest = self.full();
+        while let Some(e) = rest.pop_first() {
+            let next = op(&acc, &e);
+            *dest.at_mut(&out) = std::mem::replace(&mut acc, next);
+            dest.form_next(&mut out);
+        }
+        out
+    }
+
+    /// Writes first element of `self` followed by result of applying `op` on
+    /// every element of `self` and the element before it, to the start of
+    /// `dest`, and returns the position in `dest` just after the last written
+    /// element.
+    ///
+    /// # Precondition
+    ///   - `dest.count() >= self.count()`.
+    ///
+    /// # Postcondition
+    ///   - Written elements are `e1, op(e2, e1), ..., op(en, e(n-1))`, where
+    ///     e1, e2, ..., en are elements of `self`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 4, 9, 16];
+    /// let mut dest = [0; 4];
+    /// arr.adjacent_difference_into(&mut dest, |x, y| x - y);
+    /// assert_eq!(dest, [1, 3, 5, 7]);
+    /// ```
+    fn adjacent_difference_into<D, F>(
+        &self,
+        dest: &mut D,
+        mut op: F,
+    ) -> D::Position
+    where
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+        F: FnMut(&Self::Element, &Self::Element) -> Self::Element,
+    {
+        let mut out = dest.start();
+        let mut rest = self.full();
+        let mut prev = match rest.pop_first() {
+            Some(e) => e.clone(),
+            None => return out,
+        };
+        *dest.at_mut(&out) = prev.clone();
+        dest.form_next(&mut out);
+        while let Some(e) = rest.pop_first() {
+            *dest.at_mut(&out) = op(&e, &prev);
+            dest.form_next(&mut out);
+            prev = e.clone();
+        }
+        out
+    }
+
+    /*-----------------Copying Algorithms-----------------*/
+
+    /// Copies and returns all elements of `self` into `Vec<Self::Element>`.
+    ///
+    /// # Complexity
+    ///   - O(`self.count()`)
+    fn to_vec(&self) -> Vec<Self::Element>
+    where
+        Self::Element: Clone,
+    {
+        let mut r = Vec::with_capacity(self.underestimated_count());
+        for e in self.iter() {
+            r.push(e.clone())
+        }
+        r
+    }
+
+    /// Returns clones of elements of `self` in order, keeping only the first
+    /// occurrence of every element.
+    ///
+    /// Unlike removing consecutive duplicates, duplicates need not be
+    /// adjacent.
+    ///
+    /// # Complexity
+    ///   - O(n) expected where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [3, 1, 3, 2, 1, 4];
+    /// assert_eq!(arr.distinct_preserving_order(), vec![3, 1, 2, 4]);
+    /// ```
+    fn distinct_preserving_order(&self) -> Vec<Self::Element>
+    where
+        Self::Element: Hash + Eq + Clone,
+    {
+        self.distinct_preserving_order_by_key(|e| e.clone())
+    }
+
+    /// Returns clones of elements of `self` in order, keeping only the first
+    /// element with every key computed by `key_fn`.
+    ///
+    /// # Complexity
+    ///   - O(n) expected where `n == self.count()`, calling `key_fn` once per
+    ///     element.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    /// let firsts = arr.distinct_preserving_order_by_key(|s| s.as_bytes()[0]);
+    /// assert_eq!(firsts, vec!["apple", "banana", "cherry"]);
+    /// ```
+    fn distinct_preserving_order_by_key<Key, KeyFn>(
+        &self,
+        mut key_fn: KeyFn,
+    ) -> Vec<Self::Element>
+    where
+        Self::Element: Clone,
+        Key: Hash + Eq,
+        KeyFn: FnMut(&Self::Element) -> Key,
+    {
+        let mut seen = HashSet::new();
+        let mut res = vec![];
+        self.for_each(|e| {
+            if seen.insert(key_fn(e)) {
+                res.push(e.clone());
+            }
+        });
+        res
+    }
+
+    /// Returns an accumulator retaining clones of the `k` greatest elements
+    /// of `self`, to which more elements can be inserted later.
+    ///
+    /// # Complexity
+    ///   - O(n * log k) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut top = [5, 1, 4].top_k_accumulate(2);
+    /// top.extend([2, 6, 3].iter().copied());
+    /// assert_eq!(top.into_sorted_vec(), vec![6, 5]);
+    /// ```
+    fn top_k_accumulate(&self, k: usize) -> BoundedTopK<Self::Element>
+    where
+        Self::Element: Ord + Clone,
+    {
+        let mut res = BoundedTopK::new(k);
+        self.for_each(|e| res.insert(e.clone()));
+        res
+    }
+
+    /// Returns clones of the `k` least elements of `self` according to
+    /// `are_in_increasing_order`, in increasing order.
+    ///
+    /// Elements are retained in a bounded heap of `k` elements while
+    /// traversing `self`, so memory used is independent of `self.count()`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - If `self.count() < k`, all elements are returned.
+    ///
+    /// # Complexity
+    ///   - O(n * log k) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = ["ccc", "a", "dddd", "bb"];
+    /// let shortest = arr.smallest_k_by(2, |x, y| x.len() < y.len());
+    /// assert_eq!(shortest, ["a", "bb"]);
+    /// ```
+    fn smallest_k_by<Compare>(
+        &self,
+        k: usize,
+        are_in_increasing_order: Compare,
+    ) -> Vec<Self::Element>
+    where
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        let mut res =
+            BoundedTopK::new_by(k, |x, y| are_in_increasing_order(y, x));
+        self.for_each(|e| res.insert(e.clone()));
+        res.into_sorted_vec()
+    }
+
+    /// Returns clones of the `k` least elements of `self` in increasing order.
+    ///
+    /// # Postcondition
+    ///   - If `self.count() < k`, all elements are returned.
+    ///
+    /// # Complexity
+    ///   - O(n * log k) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 1, 4, 2, 3];
+    /// assert_eq!(arr.smallest_k(3), [1, 2, 3]);
+    /// ```
+    fn smallest_k(&self, k: usize) -> Vec<Self::Element>
+    where
+        Self::Element: Ord + Clone,
+    {
+        self.smallest_k_by(k, |x, y| x < y)
+    }
+
+    /// Returns clones of the `k` greatest elements of `self` according to
+    /// `are_in_increasing_order`, in decreasing order.
+    ///
+    /// Elements are retained in a bounded heap of `k` elements while
+    /// traversing `self`, so memory used is independent of `self.count()`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - If `self.count() < k`, all elements are returned.
+    ///
+    /// # Complexity
+    ///   - O(n * log k) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [("b", 2), ("a", 9), ("c", 5)];
+    /// let top = arr.largest_k_by(2, |x, y| x.1 < y.1);
+    /// assert_eq!(top, [("a", 9), ("c", 5)]);
+    /// ```
+    fn largest_k_by<Compare>(
+        &self,
+        k: usize,
+        are_in_increasing_order: Compare,
+    ) -> Vec<Self::Element>
+    where
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        let mut res = BoundedTopK::new_by(k, are_in_increasing_order);
+        self.for_each(|e| res.insert(e.clone()));
+        res.into_sorted_vec()
+    }
+
+    /// Returns clones of the `k` greatest elements of `self` in decreasing
+    /// order.
+    ///
+    /// # Postcondition
+    ///   - If `self.count() < k`, all elements are returned.
+    ///
+    /// # Complexity
+    ///   - O(n * log k) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 1, 4, 2, 3];
+    /// assert_eq!(arr.largest_k(2), [5, 4]);
+    /// ```
+    fn largest_k(&self, k: usize) -> Vec<Self::Element>
+    where
+        Self::Element: Ord + Clone,
+    {
+        self.largest_k_by(k, |x, y| x < y)
+    }
+
+    /// Copies elements of `self` to the start of `dest`, and returns the
+    /// position in `dest` just after the last copied element.
+    ///
+    /// If both collections are contiguous (see `Collection::contiguous_slice`),
+    /// elements are copied as slices, e.g., with `memcpy` for `Copy` elements.
+    ///
+    /// # Precondition
+    ///   - `dest.count() >= self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(`self.count()`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// let mut dest = [0; 5];
+    /// let i = arr.copy_into(&mut dest);
+    /// assert_eq!(i, 3);
+    /// assert_eq!(dest, [1, 2, 3, 0, 0]);
+    /// ```
+    fn copy_into<D>(&self, dest: &mut D) -> D::Position
+    where
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+    {
+        if let Some(src) = self.contiguous_slice(self.start(), self.end()) {
+            let from = dest.start();
+            let to = dest.next_n(from.clone(), src.len());
+            if let Some(dst) = dest.contiguous_slice_mut(from, to.clone()) {
+                dst.clone_from_slice(src);
+                return to;
+            }
+        }
+        let mut out = dest.start();
+        for e in self.iter() {
+            *dest.at_mut(&out) = e.clone();
+            dest.form_next(&mut out);
+        }
+        out
+    }
+
+    /// Copies elements of `self.suffix_from(at)` followed by elements of
+    /// `self.prefix_upto(at)` to the start of `dest`, and returns the position
+    /// in `dest` just after the last copied element.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position in `self`.
+    ///   - `dest.count() >= self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(`self.count()`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let mut dest = [0; 6];
+    /// let i = arr.rotate_copy_into(2, &mut dest);
+    /// assert_eq!(i, 5);
+    /// assert_eq!(dest, [3, 4, 5, 1, 2, 0]);
+    /// ```
+    fn rotate_copy_into<D>(
+        &self,
+        at: Self::Position,
+        dest: &mut D,
+    ) -> D::Position
+    where
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+    {
+        let mut out = dest.start();
+        let mut rest = self.suffix_from(at.clone());
+        while let Some(e) = rest.pop_first() {
+            *dest.at_mut(&out) = e.clone();
+            dest.form_next(&mut out);
+        }
+        let mut rest = self.prefix_upto(at);
+        while let Some(e) = rest.pop_first() {
+            *dest.at_mut(&out) = e.clone();
+            dest.form_next(&mut out);
+        }
+        out
+    }
+
+    /// Writes result of applying `f` on corresponding elements of `self` and
+    /// `other` to the start of `dest`, until either of `self` or `other` is
+    /// exhausted, and returns the position in `dest` just after the last
+    /// written element.
+    ///
+    /// # Precondition
+    ///   - `dest.count() >= min(self.count(), other.count())`.
+    ///
+    /// # Complexity
+    ///   - O(min(`self.count()`, `other.count()`)).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = [10, 20, 30, 40];
+    /// let mut dest = [0; 4];
+    /// let i = a.zip_with_into(&b, &mut dest, |x, y| x + y);
+    /// assert_eq!(i, 3);
+    /// assert_eq!(dest, [11, 22, 33, 0]);
+    /// ```
+    fn zip_with_into<Other, D, F>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+        mut f: F,
+    ) -> D::Position
+    where
+        Other: Collection + ?Sized,
+        D: MutableCollection + ?Sized,
+        D::Whole: MutableCollection,
+        F: FnMut(&Self::Element, &Other::Element) -> D::Element,
+    {
+        let mut out = dest.start();
+        let mut rest1 = self.full();
+        let mut rest2 = other.full();
+        while let (Some(x), Some(y)) = (rest1.pop_first(), rest2.pop_first()) {
+            *dest.at_mut(&out) = f(&x, &y);
+            dest.form_next(&mut out);
+        }
+        out
+    }
+
+    /*-----------------Selection Algorithms-----------------*/
+
+    /// Returns clone of the element that would be at offset `n` if `self` were
+    /// sorted according to `are_in_increasing_order`, without modifying
+    /// `self`. If `n >= self.count()`, returns None.
+    ///
+    /// If `n` is small relative to `self.count()`, the `n + 1` least elements
+    /// are retained in a heap, otherwise elements are copied to a scratch
+    /// buffer to select from.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - If multiple elements are equivalent to the selected element, any
+    ///     one of them is returned.
+    ///
+    /// # Complexity
+    ///   - O(m * log(n)) if heap is used, otherwise O(m) on average, where
+    ///     `m == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 1, 4, 2, 3];
+    /// assert_eq!(arr.nth_smallest_by(1, |x, y| x > y), Some(4));
+    /// assert_eq!(arr.nth_smallest_by(5, |x, y| x > y), None);
+    /// assert_eq!(arr, [5, 1, 4, 2, 3]);
+    /// ```
+    fn nth_smallest_by<Compare>(
+        &self,
+        n: usize,
+        are_in_increasing_order: Compare,
+    ) -> Option<Self::Element>
+    where
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        let count = self.count();
+        if n >= count {
+            return None;
+        }
+
+        // Heap needs O(n) memory but O(m * log(n)) time, so is only
+        // preferred when `n` is much smaller than the collection.
+        if n < count / 16 {
+            let mut least = BoundedTopK::new_by(n + 1, |x, y| {
+                are_in_increasing_order(y, x)
+            });
+            self.for_each(|e| least.insert(e.clone()));
+            return least.peek().cloned();
+        }
+
+        let mut buffer: Vec<Self::Element> = Vec::with_capacity(count);
+        self.for_each(|e| buffer.push(e.clone()));
+        buffer.nth_element_by(n, are_in_increasing_order);
+        Some(buffer.swap_remove(n))
+    }
+
+    /// Returns clone of the element that would be at offset `n` if `self` were
+    /// sorted, without modifying `self`. If `n >= self.count()`, returns None.
+    ///
+    /// # Complexity
+    ///   - O(m * log(n)) if `n` is small relative to `m`, otherwise O(m) on
+    ///     average, where `m == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 1, 4, 2, 3];
+    /// assert_eq!(arr.nth_smallest(0), Some(1));
+    /// assert_eq!(arr.nth_smallest(3), Some(4));
+    /// assert_eq!(arr.reversed().nth_smallest(4), Some(5));
+    /// ```
+    fn nth_smallest(&self, n: usize) -> Option<Self::Element>
+    where
+        Self::Element: Ord + Clone,
+    {
+        self.nth_smallest_by(n, |x, y| x < y)
+    }
+
+    /*-----------------Result Algorithms-----------------*/
+
+    /// Finds position of first element in `self` that is an error. If no such
+    /// element exists, returns None.
     ///
     /// # Complexity
     ///   - O(n) where `n == self.count()`.
     ///
-    /// # Examples
+    /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 2, 3, 4, 5];
-    /// let (evens, odds) = arr.partitioned(|x| x % 2 == 1);
-    /// assert_eq!(evens, [2, 4]);
-    /// assert_eq!(odds, [1, 3, 5]);
+    /// let arr: [Result<i32, &str>; 3] = [Ok(1), Err("bad"), Err("worse")];
+    /// assert_eq!(arr.first_error_position(), Some(1));
     /// ```
-    fn partitioned<F>(
-        &self,
-        mut belongs_in_second_half: F,
-    ) -> (Vec<Self::Element>, Vec<Self::Element>)
+    fn first_error_position<T, E>(&self) -> Option<Self::Position>
     where
-        Self::Element: Clone,
-        F: FnMut(&Self::Element) -> bool,
+        Self: Collection<Element = Result<T, E>>,
     {
-        let n = self.count();
-        let mut left = Vec::with_capacity(n);
-        let arr = left.spare_capacity_mut();
-        let mut left_idx = 0;
-        let mut right_idx = n;
+        self.first_position_where(|e| e.is_err())
+    }
 
+    /// Returns true iff no element in `self` is an error.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr: [Result<i32, &str>; 2] = [Ok(1), Ok(2)];
+    /// assert!(arr.all_ok());
+    /// ```
+    fn all_ok<T, E>(&self) -> bool
+    where
+        Self: Collection<Element = Result<T, E>>,
+    {
+        self.all_satisfy(|e| e.is_ok())
+    }
+
+    /// Returns clones of values of all elements in `self` in order, or clone of
+    /// first error if any element is an error.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr: [Result<i32, &str>; 2] = [Ok(1), Ok(2)];
+    /// assert_eq!(arr.collect_ok(), Ok(vec![1, 2]));
+    ///
+    /// let arr: [Result<i32, &str>; 3] = [Ok(1), Err("bad"), Err("worse")];
+    /// assert_eq!(arr.collect_ok(), Err("bad"));
+    /// ```
+    fn collect_ok<T, E>(&self) -> Result<Vec<T>, E>
+    where
+        Self: Collection<Element = Result<T, E>>,
+        T: Clone,
+        E: Clone,
+    {
+        let mut res = Vec::with_capacity(self.count());
         let mut rest = self.full();
         while let Some(e) = rest.pop_first() {
-            if belongs_in_second_half(&e) {
-                right_idx -= 1;
-                arr[right_idx].write((*e).clone());
-            } else {
-                arr[left_idx].write((*e).clone());
-                left_idx += 1;
-            }
+            res.push((*e).clone()?);
         }
+        Ok(res)
+    }
 
-        unsafe {
-            left.set_len(n);
-        }
+    /// Returns two Vec containing clones of values and errors of elements in
+    /// `self` respectively.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is preserved in both Vec.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = ["1", "x", "3", "y"].map(|s| s.parse::<i32>().map_err(|_| s));
+    /// let (values, errors) = arr.partitioned_results();
+    /// assert_eq!(values, [1, 3]);
+    /// assert_eq!(errors, ["x", "y"]);
+    /// ```
+    fn partitioned_results<T, E>(&self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Collection<Element = Result<T, E>>,
+        T: Clone,
+        E: Clone,
+    {
+        let mut values = vec![];
+        let mut errors = vec![];
+        self.for_each(|e| match e {
+            Ok(value) => values.push(value.clone()),
+            Err(error) => errors.push(error.clone()),
+        });
+        (values, errors)
+    }
 
-        let mut right = left.split_off(right_idx);
-        right.reverse();
+    /*-----------------Option Algorithms-----------------*/
 
-        (left, right)
+    /// Finds position of first element in `self` that is `None`. If no such
+    /// element exists, returns None.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [Some(1), Some(2), None, Some(4)];
+    /// assert_eq!(arr.first_none_position(), Some(2));
+    /// ```
+    fn first_none_position<T>(&self) -> Option<Self::Position>
+    where
+        Self: Collection<Element = Option<T>>,
+    {
+        self.first_position_where(|e| e.is_none())
     }
 
-    /*-----------------Numeric Algorithms-----------------*/
+    /// Returns a lazy collection presenting values of elements of `self` that
+    /// are `Some`, skipping elements that are `None`.
+    ///
+    /// # Postcondition
+    ///   - No allocations are done for forming collection.
+    ///   - Positions of returned collection are positions of `Some` elements
+    ///     in `self`.
+    ///
+    /// # Complexity
+    ///   - O(k) where `k` is number of leading `None` elements in `self`, for
+    ///     finding start position.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [None, Some(1), None, None, Some(4)];
+    /// let values = arr.compacted();
+    /// assert!(values.equals(&[1, 4]));
+    /// assert_eq!(values.start(), 1);
+    /// ```
+    fn compacted<T>(self) -> CompactedCollection<Self, T>
+    where
+        Self: Sized + Collection<Element = Option<T>>,
+        T: Clone,
+    {
+        CompactedCollection::new(self)
+    }
 
-    /// Returns the result of combining elements of given collection using given
-    /// accumulation operation from left to right.
+    /*-----------------Set Algorithms-----------------*/
+
+    /// Computes union of sorted `self` and sorted `other`.
+    /// Writes elements to the start of `dest` according to
+    /// `are_in_increasing_order`, and returns the position in `dest` just after
+    /// the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `max(m, n)` times. Equivalent elements are taken from `self`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///   - `dest` has enough space for written elements.
     ///
     /// # Postcondition
-    ///   - Result is `(((init + e1) + e2) + ... + en)`.
-    ///     where e1, e2, ..., en are the references to collection elements,
-    ///     where (a + b) represents op(a, b).
+    ///   - Written elements are sorted wrt `are_in_increasing_order`.
     ///
-    /// # Complexity:
-    ///   - O(`count`)
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    fn set_union_into_by<Other, D, Compare>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+        are_in_increasing_order: Compare,
+    ) -> D::Position
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        set_operation_into(
+            self,
+            other,
+            dest,
+            SetSelection {
+                only_in_first: true,
+                only_in_second: true,
+                in_both: true,
+            },
+            are_in_increasing_order,
+        )
+    }
+
+    /// Computes union of sorted `self` and sorted `other`.
+    /// Writes elements to the start of `dest`, and returns the position in
+    /// `dest` just after the last written element.
     ///
-    /// # Examples
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `max(m, n)` times. Equivalent elements are taken from `self`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    ///
+    /// # Example
     /// ```rust
     /// use stl::*;
     ///
-    /// let arr = [1, 2, 3];
-    /// assert_eq!(arr.fold_left(0, |x, y| x + y), 6);
+    /// let a = [1, 2, 2, 4, 6];
+    /// let b = [2, 3, 4, 4];
+    /// let mut dest = [0; 9];
+    /// let i = a.set_union_into(&b, &mut dest);
+    /// assert_eq!(i, 7);
+    /// assert_eq!(dest, [1, 2, 2, 3, 4, 4, 6, 0, 0]);
     /// ```
-    fn fold_left<R, F>(&self, init: R, mut op: F) -> R
+    fn set_union_into<Other, D>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+    ) -> D::Position
     where
-        F: FnMut(R, &Self::Element) -> R,
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Ord + Clone,
     {
-        let mut res = init;
-        let mut rest = self.full();
-        while let Some(e) = rest.pop_first() {
-            res = op(res, &e)
-        }
-        res
+        self.set_union_into_by(other, dest, |x, y| x < y)
     }
 
-    /*-----------------Copying Algorithms-----------------*/
+    /// Computes intersection of sorted `self` and sorted `other`.
+    /// Writes elements to the start of `dest` according to
+    /// `are_in_increasing_order`, and returns the position in `dest` just after
+    /// the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `min(m, n)` times. Elements are taken from `self`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted wrt `are_in_increasing_order`.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    fn set_intersection_into_by<Other, D, Compare>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+        are_in_increasing_order: Compare,
+    ) -> D::Position
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        set_operation_into(
+            self,
+            other,
+            dest,
+            SetSelection {
+                only_in_first: false,
+                only_in_second: false,
+                in_both: true,
+            },
+            are_in_increasing_order,
+        )
+    }
 
-    /// Copies and returns all elements of `self` into `Vec<Self::Element>`.
+    /// Computes intersection of sorted `self` and sorted `other`.
+    /// Writes elements to the start of `dest`, and returns the position in
+    /// `dest` just after the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `min(m, n)` times. Elements are taken from `self`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted.
     ///
     /// # Complexity
-    ///   - O(`self.count()`)
-    fn to_vec(&self) -> Vec<Self::Element>
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let a = [1, 2, 2, 4, 6];
+    /// let b = [2, 3, 4, 4];
+    /// let mut dest = [0; 9];
+    /// let i = a.set_intersection_into(&b, &mut dest);
+    /// assert_eq!(i, 2);
+    /// assert_eq!(dest, [2, 4, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    fn set_intersection_into<Other, D>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+    ) -> D::Position
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Ord + Clone,
+    {
+        self.set_intersection_into_by(other, dest, |x, y| x < y)
+    }
+
+    /// Computes elements of sorted `self` not present in sorted `other`.
+    /// Writes elements to the start of `dest` according to
+    /// `are_in_increasing_order`, and returns the position in `dest` just after
+    /// the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `max(m - n, 0)` times.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted wrt `are_in_increasing_order`.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    fn set_difference_into_by<Other, D, Compare>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+        are_in_increasing_order: Compare,
+    ) -> D::Position
     where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
         Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
     {
-        let mut r = Vec::with_capacity(self.underestimated_count());
-        for e in self.iter() {
-            r.push(e.clone())
+        set_operation_into(
+            self,
+            other,
+            dest,
+            SetSelection {
+                only_in_first: true,
+                only_in_second: false,
+                in_both: false,
+            },
+            are_in_increasing_order,
+        )
+    }
+
+    /// Computes elements of sorted `self` not present in sorted `other`.
+    /// Writes elements to the start of `dest`, and returns the position in
+    /// `dest` just after the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `max(m - n, 0)` times.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let a = [1, 2, 2, 4, 6];
+    /// let b = [2, 3, 4, 4];
+    /// let mut dest = [0; 9];
+    /// let i = a.set_difference_into(&b, &mut dest);
+    /// assert_eq!(i, 3);
+    /// assert_eq!(dest, [1, 2, 6, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    fn set_difference_into<Other, D>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+    ) -> D::Position
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Ord + Clone,
+    {
+        self.set_difference_into_by(other, dest, |x, y| x < y)
+    }
+
+    /// Computes elements present in exactly one of sorted `self` and sorted
+    /// `other`.
+    /// Writes elements to the start of `dest` according to
+    /// `are_in_increasing_order`, and returns the position in `dest` just after
+    /// the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `|m - n|` times.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted wrt `are_in_increasing_order`.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    fn set_symmetric_difference_into_by<Other, D, Compare>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+        are_in_increasing_order: Compare,
+    ) -> D::Position
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        set_operation_into(
+            self,
+            other,
+            dest,
+            SetSelection {
+                only_in_first: true,
+                only_in_second: true,
+                in_both: false,
+            },
+            are_in_increasing_order,
+        )
+    }
+
+    /// Computes elements present in exactly one of sorted `self` and sorted
+    /// `other`.
+    /// Writes elements to the start of `dest`, and returns the position in
+    /// `dest` just after the last written element.
+    ///
+    /// An element repeated `m` times in `self` and `n` times in `other` is
+    /// written `|m - n|` times.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted.
+    ///   - `dest` has enough space for written elements.
+    ///
+    /// # Postcondition
+    ///   - Written elements are sorted.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let a = [1, 2, 2, 4, 6];
+    /// let b = [2, 3, 4, 4];
+    /// let mut dest = [0; 9];
+    /// let i = a.set_symmetric_difference_into(&b, &mut dest);
+    /// assert_eq!(i, 5);
+    /// assert_eq!(dest, [1, 2, 3, 4, 6, 0, 0, 0, 0]);
+    /// ```
+    fn set_symmetric_difference_into<Other, D>(
+        &self,
+        other: &Other,
+        dest: &mut D,
+    ) -> D::Position
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        D: MutableCollection<Element = Self::Element> + ?Sized,
+        D::Whole: MutableCollection,
+        Self::Element: Ord + Clone,
+    {
+        self.set_symmetric_difference_into_by(other, dest, |x, y| x < y)
+    }
+
+    /// Returns true if every element of sorted `other` is present in sorted
+    /// `self` according to `are_in_increasing_order`.
+    ///
+    /// An element repeated `n` times in `other` should be repeated at least `n`
+    /// times in `self`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    fn includes_by<Other, Compare>(
+        &self,
+        other: &Other,
+        are_in_increasing_order: Compare,
+    ) -> bool
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        let (mut i, end1) = (self.start(), self.end());
+        let (mut j, end2) = (other.start(), other.end());
+        while j != end2 {
+            if i == end1 {
+                return false;
+            }
+            let x = self.at(&i);
+            let y = other.at(&j);
+            if are_in_increasing_order(&y, &x) {
+                return false;
+            }
+            if !are_in_increasing_order(&x, &y) {
+                other.form_next(&mut j);
+            }
+            self.form_next(&mut i);
         }
-        r
+        true
+    }
+
+    /// Returns true if every element of sorted `other` is present in sorted
+    /// `self`.
+    ///
+    /// An element repeated `n` times in `other` should be repeated at least `n`
+    /// times in `self`.
+    ///
+    /// # Precondition
+    ///   - `self` and `other` are sorted.
+    ///
+    /// # Complexity
+    ///   - O(m + n) where `m == self.count()` and `n == other.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let a = [1, 2, 2, 4, 6];
+    /// assert!(a.includes(&[2, 2, 6]));
+    /// assert!(!a.includes(&[2, 2, 2]));
+    /// assert!(!a.includes(&[3]));
+    /// ```
+    fn includes<Other>(&self, other: &Other) -> bool
+    where
+        Other: Collection<Element = Self::Element> + ?Sized,
+        Self::Element: Ord,
+    {
+        self.includes_by(other, |x, y| x < y)
     }
 }
 
@@ -905,3 +3367,6 @@ impl<R> CollectionExt for R where R: Collection + ?Sized {}
 
 mod parallel;
 pub use parallel::*;
+
+mod set_algorithms;
+use set_algorithms::{set_operation_into, SetSelection};
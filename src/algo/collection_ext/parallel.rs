@@ -1,7 +1,15 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
-use crate::{exec_par, Collection, CollectionExt};
+use std::time::Duration;
+
+use crate::{
+    algo::shuffle::{fisher_yates, merge_shuffled, SplitMix64},
+    exec_par, exec_par_cancellable, exec_par_until, exec_par_void, trace_span,
+    CancellationToken, Collection, CollectionExt, Deadline,
+    RandomAccessCollection, ReorderableCollection, ReorderableCollectionExt,
+    Slice, TimedOut,
+};
 
 /// Parallel Algorithms for `Collection`.
 pub trait ParallelCollectionExt: Collection
@@ -35,17 +43,41 @@ where
             .map(|n| n.get())
             .unwrap_or(1);
         let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_first_position_where",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
         let even_splits = self.splitting_evenly_in_with_min_size(
             hardware_concurrency,
             min_elements_per_core,
         );
         let num_splits = even_splits.len();
+
+        // A part having a match cancels search in later parts.
         let parallel_tasks = even_splits
             .zip(std::iter::repeat_n(pred, num_splits))
-            .map(|(slice, pred)| move || slice.first_position_where(pred));
+            .map(|(mut rest, pred)| {
+                move |token: CancellationToken| {
+                    let mut p = rest.start();
+                    while let Some(e) = rest.pop_first() {
+                        if token.is_cancelled() {
+                            return None;
+                        }
+                        if pred(&e) {
+                            token.cancel_later_tasks();
+                            return Some(p);
+                        }
+                        p = rest.start();
+                    }
+                    None
+                }
+            });
 
-        // TODO: implement cancellation.
-        exec_par(parallel_tasks).into_iter().flatten().next()
+        exec_par_cancellable(parallel_tasks)
+            .into_iter()
+            .flatten()
+            .next()
     }
 
     /// Finds position of first element in `self` equals `e`. If no such element
@@ -69,7 +101,132 @@ where
     where
         Self::Element: Eq + Sync, // TODO: is Sync really necessary??
     {
-        self.parallel_first_position_where(|x| x == e)
+        self.parallel_first_position_of_by(e, |x, y| x == y)
+    }
+
+    /// Finds position of first element in `self` equivalent to `e` by
+    /// `are_equivalent`. If no such element exists, returns `None`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 12, 3, 2];
+    /// let i = arr.parallel_first_position_of_by(&2, |x, y| x % 10 == y % 10);
+    /// assert_eq!(i, Some(1));
+    /// ```
+    fn parallel_first_position_of_by<F>(
+        &self,
+        e: &Self::Element,
+        are_equivalent: F,
+    ) -> Option<Self::Position>
+    where
+        Self::Element: Sync,
+        F: Fn(&Self::Element, &Self::Element) -> bool + Clone + Send,
+    {
+        self.parallel_first_position_where(move |x| are_equivalent(x, e))
+    }
+
+    /// Finds position of first element in `self` satisfying `pred`, giving up
+    /// with `Err(TimedOut)` if search doesn't finish within `timeout`.
+    ///
+    /// Returns `Ok(None)` if no such element exists.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use std::time::Duration;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// let i = arr.parallel_first_position_where_with_deadline(
+    ///     |x| *x == 3,
+    ///     Duration::from_secs(1),
+    /// );
+    /// assert_eq!(i, Ok(Some(2)));
+    /// ```
+    fn parallel_first_position_where_with_deadline<Pred>(
+        &self,
+        pred: Pred,
+        timeout: Duration,
+    ) -> Result<Option<Self::Position>, TimedOut>
+    where
+        Pred: Fn(&Self::Element) -> bool + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_first_position_where_with_deadline",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let deadline = Deadline::after(timeout);
+        let even_splits = self.splitting_evenly_in_with_min_size(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let num_splits = even_splits.len();
+        let parallel_tasks = even_splits
+            .zip(std::iter::repeat_n((pred, deadline), num_splits))
+            .map(|(mut rest, (pred, mut deadline))| {
+                move || {
+                    let mut p = rest.start();
+                    while let Some(e) = rest.pop_first() {
+                        if pred(&e) {
+                            return Ok(Some(p));
+                        }
+                        deadline.step()?;
+                        p = rest.start();
+                    }
+                    Ok(None)
+                }
+            });
+
+        // A part timing out hides matches of later parts.
+        for res in exec_par(parallel_tasks) {
+            if let Some(p) = res? {
+                return Ok(Some(p));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Finds position of first element in `self` equals `e`, giving up with
+    /// `Err(TimedOut)` if search doesn't finish within `timeout`.
+    ///
+    /// Returns `Ok(None)` if no such element exists.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use std::time::Duration;
+    ///
+    /// let arr = [1, 2, 3, 3];
+    /// let i = arr.parallel_first_position_of_with_deadline(
+    ///     &3,
+    ///     Duration::from_secs(1),
+    /// );
+    /// assert_eq!(i, Ok(Some(2)));
+    /// ```
+    fn parallel_first_position_of_with_deadline(
+        &self,
+        e: &Self::Element,
+        timeout: Duration,
+    ) -> Result<Option<Self::Position>, TimedOut>
+    where
+        Self::Element: Eq + Sync,
+    {
+        self.parallel_first_position_where_with_deadline(|x| x == e, timeout)
     }
 
     /// Finds position of last element in `self` satisfying `pred`. If no such
@@ -97,17 +254,46 @@ where
             .map(|n| n.get())
             .unwrap_or(1);
         let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_last_position_where",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
         let even_splits = self.splitting_evenly_in_with_min_size(
             hardware_concurrency,
             min_elements_per_core,
         );
         let num_splits = even_splits.len();
+
+        // Tasks are ordered from last part to first part, so that a part
+        // having a match cancels search in earlier parts.
+        let mut even_splits: Vec<_> = even_splits.collect();
+        even_splits.reverse();
         let parallel_tasks = even_splits
+            .into_iter()
             .zip(std::iter::repeat_n(pred, num_splits))
-            .map(|(slice, pred)| move || slice.last_position_where(pred));
+            .map(|(mut rest, pred)| {
+                move |token: CancellationToken| {
+                    let mut res = None;
+                    let mut p = rest.start();
+                    while let Some(e) = rest.pop_first() {
+                        if token.is_cancelled() {
+                            return None;
+                        }
+                        if pred(&e) {
+                            token.cancel_later_tasks();
+                            res = Some(p);
+                        }
+                        p = rest.start();
+                    }
+                    res
+                }
+            });
 
-        // TODO: implement cancellation.
-        exec_par(parallel_tasks).into_iter().flatten().last()
+        exec_par_cancellable(parallel_tasks)
+            .into_iter()
+            .flatten()
+            .next()
     }
 
     /// Finds position of `last` element equals `e`. If no such element exist,
@@ -131,7 +317,33 @@ where
     where
         Self::Element: Eq + Sync,
     {
-        self.parallel_last_position_where(|x| x == e)
+        self.parallel_last_position_of_by(e, |x, y| x == y)
+    }
+
+    /// Finds position of last element in `self` equivalent to `e` by
+    /// `are_equivalent`. If no such element exists, returns `None`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 12, 3, 2];
+    /// let i = arr.parallel_last_position_of_by(&22, |x, y| x % 10 == y % 10);
+    /// assert_eq!(i, Some(3));
+    /// ```
+    fn parallel_last_position_of_by<F>(
+        &self,
+        e: &Self::Element,
+        are_equivalent: F,
+    ) -> Option<Self::Position>
+    where
+        Self::Element: Sync,
+        F: Fn(&Self::Element, &Self::Element) -> bool + Clone + Send,
+    {
+        self.parallel_last_position_where(move |x| are_equivalent(x, e))
     }
 
     /*-----------------Predicate Test Algorithms-----------------*/
@@ -157,6 +369,11 @@ where
             .map(|n| n.get())
             .unwrap_or(1);
         let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_all_satisfy",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
         let even_splits = self.splitting_evenly_in_with_min_size(
             hardware_concurrency,
             min_elements_per_core,
@@ -164,10 +381,13 @@ where
         let num_splits = even_splits.len();
         let parallel_tasks = even_splits
             .zip(std::iter::repeat_n(pred, num_splits))
-            .map(|(slice, pred)| move || slice.all_satisfy(pred));
+            .map(|(slice, pred)| {
+                move |token: CancellationToken| {
+                    search_until_cancelled(slice, |e| !pred(e), token)
+                }
+            });
 
-        // TODO: implement cancellation.
-        exec_par(parallel_tasks).into_iter().all(|e| e)
+        exec_par_until(parallel_tasks).is_none()
     }
 
     /// Returns true iff atleast one element in `self` satisfies `pred`.
@@ -191,6 +411,11 @@ where
             .map(|n| n.get())
             .unwrap_or(1);
         let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_any_satisfy",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
         let even_splits = self.splitting_evenly_in_with_min_size(
             hardware_concurrency,
             min_elements_per_core,
@@ -198,10 +423,13 @@ where
         let num_splits = even_splits.len();
         let parallel_tasks = even_splits
             .zip(std::iter::repeat_n(pred, num_splits))
-            .map(|(slice, pred)| move || slice.any_satisfy(pred));
+            .map(|(slice, pred)| {
+                move |token: CancellationToken| {
+                    search_until_cancelled(slice, pred, token)
+                }
+            });
 
-        // TODO: implement cancellation.
-        exec_par(parallel_tasks).into_iter().any(|e| e)
+        exec_par_until(parallel_tasks).is_some()
     }
 
     /// Returns true iff no element in `self` satisfies `pred`.
@@ -225,6 +453,150 @@ where
             .map(|n| n.get())
             .unwrap_or(1);
         let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_none_satisfy",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let num_splits = even_splits.len();
+        let parallel_tasks = even_splits
+            .zip(std::iter::repeat_n(pred, num_splits))
+            .map(|(slice, pred)| {
+                move |token: CancellationToken| {
+                    search_until_cancelled(slice, pred, token)
+                }
+            });
+
+        exec_par_until(parallel_tasks).is_none()
+    }
+
+    /*-----------------Sorting Test Algorithms-----------------*/
+
+    /// Returns true iff `self` is sorted according to `are_in_increasing_order`,
+    /// i.e., no element is less than the element before it. Equivalent
+    /// adjacent elements are allowed.
+    ///
+    /// Parts of `self` are checked in parallel, each along with first element
+    /// of next part, so that pairs on boundary of parts are checked too.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 3, 3, 1];
+    /// assert!(arr.parallel_is_sorted_by(|x, y| x > y));
+    /// assert!(!arr.parallel_is_sorted_by(|x, y| x < y));
+    /// ```
+    fn parallel_is_sorted_by<Compare>(
+        &self,
+        are_in_increasing_order: Compare,
+    ) -> bool
+    where
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_is_sorted_by",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let end = self.end();
+        let parts_with_boundary: Vec<_> = even_splits
+            .map(|slice| {
+                let (from, mut to) = (slice.start(), slice.end());
+                if to != end {
+                    self.form_next(&mut to);
+                }
+                self.slice(from, to)
+            })
+            .collect();
+        let num_splits = parts_with_boundary.len();
+        let parallel_tasks = parts_with_boundary
+            .into_iter()
+            .zip(std::iter::repeat_n(are_in_increasing_order, num_splits))
+            .map(|(mut slice, are_in_increasing_order)| {
+                move |token: CancellationToken| {
+                    let mut prev = slice.pop_first()?;
+                    while let Some(e) = slice.pop_first() {
+                        if token.is_cancelled() {
+                            return None;
+                        }
+                        if are_in_increasing_order(&e, &prev) {
+                            return Some(());
+                        }
+                        prev = e;
+                    }
+                    None
+                }
+            });
+
+        exec_par_until(parallel_tasks).is_none()
+    }
+
+    /// Returns true iff `self` is sorted in non-decreasing order.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 2, 4];
+    /// assert!(arr.parallel_is_sorted());
+    /// assert!(![1, 3, 2].parallel_is_sorted());
+    /// ```
+    fn parallel_is_sorted(&self) -> bool
+    where
+        Self::Element: Ord,
+    {
+        self.parallel_is_sorted_by(|x, y| x < y)
+    }
+
+    /*-----------------Count Algorithms-----------------*/
+
+    /// Returns number of elements in `self` satisfying `pred`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// assert_eq!(arr.parallel_count_where(|x| x % 2 == 1), 3);
+    /// ```
+    fn parallel_count_where<Pred>(&self, pred: Pred) -> usize
+    where
+        Pred: Fn(&Self::Element) -> bool + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_count_where",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
         let even_splits = self.splitting_evenly_in_with_min_size(
             hardware_concurrency,
             min_elements_per_core,
@@ -232,10 +604,210 @@ where
         let num_splits = even_splits.len();
         let parallel_tasks = even_splits
             .zip(std::iter::repeat_n(pred, num_splits))
-            .map(|(slice, pred)| move || slice.none_satisfy(pred));
+            .map(|(slice, pred)| move || slice.count_where(pred));
+
+        exec_par(parallel_tasks).into_iter().sum()
+    }
+
+    /*-----------------Reordering Algorithms-----------------*/
+
+    /// Shuffles `self` uniformly at random in parallel.
+    ///
+    /// `rng` should return uniformly random 64 bit words. Parts of `self` are
+    /// shuffled in parallel and then merged pairwise in parallel, using
+    /// MergeShuffle algorithm.
+    ///
+    /// # Complexity
+    ///   - O(n * log(p)) where `n == self.count()` and `p` is number of
+    ///     parts, i.e., O(n) work per level of merging.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut state = 7u64;
+    /// let rng = || {
+    ///     state ^= state << 13;
+    ///     state ^= state >> 7;
+    ///     state ^= state << 17;
+    ///     state
+    /// };
+    /// let mut arr: Vec<i32> = (0..10000).collect();
+    /// arr.parallel_shuffle(rng);
+    /// arr.sort();
+    /// assert!(arr.iter().copied().eq(0..10000));
+    /// ```
+    fn parallel_shuffle<Rng>(&mut self, mut rng: Rng)
+    where
+        Self: ReorderableCollection + RandomAccessCollection,
+        Self::Whole: ReorderableCollection + RandomAccessCollection,
+        Rng: FnMut() -> u64,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_shuffle",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size_mut(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let mut sizes = vec![];
+        let mut parallel_tasks = vec![];
+        for slice in even_splits {
+            sizes.push(slice.count());
+            let mut part_rng = SplitMix64::new(rng());
+            parallel_tasks.push(move || fisher_yates(slice, &mut part_rng));
+        }
+        exec_par_void(parallel_tasks.into_iter());
 
-        // TODO: implement cancellation.
-        exec_par(parallel_tasks).into_iter().all(|e| e)
+        // Adjacent shuffled parts are merged pairwise till one part remains.
+        while sizes.len() > 1 {
+            let mut rest = self.full_mut();
+            let mut merged_sizes = vec![];
+            let mut parallel_tasks = vec![];
+            for pair in sizes.chunks(2) {
+                let size: usize = pair.iter().sum();
+                let part = rest.pop(size);
+                merged_sizes.push(size);
+                if let [first_size, _] = *pair {
+                    let mut part_rng = SplitMix64::new(rng());
+                    parallel_tasks.push(move || {
+                        merge_shuffled(part, first_size, &mut part_rng)
+                    });
+                }
+            }
+            exec_par_void(parallel_tasks.into_iter());
+            sizes = merged_sizes;
+        }
+    }
+
+    /*-----------------Partition Algorithms-----------------*/
+
+    /// Returns two Vec containing the elements of the collection that
+    /// don’t and do satisfy the given predicate, respectively, filling them in
+    /// parallel.
+    ///
+    /// Works in two parallel passes: parts of `self` are first partitioned in
+    /// parallel to their own buffers, whose sizes give by prefix sums where
+    /// each part goes in result, and then elements of every part are moved in
+    /// parallel to their place.
+    ///
+    /// # Postcondition
+    ///   - Returns `(falseVec, trueVec)` where `falseVec` contains all elements
+    ///     that don't satisfy predicate and `trueVec` contains all elements
+    ///     that do satisfy predicate.
+    ///   - Relative ordering of elements is preserved in both Vec.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let (evens, odds) = arr.parallel_partitioned(|x| x % 2 == 1);
+    /// assert_eq!(evens, [2, 4]);
+    /// assert_eq!(odds, [1, 3, 5]);
+    /// ```
+    fn parallel_partitioned<F>(
+        &self,
+        belongs_in_second_half: F,
+    ) -> (Vec<Self::Element>, Vec<Self::Element>)
+    where
+        Self::Element: Clone + Send,
+        F: Fn(&Self::Element) -> bool + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_partitioned",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let num_splits = even_splits.len();
+        let parallel_tasks = even_splits
+            .zip(std::iter::repeat_n(belongs_in_second_half, num_splits))
+            .map(|(slice, pred)| move || slice.partitioned(pred));
+        let (firsts, seconds): (Vec<_>, Vec<_>) =
+            exec_par(parallel_tasks).into_iter().unzip();
+        (concat_in_parallel(firsts), concat_in_parallel(seconds))
+    }
+
+    /*-----------------Numeric Algorithms-----------------*/
+
+    /// Returns the result of combining `identity` and elements of `self` using
+    /// associative operation `op`, combining parts of `self` in parallel.
+    ///
+    /// # Precondition
+    ///   - `op` is associative, i.e., `op(op(a, b), c)` is equivalent to
+    ///     `op(a, op(b, c))`.
+    ///   - `identity` is identity of `op`, i.e., `op(identity, a)` and
+    ///     `op(a, identity)` are equivalent to `a`.
+    ///
+    /// # Postcondition
+    ///   - Result is equivalent to `(((identity + e1) + e2) + ... + en)`,
+    ///     where e1, e2, ..., en are elements of `self` and (a + b) represents
+    ///     op(a, b). `op` need not be commutative.
+    ///   - Returns `identity` if `self` is empty.
+    ///
+    /// # Complexity
+    ///   - O(n) applications of `op` where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr: Vec<u64> = (1..=1000).collect();
+    /// assert_eq!(arr.parallel_reduce(0, |x, y| x + y), 500500);
+    /// ```
+    fn parallel_reduce<Op>(
+        &self,
+        identity: Self::Element,
+        op: Op,
+    ) -> Self::Element
+    where
+        Self::Element: Clone + Send,
+        Op: Fn(&Self::Element, &Self::Element) -> Self::Element + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_reduce",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let num_splits = even_splits.len();
+        let parallel_tasks = even_splits
+            .zip(std::iter::repeat_n(
+                (identity.clone(), op.clone()),
+                num_splits,
+            ))
+            .map(|(slice, (identity, op))| {
+                move || slice.fold_left(identity, |acc, e| op(&acc, e))
+            });
+
+        // Parts are combined in order, so `op` need not be commutative.
+        exec_par(parallel_tasks)
+            .into_iter()
+            .fold(identity, |acc, e| op(&acc, &e))
     }
 }
 
@@ -245,3 +817,58 @@ where
     R::Whole: Send,
 {
 }
+
+/// Searches `slice` for an element satisfying `pred`, giving up once `token`
+/// is cancelled. Returns Some(()) iff such element is found.
+fn search_until_cancelled<Whole, Pred>(
+    mut slice: Slice<'_, Whole>,
+    pred: Pred,
+    token: CancellationToken,
+) -> Option<()>
+where
+    Whole: Collection<Whole = Whole>,
+    Pred: Fn(&Whole::Element) -> bool,
+{
+    while let Some(e) = slice.pop_first() {
+        if token.is_cancelled() {
+            return None;
+        }
+        if pred(&e) {
+            return Some(());
+        }
+    }
+    None
+}
+
+/// Returns concatenation of `parts` in order, moving elements of every part
+/// to its place in result in parallel.
+///
+/// # Complexity
+///   - O(n) where `n` is total number of elements.
+fn concat_in_parallel<T: Send>(parts: Vec<Vec<T>>) -> Vec<T> {
+    let len = parts.iter().map(Vec::len).sum();
+    let mut res = Vec::with_capacity(len);
+    let mut rest = &mut res.spare_capacity_mut()[..len];
+    let mut parallel_tasks = Vec::with_capacity(parts.len());
+    for mut part in parts {
+        let (region, r) = rest.split_at_mut(part.len());
+        rest = r;
+        parallel_tasks.push(move || {
+            // SAFETY: `region` has exactly `part.len()` slots, and setting
+            // length of `part` to 0 makes moved elements owned by `region`
+            // only. Copying can't panic, so every region gets initialized.
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    part.as_ptr(),
+                    region.as_mut_ptr().cast::<T>(),
+                    part.len(),
+                );
+                part.set_len(0);
+            }
+        });
+    }
+    exec_par_void(parallel_tasks.into_iter());
+    // SAFETY: regions of all parts cover `[0, len)`, and are initialized.
+    unsafe { res.set_len(len) };
+    res
+}
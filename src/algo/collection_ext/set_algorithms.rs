@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{Collection, MutableCollection};
+
+/// Which elements a set algorithm writes, when merging two sorted
+/// collections.
+pub(crate) struct SetSelection {
+    /// Write elements of first collection having no equivalent in second.
+    pub(crate) only_in_first: bool,
+
+    /// Write elements of second collection having no equivalent in first.
+    pub(crate) only_in_second: bool,
+
+    /// Write elements of first collection having an equivalent in second.
+    pub(crate) in_both: bool,
+}
+
+/// Merges sorted `c1` and sorted `c2`, writing elements selected by
+/// `selection` to the start of `dest`, and returns the position in `dest`
+/// just after the last written element.
+///
+/// Each element is paired with at most one equivalent element of other
+/// collection, so repeated elements are treated as a multiset.
+///
+/// # Precondition
+///   - `c1` and `c2` are sorted wrt `are_in_increasing_order`.
+///   - `dest` has enough space for written elements.
+///
+/// # Complexity
+///   - O(m + n) where `m == c1.count()` and `n == c2.count()`.
+pub(crate) fn set_operation_into<C1, C2, D, Compare>(
+    c1: &C1,
+    c2: &C2,
+    dest: &mut D,
+    selection: SetSelection,
+    are_in_increasing_order: Compare,
+) -> D::Position
+where
+    C1: Collection + ?Sized,
+    C1::Element: Clone,
+    C2: Collection<Element = C1::Element> + ?Sized,
+    D: MutableCollection<Element = C1::Element> + ?Sized,
+    D::Whole: MutableCollection,
+    Compare: Fn(&C1::Element, &C1::Element) -> bool,
+{
+    let mut out = dest.start();
+    let mut write = |e: &C1::Element| {
+        *dest.at_mut(&out) = e.clone();
+        dest.form_next(&mut out);
+    };
+
+    let (mut i, end1) = (c1.start(), c1.end());
+    let (mut j, end2) = (c2.start(), c2.end());
+    while i != end1 && j != end2 {
+        let x = c1.at(&i);
+        let y = c2.at(&j);
+        if are_in_increasing_order(&x, &y) {
+            if selection.only_in_first {
+                write(&x);
+            }
+            c1.form_next(&mut i);
+        } else if are_in_increasing_order(&y, &x) {
+            if selection.only_in_second {
+                write(&y);
+            }
+            c2.form_next(&mut j);
+        } else {
+            if selection.in_both {
+                write(&x);
+            }
+            c1.form_next(&mut i);
+            c2.form_next(&mut j);
+        }
+    }
+
+    if selection.only_in_first {
+        while i != end1 {
+            write(&c1.at(&i));
+            c1.form_next(&mut i);
+        }
+    }
+    if selection.only_in_second {
+        while j != end2 {
+            write(&c2.at(&j));
+            c2.form_next(&mut j);
+        }
+    }
+    out
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Error returned by deadline-aware algorithms that couldn't finish before
+/// their deadline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "algorithm timed out")
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Number of steps between consecutive reads of clock.
+const STEPS_PER_CHECK: usize = 256;
+
+/// A point of time after which an algorithm should stop.
+///
+/// Reading clock is relatively costly, so it is read only once every
+/// `STEPS_PER_CHECK` steps.
+#[derive(Clone)]
+pub(crate) struct Deadline {
+    /// Point of time of deadline, `None` if it is too far to be represented.
+    at: Option<Instant>,
+
+    /// Number of steps taken since clock was read last.
+    steps: usize,
+}
+
+impl Deadline {
+    /// Returns a deadline `timeout` after now.
+    pub(crate) fn after(timeout: Duration) -> Self {
+        Deadline {
+            at: Instant::now().checked_add(timeout),
+            steps: 0,
+        }
+    }
+
+    /// Records a step of algorithm, and returns `Err(TimedOut)` if deadline
+    /// has passed.
+    pub(crate) fn step(&mut self) -> Result<(), TimedOut> {
+        self.steps += 1;
+        if self.steps < STEPS_PER_CHECK {
+            return Ok(());
+        }
+        self.steps = 0;
+        match self.at {
+            Some(at) if Instant::now() >= at => Err(TimedOut),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::ops::ControlFlow;
+
+/// Models a value which either continues computation with an `Output` or
+/// stops it early with a `Residual`, e.g., `Result`, `Option` and
+/// `ControlFlow`.
+///
+/// Closures of fallible algorithms like `try_fold_left` and `try_for_each`
+/// return a Fallible value, and algorithms stop at first residual.
+pub trait Fallible {
+    /// Type of value continuing computation.
+    type Output;
+
+    /// Type of value stopping computation.
+    type Residual;
+
+    /// Returns `Continue(output)` if computation should continue, otherwise
+    /// `Break(residual)`.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+
+    /// Creates value continuing computation with `output`.
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Creates value stopping computation with `residual`.
+    fn from_residual(residual: Self::Residual) -> Self;
+}
+
+impl<T, E> Fallible for Result<T, E> {
+    type Output = T;
+
+    type Residual = E;
+
+    fn branch(self) -> ControlFlow<E, T> {
+        match self {
+            Ok(x) => ControlFlow::Continue(x),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    fn from_residual(residual: E) -> Self {
+        Err(residual)
+    }
+}
+
+impl<T> Fallible for Option<T> {
+    type Output = T;
+
+    type Residual = ();
+
+    fn branch(self) -> ControlFlow<(), T> {
+        match self {
+            Some(x) => ControlFlow::Continue(x),
+            None => ControlFlow::Break(()),
+        }
+    }
+
+    fn from_output(output: T) -> Self {
+        Some(output)
+    }
+
+    fn from_residual(_: ()) -> Self {
+        None
+    }
+}
+
+impl<B, C> Fallible for ControlFlow<B, C> {
+    type Output = C;
+
+    type Residual = B;
+
+    fn branch(self) -> ControlFlow<B, C> {
+        self
+    }
+
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    fn from_residual(residual: B) -> Self {
+        ControlFlow::Break(residual)
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::Collection;
+
+/// Conversion from an owned collection, moving its elements instead of
+/// cloning them.
+///
+/// Implemented for every `FromIterator` type, from collections that can be
+/// iterated by value, like `Vec`, and owned adaptors over them.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let v = vec![String::from("a"), String::from("b")];
+/// let r: Vec<String> = Vec::from_collection(v.reversed());
+/// assert_eq!(r, ["b", "a"]);
+/// ```
+pub trait FromCollection<C>: Sized {
+    /// Returns a new instance of Self containing elements of `collection`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == collection.count()`.
+    fn from_collection(collection: C) -> Self;
+}
+
+impl<B, C> FromCollection<C> for B
+where
+    C: Collection + IntoIterator<Item = C::Element>,
+    B: FromIterator<C::Element>,
+{
+    fn from_collection(collection: C) -> Self {
+        collection.into_iter().collect()
+    }
+}
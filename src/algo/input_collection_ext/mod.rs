@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::MutableCollection;
+
+/// Algorithms for single-pass input collections, e.g., lines of a file or
+/// bytes of a socket.
+///
+/// Single-pass input collections are modeled by `Iterator`, as reading an
+/// element consumes it: `Iterator::next` reads the next element, and
+/// iterator algorithms like `for_each`, `fold` and `find` traverse input
+/// once. This trait adds algorithms connecting them to collections.
+pub trait InputCollectionExt: Iterator {
+    /// Consumes elements to write them to the start of `dest` until either
+    /// `self` or `dest` is exhausted, and returns the position in `dest` just
+    /// after the last written element.
+    ///
+    /// # Postcondition
+    ///   - Elements not fitting in `dest` are not consumed.
+    ///
+    /// # Complexity
+    ///   - O(min(n, `dest.count()`)) where `n` is number of remaining
+    ///     elements.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut input = [1, 2, 3, 4, 5].into_iter();
+    /// let mut dest = [0; 3];
+    /// let i = input.read_into(&mut dest);
+    /// assert_eq!(i, 3);
+    /// assert_eq!(dest, [1, 2, 3]);
+    /// assert_eq!(input.next(), Some(4));
+    /// ```
+    fn read_into<D>(&mut self, dest: &mut D) -> D::Position
+    where
+        D: MutableCollection<Element = Self::Item> + ?Sized,
+        D::Whole: MutableCollection,
+    {
+        let mut out = dest.start();
+        let end = dest.end();
+        while out != end {
+            match self.next() {
+                Some(e) => *dest.at_mut(&out) = e,
+                None => break,
+            }
+            dest.form_next(&mut out);
+        }
+        out
+    }
+}
+
+impl<I> InputCollectionExt for I where I: Iterator + ?Sized {}
@@ -2,9 +2,10 @@
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
 use crate::algo::collection_ext::CollectionExt;
+use crate::buffer::TempBuffer;
 use crate::collections::LazyMappedCollection;
 use crate::iterators::LazyCollectionIter;
-use crate::{BidirectionalCollection, LazyCollection};
+use crate::{BidirectionalCollection, LazyCollection, OutputCollection};
 
 /// Algorithms for `LazyCollection`.
 pub trait LazyCollectionExt: LazyCollection
@@ -66,6 +67,40 @@ where
         }
     }
 
+    /// Writes "lazily computed" elements of collection to `out` until either
+    /// `self` or `out` is exhausted, and returns the position of first element
+    /// not written.
+    ///
+    /// Unlike `copy_into`, destination elements needn't exist before, e.g.,
+    /// `out` may be an uninitialized buffer or a growable Vec.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of written elements.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut out = vec![0];
+    /// let i = (1..4).lazy_map(|x| x * 10).lazy_write_to(&mut out);
+    /// assert_eq!(i, 4);
+    /// assert_eq!(out, [0, 10, 20, 30]);
+    /// ```
+    fn lazy_write_to<O>(&self, out: &mut O) -> Self::Position
+    where
+        O: OutputCollection<Element = Self::Element> + ?Sized,
+    {
+        let mut start = self.start();
+        let end = self.end();
+        while start != end {
+            if out.write_next(self.compute_at(&start)).is_err() {
+                break;
+            }
+            self.form_next(&mut start);
+        }
+        start
+    }
+
     /*-----------------Transformation algorithms-----------------*/
 
     /// Returns a lazy collection projecting elements of mapping the given closure over lazily
@@ -77,6 +112,10 @@ where
     ///
     /// let arr = (1..=5).lazy_map(|x| x * 2);
     /// assert!(arr.equals(&[2, 4, 6, 8, 10]));
+    ///
+    /// // Result is itself lazy, so mappings compose without recomputation.
+    /// let arr = (1..=5).lazy_map(|x| x * 2).lazy_map(|x| x + 1);
+    /// assert!(arr.equals(&[3, 5, 7, 9, 11]));
     /// ```
     fn lazy_map<MapFn, MappedType>(
         self,
@@ -119,30 +158,15 @@ where
     where
         F: FnMut(&Self::Element) -> bool,
     {
-        let n = self.count();
-        let mut left = Vec::with_capacity(n);
-        let arr = left.spare_capacity_mut();
-        let mut left_idx = 0;
-        let mut right_idx = n;
-
+        let mut buffer = TempBuffer::with_capacity(self.count());
         for e in self.lazy_iter() {
             if belongs_in_second_half(&e) {
-                right_idx -= 1;
-                arr[right_idx].write(e);
+                buffer.push_second(e);
             } else {
-                arr[left_idx].write(e);
-                left_idx += 1;
+                buffer.push(e);
             }
         }
-
-        unsafe {
-            left.set_len(n);
-        }
-
-        let mut right = left.split_off(right_idx);
-        right.reverse();
-
-        (left, right)
+        buffer.into_parts()
     }
 
     /*-----------------Numeric Algorithms-----------------*/
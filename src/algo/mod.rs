@@ -18,3 +18,42 @@ pub use mutable_collection_ext::*;
 
 mod bidirectional_collection_ext;
 pub use bidirectional_collection_ext::*;
+
+mod input_collection_ext;
+pub use input_collection_ext::*;
+
+mod sorted_vec;
+pub use sorted_vec::*;
+
+mod position_ranges;
+pub use position_ranges::*;
+
+mod auto_tune;
+pub use auto_tune::*;
+
+mod alloc_policy;
+pub use alloc_policy::*;
+
+mod deadline;
+pub(crate) use deadline::Deadline;
+pub use deadline::TimedOut;
+
+mod fallible;
+pub use fallible::*;
+
+mod from_collection;
+pub use from_collection::*;
+
+#[cfg(feature = "progress")]
+mod progress;
+#[cfg(feature = "progress")]
+pub(crate) use progress::Progress;
+
+mod trace;
+pub(crate) use trace::trace_span;
+
+mod order;
+pub use order::*;
+
+mod shuffle;
+pub use shuffle::*;
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::algo::random_access_collection_ext::sort::insertion_sort;
+use crate::buffer::TempBuffer;
+use crate::{
+    BidirectionalCollection, CollectionExt, MutableCollection,
+    ReorderableCollectionExt,
+};
+
+/// Merges sorted `c.prefix_upto(mid)` and sorted `c.suffix_from(mid)` in
+/// place, using a buffer of `n1` elements.
+///
+/// # Precondition
+///   - `n1 == c.distance(c.start(), mid)`.
+///
+/// # Postcondition
+///   - Merging is stable.
+///
+/// # Complexity
+///   - O(n) where `n == c.count()`.
+pub(crate) fn merge_with_buffer<C, Compare>(
+    c: &mut C,
+    mid: C::Position,
+    n1: usize,
+    are_in_increasing_order: Compare,
+) where
+    C: MutableCollection + ?Sized,
+    C::Whole: MutableCollection,
+    C::Element: Clone,
+    Compare: Fn(&C::Element, &C::Element) -> bool,
+{
+    let mut buffer = TempBuffer::with_capacity(n1);
+    let mut i = c.start();
+    while i != mid {
+        buffer.push(c.at(&i).clone());
+        c.form_next(&mut i);
+    }
+
+    // Elements before `write` are either buffered or already merged, so
+    // they can be overwritten.
+    let mut write = c.start();
+    let mut right = mid;
+    let end = c.end();
+    // `write` stays behind `right` until left elements are exhausted, so
+    // both positions are valid whenever they are accessed.
+    let mut left = buffer.into_iter().peekable();
+    while let Some(e) = left.peek() {
+        if right != end
+            && are_in_increasing_order(unsafe { &c.at_unchecked(&right) }, e)
+        {
+            unsafe { c.swap_at_unchecked(&write, &right) };
+            c.form_next(&mut right);
+        } else {
+            *unsafe { c.at_unchecked_mut(&write) } = left.next().unwrap();
+        }
+        c.form_next(&mut write);
+    }
+}
+
+/// Merges sorted `c.prefix_upto(mid)` and sorted `c.suffix_from(mid)` in
+/// place without allocating.
+///
+/// # Precondition
+///   - `n1 == c.distance(c.start(), mid)`.
+///   - `n2 == c.distance(mid, c.end())`.
+///
+/// # Postcondition
+///   - Merging is stable.
+///
+/// # Complexity
+///   - O(n * log(n)) where `n == c.count()`.
+pub(crate) fn merge_without_buffer<C, Compare>(
+    c: &mut C,
+    mid: C::Position,
+    n1: usize,
+    n2: usize,
+    are_in_increasing_order: Compare,
+) where
+    C: MutableCollection + ?Sized,
+    C::Whole: MutableCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    if n1 == 0 || n2 == 0 {
+        return;
+    }
+    let start = c.start();
+    if n1 + n2 == 2 {
+        if are_in_increasing_order(&c.at(&mid), &c.at(&start)) {
+            c.swap_at(&start, &mid);
+        }
+        return;
+    }
+
+    // Split larger part in half, and find where its middle element would be
+    // placed in other part.
+    let (cut1, cut2, len1, len2) = if n1 >= n2 {
+        let len1 = n1 / 2;
+        let cut1 = c.next_n(start.clone(), len1);
+        let cut2 = {
+            let pivot = c.at(&cut1);
+            c.slice(mid.clone(), c.end())
+                .partition_point(|e| !are_in_increasing_order(e, &pivot))
+        };
+        let len2 = c.distance(mid.clone(), cut2.clone());
+        (cut1, cut2, len1, len2)
+    } else {
+        let len2 = n2 / 2;
+        let cut2 = c.next_n(mid.clone(), len2);
+        let cut1 = {
+            let pivot = c.at(&cut2);
+            c.slice(start.clone(), mid.clone())
+                .partition_point(|e| are_in_increasing_order(&pivot, e))
+        };
+        let len1 = c.distance(start.clone(), cut1.clone());
+        (cut1, cut2, len1, len2)
+    };
+
+    let new_mid = c.slice_mut(cut1.clone(), cut2.clone()).rotate(mid);
+    merge_without_buffer(
+        &mut c.prefix_upto_mut(new_mid.clone()),
+        cut1,
+        len1,
+        len2,
+        are_in_increasing_order.clone(),
+    );
+    merge_without_buffer(
+        &mut c.suffix_from_mut(new_mid),
+        cut2,
+        n1 - len1,
+        n2 - len2,
+        are_in_increasing_order,
+    );
+}
+
+/// Sorts `c` in place by merge sort, preserving relative order of equivalent
+/// elements. Merging uses a buffer iff `use_buffer` is true.
+///
+/// # Precondition
+///   - `n == c.count()`.
+///
+/// # Complexity
+///   - O(n * log(n)) if `use_buffer` is true, otherwise O(n * log(n)^2).
+pub(crate) fn stable_sort_by<C, Compare>(
+    c: &mut C,
+    n: usize,
+    use_buffer: bool,
+    are_in_increasing_order: Compare,
+) where
+    C: MutableCollection + BidirectionalCollection + ?Sized,
+    C::Whole: MutableCollection + BidirectionalCollection,
+    C::Element: Clone,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    if n <= 16 {
+        insertion_sort(c, are_in_increasing_order);
+        return;
+    }
+
+    let h = n / 2;
+    let mid = c.next_n(c.start(), h);
+    stable_sort_by(
+        &mut c.prefix_upto_mut(mid.clone()),
+        h,
+        use_buffer,
+        are_in_increasing_order.clone(),
+    );
+    stable_sort_by(
+        &mut c.suffix_from_mut(mid.clone()),
+        n - h,
+        use_buffer,
+        are_in_increasing_order.clone(),
+    );
+    if use_buffer {
+        merge_with_buffer(c, mid, h, are_in_increasing_order);
+    } else {
+        merge_without_buffer(c, mid, h, n - h, are_in_increasing_order);
+    }
+}
+
+/// Moves all elements satisfying `belongs_in_second_partition` into a suffix
+/// of `c`, preserving relative order of elements in both partitions, and
+/// returns the start of the resulting suffix. Elements of suffix are
+/// buffered.
+///
+/// # Complexity
+///   - O(n) where `n == c.count()`.
+pub(crate) fn stable_partition_with_buffer<C, F>(
+    c: &mut C,
+    mut belongs_in_second_partition: F,
+) -> C::Position
+where
+    C: MutableCollection + ?Sized,
+    C::Whole: MutableCollection,
+    C::Element: Clone,
+    F: FnMut(&C::Element) -> bool,
+{
+    let mut buffer = TempBuffer::with_capacity(c.count());
+    let mut write = c.start();
+    let mut i = c.start();
+    let end = c.end();
+    while i != end {
+        if belongs_in_second_partition(&c.at(&i)) {
+            buffer.push(c.at(&i).clone());
+        } else {
+            c.swap_at(&write, &i);
+            c.form_next(&mut write);
+        }
+        c.form_next(&mut i);
+    }
+
+    buffer.write_into(c, write.clone());
+    write
+}
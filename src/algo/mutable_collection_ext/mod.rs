@@ -1,9 +1,19 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::{cmp::Ordering, ops::ControlFlow};
+
 use crate::algo::reorderable_collection_ext::ReorderableCollectionExt;
+use crate::collections::ProjectedMutCollection;
 use crate::iterators::MutableCollectionIter;
-use crate::MutableCollection;
+use crate::{
+    estimate_merge_inplace_scratch_bytes,
+    estimate_stable_partition_scratch_bytes,
+    estimate_stable_sort_scratch_bytes, exec_par_void, trace_span, AllocPolicy,
+    BidirectionalCollection, Collection, Fallible, MutableCollection,
+    ParallelCollectionExt, RandomAccessCollection, RandomAccessCollectionExt,
+};
+mod buffered;
 
 /// Algorithms for `MutableCollection`.
 pub trait MutableCollectionExt: MutableCollection
@@ -37,10 +47,704 @@ where
         }
     }
 
+    /// Applies `f` to each element of collection from left to right, stopping
+    /// at first element for which `f` returns a residual, e.g., `Err`.
+    ///
+    /// # Postcondition
+    ///   - Returns first residual returned by `f` if any, otherwise the
+    ///     successful output, e.g., `Ok(())`.
+    ///   - Elements after the one `f` stopped at are not visited.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 0, 4];
+    /// let res = arr.try_for_each_mut(|e| {
+    ///     if *e == 0 {
+    ///         return Err("division by zero");
+    ///     }
+    ///     *e = 12 / *e;
+    ///     Ok(())
+    /// });
+    /// assert_eq!(res, Err("division by zero"));
+    /// assert_eq!(arr, [12, 6, 0, 4]);
+    /// ```
+    fn try_for_each_mut<F, Out>(&mut self, mut f: F) -> Out
+    where
+        F: FnMut(&mut Self::Element) -> Out,
+        Out: Fallible<Output = ()>,
+    {
+        let mut start = self.start();
+        let end = self.end();
+        while start != end {
+            if let ControlFlow::Break(r) = f(self.at_mut(&start)).branch() {
+                return Out::from_residual(r);
+            }
+            start = self.next(start);
+        }
+        Out::from_output(())
+    }
+
+    /// Applies `f` to corresponding elements of `self` and `other` in
+    /// lock-step, until either of them is exhausted.
+    ///
+    /// # Complexity
+    ///   - O(min(`self.count()`, `other.count()`)).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [10, 20];
+    /// a.for_each_zipped_mut(&mut b, |x, y| {
+    ///     *x += *y;
+    ///     *y = 0;
+    /// });
+    /// assert_eq!(a, [11, 22, 3]);
+    /// assert_eq!(b, [0, 0]);
+    /// ```
+    fn for_each_zipped_mut<Other, F>(&mut self, other: &mut Other, mut f: F)
+    where
+        Other: MutableCollection + ?Sized,
+        Other::Whole: MutableCollection,
+        F: FnMut(&mut Self::Element, &mut Other::Element),
+    {
+        let mut i = self.start();
+        let mut j = other.start();
+        let (end1, end2) = (self.end(), other.end());
+        while i != end1 && j != end2 {
+            f(self.at_mut(&i), other.at_mut(&j));
+            self.form_next(&mut i);
+            other.form_next(&mut j);
+        }
+    }
+
+    /// Assigns `value` to each element of collection.
+    ///
+    /// If collection is contiguous (see `MutableCollection::contiguous_slice_mut`),
+    /// elements are assigned as a slice, e.g., with `memset` for bytes.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// arr.fill(0);
+    /// assert_eq!(arr, [0, 0, 0]);
+    /// ```
+    fn fill(&mut self, value: Self::Element)
+    where
+        Self::Element: Clone,
+    {
+        let (start, end) = (self.start(), self.end());
+        if let Some(slice) = self.contiguous_slice_mut(start, end) {
+            slice.fill(value);
+            return;
+        }
+        self.for_each_mut(|e| *e = value.clone());
+    }
+
+    /// Applies `f` to each element of collection in parallel.
+    ///
+    /// Collection is split evenly into disjoint mutable slices, and `f` is
+    /// applied to elements of each slice on a separate task.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr: Vec<i32> = (0..10000).collect();
+    /// arr.parallel_for_each_mut(|e| *e *= 2);
+    /// assert!(arr.iter().copied().eq((0..20000).step_by(2)));
+    /// ```
+    fn parallel_for_each_mut<F>(&mut self, f: F)
+    where
+        Self::Whole: Send,
+        F: Fn(&mut Self::Element) + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_for_each_mut",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size_mut(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let num_splits = even_splits.len();
+        let parallel_tasks = even_splits
+            .zip(std::iter::repeat_n(f, num_splits))
+            .map(|(mut slice, f)| move || slice.for_each_mut(f));
+
+        exec_par_void(parallel_tasks);
+    }
+
     /// Returns an iterator to iterate over mutable element refs in collection.
     fn iter_mut(&mut self) -> MutableCollectionIter<'_, Self::Whole> {
         MutableCollectionIter::new(self.full_mut())
     }
+
+    /// Returns a mutable collection presenting the field projected by `proj`
+    /// and `proj_mut` of each element.
+    ///
+    /// Mutating or reordering returned collection mutates or reorders only the
+    /// projected fields, e.g., to fill or sort a column of a collection of
+    /// structs. Use `project` to reorder whole elements by a field instead.
+    ///
+    /// # Precondition
+    ///   - `proj` and `proj_mut` project the same field.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut pairs = [(1, 'c'), (2, 'a'), (3, 'b')];
+    /// pairs
+    ///     .full_mut()
+    ///     .project_mut(|p| &p.1, |p| &mut p.1)
+    ///     .sort_unstable();
+    /// assert_eq!(pairs, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    fn project_mut<Proj, ProjMut, Field>(
+        self,
+        proj: Proj,
+        proj_mut: ProjMut,
+    ) -> ProjectedMutCollection<Self, Proj, ProjMut, Field>
+    where
+        Self: Sized,
+        Proj: Fn(&Self::Element) -> &Field,
+        ProjMut: Fn(&mut Self::Element) -> &mut Field,
+    {
+        ProjectedMutCollection::new(self, proj, proj_mut)
+    }
+
+    /*-----------------Numeric Algorithms-----------------*/
+
+    /// Replaces every element of `self` with running accumulation of elements
+    /// upto and including it by `op`.
+    ///
+    /// # Postcondition
+    ///   - i-th element is `(((e1 + e2) + e3) + ... + ei)`, where e1, e2, ...,
+    ///     en are original elements and (a + b) represents op(a, b).
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// arr.inclusive_scan_inplace(|x, y| x * y);
+    /// assert_eq!(arr, [1, 2, 6, 24]);
+    /// ```
+    fn inclusive_scan_inplace<F>(&mut self, mut op: F)
+    where
+        Self::Element: Clone,
+        F: FnMut(&Self::Element, &Self::Element) -> Self::Element,
+    {
+        let mut i = self.start();
+        let end = self.end();
+        if i == end {
+            return;
+        }
+        let mut acc = self.at(&i).clone();
+        self.form_next(&mut i);
+        while i != end {
+            let e = self.at_mut(&i);
+            acc = op(&acc, e);
+            *e = acc.clone();
+            self.form_next(&mut i);
+        }
+    }
+
+    /*-----------------Buffered Algorithms-----------------*/
+
+    /// Sorts the collection in place, using the given predicate as comparision
+    /// between elements, and allocating buffer only as allowed by `policy`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are preserved.
+    ///   - Buffer of at most `n / 2` elements is allocated, and only if
+    ///     `policy` allows it.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) if buffer is allocated, otherwise O(n * log(n)^2),
+    ///     where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    /// arr.stable_sort_by(AllocPolicy::Forbidden, |x, y| x.0 < y.0);
+    /// assert_eq!(arr, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    /// ```
+    fn stable_sort_by<Compare>(
+        &mut self,
+        policy: AllocPolicy,
+        are_in_increasing_order: Compare,
+    ) where
+        Self: BidirectionalCollection,
+        Self::Whole: BidirectionalCollection,
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        let n = self.count();
+        let element_size = std::mem::size_of::<Self::Element>();
+        let use_buffer = policy
+            .allows_bytes(estimate_stable_sort_scratch_bytes(n, element_size));
+        trace_span!(
+            "stable_sort",
+            count = n,
+            strategy = if use_buffer { "buffered" } else { "in_place" }
+        );
+        buffered::stable_sort_by(self, n, use_buffer, are_in_increasing_order);
+    }
+
+    /// Sorts the collection in place, allocating buffer only as allowed by
+    /// `policy`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are preserved.
+    ///   - Buffer of at most `n / 2` elements is allocated, and only if
+    ///     `policy` allows it.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) if buffer is allocated, otherwise O(n * log(n)^2),
+    ///     where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 4, 1, 2, 5];
+    /// arr.stable_sort(AllocPolicy::Unlimited);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn stable_sort(&mut self, policy: AllocPolicy)
+    where
+        Self: BidirectionalCollection,
+        Self::Whole: BidirectionalCollection,
+        Self::Element: Clone + Ord,
+    {
+        self.stable_sort_by(policy, |x, y| x < y)
+    }
+
+    /// Sorts the collection in place in parallel, using the given predicate as
+    /// comparision between elements, without allocating.
+    ///
+    /// Parts of collection are sorted with `sort_unstable_by` in parallel,
+    /// or as Rust slices if they are contiguous (see
+    /// `MutableCollection::contiguous_slice_mut`), and then adjacent sorted
+    /// parts are merged pairwise in parallel without buffer.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are NOT guaranteed to be
+    ///     presevered.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)^2) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr: Vec<i32> = (0..10000).rev().collect();
+    /// arr.parallel_sort_unstable_by(|x, y| x < y);
+    /// assert!(arr.iter().copied().eq(0..10000));
+    /// ```
+    fn parallel_sort_unstable_by<Compare>(
+        &mut self,
+        are_in_increasing_order: Compare,
+    ) where
+        Self: RandomAccessCollection,
+        Self::Whole: RandomAccessCollection + Send,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_sort_unstable_by",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size_mut(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let mut sizes = vec![];
+        let mut parallel_tasks = vec![];
+        for mut slice in even_splits {
+            sizes.push(slice.count());
+            let are_in_increasing_order = are_in_increasing_order.clone();
+            parallel_tasks.push(move || {
+                let (start, end) = (slice.start(), slice.end());
+                match slice.contiguous_slice_mut(start, end) {
+                    Some(s) => s.sort_unstable_by(|x, y| {
+                        if are_in_increasing_order(x, y) {
+                            Ordering::Less
+                        } else if are_in_increasing_order(y, x) {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Equal
+                        }
+                    }),
+                    None => slice.sort_unstable_by(are_in_increasing_order),
+                }
+            });
+        }
+        exec_par_void(parallel_tasks.into_iter());
+
+        // Adjacent sorted parts are merged pairwise till one part remains.
+        while sizes.len() > 1 {
+            let mut rest = self.full_mut();
+            let mut merged_sizes = vec![];
+            let mut parallel_tasks = vec![];
+            for pair in sizes.chunks(2) {
+                let size: usize = pair.iter().sum();
+                let mut part = rest.pop(size);
+                merged_sizes.push(size);
+                if let [first_size, second_size] = *pair {
+                    let are_in_increasing_order =
+                        are_in_increasing_order.clone();
+                    parallel_tasks.push(move || {
+                        let mid = part.next_n(part.start(), first_size);
+                        buffered::merge_without_buffer(
+                            &mut part,
+                            mid,
+                            first_size,
+                            second_size,
+                            are_in_increasing_order,
+                        )
+                    });
+                }
+            }
+            exec_par_void(parallel_tasks.into_iter());
+            sizes = merged_sizes;
+        }
+
+        debug_assert!(
+            self.parallel_is_sorted_by(are_in_increasing_order),
+            "parallel_sort_unstable_by should leave collection sorted"
+        );
+    }
+
+    /// Sorts the collection in place in parallel, without allocating.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are NOT guaranteed to be
+    ///     presevered.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)^2) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 4, 1, 2, 5];
+    /// arr.parallel_sort_unstable();
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn parallel_sort_unstable(&mut self)
+    where
+        Self: RandomAccessCollection,
+        Self::Whole: RandomAccessCollection + Send,
+        Self::Element: Ord,
+    {
+        self.parallel_sort_unstable_by(|x, y| x < y)
+    }
+
+    /// Sorts the collection in place in parallel, using the given predicate as
+    /// comparision between elements, and allocating buffer only as allowed by
+    /// `policy`.
+    ///
+    /// Parts of collection are stable sorted in parallel, and then adjacent
+    /// sorted parts are merged pairwise in parallel using `merge_inplace_by`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are preserved.
+    ///   - Buffers of concurrently running tasks together stay within
+    ///     `policy`, as each task gets an even share of it.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) if buffer is allocated, otherwise O(n * log(n)^2),
+    ///     where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = (0..10000).lazy_map(|i| (i % 10, i)).to_vec();
+    /// arr.parallel_stable_sort_by(AllocPolicy::Unlimited, |x, y| x.0 < y.0);
+    /// assert!(arr.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    fn parallel_stable_sort_by<Compare>(
+        &mut self,
+        policy: AllocPolicy,
+        are_in_increasing_order: Compare,
+    ) where
+        Self: RandomAccessCollection,
+        Self::Whole: RandomAccessCollection + Send,
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone + Send,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!(
+            "parallel_stable_sort_by",
+            count = self.count(),
+            threads = hardware_concurrency
+        );
+        let even_splits = self.splitting_evenly_in_with_min_size_mut(
+            hardware_concurrency,
+            min_elements_per_core,
+        );
+        let slices: Vec<_> = even_splits.collect();
+        let task_policy = policy.split_among(slices.len().max(1));
+        let mut sizes = vec![];
+        let mut parallel_tasks = vec![];
+        for mut slice in slices {
+            sizes.push(slice.count());
+            let are_in_increasing_order = are_in_increasing_order.clone();
+            parallel_tasks.push(move || {
+                slice.stable_sort_by(task_policy, are_in_increasing_order)
+            });
+        }
+        exec_par_void(parallel_tasks.into_iter());
+
+        // Adjacent sorted parts are merged pairwise till one part remains.
+        while sizes.len() > 1 {
+            let mut rest = self.full_mut();
+            let mut merged_sizes = vec![];
+            let mut parallel_tasks = vec![];
+            let task_policy = policy.split_among(sizes.len() / 2);
+            for pair in sizes.chunks(2) {
+                let size: usize = pair.iter().sum();
+                let mut part = rest.pop(size);
+                merged_sizes.push(size);
+                if let [first_size, _] = *pair {
+                    let are_in_increasing_order =
+                        are_in_increasing_order.clone();
+                    parallel_tasks.push(move || {
+                        let mid = part.next_n(part.start(), first_size);
+                        part.merge_inplace_by(
+                            mid,
+                            task_policy,
+                            are_in_increasing_order,
+                        )
+                    });
+                }
+            }
+            exec_par_void(parallel_tasks.into_iter());
+            sizes = merged_sizes;
+        }
+
+        debug_assert!(
+            self.parallel_is_sorted_by(are_in_increasing_order),
+            "parallel_stable_sort_by should leave collection sorted"
+        );
+    }
+
+    /// Sorts the collection in place in parallel, allocating buffer only as
+    /// allowed by `policy`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are preserved.
+    ///   - Buffers of concurrently running tasks together stay within
+    ///     `policy`.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) if buffer is allocated, otherwise O(n * log(n)^2),
+    ///     where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr: Vec<i32> = (0..10000).rev().collect();
+    /// arr.parallel_stable_sort(AllocPolicy::Forbidden);
+    /// assert!(arr.iter().copied().eq(0..10000));
+    /// ```
+    fn parallel_stable_sort(&mut self, policy: AllocPolicy)
+    where
+        Self: RandomAccessCollection,
+        Self::Whole: RandomAccessCollection + Send,
+        Self::Element: Clone + Ord,
+    {
+        self.parallel_stable_sort_by(policy, |x, y| x < y)
+    }
+
+    /// Merges sorted `self.prefix_upto(mid)` and sorted `self.suffix_from(mid)`
+    /// in place, using the given predicate as comparision between elements, and
+    /// allocating buffer only as allowed by `policy`.
+    ///
+    /// # Precondition
+    ///   - `mid` is a valid position in `self`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///   - Both parts are sorted wrt `are_in_increasing_order`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are preserved, elements of
+    ///     first part being ordered before equivalent elements of second part.
+    ///   - Buffer of `self.distance(self.start(), mid)` elements is
+    ///     allocated, and only if `policy` allows it.
+    ///
+    /// # Complexity
+    ///   - O(n) if buffer is allocated, otherwise O(n * log(n)), where
+    ///     `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 4, 6, 2, 3, 5];
+    /// arr.merge_inplace_by(3, AllocPolicy::Bounded(0), |x, y| x < y);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn merge_inplace_by<Compare>(
+        &mut self,
+        mid: Self::Position,
+        policy: AllocPolicy,
+        are_in_increasing_order: Compare,
+    ) where
+        Self::Element: Clone,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        let n1 = self.distance(self.start(), mid.clone());
+        let element_size = std::mem::size_of::<Self::Element>();
+        if policy.allows_bytes(estimate_merge_inplace_scratch_bytes(
+            n1,
+            element_size,
+        )) {
+            buffered::merge_with_buffer(self, mid, n1, are_in_increasing_order);
+        } else {
+            let n2 = self.distance(mid.clone(), self.end());
+            buffered::merge_without_buffer(
+                self,
+                mid,
+                n1,
+                n2,
+                are_in_increasing_order,
+            );
+        }
+    }
+
+    /// Merges sorted `self.prefix_upto(mid)` and sorted `self.suffix_from(mid)`
+    /// in place, allocating buffer only as allowed by `policy`.
+    ///
+    /// # Precondition
+    ///   - `mid` is a valid position in `self`.
+    ///   - Both parts are sorted.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are preserved, elements of
+    ///     first part being ordered before equivalent elements of second part.
+    ///   - Buffer of `self.distance(self.start(), mid)` elements is
+    ///     allocated, and only if `policy` allows it.
+    ///
+    /// # Complexity
+    ///   - O(n) if buffer is allocated, otherwise O(n * log(n)), where
+    ///     `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 4, 6, 2, 3, 5];
+    /// arr.merge_inplace(3, AllocPolicy::Unlimited);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn merge_inplace(&mut self, mid: Self::Position, policy: AllocPolicy)
+    where
+        Self::Element: Clone + Ord,
+    {
+        self.merge_inplace_by(mid, policy, |x, y| x < y)
+    }
+
+    /// Moves all elements satisfying the given predicate into a suffix of the
+    /// collection, preserving the relative order of the elements in both
+    /// partitions, allocating buffer only as allowed by `policy`, and returns
+    /// the start of the resulting suffix.
+    ///
+    /// # Postcondition
+    ///   - If no element exists in suffix, returns `self.end()`.
+    ///   - Buffer of at most `n` elements is allocated, and only if `policy`
+    ///     allows it.
+    ///
+    /// # Complexity
+    ///   - O(n) if buffer is allocated, otherwise O(n * log(n)), where
+    ///     `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let i = arr.stable_partition_with(AllocPolicy::Unlimited, |x| x % 2 == 1);
+    /// assert_eq!(i, 2);
+    /// assert!(arr.equals(&[2, 4, 1, 3, 5]));
+    /// ```
+    fn stable_partition_with<F>(
+        &mut self,
+        policy: AllocPolicy,
+        belongs_in_second_partition: F,
+    ) -> Self::Position
+    where
+        Self::Element: Clone,
+        F: FnMut(&Self::Element) -> bool + Clone,
+    {
+        let element_size = std::mem::size_of::<Self::Element>();
+        let n = self.count();
+        let use_buffer = policy.allows_bytes(
+            estimate_stable_partition_scratch_bytes(n, element_size),
+        );
+        trace_span!(
+            "stable_partition_with",
+            count = n,
+            strategy = if use_buffer { "buffered" } else { "in_place" }
+        );
+        if use_buffer {
+            buffered::stable_partition_with_buffer(
+                self,
+                belongs_in_second_partition,
+            )
+        } else {
+            self.stable_partition(belongs_in_second_partition)
+        }
+    }
 }
 
 impl<R> MutableCollectionExt for R
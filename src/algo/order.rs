@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Dependency ordering of offsets of a random access collection.
+//!
+//! Nodes `0..n` are offsets of elements in a collection of `n` elements, and
+//! an edge `(u, v)` states that element at offset `u` should come before
+//! element at offset `v`, e.g., build step `u` should run before build step
+//! `v`.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{Collection, CollectionExt};
+
+/// Returns adjacency lists and number of incoming edges of each node.
+///
+/// # Precondition
+///   - Both ends of every edge are less than `n`.
+fn adjacency<E>(n: usize, edges: &E) -> (Vec<Vec<usize>>, Vec<usize>)
+where
+    E: Collection<Element = (usize, usize)> + ?Sized,
+{
+    let mut successors = vec![vec![]; n];
+    let mut in_degree = vec![0; n];
+    edges.for_each(|&(u, v)| {
+        assert!(u < n && v < n, "edge ({u}, {v}) is out of bounds");
+        successors[u].push(v);
+        in_degree[v] += 1;
+    });
+    (successors, in_degree)
+}
+
+/// Returns an order of nodes `0..n` in which `u` comes before `v` for every
+/// edge `(u, v)` in `edges`, or `None` if edges form a cycle.
+///
+/// # Precondition
+///   - Both ends of every edge are less than `n`.
+///
+/// # Complexity
+///   - O(n + e) where `e == edges.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let order = topological_sort(3, &[(2, 0), (0, 1)]).unwrap();
+/// assert_eq!(order, vec![2, 0, 1]);
+/// assert_eq!(topological_sort(2, &[(0, 1), (1, 0)]), None);
+/// ```
+pub fn topological_sort<E>(n: usize, edges: &E) -> Option<Vec<usize>>
+where
+    E: Collection<Element = (usize, usize)> + ?Sized,
+{
+    let (successors, mut in_degree) = adjacency(n, edges);
+    let mut res: Vec<usize> = (0..n).filter(|&u| in_degree[u] == 0).collect();
+    let mut i = 0;
+    while i < res.len() {
+        let u = res[i];
+        for &v in &successors[u] {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                res.push(v);
+            }
+        }
+        i += 1;
+    }
+    (res.len() == n).then_some(res)
+}
+
+/// Returns an order of nodes `0..n` in which `u` comes before `v` for every
+/// edge `(u, v)` in `edges`, or `None` if edges form a cycle.
+///
+/// Among all such orders, returns the lexicographically smallest one, so
+/// nodes not constrained by edges keep their original relative order.
+///
+/// # Precondition
+///   - Both ends of every edge are less than `n`.
+///
+/// # Complexity
+///   - O(n * log n + e) where `e == edges.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let order = stable_topo_sort(4, &[(3, 1)]).unwrap();
+/// assert_eq!(order, vec![0, 2, 3, 1]);
+/// ```
+pub fn stable_topo_sort<E>(n: usize, edges: &E) -> Option<Vec<usize>>
+where
+    E: Collection<Element = (usize, usize)> + ?Sized,
+{
+    let (successors, mut in_degree) = adjacency(n, edges);
+    let mut ready: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|&u| in_degree[u] == 0).map(Reverse).collect();
+    let mut res = Vec::with_capacity(n);
+    while let Some(Reverse(u)) = ready.pop() {
+        res.push(u);
+        for &v in &successors[u] {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                ready.push(Reverse(v));
+            }
+        }
+    }
+    (res.len() == n).then_some(res)
+}
@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Set algebra over ranges of positions.
+//!
+//! A range `(from, to)` represents the positions `p` with `from <= p < to`,
+//! i.e., the positions covered by `whole.slice(from, to)`. A collection of
+//! ranges is *normalized* if its ranges are non-empty, sorted by start and
+//! neither overlapping nor adjacent to each other.
+
+use crate::{Collection, CollectionExt};
+
+/// Pushes range `(from, to)` at the end of normalized `ranges`, coalescing it
+/// with the last range if they overlap or are adjacent.
+///
+/// # Precondition
+///   - `from` is not less than start of any range in `ranges`.
+fn push_coalesced<P>(ranges: &mut Vec<(P, P)>, from: P, to: P)
+where
+    P: Ord,
+{
+    if from >= to {
+        return;
+    }
+    match ranges.last_mut() {
+        Some(last) if from <= last.1 => {
+            if to > last.1 {
+                last.1 = to;
+            }
+        }
+        _ => ranges.push((from, to)),
+    }
+}
+
+/// Sorts ranges of `ranges` and coalesces overlapping or adjacent ones, such
+/// that `ranges` becomes normalized covering same positions as before.
+///
+/// # Postcondition
+///   - Empty ranges are removed.
+///
+/// # Complexity
+///   - O(n * log n) where `n == ranges.len()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut ranges = vec![(5, 7), (1, 3), (2, 4), (4, 4), (7, 8)];
+/// normalize_ranges(&mut ranges);
+/// assert_eq!(ranges, vec![(1, 4), (5, 8)]);
+/// ```
+pub fn normalize_ranges<P>(ranges: &mut Vec<(P, P)>)
+where
+    P: Ord,
+{
+    ranges.sort_unstable_by(|x, y| x.0.cmp(&y.0));
+    let mut res = Vec::with_capacity(ranges.len());
+    for (from, to) in ranges.drain(..) {
+        push_coalesced(&mut res, from, to);
+    }
+    *ranges = res;
+}
+
+/// Returns normalized ranges covering positions covered by either `a` or `b`.
+///
+/// # Precondition
+///   - `a` and `b` are normalized.
+///
+/// # Complexity
+///   - O(m + n) where `m == a.count()` and `n == b.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let a = [(1, 3), (6, 8)];
+/// let b = [(2, 4), (8, 9)];
+/// assert_eq!(union_of_ranges(&a, &b), vec![(1, 4), (6, 9)]);
+/// ```
+pub fn union_of_ranges<A, B, P>(a: &A, b: &B) -> Vec<(P, P)>
+where
+    A: Collection<Element = (P, P)> + ?Sized,
+    B: Collection<Element = (P, P)> + ?Sized,
+    P: Ord + Clone,
+{
+    let mut res =
+        Vec::with_capacity(a.underestimated_count() + b.underestimated_count());
+    let mut a = a.full();
+    let mut b = b.full();
+    loop {
+        let is_b_first = match (a.first(), b.first()) {
+            (Some(x), Some(y)) => y.0 < x.0,
+            (Some(_), None) => false,
+            (None, Some(_)) => true,
+            (None, None) => break,
+        };
+        if is_b_first {
+            let e = b.pop_first().unwrap();
+            push_coalesced(&mut res, e.0.clone(), e.1.clone());
+        } else {
+            let e = a.pop_first().unwrap();
+            push_coalesced(&mut res, e.0.clone(), e.1.clone());
+        }
+    }
+    res
+}
+
+/// Returns normalized ranges covering positions covered by both `a` and `b`.
+///
+/// # Precondition
+///   - `a` and `b` are normalized.
+///
+/// # Complexity
+///   - O(m + n) where `m == a.count()` and `n == b.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let a = [(1, 5), (7, 10)];
+/// let b = [(3, 8), (9, 12)];
+/// assert_eq!(intersection_of_ranges(&a, &b), vec![(3, 5), (7, 8), (9, 10)]);
+/// ```
+pub fn intersection_of_ranges<A, B, P>(a: &A, b: &B) -> Vec<(P, P)>
+where
+    A: Collection<Element = (P, P)> + ?Sized,
+    B: Collection<Element = (P, P)> + ?Sized,
+    P: Ord + Clone,
+{
+    let mut res = vec![];
+    let mut a = a.full();
+    let mut b = b.full();
+    loop {
+        let is_a_ending_first = match (a.first(), b.first()) {
+            (Some(x), Some(y)) => {
+                let from = std::cmp::max(&x.0, &y.0);
+                let to = std::cmp::min(&x.1, &y.1);
+                if from < to {
+                    res.push((from.clone(), to.clone()));
+                }
+                x.1 < y.1
+            }
+            _ => break,
+        };
+        if is_a_ending_first {
+            a.drop_first();
+        } else {
+            b.drop_first();
+        }
+    }
+    res
+}
+
+/// Returns normalized ranges covering positions in range `(from, to)` that
+/// are not covered by `ranges`.
+///
+/// # Precondition
+///   - `ranges` is normalized.
+///
+/// # Complexity
+///   - O(n) where `n == ranges.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let ranges = [(2, 4), (6, 7)];
+/// assert_eq!(
+///     complement_of_ranges(&ranges, 0, 10),
+///     vec![(0, 2), (4, 6), (7, 10)]
+/// );
+/// ```
+pub fn complement_of_ranges<C, P>(ranges: &C, from: P, to: P) -> Vec<(P, P)>
+where
+    C: Collection<Element = (P, P)> + ?Sized,
+    P: Ord + Clone,
+{
+    let mut res = vec![];
+    let mut current = from;
+    let mut rest = ranges.full();
+    while let Some(r) = rest.pop_first() {
+        if r.0 >= to {
+            break;
+        }
+        if r.0 > current {
+            res.push((current.clone(), r.0.clone()));
+        }
+        if r.1 > current {
+            current = r.1.clone();
+        }
+    }
+    if current < to {
+        res.push((current, to));
+    }
+    res
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+/// Maximum number of reports made during a run of an algorithm, excluding the
+/// final report.
+const MAX_REPORTS: usize = 100;
+
+/// Tracks progress of an algorithm over `total` units of work, and reports
+/// completed fraction of work to `report` at chunk granularity.
+pub(crate) struct Progress<Report>
+where
+    Report: FnMut(f32),
+{
+    /// Total units of work.
+    total: usize,
+
+    /// Units of work done till now.
+    done: usize,
+
+    /// Units of work done after which next report is made.
+    next_report_at: usize,
+
+    /// The callback receiving completed fraction of work.
+    report: Report,
+}
+
+impl<Report> Progress<Report>
+where
+    Report: FnMut(f32),
+{
+    /// Returns a tracker of `total` units of work.
+    pub(crate) fn new(total: usize, report: Report) -> Self {
+        let mut progress = Progress {
+            total,
+            done: 0,
+            next_report_at: 0,
+            report,
+        };
+        progress.next_report_at = progress.chunk();
+        progress
+    }
+
+    /// Returns units of work between consecutive reports.
+    fn chunk(&self) -> usize {
+        usize::max(self.total / MAX_REPORTS, 1)
+    }
+
+    /// Records `k` more units of work as done.
+    pub(crate) fn advance(&mut self, k: usize) {
+        self.done += k;
+        if self.done >= self.next_report_at && self.done < self.total {
+            (self.report)(self.done as f32 / self.total as f32);
+            self.next_report_at = self.done + self.chunk();
+        }
+    }
+
+    /// Reports completion of all work.
+    pub(crate) fn finish(mut self) {
+        (self.report)(1.0);
+    }
+}
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    Collection, RandomAccessCollection, ReorderableCollection,
+    ReorderableCollectionExt,
+};
+
+/// Restore the property of heap for subtree at offset `root`, where subtrees
+/// of children of `root` are already heaps.
+///
+/// # Precondition
+///   - `are_in_increasing_order` should follow a total preorder.
+///
+/// # Complexity
+///   - O(log n) where `n == elements.count()`.
+pub(crate) fn heapify<C, Compare>(
+    elements: &mut C,
+    mut root: usize,
+    are_in_increasing_order: Compare,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool,
+{
+    // Positions are computed from offsets less than `n`, so they are
+    // validated once here instead of on every access.
+    let n = elements.count();
+    loop {
+        let left_child = 2 * root + 1;
+        let right_child = 2 * root + 2;
+
+        let root_pos = elements.next_n(elements.start(), root);
+
+        let mut largest = root;
+        let mut largest_pos = root_pos.clone();
+
+        if left_child < n {
+            let left_pos = elements.next_n(elements.start(), left_child);
+            if are_in_increasing_order(
+                unsafe { &elements.at_unchecked(&largest_pos) },
+                unsafe { &elements.at_unchecked(&left_pos) },
+            ) {
+                largest_pos = left_pos;
+                largest = left_child;
+            }
+        }
+
+        if right_child < n {
+            let right_pos = elements.next_n(elements.start(), right_child);
+            if are_in_increasing_order(
+                unsafe { &elements.at_unchecked(&largest_pos) },
+                unsafe { &elements.at_unchecked(&right_pos) },
+            ) {
+                largest_pos = right_pos;
+                largest = right_child;
+            }
+        }
+
+        if largest == root {
+            break;
+        }
+
+        unsafe { elements.swap_at_unchecked(&root_pos, &largest_pos) };
+        root = largest;
+    }
+}
+
+/// Transforms `elements` into max heap according to `are_in_increasing_order`.
+///
+/// # Precondition
+///   - `are_in_increasing_order` follows total preorder.
+///
+/// # Complexity
+///   - O(n) where `n == elements.count()`.
+pub(crate) fn make_heap<C, Compare>(
+    elements: &mut C,
+    are_in_increasing_order: Compare,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    let n = elements.count();
+    for root in (0..n / 2).rev() {
+        heapify(elements, root, are_in_increasing_order.clone());
+    }
+}
+
+/// Moves the last element of `elements` to its correct place in the max heap
+/// formed by the rest of elements.
+///
+/// # Precondition
+///   - `elements.prefix_upto(elements.prior(elements.end()))` is a max heap
+///     according to `are_in_increasing_order`.
+///   - `are_in_increasing_order` follows total preorder.
+///
+/// # Complexity
+///   - O(log n) where `n == elements.count()`.
+pub(crate) fn push_heap<C, Compare>(
+    elements: &mut C,
+    are_in_increasing_order: Compare,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool,
+{
+    let n = elements.count();
+    if n <= 1 {
+        return;
+    }
+
+    let mut child = n - 1;
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        let parent_pos = elements.next_n(elements.start(), parent);
+        let child_pos = elements.next_n(elements.start(), child);
+        // Both offsets are less than `n`.
+        if !are_in_increasing_order(
+            unsafe { &elements.at_unchecked(&parent_pos) },
+            unsafe { &elements.at_unchecked(&child_pos) },
+        ) {
+            break;
+        }
+        unsafe { elements.swap_at_unchecked(&parent_pos, &child_pos) };
+        child = parent;
+    }
+}
+
+/// Moves the largest element of max heap `elements` to the last position and
+/// makes the rest of elements a max heap.
+///
+/// # Precondition
+///   - `elements` is a max heap according to `are_in_increasing_order`.
+///   - `are_in_increasing_order` follows total preorder.
+///
+/// # Complexity
+///   - O(log n) where `n == elements.count()`.
+pub(crate) fn pop_heap<C, Compare>(
+    elements: &mut C,
+    are_in_increasing_order: Compare,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool,
+{
+    if elements.count() <= 1 {
+        return;
+    }
+
+    let last = elements.prior(elements.end());
+    elements.swap_at(&elements.start(), &last);
+    heapify(
+        &mut elements.prefix_upto_mut(last),
+        0,
+        are_in_increasing_order,
+    );
+}
+
+/// Sorts the max heap `elements` in place.
+///
+/// # Precondition
+///   - `elements` is a max heap according to `are_in_increasing_order`.
+///   - `are_in_increasing_order` follows total preorder.
+///
+/// # Complexity
+///   - O(n * log(n)) where `n == elements.count()`.
+pub(crate) fn sort_heap<C, Compare>(
+    elements: &mut C,
+    are_in_increasing_order: Compare,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    let mut heap = elements.full_mut();
+    while heap.count() > 1 {
+        pop_heap(&mut heap, are_in_increasing_order.clone());
+        heap.drop_last();
+    }
+}
+
+/// Returns the position of first element in `elements` which violates the max
+/// heap property, or `elements.end()` if `elements` is a max heap.
+///
+/// # Precondition
+///   - `are_in_increasing_order` follows total preorder.
+///
+/// # Complexity
+///   - O(n) where `n == elements.count()`.
+pub(crate) fn is_heap_until<C, Compare>(
+    elements: &C,
+    are_in_increasing_order: Compare,
+) -> C::Position
+where
+    C: RandomAccessCollection + ?Sized,
+    C::Whole: RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool,
+{
+    let n = elements.count();
+    let mut child = 1;
+    while child < n {
+        let parent_pos = elements.next_n(elements.start(), (child - 1) / 2);
+        let child_pos = elements.next_n(elements.start(), child);
+        if are_in_increasing_order(
+            &elements.at(&parent_pos),
+            &elements.at(&child_pos),
+        ) {
+            return child_pos;
+        }
+        child += 1;
+    }
+    elements.end()
+}
@@ -1,8 +1,19 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
-use crate::{RandomAccessCollection, ReorderableCollection};
-mod sort;
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Sub},
+    time::Duration,
+};
+
+use crate::{
+    Deadline, MutableCollection, RandomAccessCollection, ReorderableCollection,
+    ReorderableCollectionExt, SliceMut, TimedOut,
+};
+mod heap;
+mod select;
+pub(crate) mod sort;
 
 /// Algorithms for `RandomAccessCollection`.
 pub trait RandomAccessCollectionExt: RandomAccessCollection
@@ -63,6 +74,1277 @@ where
     {
         self.sort_unstable_by(|x, y| x < y)
     }
+
+    /// Sorts the collection in place, comparing elements by keys extracted
+    /// with `key`.
+    ///
+    /// `key` is called O(n * log(n)) times, so it should be cheap. For
+    /// expensive keys, use `sort_by_cached_key`.
+    ///
+    /// # Postcondition:
+    ///   - Relative ordering of elements with equal keys are NOT guaranteed to be presevered.
+    ///
+    /// # Complexity:
+    ///   - O(n * log(n)) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [-3, 1, -2, 4];
+    /// arr.sort_unstable_by_key(|x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3, 4]);
+    /// ```
+    fn sort_unstable_by_key<Key, KeyFn>(&mut self, key: KeyFn)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Key: Ord,
+        KeyFn: Fn(&Self::Element) -> Key + Clone,
+    {
+        self.sort_unstable_by(move |x, y| key(x) < key(y))
+    }
+
+    /// Sorts the collection in place, comparing elements by keys extracted
+    /// with `key`, calling `key` only once per element.
+    ///
+    /// Keys are computed into a buffer along with original offsets of
+    /// elements, the buffer is sorted, and then elements are moved to their
+    /// sorted positions by swaps.
+    ///
+    /// # Postcondition:
+    ///   - Relative ordering of elements with equal keys are preserved.
+    ///   - `key` is called exactly once per element.
+    ///   - Buffer of `n` keys and offsets is allocated.
+    ///
+    /// # Complexity:
+    ///   - O(n * log(n)) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = ["ccc", "a", "bb", "d"];
+    /// arr.sort_by_cached_key(|s| s.len());
+    /// assert_eq!(arr, ["a", "d", "bb", "ccc"]);
+    /// ```
+    fn sort_by_cached_key<Key, KeyFn>(&mut self, mut key: KeyFn)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Key: Ord,
+        KeyFn: FnMut(&Self::Element) -> Key,
+    {
+        let start = self.start();
+        let n = self.count();
+        let mut keyed: Vec<(Key, usize)> = Vec::with_capacity(n);
+        let mut p = start.clone();
+        for i in 0..n {
+            keyed.push((key(&self.at(&p)), i));
+            self.form_next(&mut p);
+        }
+        keyed.sort_unstable();
+
+        // `keyed[i].1` is original offset of element belonging at `i`. Elements
+        // before `i` have been placed already, so an offset before `i` is
+        // followed to where its element has been moved.
+        for i in 0..n {
+            let mut j = keyed[i].1;
+            while j < i {
+                j = keyed[j].1;
+            }
+            keyed[i].1 = j;
+            let (x, y) =
+                (self.next_n(start.clone(), i), self.next_n(start.clone(), j));
+            self.swap_at(&x, &y);
+        }
+    }
+
+    /// Returns offsets of elements in the order they would be in if collection
+    /// were sorted, using the given predicate as comparision between elements.
+    ///
+    /// Collection is not mutated. The returned permutation can be applied to
+    /// this or any other collection of same length with `apply_permutation`,
+    /// e.g., to sort parallel arrays together.
+    ///
+    /// # Precondition:
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition:
+    ///   - Returns a permutation of `0..n`.
+    ///   - Offsets of equivalent elements are in increasing order.
+    ///
+    /// # Complexity:
+    ///   - O(n * log(n)) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [30, 10, 20];
+    /// assert_eq!(arr.sort_index_by(|x, y| x < y), [1, 2, 0]);
+    /// ```
+    fn sort_index_by<Compare>(
+        &self,
+        mut are_in_increasing_order: Compare,
+    ) -> Vec<usize>
+    where
+        Compare: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        let start = self.start();
+        let mut perm: Vec<usize> = (0..self.count()).collect();
+        perm.as_mut_slice().sort_by(|i, j| {
+            let x = self.at(&self.next_n(start.clone(), *i));
+            let y = self.at(&self.next_n(start.clone(), *j));
+            if are_in_increasing_order(&x, &y) {
+                Ordering::Less
+            } else if are_in_increasing_order(&y, &x) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+        perm
+    }
+
+    /// Returns offsets of elements in the order they would be in if collection
+    /// were sorted.
+    ///
+    /// # Postcondition:
+    ///   - Returns a permutation of `0..n`.
+    ///   - Offsets of equal elements are in increasing order.
+    ///
+    /// # Complexity:
+    ///   - O(n * log(n)) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let names = ["carol", "alice", "bob"];
+    /// let mut ages = [35, 30, 25];
+    /// let perm = names.sort_index();
+    /// ages.apply_permutation(&perm);
+    /// assert_eq!(ages, [30, 25, 35]);
+    /// ```
+    fn sort_index(&self) -> Vec<usize>
+    where
+        Self::Element: Ord,
+    {
+        self.sort_index_by(|x, y| x < y)
+    }
+
+    /// Reorders the collection in place such that element at offset `i` is the
+    /// element previously at offset `perm[i]`.
+    ///
+    /// Elements are moved by swaps along cycles of permutation.
+    ///
+    /// # Precondition
+    ///   - `perm` is a permutation of `0..n`.
+    ///
+    /// # Postcondition
+    ///   - Buffer of `n` flags is allocated to track visited offsets.
+    ///   - Panics if `perm` is not a permutation of `0..n`, leaving elements
+    ///     in an unspecified order.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = ['a', 'b', 'c', 'd'];
+    /// arr.apply_permutation(&[2, 0, 3, 1]);
+    /// assert_eq!(arr, ['c', 'a', 'd', 'b']);
+    /// ```
+    fn apply_permutation(&mut self, perm: &[usize])
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+    {
+        let n = self.count();
+        assert_eq!(perm.len(), n, "permutation should be of every element");
+        let start = self.start();
+        let mut visited = vec![false; n];
+        for i in 0..n {
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+            let mut j = i;
+            while perm[j] != i {
+                let k = perm[j];
+                assert!(!visited[k], "perm should be a permutation of 0..n");
+                let x = self.next_n(start.clone(), j);
+                let y = self.next_n(start.clone(), k);
+                self.swap_at(&x, &y);
+                visited[k] = true;
+                j = k;
+            }
+        }
+    }
+
+    /// Sorts the collection in place, using the given predicate as comparision
+    /// between elements, and calls `progress` with fraction of elements sorted
+    /// from time to time.
+    ///
+    /// Available with `progress` feature.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are NOT guaranteed to be
+    ///     presevered.
+    ///   - `progress` is called at most 101 times with increasing fractions in
+    ///     `[0, 1]`, the last call being with `1.0`.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 4, 1, 2, 5];
+    /// let mut last = 0.0;
+    /// arr.sort_unstable_by_with_progress(|x, y| x < y, |f| last = f);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// assert_eq!(last, 1.0);
+    /// ```
+    #[cfg(feature = "progress")]
+    fn sort_unstable_by_with_progress<Compare, OnProgress>(
+        &mut self,
+        are_in_increasing_order: Compare,
+        progress: OnProgress,
+    ) where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+        OnProgress: FnMut(f32),
+    {
+        let mut progress = crate::Progress::new(self.count(), progress);
+        sort::sort_unstable_by_reporting(
+            self,
+            are_in_increasing_order,
+            &mut |k| progress.advance(k),
+        );
+        progress.finish();
+    }
+
+    /// Sorts the collection in place, and calls `progress` with fraction of
+    /// elements sorted from time to time.
+    ///
+    /// Available with `progress` feature.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are NOT guaranteed to be
+    ///     presevered.
+    ///   - `progress` is called at most 101 times with increasing fractions in
+    ///     `[0, 1]`, the last call being with `1.0`.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 4, 1, 2, 5];
+    /// let mut calls = 0;
+    /// arr.sort_unstable_with_progress(|_| calls += 1);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// assert!(calls >= 1);
+    /// ```
+    #[cfg(feature = "progress")]
+    fn sort_unstable_with_progress<OnProgress>(&mut self, progress: OnProgress)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+        OnProgress: FnMut(f32),
+    {
+        self.sort_unstable_by_with_progress(|x, y| x < y, progress)
+    }
+
+    /// Sorts the collection in place, using the given predicate as comparision
+    /// between elements, giving up with `Err(TimedOut)` if sorting doesn't
+    /// finish within `timeout`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are NOT guaranteed to be
+    ///     presevered.
+    ///   - On timeout, `self` is a permutation of original elements, whose
+    ///     suffix holds greatest elements in sorted order.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut arr = [3, 4, 1, 2, 5];
+    /// let res = arr
+    ///     .sort_unstable_by_with_deadline(|x, y| x < y, Duration::from_secs(1));
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn sort_unstable_by_with_deadline<Compare>(
+        &mut self,
+        are_in_increasing_order: Compare,
+        timeout: Duration,
+    ) -> Result<(), TimedOut>
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        let mut deadline = Deadline::after(timeout);
+        sort::heap_sort_with_deadline(
+            self,
+            are_in_increasing_order,
+            &mut deadline,
+        )
+    }
+
+    /// Sorts the collection in place, giving up with `Err(TimedOut)` if
+    /// sorting doesn't finish within `timeout`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of equivalent elements are NOT guaranteed to be
+    ///     presevered.
+    ///   - On timeout, `self` is a permutation of original elements, whose
+    ///     suffix holds greatest elements in sorted order.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut arr = [3, 4, 1, 2, 5];
+    /// assert_eq!(arr.sort_unstable_with_deadline(Duration::from_secs(1)), Ok(()));
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn sort_unstable_with_deadline(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), TimedOut>
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.sort_unstable_by_with_deadline(|x, y| x < y, timeout)
+    }
+
+    /*-----------------Heap Algorithms-----------------*/
+
+    /// Rearranges elements of `self` into a max heap according to
+    /// `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - For every element at offset `i`, elements at offset `2i + 1` and
+    ///     `2i + 2` (if exist) are not greater than it.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 1, 4, 1, 5];
+    /// arr.make_heap_by(|x, y| x > y);
+    /// assert!(arr.is_heap_by(|x, y| x > y));
+    /// assert_eq!(arr[0], 1);
+    /// ```
+    fn make_heap_by<Compare>(&mut self, are_in_increasing_order: Compare)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        if self.start() != self.end() {
+            heap::make_heap(self, are_in_increasing_order);
+        }
+    }
+
+    /// Rearranges elements of `self` into a max heap.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 1, 4, 1, 5];
+    /// arr.make_heap();
+    /// assert!(arr.is_heap());
+    /// assert_eq!(arr[0], 5);
+    /// ```
+    fn make_heap(&mut self)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.make_heap_by(|x, y| x < y)
+    }
+
+    /// Inserts the last element of `self` into the max heap formed by rest of
+    /// the elements, according to `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - All elements except the last one form a max heap according to
+    ///     `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut v = vec![1, 3, 2];
+    /// v.push(0);
+    /// v.push_heap_by(|x, y| x > y);
+    /// assert_eq!(v[0], 0);
+    /// assert!(v.is_heap_by(|x, y| x > y));
+    /// ```
+    fn push_heap_by<Compare>(&mut self, are_in_increasing_order: Compare)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        heap::push_heap(self, are_in_increasing_order);
+    }
+
+    /// Inserts the last element of `self` into the max heap formed by rest of
+    /// the elements.
+    ///
+    /// # Precondition
+    ///   - All elements except the last one form a max heap.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut v = vec![3, 1, 2];
+    /// v.push(4);
+    /// v.push_heap();
+    /// assert_eq!(v[0], 4);
+    /// assert!(v.is_heap());
+    /// ```
+    fn push_heap(&mut self)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.push_heap_by(|x, y| x < y)
+    }
+
+    /// Moves the greatest element of max heap `self` according to
+    /// `are_in_increasing_order` to the last position, and makes the rest of
+    /// elements a max heap.
+    ///
+    /// # Precondition
+    ///   - `self` is a max heap according to `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut v = vec![1, 2, 3];
+    /// v.pop_heap_by(|x, y| x > y);
+    /// assert_eq!(v.pop(), Some(1));
+    /// assert!(v.is_heap_by(|x, y| x > y));
+    /// ```
+    fn pop_heap_by<Compare>(&mut self, are_in_increasing_order: Compare)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        heap::pop_heap(self, are_in_increasing_order);
+    }
+
+    /// Moves the greatest element of max heap `self` to the last position, and
+    /// makes the rest of elements a max heap.
+    ///
+    /// # Precondition
+    ///   - `self` is a max heap.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut v = vec![3, 1, 2];
+    /// v.pop_heap();
+    /// assert_eq!(v.pop(), Some(3));
+    /// assert!(v.is_heap());
+    /// ```
+    fn pop_heap(&mut self)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.pop_heap_by(|x, y| x < y)
+    }
+
+    /// Sorts the max heap `self` in place according to
+    /// `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `self` is a max heap according to `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// arr.sort_heap_by(|x, y| x > y);
+    /// assert_eq!(arr, [4, 3, 2, 1]);
+    /// ```
+    fn sort_heap_by<Compare>(&mut self, are_in_increasing_order: Compare)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        heap::sort_heap(self, are_in_increasing_order);
+    }
+
+    /// Sorts the max heap `self` in place.
+    ///
+    /// # Precondition
+    ///   - `self` is a max heap.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [5, 3, 4, 1, 2];
+    /// arr.sort_heap();
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn sort_heap(&mut self)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.sort_heap_by(|x, y| x < y)
+    }
+
+    /// Returns the position of first element in `self` that violates the max
+    /// heap property according to `are_in_increasing_order`, or `self.end()`
+    /// if `self` is a max heap.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 0];
+    /// assert_eq!(arr.is_heap_until_by(|x, y| x > y), 3);
+    /// ```
+    fn is_heap_until_by<Compare>(
+        &self,
+        are_in_increasing_order: Compare,
+    ) -> Self::Position
+    where
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        heap::is_heap_until(self, are_in_increasing_order)
+    }
+
+    /// Returns the position of first element in `self` that violates the max
+    /// heap property, or `self.end()` if `self` is a max heap.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 3, 4, 6];
+    /// assert_eq!(arr.is_heap_until(), 3);
+    /// ```
+    fn is_heap_until(&self) -> Self::Position
+    where
+        Self::Element: Ord,
+    {
+        self.is_heap_until_by(|x, y| x < y)
+    }
+
+    /// Returns true if `self` is a max heap according to
+    /// `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3];
+    /// assert!(arr.is_heap_by(|x, y| x > y));
+    /// ```
+    fn is_heap_by<Compare>(&self, are_in_increasing_order: Compare) -> bool
+    where
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        self.is_heap_until_by(are_in_increasing_order) == self.end()
+    }
+
+    /// Returns true if `self` is a max heap.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [5, 3, 4, 1, 2];
+    /// assert!(arr.is_heap());
+    /// ```
+    fn is_heap(&self) -> bool
+    where
+        Self::Element: Ord,
+    {
+        self.is_heap_by(|x, y| x < y)
+    }
+
+    /*-----------------Partition Algorithms-----------------*/
+
+    /// Partitions `self` in three parts around element at `pivot`, i.e.,
+    /// elements less than it, equivalent to it, and greater than it, and
+    /// returns positions `(p, q)` such that these parts are
+    /// `self.prefix_upto(p)`, `self.slice(p, q)` and `self.suffix_from(q)`.
+    ///
+    /// Uses Bentley-McIlroy fat pivot partitioning, which swaps equivalent
+    /// elements only twice, so it remains efficient for collections with
+    /// many repeated elements.
+    ///
+    /// # Precondition
+    ///   - `pivot` is a valid position of an element in `self`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - `p < q`, as part equivalent to pivot contains at least pivot.
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [3, 5, 1, 3, 4, 3, 2];
+    /// let (p, q) = arr.partition_around_pivot_by(0, |x, y| x < y);
+    /// assert_eq!((p, q), (2, 5));
+    /// assert!(arr[..p].iter().all(|x| *x < 3));
+    /// assert_eq!(arr[p..q], [3, 3, 3]);
+    /// assert!(arr[q..].iter().all(|x| *x > 3));
+    /// ```
+    fn partition_around_pivot_by<Compare>(
+        &mut self,
+        pivot: Self::Position,
+        are_in_increasing_order: Compare,
+    ) -> (Self::Position, Self::Position)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool,
+    {
+        let start = self.start();
+        let n = self.count();
+        self.swap_at(&start, &pivot);
+        if n == 1 {
+            return (start.clone(), self.end());
+        }
+
+        // Offsets are signed as `j` goes before start while moving elements
+        // equivalent to pivot to middle.
+        let at = |c: &Self, k: isize| c.next_n(start.clone(), k as usize);
+        let less = |c: &Self, x: isize, y: isize| {
+            are_in_increasing_order(&c.at(&at(c, x)), &c.at(&at(c, y)))
+        };
+        let equiv = |c: &Self, x: isize| !less(c, x, 0) && !less(c, 0, x);
+        let swap = |c: &mut Self, x: isize, y: isize| {
+            let (x, y) = (at(c, x), at(c, y));
+            c.swap_at(&x, &y)
+        };
+
+        // Pivot stays at offset 0 while scanning. Elements equivalent to
+        // pivot are gathered at both ends, in `[1, p]` and `[q, r]`.
+        let r = n as isize - 1;
+        let (mut i, mut j, mut p, mut q) = (0, r + 1, 0, r + 1);
+        loop {
+            loop {
+                i += 1;
+                if !less(self, i, 0) || i == r {
+                    break;
+                }
+            }
+            loop {
+                j -= 1;
+                if !less(self, 0, j) || j == 0 {
+                    break;
+                }
+            }
+            if i == j && equiv(self, i) {
+                p += 1;
+                swap(self, p, i);
+            }
+            if i >= j {
+                break;
+            }
+            swap(self, i, j);
+            if equiv(self, i) {
+                p += 1;
+                swap(self, p, i);
+            }
+            if equiv(self, j) {
+                q -= 1;
+                swap(self, q, j);
+            }
+        }
+
+        // Move gathered equivalent elements to middle.
+        i = j + 1;
+        for k in 0..=p {
+            swap(self, k, j);
+            j -= 1;
+        }
+        for k in (q..=r).rev() {
+            swap(self, k, i);
+            i += 1;
+        }
+        (at(self, j + 1), at(self, i))
+    }
+
+    /// Partitions `self` in three parts around element at `pivot`, i.e.,
+    /// elements less than it, equal to it, and greater than it, and returns
+    /// positions `(p, q)` such that these parts are `self.prefix_upto(p)`,
+    /// `self.slice(p, q)` and `self.suffix_from(q)`.
+    ///
+    /// # Precondition
+    ///   - `pivot` is a valid position of an element in `self`.
+    ///
+    /// # Postcondition
+    ///   - `p < q`, as part equal to pivot contains at least pivot.
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// // Quickselect: find 4th smallest element.
+    /// let mut arr = vec![7, 2, 9, 2, 5, 7, 1];
+    /// let (mut from, mut to) = (0, arr.len());
+    /// let k = 3;
+    /// let kth = loop {
+    ///     let mid = from + (to - from) / 2;
+    ///     let (p, q) = arr.slice_mut(from, to).partition_around_pivot(mid);
+    ///     if k < p {
+    ///         to = p;
+    ///     } else if k >= q {
+    ///         from = q;
+    ///     } else {
+    ///         break arr[k];
+    ///     }
+    /// };
+    /// assert_eq!(kth, 5);
+    /// ```
+    fn partition_around_pivot(
+        &mut self,
+        pivot: Self::Position,
+    ) -> (Self::Position, Self::Position)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.partition_around_pivot_by(pivot, |x, y| x < y)
+    }
+
+    /*-----------------Selection Algorithms-----------------*/
+
+    /// Reorders `self` such that the element at `at` is the element that
+    /// would be there if `self` were sorted according to
+    /// `are_in_increasing_order`, no element before it is greater than it, and
+    /// no element after it is less than it.
+    ///
+    /// Uses quickselect with `partition_around_pivot_by`.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position of an element in `self`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) on average where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [7, 2, 9, 2, 5, 7, 1];
+    /// arr.nth_element_by(3, |x, y| x > y);
+    /// assert_eq!(arr[3], 5);
+    /// assert!(arr[..3].iter().all(|x| *x >= 5));
+    /// assert!(arr[4..].iter().all(|x| *x <= 5));
+    /// ```
+    fn nth_element_by<Compare>(
+        &mut self,
+        at: Self::Position,
+        are_in_increasing_order: Compare,
+    ) where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        assert!(at < self.end(), "position should be of an element");
+        let (mut from, mut to) = (self.start(), self.end());
+        loop {
+            let n = self.distance(from.clone(), to.clone());
+            let pivot = self.next_n(from.clone(), n / 2);
+            let (p, q) = self
+                .slice_mut(from.clone(), to.clone())
+                .partition_around_pivot_by(
+                    pivot,
+                    are_in_increasing_order.clone(),
+                );
+            if at < p {
+                to = p;
+            } else if at >= q {
+                from = q;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Reorders `self` such that the element at `at` is the element that
+    /// would be there if `self` were sorted, no element before it is greater
+    /// than it, and no element after it is less than it.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position of an element in `self`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) on average where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// // Median of odd number of elements.
+    /// let mut arr = [7, 2, 9, 2, 5, 7, 1];
+    /// arr.nth_element(3);
+    /// assert_eq!(arr[3], 5);
+    /// ```
+    fn nth_element(&mut self, at: Self::Position)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.nth_element_by(at, |x, y| x < y)
+    }
+
+    /// Reorders `self` such that the element at `at` is the element that
+    /// would be there if `self` were sorted according to
+    /// `are_in_increasing_order`, no element before it is greater than it, and
+    /// no element after it is less than it.
+    ///
+    /// Unlike `nth_element_by`, pivot is chosen by median of medians, so
+    /// selection takes linear time even for adversarial inputs, at cost of a
+    /// larger constant factor.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position of an element in `self`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [7, 2, 9, 2, 5, 7, 1];
+    /// arr.nth_element_deterministic_by(3, |x, y| x > y);
+    /// assert_eq!(arr[3], 5);
+    /// assert!(arr[..3].iter().all(|x| *x >= 5));
+    /// assert!(arr[4..].iter().all(|x| *x <= 5));
+    /// ```
+    fn nth_element_deterministic_by<Compare>(
+        &mut self,
+        at: Self::Position,
+        are_in_increasing_order: Compare,
+    ) where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        assert!(at < self.end(), "position should be of an element");
+        let k = self.distance(self.start(), at);
+        select::select_by_median_of_medians(self, k, are_in_increasing_order);
+    }
+
+    /// Reorders `self` such that the element at `at` is the element that
+    /// would be there if `self` were sorted, no element before it is greater
+    /// than it, and no element after it is less than it.
+    ///
+    /// Unlike `nth_element`, selection takes linear time even for adversarial
+    /// inputs.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position of an element in `self`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) worst case where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [7, 2, 9, 2, 5, 7, 1];
+    /// arr.nth_element_deterministic(3);
+    /// assert_eq!(arr[3], 5);
+    /// ```
+    fn nth_element_deterministic(&mut self, at: Self::Position)
+    where
+        Self: ReorderableCollection,
+        Self::Whole: ReorderableCollection,
+        Self::Element: Ord,
+    {
+        self.nth_element_deterministic_by(at, |x, y| x < y)
+    }
+
+    /// Reorders `self` as `nth_element_by` does, and returns the mutable
+    /// slice before `at`, mutable reference to element at `at`, and the
+    /// mutable slice after `at`.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position of an element in `self`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) on average where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// let (less, e, greater) =
+    ///     arr.select_nth_unstable_by(1, |x, y| x < y);
+    /// assert_eq!(*e, 2);
+    /// assert!(less.equals(&[1]));
+    /// assert!(greater.all_satisfy(|x| *x > 2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn select_nth_unstable_by<Compare>(
+        &mut self,
+        at: Self::Position,
+        are_in_increasing_order: Compare,
+    ) -> (
+        SliceMut<'_, Self::Whole>,
+        &mut Self::Element,
+        SliceMut<'_, Self::Whole>,
+    )
+    where
+        Self: MutableCollection,
+        Self::Whole: MutableCollection,
+        Compare: Fn(&Self::Element, &Self::Element) -> bool + Clone,
+    {
+        self.nth_element_by(at.clone(), are_in_increasing_order);
+        let (left, mut right) = self.splitting_at_mut(at);
+        let e = right.pop_first_mut().unwrap();
+        (left, e, right)
+    }
+
+    /// Reorders `self` as `nth_element` does, and returns the mutable slice
+    /// before `at`, mutable reference to element at `at`, and the mutable
+    /// slice after `at`.
+    ///
+    /// # Precondition
+    ///   - `at` is a valid position of an element in `self`.
+    ///
+    /// # Postcondition
+    ///   - Relative ordering of elements is NOT preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) on average where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// let (less, e, greater) = arr.select_nth_unstable(2);
+    /// *e *= 10;
+    /// assert!(less.all_satisfy(|x| *x < 3));
+    /// assert!(greater.all_satisfy(|x| *x > 3));
+    /// assert_eq!(arr[2], 30);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn select_nth_unstable(
+        &mut self,
+        at: Self::Position,
+    ) -> (
+        SliceMut<'_, Self::Whole>,
+        &mut Self::Element,
+        SliceMut<'_, Self::Whole>,
+    )
+    where
+        Self: MutableCollection,
+        Self::Whole: MutableCollection,
+        Self::Element: Ord,
+    {
+        self.select_nth_unstable_by(at, |x, y| x < y)
+    }
+
+    /*-----------------Two Pointer Algorithms-----------------*/
+
+    /// Finds positions `(i, j)` of two distinct elements of sorted `self`
+    /// whose sum is `target`, with `i` before `j`. If no such pair exists,
+    /// returns `None`.
+    ///
+    /// Moves a position from each end of collection towards the other, so
+    /// pair with the leftmost possible `i` is found.
+    ///
+    /// # Precondition
+    ///   - `self` is sorted in increasing order.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 4, 7, 11, 15];
+    /// assert_eq!(arr.find_pair_summing_to(&15), Some((2, 4)));
+    /// assert_eq!(arr.find_pair_summing_to(&100), None);
+    /// ```
+    fn find_pair_summing_to(
+        &self,
+        target: &Self::Element,
+    ) -> Option<(Self::Position, Self::Position)>
+    where
+        Self::Element: Ord + Clone + Add<Output = Self::Element>,
+    {
+        let mut i = self.start();
+        let mut j = self.end();
+        if i == j {
+            return None;
+        }
+        self.form_prior(&mut j);
+        while i < j {
+            let sum = self.at(&i).clone() + self.at(&j).clone();
+            match sum.cmp(target) {
+                Ordering::Less => self.form_next(&mut i),
+                Ordering::Greater => self.form_prior(&mut j),
+                Ordering::Equal => return Some((i, j)),
+            }
+        }
+        None
+    }
+
+    /// Returns number of pairs of distinct elements `x` before `y` in sorted
+    /// `self` satisfying `pred(x, y)`.
+    ///
+    /// # Precondition
+    ///   - `self` is sorted in increasing order.
+    ///   - `pred` is monotonic, i.e., if `pred(x, y)` holds, then `pred(a, b)`
+    ///     holds for all `a <= x` and `b <= y`, e.g., `|x, y| x + y < k`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// assert_eq!(arr.count_pairs_where(|x, y| x + y <= 6), 6);
+    /// ```
+    fn count_pairs_where<Pred>(&self, mut pred: Pred) -> usize
+    where
+        Pred: FnMut(&Self::Element, &Self::Element) -> bool,
+    {
+        let mut i = self.start();
+        let mut j = self.end();
+        if i == j {
+            return 0;
+        }
+        self.form_prior(&mut j);
+        let mut count = 0;
+        while i < j {
+            if pred(&self.at(&i), &self.at(&j)) {
+                // `x` at `i` pairs with every element upto `j`.
+                count += self.distance(i.clone(), j.clone());
+                self.form_next(&mut i);
+            } else {
+                self.form_prior(&mut j);
+            }
+        }
+        count
+    }
+
+    /*-----------------Geometric Algorithms-----------------*/
+
+    /// Returns positions of elements of `self` forming vertices of convex hull
+    /// of points `(x_fn(e), y_fn(e))`, in counter-clockwise order starting from
+    /// point with least x (and least y among them).
+    ///
+    /// Points lying on edges of hull are excluded, and only one position is
+    /// returned for repeated points.
+    ///
+    /// # Precondition
+    ///   - Coordinates are totally ordered (e.g., not NaN), and computing
+    ///     cross product of their differences doesn't overflow.
+    ///
+    /// # Complexity
+    ///   - O(n * log(n)) where `n == self.count()`, allocating positions and
+    ///     coordinates of all points.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let points = [(0, 0), (2, 2), (1, 1), (2, 0), (0, 2), (1, 0)];
+    /// let hull = points.convex_hull_by(|p| p.0, |p| p.1);
+    /// assert_eq!(hull, vec![0, 3, 1, 4]);
+    /// ```
+    fn convex_hull_by<T, XFn, YFn>(
+        &self,
+        x_fn: XFn,
+        y_fn: YFn,
+    ) -> Vec<Self::Position>
+    where
+        T: Copy + PartialOrd + Sub<Output = T> + Mul<Output = T>,
+        XFn: Fn(&Self::Element) -> T,
+        YFn: Fn(&Self::Element) -> T,
+    {
+        let mut points = Vec::with_capacity(self.count());
+        let mut i = self.start();
+        let end = self.end();
+        while i != end {
+            let e = self.at(&i);
+            points.push((x_fn(&e), y_fn(&e), i.clone()));
+            self.form_next(&mut i);
+        }
+        RandomAccessCollectionExt::sort_unstable_by(&mut points, |p, q| {
+            p.0 < q.0 || (p.0 == q.0 && p.1 < q.1)
+        });
+        points.dedup_by(|p, q| p.0 == q.0 && p.1 == q.1);
+        if points.len() < 3 {
+            return points.into_iter().map(|p| p.2).collect();
+        }
+
+        // Returns true if `o -> a -> b` doesn't turn counter-clockwise.
+        let is_not_left_turn = |o: &(T, T, _), a: &(T, T, _), b: &(T, T, _)| {
+            (a.0 - o.0) * (b.1 - o.1) <= (a.1 - o.1) * (b.0 - o.0)
+        };
+
+        // Lower hull from left to right, then upper hull from right to left,
+        // never popping points of lower hull while building upper hull.
+        let mut hull: Vec<&(T, T, Self::Position)> = vec![];
+        for p in &points {
+            while hull.len() >= 2
+                && is_not_left_turn(
+                    hull[hull.len() - 2],
+                    hull[hull.len() - 1],
+                    p,
+                )
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        let lower_len = hull.len();
+        for p in points.iter().rev().skip(1) {
+            while hull.len() > lower_len
+                && is_not_left_turn(
+                    hull[hull.len() - 2],
+                    hull[hull.len() - 1],
+                    p,
+                )
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+        hull.into_iter().map(|p| p.2.clone()).collect()
+    }
 }
 
 impl<R> RandomAccessCollectionExt for R
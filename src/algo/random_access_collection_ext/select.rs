@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use super::sort::insertion_sort;
+use crate::{
+    RandomAccessCollection, RandomAccessCollectionExt, ReorderableCollection,
+};
+
+/// Reorders `c` such that element at offset `k` is the element that would be
+/// there if `c` were sorted, using median of medians as pivot for
+/// partitioning.
+///
+/// # Precondition
+///   - `k < c.count()`.
+///   - `are_in_increasing_order` should follow strict weak ordering.
+///
+/// # Complexity
+///   - O(n) worst case where `n == c.count()`.
+pub(crate) fn select_by_median_of_medians<C, Compare>(
+    c: &mut C,
+    k: usize,
+    are_in_increasing_order: Compare,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    let at = |c: &C, i: usize| c.next_n(c.start(), i);
+    let (mut from, mut to) = (0, c.count());
+    loop {
+        let n = to - from;
+        if n <= 5 {
+            insertion_sort(
+                &mut c.slice_mut(at(c, from), at(c, to)),
+                are_in_increasing_order.clone(),
+            );
+            return;
+        }
+
+        // Median of each group of 5 elements is moved to the front of range.
+        // Front positions only belong to groups already visited.
+        let num_groups = n.div_ceil(5);
+        for g in 0..num_groups {
+            let group_from = from + 5 * g;
+            let group_to = (group_from + 5).min(to);
+            insertion_sort(
+                &mut c.slice_mut(at(c, group_from), at(c, group_to)),
+                are_in_increasing_order.clone(),
+            );
+            let median = group_from + (group_to - group_from) / 2;
+            c.swap_at(&at(c, from + g), &at(c, median));
+        }
+
+        // Pivot is median of group medians, so at least 3/10 of elements are
+        // on each side of it.
+        select_by_median_of_medians(
+            &mut c.slice_mut(at(c, from), at(c, from + num_groups)),
+            num_groups / 2,
+            are_in_increasing_order.clone(),
+        );
+        let pivot = at(c, from + num_groups / 2);
+        let (p, q) = c
+            .slice_mut(at(c, from), at(c, to))
+            .partition_around_pivot_by(pivot, are_in_increasing_order.clone());
+        let (p, q) = (c.distance(c.start(), p), c.distance(c.start(), q));
+        if k < p {
+            to = p;
+        } else if k >= q {
+            from = q;
+        } else {
+            return;
+        }
+    }
+}
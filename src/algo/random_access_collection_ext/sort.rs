@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use super::heap::{heapify, make_heap, pop_heap};
 use crate::{
-    BidirectionalCollection, Collection, CollectionExt, RandomAccessCollection,
-    ReorderableCollection, ReorderableCollectionExt,
+    trace_span, BidirectionalCollection, Collection, CollectionExt, Deadline,
+    RandomAccessCollection, ReorderableCollection, ReorderableCollectionExt,
+    TimedOut,
 };
 
 /// Sorts the collection in place, using the given predicate as comparision between elements.
@@ -23,18 +25,67 @@ pub(crate) fn sort_unstable_by<C, Compare>(
     C: ReorderableCollection + RandomAccessCollection + ?Sized,
     C::Whole: ReorderableCollection + RandomAccessCollection,
     Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    sort_unstable_by_reporting(
+        collection,
+        are_in_increasing_order,
+        &mut |_| {},
+    );
+}
+
+/// Sorts the collection in place, using the given predicate as comparision between elements,
+/// and calls `on_sorted(k)` whenever `k` more elements reach their final position.
+///
+/// # Precondition:
+///   - `are_in_increasing_order` should follow strict weak ordering.
+///
+/// # Postcondition:
+///   - Relative ordering of equivalent elements are NOT guaranteed to be presevered.
+///   - Sum of all `k` passed to `on_sorted` is `collection.count()`.
+///
+/// # Complexity:
+///   - O(n * log(n)) worst case where `n == collection.count()`.
+pub(crate) fn sort_unstable_by_reporting<C, Compare, OnSorted>(
+    collection: &mut C,
+    are_in_increasing_order: Compare,
+    on_sorted: &mut OnSorted,
+) where
+    C: ReorderableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: ReorderableCollection + RandomAccessCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+    OnSorted: FnMut(usize),
 {
     let n = collection.count();
     if n <= 16 {
+        trace_span!("sort_unstable", count = n, strategy = "insertion_sort");
         insertion_sort(collection, are_in_increasing_order);
+        on_sorted(n);
     } else {
+        trace_span!("sort_unstable", count = n, strategy = "introsort");
         let quick_sort_depth = 2 * n.ilog2() as usize;
-        if !quick_sort_within(
+        let mut num_sorted = 0;
+        let is_sorted = quick_sort_within(
             collection,
             are_in_increasing_order.clone(),
             quick_sort_depth,
-        ) {
-            heap_sort(collection, are_in_increasing_order);
+            &mut |k| {
+                num_sorted += k;
+                on_sorted(k);
+            },
+        );
+        if !is_sorted {
+            trace_span!("heap_sort_fallback", count = n);
+            // Heap sort places all elements again, so remaining elements are
+            // reported in proportion to elements it places.
+            let remaining = n - num_sorted;
+            let mut placed = 0;
+            let mut reported = 0;
+            heap_sort(collection, are_in_increasing_order, &mut |k| {
+                placed += k;
+                let to_report = placed * remaining / n;
+                on_sorted(to_report - reported);
+                reported = to_report;
+            });
         }
     }
 }
@@ -66,12 +117,15 @@ pub(crate) fn insertion_sort<C, Compare>(
     while sorted_end != end {
         let mut i = sorted_end.clone();
         loop {
+            // `start() <= j < i < end()`, so both positions are valid.
             let j = collection.prior(i.clone());
-            if !are_in_increasing_order(&collection.at(&i), &collection.at(&j))
-            {
+            if !are_in_increasing_order(
+                unsafe { &collection.at_unchecked(&i) },
+                unsafe { &collection.at_unchecked(&j) },
+            ) {
                 break;
             }
-            collection.swap_at(&i, &j);
+            unsafe { collection.swap_at_unchecked(&i, &j) };
             i = j;
             if i == collection.start() {
                 break;
@@ -90,22 +144,27 @@ pub(crate) fn insertion_sort<C, Compare>(
 ///
 /// # Postcondition:
 ///   - Relative ordering of equivalent elements are NOT guaranteed to be preserved.
+///   - Calls `on_sorted(k)` whenever `k` more elements reach their final position.
 ///
 /// # Complexity:
 ///   - O(n * `depth`) where `n == collection.count()`.
-pub(crate) fn quick_sort_within<C, Compare>(
+pub(crate) fn quick_sort_within<C, Compare, OnSorted>(
     collection: &mut C,
     are_in_increasing_order: Compare,
     depth: usize,
+    on_sorted: &mut OnSorted,
 ) -> bool
 where
     C: ReorderableCollection + RandomAccessCollection + ?Sized,
     C::Whole: ReorderableCollection + RandomAccessCollection,
     Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+    OnSorted: FnMut(usize),
 {
-    if collection.start() == collection.end()
-        || collection.next(collection.start()) == collection.end()
-    {
+    if collection.start() == collection.end() {
+        return true;
+    }
+    if collection.next(collection.start()) == collection.end() {
+        on_sorted(1);
         return true;
     }
 
@@ -125,136 +184,95 @@ where
     // Fix posiiton of first element.
     let partition_point = collection.prior(p);
     collection.swap_at(&start, &partition_point);
+    on_sorted(1);
 
     // Quick sort both parts.
     let left = quick_sort_within(
         &mut collection.prefix_upto_mut(partition_point.clone()),
         are_in_increasing_order.clone(),
         depth - 1,
+        on_sorted,
     );
 
     let right = quick_sort_within(
         &mut collection.suffix_from_mut(partition_point),
         are_in_increasing_order,
         depth - 1,
+        on_sorted,
     );
 
     left && right
 }
 
-/// Restore the property of heap where all elements are heap except the root.
-///
-/// # Precondition
-///   - `are_in_increasing_order` should follow a total preorder.
+/// Sorts the `elements` in place, using the given predicate as comparision between elements,
+/// and calls `on_sorted(k)` whenever `k` more elements reach their final position.
 ///
-/// # Complexity
-///   - O(log n) where `n == elements.count()`.
-pub(crate) fn heapify<C, Compare>(
-    elements: &mut C,
-    are_in_increasing_order: Compare,
-) where
-    C: ReorderableCollection + RandomAccessCollection + ?Sized,
-    C::Whole: ReorderableCollection + RandomAccessCollection,
-    Compare: Fn(&C::Element, &C::Element) -> bool,
-{
-    let n = elements.count();
-    let mut root = 0;
-    loop {
-        let left_child = 2 * root + 1;
-        let right_child = 2 * root + 2;
-
-        let root_pos = elements.next_n(elements.start(), root);
-
-        let mut largest = root;
-        let mut largest_pos = root_pos.clone();
-
-        if left_child < n {
-            let left_pos = elements.next_n(elements.start(), left_child);
-            if are_in_increasing_order(
-                &elements.at(&largest_pos),
-                &elements.at(&left_pos),
-            ) {
-                largest_pos = left_pos;
-                largest = left_child;
-            }
-        }
-
-        if right_child < n {
-            let right_pos = elements.next_n(elements.start(), right_child);
-            if are_in_increasing_order(
-                &elements.at(&largest_pos),
-                &elements.at(&right_pos),
-            ) {
-                largest_pos = right_pos;
-                largest = right_child;
-            }
-        }
-
-        if largest == root {
-            break;
-        }
-
-        elements.swap_at(&root_pos, &largest_pos);
-        root = largest;
-    }
-}
-
-/// Transforms `elements` into max heap according to `are_in_increasing_order`.
+/// # Precondition:
+///   - `are_in_increasing_order` should follow total preorder.
 ///
-/// # Precondition
-///   - `are_in_increasing_order` follows total preorder.
+/// # Postcondition:
+///   - Relative ordering of equivalent elements are NOT guaranteed to be presevered.
+///   - Sum of all `k` passed to `on_sorted` is `elements.count()`.
 ///
-/// # Complexity
-///   - O(n) where `n == elements.count()`.
-pub(crate) fn make_heap<C, Compare>(
+/// # Complexity:
+///   - O(n * log(n)) worst case where `n == elements.count()`.
+pub(crate) fn heap_sort<C, Compare, OnSorted>(
     elements: &mut C,
     are_in_increasing_order: Compare,
+    on_sorted: &mut OnSorted,
 ) where
     C: ReorderableCollection + RandomAccessCollection + ?Sized,
     C::Whole: ReorderableCollection + RandomAccessCollection,
     Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+    OnSorted: FnMut(usize),
 {
-    let n = elements.count();
-
-    let mut cur = elements.next_n(elements.start(), n / 2);
-    loop {
-        heapify(
-            &mut elements.suffix_from_mut(cur.clone()),
-            are_in_increasing_order.clone(),
-        );
-        if cur == elements.start() {
-            break;
-        }
-        elements.form_prior(&mut cur);
+    make_heap(elements, are_in_increasing_order.clone());
+    let mut heap = elements.full_mut();
+    while heap.count() > 1 {
+        pop_heap(&mut heap, are_in_increasing_order.clone());
+        heap.drop_last();
+        on_sorted(1);
     }
+    on_sorted(heap.count());
 }
 
-/// Sorts the `elements` in place, using the given predicate as comparision between elements.
+/// Sorts the `elements` in place by heap sort, using the given predicate as
+/// comparision between elements, and stops with `Err(TimedOut)` once
+/// `deadline` has passed.
 ///
 /// # Precondition:
 ///   - `are_in_increasing_order` should follow total preorder.
 ///
 /// # Postcondition:
 ///   - Relative ordering of equivalent elements are NOT guaranteed to be presevered.
+///   - On timeout, `elements` is a permutation of original elements, whose
+///     suffix holds greatest elements in sorted order.
 ///
 /// # Complexity:
 ///   - O(n * log(n)) worst case where `n == elements.count()`.
-pub(crate) fn heap_sort<C, Compare>(
+pub(crate) fn heap_sort_with_deadline<C, Compare>(
     elements: &mut C,
     are_in_increasing_order: Compare,
-) where
+    deadline: &mut Deadline,
+) -> Result<(), TimedOut>
+where
     C: ReorderableCollection + RandomAccessCollection + ?Sized,
     C::Whole: ReorderableCollection + RandomAccessCollection,
     Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
 {
-    make_heap(elements, are_in_increasing_order.clone());
+    let n = elements.count();
+    for root in (0..n / 2).rev() {
+        heapify(elements, root, are_in_increasing_order.clone());
+        deadline.step()?;
+    }
 
     let mut heap = elements.full_mut();
     while heap.count() > 1 {
-        heap.swap_at(&heap.start(), &heap.prior(heap.end()));
+        pop_heap(&mut heap, are_in_increasing_order.clone());
         heap.drop_last();
-        heapify(&mut heap, are_in_increasing_order.clone());
+        deadline.step()?;
     }
+    Ok(())
 }
 
 mod tests {
@@ -264,6 +282,7 @@ mod tests {
         crate::algo::random_access_collection_ext::sort::heap_sort(
             &mut arr,
             |x, y| x < y,
+            &mut |_| {},
         );
         assert_eq!(arr, [1, 2, 3, 4]);
 
@@ -271,6 +290,7 @@ mod tests {
         crate::algo::random_access_collection_ext::sort::heap_sort(
             &mut arr,
             |x, y| x < y,
+            &mut |_| {},
         );
         assert_eq!(arr, [1]);
 
@@ -278,6 +298,7 @@ mod tests {
         crate::algo::random_access_collection_ext::sort::heap_sort(
             &mut arr,
             |x, y| x < y,
+            &mut |_| {},
         );
         assert_eq!(arr, []);
     }
@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::ops::Range;
+
 use crate::algo::collection_ext::CollectionExt;
 use crate::iterators::{SplitEvenlyIteratorMut, SplitWhereIteratorMut};
-use crate::{ReorderableCollection, SliceMut};
+use crate::{trace_span, ReorderableCollection, SliceMut};
 mod stable_partition;
 use stable_partition::*;
 
@@ -268,6 +270,32 @@ where
         self.slice_mut(from, self.end())
     }
 
+    /// Returns mutable slice of `self` covering elements with offsets in
+    /// `range`.
+    ///
+    /// # Precondition
+    ///   - `range.start <= range.end <= self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(range.end) otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// arr.slice_at_offsets_mut(1..3).reverse();
+    /// assert_eq!(arr, [1, 3, 2, 4, 5]);
+    /// ```
+    fn slice_at_offsets_mut(
+        &mut self,
+        range: Range<usize>,
+    ) -> SliceMut<'_, Self::Whole> {
+        let from = self.position_at(range.start);
+        let to = self.next_n(from.clone(), range.end - range.start);
+        self.slice_mut(from, to)
+    }
+
     /// Returns two disjoint mutable slices of `self` split at the given `position`.
     ///
     /// # Examples
@@ -512,6 +540,40 @@ where
         write_pos
     }
 
+    /// Moves all elements satisfying the given predicate into a suffix of the
+    /// collection, and returns disjoint mutable slices of the resulting prefix
+    /// and suffix.
+    ///
+    /// # Postcondition
+    ///   - First slice contains all elements not satisfying predicate, and
+    ///     second slice contains all elements satisfying predicate.
+    ///   - Relative ordering of elements in the partitions are not preserved.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let (mut evens, mut odds) = arr.partition_views_mut(|x| x % 2 == 1);
+    /// evens.for_each_mut(|x| *x /= 2);
+    /// odds.for_each_mut(|x| *x = 0);
+    /// assert!(arr.prefix(2).all_satisfy(|x| *x == 1 || *x == 2));
+    /// assert!(arr.suffix(3).all_satisfy(|x| *x == 0));
+    /// ```
+    fn partition_views_mut<F>(
+        &mut self,
+        belongs_in_second_partition: F,
+    ) -> (SliceMut<'_, Self::Whole>, SliceMut<'_, Self::Whole>)
+    where
+        F: FnMut(&Self::Element) -> bool + Clone,
+    {
+        let p = self.partition(belongs_in_second_partition);
+        self.splitting_at_mut(p)
+    }
+
     /// Moves all elements satisfying the given predicate into a suffix of the
     /// given range, preserving the relative order of the elements in both
     /// partitions, and returns the start of the resulting suffix.
@@ -539,7 +601,54 @@ where
         F: FnMut(&Self::Element) -> bool + Clone,
     {
         let n = self.count();
-        stable_partition(self, belongs_in_second_partition, n)
+        trace_span!("stable_partition", count = n);
+        stable_partition(self, belongs_in_second_partition, n, &mut |_| {})
+    }
+
+    /// Moves all elements satisfying the given predicate into a suffix of the
+    /// given range, preserving the relative order of the elements in both
+    /// partitions, and returns the start of the resulting suffix. Calls
+    /// `progress` with fraction of elements partitioned from time to time.
+    ///
+    /// Available with `progress` feature.
+    ///
+    /// # Postcondition
+    ///   - If no element exists in suffix, returns `self.end()`.
+    ///   - `progress` is called at most 101 times with increasing fractions in
+    ///     `[0, 1]`, the last call being with `1.0`.
+    ///
+    /// # Complexity
+    ///   - O(n log(n)) where `n == self.count()`
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut last = 0.0;
+    /// let i = arr.stable_partition_with_progress(|x| x % 2 == 1, |f| last = f);
+    /// assert_eq!(i, 2);
+    /// assert!(arr.equals(&[2, 4, 1, 3, 5]));
+    /// assert_eq!(last, 1.0);
+    /// ```
+    #[cfg(feature = "progress")]
+    fn stable_partition_with_progress<F, OnProgress>(
+        &mut self,
+        belongs_in_second_partition: F,
+        progress: OnProgress,
+    ) -> Self::Position
+    where
+        F: FnMut(&Self::Element) -> bool + Clone,
+        OnProgress: FnMut(f32),
+    {
+        let n = self.count();
+        let mut progress = crate::Progress::new(n, progress);
+        let p =
+            stable_partition(self, belongs_in_second_partition, n, &mut |k| {
+                progress.advance(k)
+            });
+        progress.finish();
+        p
     }
 }
 
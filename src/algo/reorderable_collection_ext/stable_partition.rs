@@ -13,23 +13,27 @@ use crate::ReorderableCollection;
 ///
 /// # Postcondition
 ///   - If no element exists in suffix, returns `c.end()`.
+///   - Calls `on_partitioned(k)` whenever `k` more elements are partitioned.
 ///
 /// # Complexity
 ///   - O(n log(n))
-pub fn stable_partition<C, F>(
+pub fn stable_partition<C, F, OnPartitioned>(
     c: &mut C,
     mut belongs_in_second_partition: F,
     n: usize,
+    on_partitioned: &mut OnPartitioned,
 ) -> C::Position
 where
     C: ReorderableCollection + ?Sized,
     C::Whole: ReorderableCollection,
     F: FnMut(&C::Element) -> bool + Clone,
+    OnPartitioned: FnMut(usize),
 {
     if n == 0 {
         return c.start();
     }
     if n == 1 {
+        on_partitioned(1);
         if belongs_in_second_partition(&c.at(&c.start())) {
             return c.start();
         } else {
@@ -43,11 +47,13 @@ where
         &mut c.prefix_upto_mut(i.clone()),
         belongs_in_second_partition.clone(),
         h,
+        on_partitioned,
     );
     let k = stable_partition(
         &mut c.suffix_from_mut(i.clone()),
         belongs_in_second_partition,
         n - h,
+        on_partitioned,
     );
 
     c.slice_mut(j, k).rotate(i)
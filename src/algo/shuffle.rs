@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Random shuffling of random access collections.
+//!
+//! Randomness is supplied by caller as `rng`, a function returning uniformly
+//! random 64 bit words on every call. Parallel parts draw their seed from
+//! `rng` and generate their own random words from it.
+
+use crate::{
+    Collection, ParallelCollectionExt, RandomAccessCollection,
+    ReorderableCollection, SliceMut,
+};
+
+/// SplitMix64 generator, used by each parallel part to generate random words
+/// from its seed.
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a generator seeded with `seed`.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns next random word.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns uniformly random number in `0..bound`.
+    ///
+    /// # Precondition
+    ///   - `bound > 0`.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        // Lemire's multiply and reject, so that result is unbiased.
+        let bound = bound as u64;
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let m = (self.next_u64() as u128) * (bound as u128);
+            if m as u64 >= threshold {
+                return (m >> 64) as usize;
+            }
+        }
+    }
+}
+
+/// Shuffles `s` uniformly at random by Fisher-Yates algorithm.
+///
+/// # Complexity
+///   - O(n) where `n == s.count()`.
+pub(crate) fn fisher_yates<Whole>(
+    mut s: SliceMut<'_, Whole>,
+    rng: &mut SplitMix64,
+) where
+    Whole: ReorderableCollection<Whole = Whole> + RandomAccessCollection,
+{
+    let start = s.start();
+    let n = s.count();
+    for i in (1..n).rev() {
+        let j = rng.below(i + 1);
+        let (i, j) = (s.next_n(start.clone(), i), s.next_n(start.clone(), j));
+        s.swap_at(&i, &j);
+    }
+}
+
+/// Merges uniformly shuffled `s.prefix(m)` and uniformly shuffled
+/// `s.dropping_prefix(m)` such that `s` is uniformly shuffled.
+///
+/// Elements are taken from either part by coin flips till one part is
+/// exhausted, then rest of elements are inserted at random positions of
+/// merged prefix, as in MergeShuffle algorithm.
+///
+/// # Precondition
+///   - `m <= s.count()`.
+///
+/// # Complexity
+///   - O(n) on average where `n == s.count()`.
+pub(crate) fn merge_shuffled<Whole>(
+    mut s: SliceMut<'_, Whole>,
+    m: usize,
+    rng: &mut SplitMix64,
+) where
+    Whole: ReorderableCollection<Whole = Whole> + RandomAccessCollection,
+{
+    let start = s.start();
+    let n = s.count();
+    let swap = |s: &mut SliceMut<'_, Whole>, x: usize, y: usize| {
+        let (x, y) = (s.next_n(start.clone(), x), s.next_n(start.clone(), y));
+        s.swap_at(&x, &y)
+    };
+
+    let (mut i, mut j) = (0, m);
+    loop {
+        if rng.next_u64() & 1 == 1 {
+            if j == n {
+                break;
+            }
+            swap(&mut s, i, j);
+            j += 1;
+        } else if i == j {
+            break;
+        }
+        i += 1;
+    }
+    while i < n {
+        let k = rng.below(i + 1);
+        swap(&mut s, i, k);
+        i += 1;
+    }
+}
+
+/// Returns a uniformly random permutation of `0..n`.
+///
+/// `rng` should return uniformly random 64 bit words. Permutation is
+/// generated in parallel using `parallel_shuffle`.
+///
+/// # Complexity
+///   - O(n).
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut state = 42u64;
+/// let rng = || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// };
+/// let mut p = random_permutation(5, rng);
+/// p.sort();
+/// assert_eq!(p, vec![0, 1, 2, 3, 4]);
+/// ```
+pub fn random_permutation<Rng>(n: usize, rng: Rng) -> Vec<usize>
+where
+    Rng: FnMut() -> u64,
+{
+    let mut res: Vec<usize> = (0..n).collect();
+    res.parallel_shuffle(rng);
+    res
+}
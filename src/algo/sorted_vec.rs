@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::CollectionExt;
+
+/// Inserts `element` into sorted `vec` wrt `are_in_increasing_order`, such
+/// that `vec` remains sorted, and returns the position of inserted element.
+///
+/// # Precondition
+///   - `are_in_increasing_order` should follow strict weak ordering.
+///   - `vec` is sorted wrt `are_in_increasing_order`.
+///
+/// # Postcondition
+///   - `element` is inserted after all elements equivalent to it, thus
+///     repeated insertions preserve insertion order of equivalent elements.
+///
+/// # Complexity
+///   - O(log n) comparisons and O(n) moves where `n == vec.len()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut v = vec![(1, 'a'), (3, 'b'), (5, 'c')];
+/// let i = insert_sorted_by(&mut v, (3, 'd'), |x, y| x.0 < y.0);
+/// assert_eq!(i, 2);
+/// assert_eq!(v, vec![(1, 'a'), (3, 'b'), (3, 'd'), (5, 'c')]);
+/// ```
+pub fn insert_sorted_by<T, Compare>(
+    vec: &mut Vec<T>,
+    element: T,
+    are_in_increasing_order: Compare,
+) -> usize
+where
+    Compare: Fn(&T, &T) -> bool,
+{
+    let i = CollectionExt::partition_point(vec, |x| {
+        are_in_increasing_order(&element, x)
+    });
+    vec.insert(i, element);
+    i
+}
+
+/// Inserts `element` into sorted `vec`, such that `vec` remains sorted, and
+/// returns the position of inserted element.
+///
+/// # Precondition
+///   - `vec` is sorted.
+///
+/// # Postcondition
+///   - `element` is inserted after all elements equal to it.
+///
+/// # Complexity
+///   - O(log n) comparisons and O(n) moves where `n == vec.len()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut v = vec![1, 3, 5];
+/// insort(&mut v, 4);
+/// insort(&mut v, 0);
+/// assert_eq!(v, vec![0, 1, 3, 4, 5]);
+/// ```
+pub fn insort<T>(vec: &mut Vec<T>, element: T) -> usize
+where
+    T: Ord,
+{
+    insert_sorted_by(vec, element, |x, y| x < y)
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+/// Enters a `tracing` span at debug level with given name and fields, which is
+/// exited at end of the enclosing block.
+///
+/// Expands to nothing without `tracing` feature. Fields are evaluated only if
+/// span is enabled by subscriber, so they can be costly, e.g., `count`.
+macro_rules! trace_span {
+    ($name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($name $(, $($fields)*)?).entered();
+    };
+}
+
+pub(crate) use trace_span;
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::mem::ManuallyDrop;
+
+use crate::{MutableCollection, OutputCollection};
+
+/// A fixed-capacity temporary buffer for algorithms, filled from both ends.
+///
+/// Elements are pushed either to the first part, growing from start of
+/// buffer, or to the second part, growing from end of buffer, so that an
+/// algorithm distributing `n` elements in two parts needs a single allocation
+/// of `n` elements. Elements are moved out with `into_parts`, `into_iter` or
+/// `write_into`; elements left in buffer are dropped with it.
+///
+/// Representation:
+/// ```text
+///   f f f _ _ _ s s
+///
+///   ^     ^     ^   ^
+///   |     |     |   |
+/// start  first second capacity
+///          end  start
+/// ```
+pub struct TempBuffer<T> {
+    /// The storage, whose length is always zero; elements live in its spare
+    /// capacity.
+    storage: Vec<T>,
+
+    /// Number of elements the buffer can hold.
+    capacity: usize,
+
+    /// Number of elements in first part.
+    first_len: usize,
+
+    /// Start index of second part, which ends at `capacity`.
+    second_start: usize,
+}
+
+impl<T> TempBuffer<T> {
+    /// Returns an empty buffer which can hold `capacity` elements.
+    ///
+    /// # Complexity
+    ///   - O(1) besides allocation.
+    pub fn with_capacity(capacity: usize) -> Self {
+        TempBuffer {
+            storage: Vec::with_capacity(capacity),
+            capacity,
+            first_len: 0,
+            second_start: capacity,
+        }
+    }
+
+    /// Returns number of elements the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns number of elements in buffer.
+    pub fn len(&self) -> usize {
+        self.first_len + (self.capacity - self.second_start)
+    }
+
+    /// Returns true if buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if buffer can't hold any more elements.
+    pub fn is_full(&self) -> bool {
+        self.first_len == self.second_start
+    }
+
+    /// Appends `e` to the first part.
+    ///
+    /// # Precondition
+    ///   - `!self.is_full()`.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut buffer = buffer::TempBuffer::with_capacity(2);
+    /// buffer.push(1);
+    /// buffer.push(2);
+    /// assert!(buffer.is_full());
+    /// assert_eq!(buffer.into_parts(), (vec![1, 2], vec![]));
+    /// ```
+    pub fn push(&mut self, e: T) {
+        assert!(!self.is_full(), "TempBuffer is full");
+        self.storage.spare_capacity_mut()[self.first_len].write(e);
+        self.first_len += 1;
+    }
+
+    /// Appends `e` to the second part.
+    ///
+    /// # Precondition
+    ///   - `!self.is_full()`.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut buffer = buffer::TempBuffer::with_capacity(4);
+    /// for x in 1..5 {
+    ///     if x % 2 == 0 {
+    ///         buffer.push_second(x);
+    ///     } else {
+    ///         buffer.push(x);
+    ///     }
+    /// }
+    /// assert_eq!(buffer.into_parts(), (vec![1, 3], vec![2, 4]));
+    /// ```
+    pub fn push_second(&mut self, e: T) {
+        assert!(!self.is_full(), "TempBuffer is full");
+        self.second_start -= 1;
+        self.storage.spare_capacity_mut()[self.second_start].write(e);
+    }
+
+    /// Moves elements out of buffer as Vec of first part and Vec of second
+    /// part, each in the order of pushing.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of elements in second part; first part
+    ///     reuses the buffer allocation.
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        let mut this = ManuallyDrop::new(self);
+        let mut first = std::mem::take(&mut this.storage);
+        let second_len = this.capacity - this.second_start;
+        // SAFETY: both parts are initialized, and moving second part to just
+        // after first part makes `[0, first_len + second_len)` initialized.
+        unsafe {
+            let p = first.as_mut_ptr();
+            std::ptr::copy(
+                p.add(this.second_start),
+                p.add(this.first_len),
+                second_len,
+            );
+            first.set_len(this.first_len + second_len);
+        }
+        let mut second = first.split_off(this.first_len);
+        second.reverse();
+        (first, second)
+    }
+
+    /// Moves elements of first part and then of second part, each in the
+    /// order of pushing, to `dest` starting at `from`, and returns the
+    /// position just after the last written element.
+    ///
+    /// Algorithms use it to merge buffered elements back to a collection.
+    ///
+    /// # Precondition
+    ///   - `dest` has at least `self.len()` positions starting at `from`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.len()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [0; 4];
+    /// let mut buffer = buffer::TempBuffer::with_capacity(2);
+    /// buffer.push(1);
+    /// buffer.push_second(2);
+    /// assert_eq!(buffer.write_into(&mut arr, 1), 3);
+    /// assert_eq!(arr, [0, 1, 2, 0]);
+    /// ```
+    pub fn write_into<C>(self, dest: &mut C, from: C::Position) -> C::Position
+    where
+        C: MutableCollection<Element = T> + ?Sized,
+        C::Whole: MutableCollection,
+    {
+        let mut write = from;
+        for e in self {
+            *dest.at_mut(&write) = e;
+            dest.form_next(&mut write);
+        }
+        write
+    }
+}
+
+impl<T> Drop for TempBuffer<T> {
+    fn drop(&mut self) {
+        let spare = self.storage.spare_capacity_mut();
+        // SAFETY: exactly first and second parts are initialized.
+        unsafe {
+            for e in &mut spare[..self.first_len] {
+                e.assume_init_drop();
+            }
+            for e in &mut spare[self.second_start..self.capacity] {
+                e.assume_init_drop();
+            }
+        }
+    }
+}
+
+impl<T> IntoIterator for TempBuffer<T> {
+    type Item = T;
+
+    type IntoIter =
+        std::iter::Chain<std::vec::IntoIter<T>, std::vec::IntoIter<T>>;
+
+    /// Returns an iterator moving out elements of first part and then of
+    /// second part, each in the order of pushing.
+    fn into_iter(self) -> Self::IntoIter {
+        let (first, second) = self.into_parts();
+        first.into_iter().chain(second)
+    }
+}
+
+/// Writing to a TempBuffer appends to its first part.
+impl<T> OutputCollection for TempBuffer<T> {
+    type Element = T;
+
+    fn write_next(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.push(value);
+        Ok(())
+    }
+}
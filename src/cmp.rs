@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Combinators building `are_in_increasing_order` comparators.
+//!
+//! Comparators built here follow strict weak ordering whenever their parts
+//! do, and can be passed to any `_by` algorithm, e.g.,
+//! `cmp::by_key(|p: &(u32, &str)| p.0).then_by_key(|p| p.1).reversed()`.
+//!
+//! Built comparators are `Clone` so that they can be used with algorithms
+//! requiring `Clone` comparators, e.g., `stable_sort_by`; thus all parts
+//! should be `Clone`, as closures usually are.
+
+/// Returns comparator ordering elements by their key `key(e)`.
+///
+/// Type of element needs to be annotated in `key` if it can't be inferred
+/// from its body.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let mut arr = [(2, 'a'), (1, 'b'), (3, 'c')];
+/// arr.sort_unstable_by(cmp::by_key(|x: &(i32, char)| x.0));
+/// assert_eq!(arr, [(1, 'b'), (2, 'a'), (3, 'c')]);
+/// ```
+pub fn by_key<T, Key, KeyFn>(key: KeyFn) -> impl Fn(&T, &T) -> bool + Clone
+where
+    T: ?Sized,
+    Key: Ord,
+    KeyFn: Fn(&T) -> Key + Clone,
+{
+    move |x, y| key(x) < key(y)
+}
+
+/// Extension methods combining comparators.
+///
+/// Implemented for every `are_in_increasing_order` comparator.
+pub trait ComparatorExt<T>: Fn(&T, &T) -> bool + Clone
+where
+    T: ?Sized,
+{
+    /// Returns comparator ordering elements by `self`, and elements
+    /// equivalent wrt `self` by `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `self` and `are_in_increasing_order` follow strict weak ordering.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::cmp::ComparatorExt;
+    ///
+    /// let mut arr = [(1, 'b'), (0, 'z'), (1, 'a')];
+    /// let by_first = |x: &(i32, char), y: &(i32, char)| x.0 < y.0;
+    /// arr.sort_unstable_by(by_first.then_by(|x, y| x.1 < y.1));
+    /// assert_eq!(arr, [(0, 'z'), (1, 'a'), (1, 'b')]);
+    /// ```
+    fn then_by<Compare>(
+        self,
+        are_in_increasing_order: Compare,
+    ) -> impl Fn(&T, &T) -> bool + Clone
+    where
+        Compare: Fn(&T, &T) -> bool + Clone,
+    {
+        move |x, y| self(x, y) || (!self(y, x) && are_in_increasing_order(x, y))
+    }
+
+    /// Returns comparator ordering elements by `self`, and elements
+    /// equivalent wrt `self` by their key `key(e)`.
+    ///
+    /// # Precondition
+    ///   - `self` follows strict weak ordering.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::cmp::ComparatorExt;
+    ///
+    /// let mut words = ["bb", "c", "ab", "a"];
+    /// words.sort_unstable_by(
+    ///     cmp::by_key(|w: &&str| w.len()).then_by_key(|w| *w),
+    /// );
+    /// assert_eq!(words, ["a", "c", "ab", "bb"]);
+    /// ```
+    fn then_by_key<Key, KeyFn>(
+        self,
+        key: KeyFn,
+    ) -> impl Fn(&T, &T) -> bool + Clone
+    where
+        Key: Ord,
+        KeyFn: Fn(&T) -> Key + Clone,
+    {
+        self.then_by(by_key(key))
+    }
+
+    /// Returns comparator ordering elements in opposite order of `self`.
+    ///
+    /// # Postcondition
+    ///   - Elements equivalent wrt `self` are equivalent wrt returned
+    ///     comparator, so stable algorithms keep their relative order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::cmp::ComparatorExt;
+    ///
+    /// let mut arr = [(2, 'a'), (1, 'b'), (2, 'c')];
+    /// arr.stable_sort_by(
+    ///     AllocPolicy::Unlimited,
+    ///     cmp::by_key(|x: &(i32, char)| x.0).reversed(),
+    /// );
+    /// assert_eq!(arr, [(2, 'a'), (2, 'c'), (1, 'b')]);
+    /// ```
+    fn reversed(self) -> impl Fn(&T, &T) -> bool + Clone {
+        move |x, y| self(y, x)
+    }
+}
+
+impl<T, F> ComparatorExt<T> for F
+where
+    T: ?Sized,
+    F: Fn(&T, &T) -> bool + Clone,
+{
+}
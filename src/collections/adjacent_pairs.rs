@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::cmp::Ordering;
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    Slice,
+};
+
+/// A lazy collection presenting pairs of neighboring elements of `base`,
+/// i.e., `(e1, e2), (e2, e3), ..., (e(n-1), en)`.
+pub struct AdjacentPairsCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    /// The base collection.
+    pub base: C,
+}
+
+/// Position type of AdjacentPairsCollection.
+///
+/// Positions are compared by `current` only, as `previous` is determined by
+/// it.
+#[derive(Clone)]
+pub struct AdjacentPairsPosition<P> {
+    /// Position in base collection of first element of pair, meaningless for
+    /// end position.
+    pub previous: P,
+
+    /// Position in base collection of second element of pair.
+    pub current: P,
+}
+
+impl<P: PartialEq> PartialEq for AdjacentPairsPosition<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.current == other.current
+    }
+}
+
+impl<P: Eq> Eq for AdjacentPairsPosition<P> {}
+
+impl<P: Ord> PartialOrd for AdjacentPairsPosition<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Ord> Ord for AdjacentPairsPosition<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.current.cmp(&other.current)
+    }
+}
+
+impl<C> AdjacentPairsCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    /// Returns a new instance of AdjacentPairsCollection presenting pairs of
+    /// neighboring elements of `base`.
+    pub fn new(base: C) -> Self {
+        AdjacentPairsCollection { base }
+    }
+}
+
+impl<C> Collection for AdjacentPairsCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    type Position = AdjacentPairsPosition<C::Position>;
+
+    type Element = (C::Element, C::Element);
+
+    type ElementRef<'a>
+        = ValueRef<(C::Element, C::Element)>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        let start = self.base.start();
+        if start == self.base.end() {
+            return self.end();
+        }
+        AdjacentPairsPosition {
+            current: self.base.next(start.clone()),
+            previous: start,
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        AdjacentPairsPosition {
+            previous: self.base.end(),
+            current: self.base.end(),
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        position.previous = position.current.clone();
+        self.base.form_next(&mut position.current);
+    }
+
+    fn count(&self) -> usize {
+        self.base.count().saturating_sub(1)
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count().saturating_sub(1)
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new((
+            self.base.at(&i.previous).clone(),
+            self.base.at(&i.current).clone(),
+        ))
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> BidirectionalCollection for AdjacentPairsCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: Clone,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.base.form_prior(&mut position.current);
+        position.previous = self.base.prior(position.current.clone());
+    }
+}
+
+impl<C> LazyCollection for AdjacentPairsCollection<C>
+where
+    C: LazyCollection,
+    C::Whole: LazyCollection,
+    C::Element: Clone,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        (
+            self.base.compute_at(&i.previous),
+            self.base.compute_at(&i.current),
+        )
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    CollectionExt, MutableCollection, RandomAccessCollection,
+    RandomAccessCollectionExt, ReorderableCollectionExt, Slice,
+};
+
+/// A priority queue maintaining max heap invariant over prefix of `base`
+/// collection.
+///
+/// The adaptor doesn't own any memory by itself. The heap occupies the first
+/// `len()` elements of `base`, while the rest of `base` serves as spare
+/// capacity for pushing new elements.
+pub struct BinaryHeapAdaptor<C, Compare>
+where
+    C: RandomAccessCollection + MutableCollection,
+    C::Whole: RandomAccessCollection + MutableCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    /// The base collection.
+    base: C,
+
+    /// Number of elements in heap.
+    len: usize,
+
+    /// The ordering of elements in heap.
+    are_in_increasing_order: Compare,
+}
+
+impl<C> BinaryHeapAdaptor<C, fn(&C::Element, &C::Element) -> bool>
+where
+    C: RandomAccessCollection + MutableCollection,
+    C::Whole: RandomAccessCollection + MutableCollection,
+    C::Element: Ord,
+{
+    /// Creates a max heap of all elements of `base`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == base.count()`.
+    pub fn new(base: C) -> Self {
+        Self::new_by(base, |x, y| x < y)
+    }
+}
+
+impl<C, Compare> BinaryHeapAdaptor<C, Compare>
+where
+    C: RandomAccessCollection + MutableCollection,
+    C::Whole: RandomAccessCollection + MutableCollection,
+    Compare: Fn(&C::Element, &C::Element) -> bool + Clone,
+{
+    /// Creates a max heap of all elements of `base` according to
+    /// `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == base.count()`.
+    pub fn new_by(mut base: C, are_in_increasing_order: Compare) -> Self {
+        base.make_heap_by(are_in_increasing_order.clone());
+        let len = base.count();
+        Self {
+            base,
+            len,
+            are_in_increasing_order,
+        }
+    }
+
+    /// Creates an empty heap using all elements of `base` as spare capacity.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    pub fn empty_by(base: C, are_in_increasing_order: Compare) -> Self {
+        Self {
+            base,
+            len: 0,
+            are_in_increasing_order,
+        }
+    }
+
+    /// Returns number of elements in heap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if heap has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns maximum number of elements heap can hold.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn capacity(&self) -> usize {
+        self.base.count()
+    }
+
+    /// Returns the greatest element of heap, or None if heap is empty.
+    pub fn peek(&self) -> Option<C::ElementRef<'_>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.base.at(&self.base.start()))
+        }
+    }
+
+    /// Inserts `element` into heap.
+    ///
+    /// # Precondition
+    ///   - `self.len() < self.capacity()`.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.len()`.
+    pub fn push(&mut self, element: C::Element) {
+        let p = self.base.next_n(self.base.start(), self.len);
+        *self.base.at_mut(&p) = element;
+        self.len += 1;
+        self.base
+            .prefix_mut(self.len)
+            .push_heap_by(self.are_in_increasing_order.clone());
+    }
+
+    /// Removes the greatest element from heap and returns it, or None if heap
+    /// is empty.
+    ///
+    /// # Postcondition
+    ///   - The removed element is placed just after the heap in `base`.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.len()`.
+    pub fn pop(&mut self) -> Option<C::ElementRef<'_>> {
+        if self.is_empty() {
+            return None;
+        }
+        self.base
+            .prefix_mut(self.len)
+            .pop_heap_by(self.are_in_increasing_order.clone());
+        self.len -= 1;
+        let p = self.base.next_n(self.base.start(), self.len);
+        Some(self.base.at(&p))
+    }
+
+    /// Returns slice of `base` containing elements of heap in heap order.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn as_slice(&self) -> Slice<'_, C::Whole> {
+        self.base.prefix(self.len)
+    }
+
+    /// Returns the base collection.
+    ///
+    /// # Postcondition
+    ///   - The first `self.len()` elements of base collection forms a max heap.
+    pub fn into_base(self) -> C {
+        self.base
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::RandomAccessCollectionExt;
+
+/// An accumulator retaining the `k` greatest elements inserted into it.
+///
+/// Elements are kept in a min heap of size atmost `k`, so that inserting
+/// elements over time, e.g. while processing a collection chunk by chunk,
+/// never needs more than `k` elements of memory.
+pub struct BoundedTopK<T, Compare = fn(&T, &T) -> bool>
+where
+    Compare: Fn(&T, &T) -> bool + Clone,
+{
+    /// Min heap of retained elements.
+    heap: Vec<T>,
+
+    /// Maximum number of retained elements.
+    k: usize,
+
+    /// The ordering of elements.
+    are_in_increasing_order: Compare,
+}
+
+impl<T> BoundedTopK<T>
+where
+    T: Ord,
+{
+    /// Creates an empty accumulator retaining `k` greatest elements.
+    pub fn new(k: usize) -> Self {
+        Self::new_by(k, |x, y| x < y)
+    }
+}
+
+impl<T, Compare> BoundedTopK<T, Compare>
+where
+    Compare: Fn(&T, &T) -> bool + Clone,
+{
+    /// Creates an empty accumulator retaining `k` greatest elements according
+    /// to `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    pub fn new_by(k: usize, are_in_increasing_order: Compare) -> Self {
+        Self {
+            heap: Vec::with_capacity(k),
+            k,
+            are_in_increasing_order,
+        }
+    }
+
+    /// Returns maximum number of elements retained.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns number of elements currently retained.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if no element is retained.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the least retained element, or None if no element is retained.
+    ///
+    /// Once `k` elements are retained, any newly inserted element not greater
+    /// than this element is discarded.
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+
+    /// Inserts `element` into accumulator, discarding least element if more
+    /// than `k` elements would be retained.
+    ///
+    /// # Complexity
+    ///   - O(log k).
+    pub fn insert(&mut self, element: T) {
+        let are_in_increasing_order = &self.are_in_increasing_order;
+        let is_greater = |x: &T, y: &T| are_in_increasing_order(y, x);
+        if self.heap.len() < self.k {
+            self.heap.push(element);
+            self.heap.push_heap_by(is_greater);
+        } else if self.k > 0 && are_in_increasing_order(&self.heap[0], &element)
+        {
+            self.heap.pop_heap_by(is_greater);
+            *self.heap.last_mut().unwrap() = element;
+            self.heap.push_heap_by(is_greater);
+        }
+    }
+
+    /// Returns retained elements in decreasing order.
+    ///
+    /// # Complexity
+    ///   - O(k * log k).
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let are_in_increasing_order = &self.are_in_increasing_order;
+        self.heap
+            .sort_heap_by(|x: &T, y: &T| are_in_increasing_order(y, x));
+        self.heap
+    }
+}
+
+impl<T, Compare> Extend<T> for BoundedTopK<T, Compare>
+where
+    Compare: Fn(&T, &T) -> bool + Clone,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|e| self.insert(e));
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::cell::RefCell;
+use std::iter::Fuse;
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// Position of end of BufferedIteratorCollection.
+///
+/// Number of elements is not known until the iterator is exhausted, so end
+/// is represented by a sentinel ordered after every index.
+const END: usize = usize::MAX;
+
+/// A multi-pass collection of elements of an iterator.
+///
+/// Elements are pulled from the iterator only when a position reaches them,
+/// and are cached so that they can be accessed again. Positions are indices
+/// of elements, and accessing elements clones them from the cache.
+pub struct BufferedIteratorCollection<I>
+where
+    I: Iterator,
+{
+    /// The base iterator.
+    iter: RefCell<Fuse<I>>,
+
+    /// Elements consumed from iterator till now.
+    buffer: RefCell<Vec<I::Item>>,
+}
+
+impl<I> BufferedIteratorCollection<I>
+where
+    I: Iterator,
+{
+    /// Returns a new instance of BufferedIteratorCollection over elements of
+    /// `iter`.
+    pub fn new(iter: I) -> Self {
+        BufferedIteratorCollection {
+            iter: RefCell::new(iter.fuse()),
+            buffer: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns number of elements consumed from iterator till now.
+    pub fn buffered_count(&self) -> usize {
+        self.buffer.borrow().len()
+    }
+
+    /// Consumes iterator till element at index `i` is buffered, and returns
+    /// true if such element exists.
+    fn fill_upto(&self, i: usize) -> bool {
+        let mut buffer = self.buffer.borrow_mut();
+        let mut iter = self.iter.borrow_mut();
+        while buffer.len() <= i {
+            match iter.next() {
+                Some(e) => buffer.push(e),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Consumes iterator completely and returns number of elements.
+    fn fill_all(&self) -> usize {
+        let mut buffer = self.buffer.borrow_mut();
+        buffer.extend(&mut *self.iter.borrow_mut());
+        buffer.len()
+    }
+
+    /// Returns `i` if there is an element at index `i`, otherwise end.
+    fn position_or_end(&self, i: usize) -> usize {
+        if self.fill_upto(i) {
+            i
+        } else {
+            END
+        }
+    }
+}
+
+impl<I> Collection for BufferedIteratorCollection<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Position = usize;
+
+    type Element = I::Item;
+
+    type ElementRef<'a>
+        = ValueRef<I::Item>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.position_or_end(0)
+    }
+
+    fn end(&self) -> Self::Position {
+        END
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        *position = self.position_or_end(*position + 1);
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        if n > 0 {
+            *position = self.position_or_end(*position + n);
+        }
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if n == 0 {
+            return true;
+        }
+        if *position == END {
+            return false;
+        }
+        let next = *position + n;
+        if limit != END && next > limit {
+            *position = limit;
+            return false;
+        }
+        if next == limit || self.fill_upto(next) {
+            *position = next;
+            true
+        } else {
+            // Only end could be reached after last element.
+            let count = self.fill_all();
+            *position = END;
+            next == count
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        let resolve = |p| if p == END { self.fill_all() } else { p };
+        resolve(to) - resolve(from)
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.buffered_count()
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new(self.buffer.borrow()[*i].clone())
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<I> BidirectionalCollection for BufferedIteratorCollection<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.form_prior_n(position, 1)
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        if *position == END && n > 0 {
+            *position = self.fill_all();
+        }
+        *position -= n;
+    }
+}
+
+impl<I> RandomAccessCollection for BufferedIteratorCollection<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+impl<I> LazyCollection for BufferedIteratorCollection<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.at(i).val
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// A lazy collection of all pairs `(x, y)` where `x` is an element of `outer`
+/// and `y` is an element of `inner`, ordered by position of `x` and then
+/// position of `y`.
+pub struct CartesianProductCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection,
+    B::Element: Clone,
+{
+    /// The outer base collection.
+    pub outer: A,
+
+    /// The inner base collection.
+    pub inner: B,
+
+    /// Number of elements in inner base collection.
+    inner_count: usize,
+}
+
+/// Position type of CartesianProductCollection.
+///
+/// End position has `outer_position` as end of outer base collection and
+/// `inner_position` as start of inner base collection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct CartesianProductCollectionPosition<P, Q> {
+    /// The position in outer base collection.
+    pub outer_position: P,
+
+    /// The position in inner base collection.
+    pub inner_position: Q,
+}
+
+/// Returns a lazy collection of all pairs `(x, y)` where `x` is an element of
+/// `outer` and `y` is an element of `inner`.
+///
+/// # Postcondition
+///   - Pairs are ordered by position of `x` and then position of `y`.
+///   - Returned collection is random access if both collections are.
+///
+/// # Complexity
+///   - O(1) for RandomAccessCollection; O(n) otherwise where
+///     `n == inner.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let grid = collections::cartesian_product([1, 2], ['a', 'b']);
+/// assert!(grid.equals(&[(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]));
+/// ```
+pub fn cartesian_product<A, B>(
+    outer: A,
+    inner: B,
+) -> CartesianProductCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection,
+    B::Element: Clone,
+{
+    let inner_count = inner.count();
+    CartesianProductCollection {
+        outer,
+        inner,
+        inner_count,
+    }
+}
+
+impl<A, B> CartesianProductCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection,
+    B::Element: Clone,
+{
+    /// Returns number of inner elements before `position` in its row.
+    fn inner_offset(&self, position: &<Self as Collection>::Position) -> usize {
+        self.inner
+            .distance(self.inner.start(), position.inner_position.clone())
+    }
+}
+
+impl<A, B> Collection for CartesianProductCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection,
+    B::Element: Clone,
+{
+    type Position =
+        CartesianProductCollectionPosition<A::Position, B::Position>;
+
+    type Element = (A::Element, B::Element);
+
+    type ElementRef<'a>
+        = ValueRef<(A::Element, B::Element)>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        if self.inner_count == 0 {
+            return self.end();
+        }
+        CartesianProductCollectionPosition {
+            outer_position: self.outer.start(),
+            inner_position: self.inner.start(),
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        CartesianProductCollectionPosition {
+            outer_position: self.outer.end(),
+            inner_position: self.inner.start(),
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.inner.form_next(&mut position.inner_position);
+        if position.inner_position == self.inner.end() {
+            position.inner_position = self.inner.start();
+            self.outer.form_next(&mut position.outer_position);
+        }
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        if n == 0 {
+            return;
+        }
+        let offset = self.inner_offset(position) + n;
+        self.outer.form_next_n(
+            &mut position.outer_position,
+            offset / self.inner_count,
+        );
+        position.inner_position = self
+            .inner
+            .next_n(self.inner.start(), offset % self.inner_count);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        let rows = self
+            .outer
+            .distance(from.outer_position.clone(), to.outer_position.clone());
+        rows * self.inner_count + self.inner_offset(&to)
+            - self.inner_offset(&from)
+    }
+
+    fn count(&self) -> usize {
+        self.outer.count() * self.inner_count
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new((
+            self.outer.at(&i.outer_position).clone(),
+            self.inner.at(&i.inner_position).clone(),
+        ))
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<A, B> LazyCollection for CartesianProductCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection,
+    B::Element: Clone,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.at(i).val
+    }
+}
+
+impl<A, B> BidirectionalCollection for CartesianProductCollection<A, B>
+where
+    A: BidirectionalCollection,
+    A::Whole: BidirectionalCollection,
+    A::Element: Clone,
+    B: BidirectionalCollection,
+    B::Whole: BidirectionalCollection,
+    B::Element: Clone,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        if position.inner_position == self.inner.start() {
+            self.outer.form_prior(&mut position.outer_position);
+            position.inner_position = self.inner.end();
+        }
+        self.inner.form_prior(&mut position.inner_position);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        let offset = self.inner_offset(position);
+        if n <= offset {
+            self.inner.form_prior_n(&mut position.inner_position, n);
+            return;
+        }
+        let rows = (n - offset).div_ceil(self.inner_count);
+        self.outer.form_prior_n(&mut position.outer_position, rows);
+        position.inner_position = self
+            .inner
+            .next_n(self.inner.start(), rows * self.inner_count + offset - n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<A, B> RandomAccessCollection for CartesianProductCollection<A, B>
+where
+    A: RandomAccessCollection,
+    A::Whole: RandomAccessCollection,
+    A::Element: Clone,
+    B: RandomAccessCollection,
+    B::Whole: RandomAccessCollection,
+    B::Element: Clone,
+{
+}
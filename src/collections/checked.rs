@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    BidirectionalCollection, Collection, LazyCollection, MutableCollection,
+    RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
+};
+
+/// Source of unique owner ids of CheckedCollection instances.
+static NEXT_OWNER: AtomicUsize = AtomicUsize::new(0);
+
+/// A collection presenting elements of a base collection, whose positions
+/// remember the collection they were created by.
+///
+/// Every operation panics if given a position of another collection, e.g.,
+/// a position of a different `Vec` of same length, which would otherwise
+/// silently access wrong elements. Slices of a CheckedCollection share its
+/// positions, so they are checked too.
+///
+/// Available with `checked-positions` feature, and meant for debugging as
+/// every operation pays for the check.
+pub struct CheckedCollection<C>
+where
+    C: Collection,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Unique id of this collection, stored in each of its positions.
+    owner: usize,
+}
+
+/// Position type of CheckedCollection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct CheckedCollectionPosition<P> {
+    /// Unique id of collection this position belongs to.
+    owner: usize,
+
+    /// The position in base collection.
+    pub position: P,
+}
+
+/// Returns a collection presenting elements of `base`, which panics on use of
+/// positions not created by it.
+///
+/// # Complexity
+///   - O(1).
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let a = collections::checked(vec![1, 2, 3]);
+/// let b = collections::checked(vec![4, 5, 6]);
+/// let i = a.next(a.start());
+/// assert_eq!(*a.at(&i), 2);
+///
+/// let misuse = std::panic::catch_unwind(|| *b.at(&i));
+/// assert!(misuse.is_err());
+/// ```
+pub fn checked<C>(base: C) -> CheckedCollection<C>
+where
+    C: Collection,
+{
+    CheckedCollection {
+        base,
+        owner: NEXT_OWNER.fetch_add(1, Ordering::Relaxed),
+    }
+}
+
+impl<C> CheckedCollection<C>
+where
+    C: Collection,
+{
+    /// Panics if `position` doesn't belong to `self`.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    fn assert_owned(&self, position: &CheckedCollectionPosition<C::Position>) {
+        if position.owner != self.owner {
+            panic!("Position of another collection used with collection.");
+        }
+    }
+
+    /// Returns position of `self` for `position` of base collection.
+    fn own(
+        &self,
+        position: C::Position,
+    ) -> CheckedCollectionPosition<C::Position> {
+        CheckedCollectionPosition {
+            owner: self.owner,
+            position,
+        }
+    }
+}
+
+impl<C> Collection for CheckedCollection<C>
+where
+    C: Collection,
+{
+    type Position = CheckedCollectionPosition<C::Position>;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = C::ElementRef<'a>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.own(self.base.start())
+    }
+
+    fn end(&self) -> Self::Position {
+        self.own(self.base.end())
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.assert_owned(position);
+        self.base.form_next(&mut position.position)
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        self.assert_owned(position);
+        self.base.form_next_n(&mut position.position, n)
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.assert_owned(position);
+        self.assert_owned(&limit);
+        self.base.form_next_n_limited_by(
+            &mut position.position,
+            n,
+            limit.position,
+        )
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.assert_owned(&from);
+        self.assert_owned(&to);
+        self.base.distance(from.position, to.position)
+    }
+
+    fn count(&self) -> usize {
+        self.base.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count()
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.assert_owned(i);
+        self.base.at(&i.position)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        self.assert_owned(&from);
+        self.assert_owned(&to);
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> LazyCollection for CheckedCollection<C>
+where
+    C: LazyCollection,
+    C::Whole: LazyCollection,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.assert_owned(i);
+        self.base.compute_at(&i.position)
+    }
+}
+
+impl<C> BidirectionalCollection for CheckedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.assert_owned(position);
+        self.base.form_prior(&mut position.position)
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        self.assert_owned(position);
+        self.base.form_prior_n(&mut position.position, n)
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.assert_owned(position);
+        self.assert_owned(&limit);
+        self.base.form_prior_n_limited_by(
+            &mut position.position,
+            n,
+            limit.position,
+        )
+    }
+}
+
+impl<C> RandomAccessCollection for CheckedCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+{
+}
+
+impl<C> ReorderableCollection for CheckedCollection<C>
+where
+    C: ReorderableCollection,
+    C::Whole: ReorderableCollection,
+{
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.assert_owned(i);
+        self.assert_owned(j);
+        self.base.swap_at(&i.position, &j.position)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        self.assert_owned(&from);
+        self.assert_owned(&to);
+        SliceMut::new(self, from, to)
+    }
+}
+
+impl<C> MutableCollection for CheckedCollection<C>
+where
+    C: MutableCollection,
+    C::Whole: MutableCollection,
+{
+    fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
+        self.assert_owned(i);
+        self.base.at_mut(&i.position)
+    }
+}
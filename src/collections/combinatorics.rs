@@ -0,0 +1,327 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// A lazy collection of all `k` element subsets of a base collection, in
+/// lexicographic order of positions of chosen elements.
+pub struct CombinationsCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Number of chosen elements.
+    k: usize,
+
+    /// Number of elements in base collection.
+    n: usize,
+
+    /// Number of combinations.
+    count: usize,
+}
+
+/// A lazy collection of all arrangements of `k` distinct elements of a base
+/// collection, in lexicographic order of positions of chosen elements.
+pub struct PermutationsCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Number of chosen elements.
+    k: usize,
+
+    /// Number of elements in base collection.
+    n: usize,
+
+    /// Number of permutations.
+    count: usize,
+}
+
+/// Returns a lazy collection of all `k` element subsets of `base`, each as a
+/// `Vec` of elements in order of their position in `base`.
+///
+/// # Precondition
+///   - Number of combinations is representable by `usize`.
+///
+/// # Postcondition
+///   - Combinations are in lexicographic order of positions of chosen
+///     elements.
+///   - Elements at equal values but different positions in `base` are
+///     treated as different.
+///
+/// # Complexity
+///   - O(n * k) for accessing an element where `n == base.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let c = collections::combinations([1, 2, 3], 2);
+/// assert_eq!(c.to_vec(), [vec![1, 2], vec![1, 3], vec![2, 3]]);
+/// ```
+pub fn combinations<C>(base: C, k: usize) -> CombinationsCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+    let n = base.count();
+    let count = binomial(n, k).expect("count should be representable");
+    CombinationsCollection { base, k, n, count }
+}
+
+/// Returns a lazy collection of all arrangements of `k` distinct elements of
+/// `base`, each as a `Vec` of elements.
+///
+/// # Precondition
+///   - Number of permutations is representable by `usize`.
+///
+/// # Postcondition
+///   - Permutations are in lexicographic order of positions of chosen
+///     elements.
+///   - Elements at equal values but different positions in `base` are
+///     treated as different.
+///
+/// # Complexity
+///   - O(n * k) for accessing an element where `n == base.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let p = collections::permutations(['a', 'b', 'c'], 2);
+/// assert_eq!(p.count(), 6);
+/// assert_eq!(p.at(&1).val, ['a', 'c']);
+/// assert_eq!(p.at(&2).val, ['b', 'a']);
+/// ```
+pub fn permutations<C>(base: C, k: usize) -> PermutationsCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+    let n = base.count();
+    let count = arrangements(n, k).expect("count should be representable");
+    PermutationsCollection { base, k, n, count }
+}
+
+/// Returns number of ways of choosing `k` out of `n` elements, or None if
+/// not representable by `usize`.
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut res: u128 = 1;
+    for i in 0..k {
+        // Product of `i + 1` consecutive numbers is divisible by `(i + 1)!`.
+        res = res.checked_mul((n - i) as u128)? / (i + 1) as u128;
+    }
+    res.try_into().ok()
+}
+
+/// Returns number of ways of arranging `k` out of `n` elements, or None if
+/// not representable by `usize`.
+fn arrangements(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1usize, |res, x| res.checked_mul(x))
+}
+
+impl<C> CombinationsCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+    /// Returns element at offset `i`.
+    fn element_at(&self, mut i: usize) -> Vec<C::Element> {
+        let mut res = Vec::with_capacity(self.k);
+        let mut index = 0;
+        for chosen in 0..self.k {
+            // Combinations starting with `index` are ones choosing rest of
+            // elements after `index`.
+            loop {
+                let rest = self.k - chosen - 1;
+                let starting_here = binomial(self.n - index - 1, rest)
+                    .expect("count should be representable");
+                if i < starting_here {
+                    break;
+                }
+                i -= starting_here;
+                index += 1;
+            }
+            res.push(
+                self.base
+                    .at(&self.base.next_n(self.base.start(), index))
+                    .clone(),
+            );
+            index += 1;
+        }
+        res
+    }
+}
+
+impl<C> PermutationsCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+    /// Returns element at offset `i`.
+    fn element_at(&self, mut i: usize) -> Vec<C::Element> {
+        let mut unused: Vec<usize> = (0..self.n).collect();
+        let mut res = Vec::with_capacity(self.k);
+        for chosen in 0..self.k {
+            // Every choice for this slot starts an equal block of
+            // arrangements of rest of slots.
+            let block = arrangements(self.n - chosen - 1, self.k - chosen - 1)
+                .expect("count should be representable");
+            let index = unused.remove(i / block);
+            i %= block;
+            res.push(
+                self.base
+                    .at(&self.base.next_n(self.base.start(), index))
+                    .clone(),
+            );
+        }
+        res
+    }
+}
+
+macro_rules! impl_combinatoric_collection {
+    ($collection:ident) => {
+        impl<C> Collection for $collection<C>
+        where
+            C: RandomAccessCollection,
+            C::Whole: RandomAccessCollection,
+            C::Element: Clone,
+        {
+            type Position = usize;
+
+            type Element = Vec<C::Element>;
+
+            type ElementRef<'a>
+                = ValueRef<Vec<C::Element>>
+            where
+                Self: 'a;
+
+            type Whole = Self;
+
+            fn start(&self) -> Self::Position {
+                0
+            }
+
+            fn end(&self) -> Self::Position {
+                self.count
+            }
+
+            fn form_next(&self, position: &mut Self::Position) {
+                *position += 1
+            }
+
+            fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+                *position += n
+            }
+
+            fn form_next_n_limited_by(
+                &self,
+                position: &mut Self::Position,
+                n: usize,
+                limit: Self::Position,
+            ) -> bool {
+                if *position + n <= limit {
+                    *position += n;
+                    true
+                } else {
+                    *position = limit;
+                    false
+                }
+            }
+
+            fn distance(
+                &self,
+                from: Self::Position,
+                to: Self::Position,
+            ) -> usize {
+                to - from
+            }
+
+            fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+                ValueRef::new(self.element_at(*i))
+            }
+
+            fn slice(
+                &self,
+                from: Self::Position,
+                to: Self::Position,
+            ) -> Slice<'_, Self::Whole> {
+                Slice::new(self, from, to)
+            }
+        }
+
+        impl<C> LazyCollection for $collection<C>
+        where
+            C: RandomAccessCollection,
+            C::Whole: RandomAccessCollection,
+            C::Element: Clone,
+        {
+            fn compute_at(&self, i: &Self::Position) -> Self::Element {
+                self.element_at(*i)
+            }
+        }
+
+        impl<C> BidirectionalCollection for $collection<C>
+        where
+            C: RandomAccessCollection,
+            C::Whole: RandomAccessCollection,
+            C::Element: Clone,
+        {
+            fn form_prior(&self, position: &mut Self::Position) {
+                *position -= 1
+            }
+
+            fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+                *position -= n
+            }
+
+            fn form_prior_n_limited_by(
+                &self,
+                position: &mut Self::Position,
+                n: usize,
+                limit: Self::Position,
+            ) -> bool {
+                if *position >= limit + n {
+                    *position -= n;
+                    true
+                } else {
+                    *position = limit;
+                    false
+                }
+            }
+        }
+
+        impl<C> RandomAccessCollection for $collection<C>
+        where
+            C: RandomAccessCollection,
+            C::Whole: RandomAccessCollection,
+            C::Element: Clone,
+        {
+        }
+    };
+}
+
+impl_combinatoric_collection!(CombinationsCollection);
+impl_combinatoric_collection!(PermutationsCollection);
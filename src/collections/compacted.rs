@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::iterators::LazyCollectionIntoIter;
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    Slice,
+};
+
+/// A lazy collection presenting values of elements of `base` that are
+/// `Some`, skipping elements that are `None`.
+///
+/// Positions are positions of `Some` elements in `base`. Start position is
+/// found once on construction, so `base` isn't exposed for modification.
+pub struct CompactedCollection<C, T>
+where
+    C: Collection<Element = Option<T>>,
+    T: Clone,
+{
+    /// The base collection.
+    base: C,
+
+    /// Position of first `Some` element of `base`, or end of `base` if there
+    /// is none.
+    start: C::Position,
+}
+
+impl<C, T> CompactedCollection<C, T>
+where
+    C: Collection<Element = Option<T>>,
+    T: Clone,
+{
+    /// Returns a new instance of CompactedCollection presenting values of
+    /// `Some` elements of `base`.
+    ///
+    /// # Complexity
+    ///   - O(k) where `k` is number of leading `None` elements in `base`.
+    pub fn new(base: C) -> Self {
+        let mut start = base.start();
+        Self::skip_nones(&base, &mut start);
+        CompactedCollection { base, start }
+    }
+
+    /// Returns reference to the base collection.
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    /// Moves `position` forward to the first `Some` element of `base` at or
+    /// after it, or to end of `base` if there is none.
+    fn skip_nones(base: &C, position: &mut C::Position) {
+        let end = base.end();
+        while *position != end && base.at(position).is_none() {
+            base.form_next(position);
+        }
+    }
+}
+
+impl<C, T> Collection for CompactedCollection<C, T>
+where
+    C: Collection<Element = Option<T>>,
+    T: Clone,
+{
+    type Position = C::Position;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = ValueRef<T>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.start.clone()
+    }
+
+    fn end(&self) -> Self::Position {
+        self.base.end()
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(position);
+        Self::skip_nones(&self.base, position);
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new(self.compute_at(i))
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C, T> BidirectionalCollection for CompactedCollection<C, T>
+where
+    C: BidirectionalCollection<Element = Option<T>>,
+    C::Whole: BidirectionalCollection,
+    T: Clone,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.base.form_prior(position);
+        while self.base.at(position).is_none() {
+            self.base.form_prior(position);
+        }
+    }
+}
+
+impl<C, T> LazyCollection for CompactedCollection<C, T>
+where
+    C: Collection<Element = Option<T>>,
+    T: Clone,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.base
+            .at(i)
+            .as_ref()
+            .expect("position should be of Some element")
+            .clone()
+    }
+}
+
+impl<C, T> IntoIterator for CompactedCollection<C, T>
+where
+    C: Collection<Element = Option<T>>,
+    T: Clone,
+{
+    type Item = T;
+
+    type IntoIter = LazyCollectionIntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LazyCollectionIntoIter::new(self)
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// A collection that presents elements of base collection repeated given
+/// number of times.
+pub struct CycleCollection<C>
+where
+    C: Collection,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Number of times base collection is repeated.
+    times: usize,
+}
+
+/// Position type of CycleCollection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct CycleCollectionPosition<P> {
+    /// Number of complete cycles before this position.
+    pub cycle: usize,
+
+    /// The position in base collection.
+    pub base_position: P,
+}
+
+impl<C> CycleCollection<C>
+where
+    C: Collection,
+{
+    /// Returns a new instance of CycleCollection repeating `base` `times`
+    /// times.
+    pub fn new(base: C, times: usize) -> Self {
+        CycleCollection { base, times }
+    }
+
+    /// Returns number of times base collection is repeated.
+    pub fn times(&self) -> usize {
+        self.times
+    }
+
+    /// Returns number of elements before `position` in `self`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise where
+    ///     `n == self.base.count()`.
+    fn offset_of(
+        &self,
+        position: &CycleCollectionPosition<C::Position>,
+    ) -> usize {
+        position.cycle * self.base.count()
+            + self
+                .base
+                .distance(self.base.start(), position.base_position.clone())
+    }
+
+    /// Returns position in `self` having `offset` elements before it.
+    ///
+    /// # Precondition
+    ///   - `offset <= self.count()`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise where
+    ///     `n == self.base.count()`.
+    fn position_at_offset(
+        &self,
+        offset: usize,
+    ) -> CycleCollectionPosition<C::Position> {
+        let n = self.base.count();
+        if n == 0 {
+            return self.end();
+        }
+        CycleCollectionPosition {
+            cycle: offset / n,
+            base_position: self.base.next_n(self.base.start(), offset % n),
+        }
+    }
+}
+
+impl<C> Collection for CycleCollection<C>
+where
+    C: Collection,
+{
+    type Position = CycleCollectionPosition<C::Position>;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = C::ElementRef<'a>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        if self.base.start() == self.base.end() {
+            return self.end();
+        }
+        CycleCollectionPosition {
+            cycle: 0,
+            base_position: self.base.start(),
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        CycleCollectionPosition {
+            cycle: self.times,
+            base_position: self.base.start(),
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(&mut position.base_position);
+        if position.base_position == self.base.end() {
+            position.cycle += 1;
+            position.base_position = self.base.start();
+        }
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        *position = self.position_at_offset(self.offset_of(position) + n);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        if from.cycle == to.cycle {
+            self.base.distance(from.base_position, to.base_position)
+        } else {
+            self.offset_of(&to) - self.offset_of(&from)
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.times * self.base.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.times * self.base.underestimated_count()
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base.at(&i.base_position)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> BidirectionalCollection for CycleCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        if position.base_position == self.base.start() {
+            position.cycle -= 1;
+            position.base_position = self.base.end();
+        }
+        self.base.form_prior(&mut position.base_position);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        *position = self.position_at_offset(self.offset_of(position) - n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<C> RandomAccessCollection for CycleCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+{
+}
+
+impl<C> LazyCollection for CycleCollection<C>
+where
+    C: LazyCollection,
+    C::Whole: LazyCollection,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.base.compute_at(&i.base_position)
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    collections::joined::JoinedCollectionPosition, value_ref::ValueRef,
+    BidirectionalCollection, Collection, CollectionExt, LazyCollection, Slice,
+};
+
+/// A lazy collection presenting elements of collections obtained by applying
+/// closure on elements of `base`, one collection after another.
+///
+/// Inner collections are not stored, they are computed again every time they
+/// are accessed.
+///
+/// # Complexity
+///   - `start`, `form_next`, `form_prior` and `at` each call `map_fn` at least
+///     once; skipping empty inner collections calls it once per skipped
+///     element. A full traversal thus calls `map_fn` O(m) times for each
+///     element of `base`, where `m` is length of its inner collection.
+pub struct FlatMappedCollection<Base, MapFn, Inner>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> Inner,
+    Inner: Collection,
+    Inner::Element: Clone,
+{
+    /// The base collection.
+    pub base: Base,
+
+    /// The mapping function.
+    map_fn: MapFn,
+}
+
+impl<Base, MapFn, Inner> FlatMappedCollection<Base, MapFn, Inner>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> Inner,
+    Inner: Collection,
+    Inner::Element: Clone,
+{
+    pub(crate) fn new(base: Base, map_fn: MapFn) -> Self {
+        FlatMappedCollection { base, map_fn }
+    }
+
+    /// Returns inner collection for element at `outer_position` in `base`.
+    fn inner_at(&self, outer_position: &Base::Position) -> Inner {
+        (self.map_fn)(&self.base.at(outer_position))
+    }
+
+    /// Returns position of first element of first non-empty inner collection
+    /// at or after `outer_position`, or end position if there is none.
+    fn first_position_from(
+        &self,
+        mut outer_position: Base::Position,
+    ) -> <Self as Collection>::Position {
+        while outer_position != self.base.end() {
+            let inner = self.inner_at(&outer_position);
+            if !inner.is_empty() {
+                return JoinedCollectionPosition {
+                    outer_position,
+                    inner_position: Some(inner.start()),
+                };
+            }
+            self.base.form_next(&mut outer_position);
+        }
+        self.end()
+    }
+}
+
+impl<Base, MapFn, Inner> Collection for FlatMappedCollection<Base, MapFn, Inner>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> Inner,
+    Inner: Collection,
+    Inner::Element: Clone,
+{
+    type Position = JoinedCollectionPosition<Base::Position, Inner::Position>;
+
+    type Element = Inner::Element;
+
+    type ElementRef<'a>
+        = ValueRef<Inner::Element>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.first_position_from(self.base.start())
+    }
+
+    fn end(&self) -> Self::Position {
+        JoinedCollectionPosition {
+            outer_position: self.base.end(),
+            inner_position: None,
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        let inner = self.inner_at(&position.outer_position);
+        let mut inner_position = position.inner_position.take().unwrap();
+        inner.form_next(&mut inner_position);
+        if inner_position == inner.end() {
+            *position = self.first_position_from(
+                self.base.next(position.outer_position.clone()),
+            );
+        } else {
+            position.inner_position = Some(inner_position);
+        }
+    }
+
+    fn count(&self) -> usize {
+        let mut count = 0;
+        self.base.for_each(|e| count += (self.map_fn)(e).count());
+        count
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new(self.compute_at(i))
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<Base, MapFn, Inner> LazyCollection
+    for FlatMappedCollection<Base, MapFn, Inner>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> Inner,
+    Inner: Collection,
+    Inner::Element: Clone,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.inner_at(&i.outer_position)
+            .at(i.inner_position.as_ref().unwrap())
+            .clone()
+    }
+}
+
+impl<Base, MapFn, Inner> BidirectionalCollection
+    for FlatMappedCollection<Base, MapFn, Inner>
+where
+    Base: BidirectionalCollection,
+    Base::Whole: BidirectionalCollection,
+    MapFn: Fn(&Base::Element) -> Inner,
+    Inner: BidirectionalCollection,
+    Inner::Whole: BidirectionalCollection,
+    Inner::Element: Clone,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        if let Some(inner_position) = &mut position.inner_position {
+            let inner = self.inner_at(&position.outer_position);
+            if *inner_position != inner.start() {
+                inner.form_prior(inner_position);
+                return;
+            }
+        }
+        let mut outer_position = position.outer_position.clone();
+        loop {
+            self.base.form_prior(&mut outer_position);
+            let inner = self.inner_at(&outer_position);
+            if !inner.is_empty() {
+                *position = JoinedCollectionPosition {
+                    inner_position: Some(inner.prior(inner.end())),
+                    outer_position,
+                };
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    BidirectionalCollection, Collection, RandomAccessCollection, Slice,
+};
+
+/// Handle to an element of `IndexedHeap`.
+///
+/// Handle stays valid until its element is popped or removed from heap, no
+/// matter how other elements move in heap. After that, heap may reuse the
+/// handle for a newly pushed element.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct HeapHandle(usize);
+
+/// A priority queue maintaining max heap invariant, whose elements can be
+/// accessed, updated and removed through handles.
+///
+/// Updating an element moves it up or down in heap as needed, so heap
+/// supports both decrease-key and increase-key operations. This is what graph
+/// algorithms like Dijkstra's shortest path need: with
+/// `are_in_increasing_order` being `|x, y| x > y`, heap is a min heap and
+/// lowering distance of a vertex is an `update` of its handle.
+///
+/// As a collection, heap presents its elements in heap order.
+pub struct IndexedHeap<T, Compare = fn(&T, &T) -> bool>
+where
+    Compare: Fn(&T, &T) -> bool,
+{
+    /// Handles of elements in heap order.
+    heap: Vec<usize>,
+
+    /// Elements by handle, `None` for unused handles.
+    elements: Vec<Option<T>>,
+
+    /// Index in `heap` of element of each used handle.
+    heap_index: Vec<usize>,
+
+    /// Unused handles available for reuse.
+    free: Vec<usize>,
+
+    /// The ordering of elements in heap.
+    are_in_increasing_order: Compare,
+}
+
+impl<T> IndexedHeap<T>
+where
+    T: Ord,
+{
+    /// Creates an empty max heap.
+    pub fn new() -> Self {
+        Self::new_by(|x, y| x < y)
+    }
+}
+
+impl<T> Default for IndexedHeap<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Compare> IndexedHeap<T, Compare>
+where
+    Compare: Fn(&T, &T) -> bool,
+{
+    /// Creates an empty max heap according to `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    pub fn new_by(are_in_increasing_order: Compare) -> Self {
+        Self {
+            heap: Vec::new(),
+            elements: Vec::new(),
+            heap_index: Vec::new(),
+            free: Vec::new(),
+            are_in_increasing_order,
+        }
+    }
+
+    /// Returns number of elements in heap.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns true if heap has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns true if `handle` refers to an element in heap.
+    pub fn contains(&self, handle: HeapHandle) -> bool {
+        matches!(self.elements.get(handle.0), Some(Some(_)))
+    }
+
+    /// Returns element referred by `handle`, or `None` if `handle` doesn't
+    /// refer to an element in heap.
+    pub fn get(&self, handle: HeapHandle) -> Option<&T> {
+        self.elements.get(handle.0)?.as_ref()
+    }
+
+    /// Returns the greatest element with its handle, or `None` if heap is
+    /// empty.
+    pub fn peek(&self) -> Option<(HeapHandle, &T)> {
+        let h = *self.heap.first()?;
+        Some((HeapHandle(h), self.element(h)))
+    }
+
+    /// Pushes `element` to heap and returns its handle.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.len()`.
+    pub fn push(&mut self, element: T) -> HeapHandle {
+        let i = self.heap.len();
+        let h = match self.free.pop() {
+            Some(h) => {
+                self.elements[h] = Some(element);
+                self.heap_index[h] = i;
+                h
+            }
+            None => {
+                self.elements.push(Some(element));
+                self.heap_index.push(i);
+                self.elements.len() - 1
+            }
+        };
+        self.heap.push(h);
+        self.sift_up(i);
+        HeapHandle(h)
+    }
+
+    /// Removes the greatest element from heap and returns it with its handle,
+    /// or `None` if heap is empty.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.len()`.
+    pub fn pop(&mut self) -> Option<(HeapHandle, T)> {
+        let h = HeapHandle(*self.heap.first()?);
+        let e = self.remove(h)?;
+        Some((h, e))
+    }
+
+    /// Replaces element referred by `handle` with `element`, restores heap
+    /// invariant, and returns the replaced element. Returns `None` and leaves
+    /// heap unchanged if `handle` doesn't refer to an element in heap.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.len()`.
+    pub fn update(&mut self, handle: HeapHandle, element: T) -> Option<T> {
+        if !self.contains(handle) {
+            return None;
+        }
+        let old = self.elements[handle.0].replace(element);
+        let i = self.heap_index[handle.0];
+        self.sift_up(i);
+        self.sift_down(self.heap_index[handle.0]);
+        old
+    }
+
+    /// Removes element referred by `handle` from heap and returns it, or
+    /// returns `None` if `handle` doesn't refer to an element in heap.
+    ///
+    /// # Complexity
+    ///   - O(log n) where `n == self.len()`.
+    pub fn remove(&mut self, handle: HeapHandle) -> Option<T> {
+        if !self.contains(handle) {
+            return None;
+        }
+        let i = self.heap_index[handle.0];
+        let last = self.heap.len() - 1;
+        self.swap_nodes(i, last);
+        self.heap.pop();
+        self.free.push(handle.0);
+        if i < self.heap.len() {
+            self.sift_up(i);
+            self.sift_down(self.heap_index[self.heap[i]]);
+        }
+        self.elements[handle.0].take()
+    }
+
+    /// Returns element of used handle `h`.
+    fn element(&self, h: usize) -> &T {
+        self.elements[h].as_ref().unwrap()
+    }
+
+    /// Returns true if element at index `i` of heap is ordered before element
+    /// at index `j`.
+    fn is_before(&self, i: usize, j: usize) -> bool {
+        (self.are_in_increasing_order)(
+            self.element(self.heap[i]),
+            self.element(self.heap[j]),
+        )
+    }
+
+    /// Swaps elements at index `i` and `j` of heap.
+    fn swap_nodes(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.heap_index[self.heap[i]] = i;
+        self.heap_index[self.heap[j]] = j;
+    }
+
+    /// Moves element at index `i` of heap up till its parent is not ordered
+    /// before it.
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.is_before(parent, i) {
+                break;
+            }
+            self.swap_nodes(parent, i);
+            i = parent;
+        }
+    }
+
+    /// Moves element at index `i` of heap down till none of its children are
+    /// ordered after it.
+    fn sift_down(&mut self, mut i: usize) {
+        let n = self.heap.len();
+        loop {
+            let mut largest = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < n && self.is_before(largest, child) {
+                    largest = child;
+                }
+            }
+            if largest == i {
+                break;
+            }
+            self.swap_nodes(i, largest);
+            i = largest;
+        }
+    }
+}
+
+impl<T, Compare> Collection for IndexedHeap<T, Compare>
+where
+    Compare: Fn(&T, &T) -> bool,
+{
+    type Position = usize;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        0
+    }
+
+    fn end(&self) -> Self::Position {
+        self.heap.len()
+    }
+
+    fn form_next(&self, i: &mut Self::Position) {
+        *i += 1
+    }
+
+    fn form_next_n(&self, i: &mut Self::Position, n: usize) {
+        *i += n
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position + n <= limit {
+            *position += n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to - from
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.element(self.heap[*i])
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<T, Compare> BidirectionalCollection for IndexedHeap<T, Compare>
+where
+    Compare: Fn(&T, &T) -> bool,
+{
+    fn form_prior(&self, i: &mut Self::Position) {
+        *i -= 1
+    }
+
+    fn form_prior_n(&self, i: &mut Self::Position, n: usize) {
+        *i -= n
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position >= limit + n {
+            *position -= n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<T, Compare> RandomAccessCollection for IndexedHeap<T, Compare> where
+    Compare: Fn(&T, &T) -> bool
+{
+}
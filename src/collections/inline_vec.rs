@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::mem::MaybeUninit;
+
+use crate::{
+    BidirectionalCollection, Collection, MutableCollection,
+    RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
+};
+
+/// A growable array of at most `N` elements, stored inline without any
+/// allocation.
+///
+/// Useful as a temporary buffer of algorithms over small collections, where
+/// allocating a `Vec` would dominate the cost of algorithm.
+pub struct InlineVec<T, const N: usize> {
+    /// Storage of elements, where only first `len` slots are initialized.
+    storage: [MaybeUninit<T>; N],
+
+    /// Number of elements.
+    len: usize,
+}
+
+impl<T, const N: usize> InlineVec<T, N> {
+    /// Creates an empty InlineVec.
+    pub fn new() -> Self {
+        Self {
+            storage: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns maximum number of elements InlineVec can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns true if InlineVec holds `capacity()` elements.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends `element` at end, or returns `element` back if InlineVec is
+    /// full.
+    pub fn push(&mut self, element: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(element);
+        }
+        self.storage[self.len].write(element);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the last element, or None if InlineVec is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: slot at old `len - 1` lies in initialized prefix, and after
+        // decrementing `len` it is out of it, so it is read exactly once and
+        // never dropped again.
+        let element = unsafe { self.storage[self.len].assume_init_read() };
+        Some(element)
+    }
+
+    /// Removes all elements after first `len` elements.
+    ///
+    /// # Complexity
+    ///   - O(m) where `m` is number of removed elements.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    /// Removes all elements.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Returns slice of all elements.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: first `len` slots are initialized, and `MaybeUninit<T>` has
+        // same layout as `T`.
+        unsafe {
+            std::slice::from_raw_parts(
+                self.storage.as_ptr() as *const T,
+                self.len,
+            )
+        }
+    }
+
+    /// Returns mutable slice of all elements.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: first `len` slots are initialized, and `MaybeUninit<T>` has
+        // same layout as `T`. Borrow of `self` is exclusive.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.storage.as_mut_ptr() as *mut T,
+                self.len,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for InlineVec<T, N> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T, const N: usize> Default for InlineVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Clone for InlineVec<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut res = Self::new();
+        for e in self.as_slice() {
+            let _ = res.push(e.clone());
+        }
+        res
+    }
+}
+
+impl<T, const N: usize> std::fmt::Debug for InlineVec<T, N>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize> PartialEq for InlineVec<T, N>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const N: usize> Eq for InlineVec<T, N> where T: Eq {}
+
+impl<T, const N: usize> Collection for InlineVec<T, N> {
+    type Position = usize;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        0
+    }
+
+    fn end(&self) -> Self::Position {
+        self.len
+    }
+
+    fn form_next(&self, i: &mut Self::Position) {
+        *i += 1
+    }
+
+    fn form_next_n(&self, i: &mut Self::Position, n: usize) {
+        *i += n
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position + n <= limit {
+            *position += n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to - from
+    }
+
+    fn at(&self, i: &Self::Position) -> &T {
+        &self.as_slice()[*i]
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        Some(&self.as_slice()[from..to])
+    }
+}
+
+impl<T, const N: usize> BidirectionalCollection for InlineVec<T, N> {
+    fn form_prior(&self, i: &mut Self::Position) {
+        *i -= 1
+    }
+
+    fn form_prior_n(&self, i: &mut Self::Position, n: usize) {
+        *i -= n
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position >= limit + n {
+            *position -= n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<T, const N: usize> RandomAccessCollection for InlineVec<T, N> {}
+
+impl<T, const N: usize> ReorderableCollection for InlineVec<T, N> {
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.as_mut_slice().swap(*i, *j)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
+impl<T, const N: usize> MutableCollection for InlineVec<T, N> {
+    fn at_mut(&mut self, i: &Self::Position) -> &mut T {
+        &mut self.as_mut_slice()[*i]
+    }
+
+    fn contiguous_slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&mut [Self::Element]> {
+        Some(&mut self.as_mut_slice()[from..to])
+    }
+}
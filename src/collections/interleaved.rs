@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// A lazy collection presenting elements of `first` and `second` collections
+/// alternately, starting with `first`.
+///
+/// Once the shorter collection is exhausted, remaining elements of the longer
+/// collection are presented in order.
+pub struct InterleavedCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+{
+    /// The first base collection.
+    pub first: A,
+
+    /// The second base collection.
+    pub second: B,
+
+    /// Number of elements in first base collection.
+    first_count: usize,
+
+    /// Number of elements in second base collection.
+    second_count: usize,
+}
+
+/// Position type of InterleavedCollection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct InterleavedCollectionPosition<P, Q> {
+    /// Number of elements presented before this position.
+    pub offset: usize,
+
+    /// The position in first base collection.
+    pub first_position: P,
+
+    /// The position in second base collection.
+    pub second_position: Q,
+}
+
+impl<A, B> InterleavedCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+{
+    /// Returns a new instance of InterleavedCollection presenting elements of
+    /// `first` and `second` alternately.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise where `n` is total
+    ///     number of elements.
+    pub fn new(first: A, second: B) -> Self {
+        let first_count = first.count();
+        let second_count = second.count();
+        InterleavedCollection {
+            first,
+            second,
+            first_count,
+            second_count,
+        }
+    }
+
+    /// Returns true if element at `offset` is from first base collection.
+    fn is_in_first(&self, offset: usize) -> bool {
+        if offset < 2 * self.first_count.min(self.second_count) {
+            offset.is_multiple_of(2)
+        } else {
+            self.first_count > self.second_count
+        }
+    }
+
+    /// Returns number of elements of first base collection presented before
+    /// `offset`.
+    fn first_taken(&self, offset: usize) -> usize {
+        let common = self.first_count.min(self.second_count);
+        if offset <= 2 * common {
+            offset.div_ceil(2)
+        } else if self.first_count > self.second_count {
+            offset - self.second_count
+        } else {
+            self.first_count
+        }
+    }
+}
+
+impl<A, B> Collection for InterleavedCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+{
+    type Position = InterleavedCollectionPosition<A::Position, B::Position>;
+
+    type Element = A::Element;
+
+    type ElementRef<'a>
+        = ValueRef<A::Element>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        InterleavedCollectionPosition {
+            offset: 0,
+            first_position: self.first.start(),
+            second_position: self.second.start(),
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        InterleavedCollectionPosition {
+            offset: self.first_count + self.second_count,
+            first_position: self.first.end(),
+            second_position: self.second.end(),
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        if self.is_in_first(position.offset) {
+            self.first.form_next(&mut position.first_position);
+        } else {
+            self.second.form_next(&mut position.second_position);
+        }
+        position.offset += 1;
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        let from_first = self.first_taken(position.offset);
+        let from_second = position.offset - from_first;
+        position.offset += n;
+        let to_first = self.first_taken(position.offset);
+        let to_second = position.offset - to_first;
+        self.first
+            .form_next_n(&mut position.first_position, to_first - from_first);
+        self.second.form_next_n(
+            &mut position.second_position,
+            to_second - from_second,
+        );
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to.offset - from.offset
+    }
+
+    fn count(&self) -> usize {
+        self.first_count + self.second_count
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        if self.is_in_first(i.offset) {
+            ValueRef::new(self.first.at(&i.first_position).clone())
+        } else {
+            ValueRef::new(self.second.at(&i.second_position).clone())
+        }
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<A, B> LazyCollection for InterleavedCollection<A, B>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.at(i).val
+    }
+}
+
+impl<A, B> BidirectionalCollection for InterleavedCollection<A, B>
+where
+    A: BidirectionalCollection,
+    A::Whole: BidirectionalCollection,
+    A::Element: Clone,
+    B: BidirectionalCollection<Element = A::Element>,
+    B::Whole: BidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        position.offset -= 1;
+        if self.is_in_first(position.offset) {
+            self.first.form_prior(&mut position.first_position);
+        } else {
+            self.second.form_prior(&mut position.second_position);
+        }
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        let from_first = self.first_taken(position.offset);
+        let from_second = position.offset - from_first;
+        position.offset -= n;
+        let to_first = self.first_taken(position.offset);
+        let to_second = position.offset - to_first;
+        self.first
+            .form_prior_n(&mut position.first_position, from_first - to_first);
+        self.second.form_prior_n(
+            &mut position.second_position,
+            from_second - to_second,
+        );
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<A, B> RandomAccessCollection for InterleavedCollection<A, B>
+where
+    A: RandomAccessCollection,
+    A::Whole: RandomAccessCollection,
+    A::Element: Clone,
+    B: RandomAccessCollection<Element = A::Element>,
+    B::Whole: RandomAccessCollection,
+{
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{BidirectionalCollection, Collection, CollectionExt, Slice};
+
+/// A collection that presents elements of all inner collections of `base`,
+/// one inner collection after another.
+///
+/// Elements of `base` must be stored in memory, i.e., `base` yields plain
+/// references to its inner collections.
+pub struct JoinedCollection<'a, C>
+where
+    C: Collection<ElementRef<'a> = &'a <C as Collection>::Element> + ?Sized,
+    C::Element: Collection,
+{
+    /// The base collection.
+    base: &'a C,
+}
+
+/// Position type of JoinedCollection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct JoinedCollectionPosition<P, Q> {
+    /// The position of inner collection in base collection.
+    pub outer_position: P,
+
+    /// The position in inner collection, None for end position.
+    pub inner_position: Option<Q>,
+}
+
+impl<'a, C> JoinedCollection<'a, C>
+where
+    C: Collection<ElementRef<'a> = &'a <C as Collection>::Element> + ?Sized,
+    C::Element: Collection,
+{
+    /// Returns a new instance of JoinedCollection presenting elements of inner
+    /// collections of `base`.
+    pub fn new(base: &'a C) -> Self {
+        JoinedCollection { base }
+    }
+
+    /// Returns the base collection.
+    pub fn base(&self) -> &'a C {
+        self.base
+    }
+
+    /// Returns position of first element of first non-empty inner collection
+    /// at or after `outer_position`, or end position if there is none.
+    fn first_position_from(
+        &self,
+        mut outer_position: C::Position,
+    ) -> <Self as Collection>::Position {
+        while outer_position != self.base.end() {
+            let inner = self.base.at(&outer_position);
+            if !inner.is_empty() {
+                return JoinedCollectionPosition {
+                    outer_position,
+                    inner_position: Some(inner.start()),
+                };
+            }
+            self.base.form_next(&mut outer_position);
+        }
+        self.end()
+    }
+}
+
+impl<'a, C> Collection for JoinedCollection<'a, C>
+where
+    C: Collection<ElementRef<'a> = &'a <C as Collection>::Element> + ?Sized,
+    C::Element: Collection,
+{
+    type Position = JoinedCollectionPosition<
+        C::Position,
+        <C::Element as Collection>::Position,
+    >;
+
+    type Element = <C::Element as Collection>::Element;
+
+    type ElementRef<'b>
+        = <C::Element as Collection>::ElementRef<'a>
+    where
+        Self: 'b;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.first_position_from(self.base.start())
+    }
+
+    fn end(&self) -> Self::Position {
+        JoinedCollectionPosition {
+            outer_position: self.base.end(),
+            inner_position: None,
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        let inner = self.base.at(&position.outer_position);
+        let mut inner_position = position.inner_position.take().unwrap();
+        inner.form_next(&mut inner_position);
+        if inner_position == inner.end() {
+            *position = self.first_position_from(
+                self.base.next(position.outer_position.clone()),
+            );
+        } else {
+            position.inner_position = Some(inner_position);
+        }
+    }
+
+    fn count(&self) -> usize {
+        let mut count = 0;
+        self.base.for_each(|inner| count += inner.count());
+        count
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base
+            .at(&i.outer_position)
+            .at(i.inner_position.as_ref().unwrap())
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<'a, C> BidirectionalCollection for JoinedCollection<'a, C>
+where
+    C: Collection<ElementRef<'a> = &'a <C as Collection>::Element>
+        + BidirectionalCollection
+        + ?Sized,
+    C::Whole: BidirectionalCollection,
+    C::Element: BidirectionalCollection,
+    <C::Element as Collection>::Whole: BidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        if let Some(inner_position) = &mut position.inner_position {
+            let inner = self.base.at(&position.outer_position);
+            if *inner_position != inner.start() {
+                inner.form_prior(inner_position);
+                return;
+            }
+        }
+        let mut outer_position = position.outer_position.clone();
+        loop {
+            self.base.form_prior(&mut outer_position);
+            let inner = self.base.at(&outer_position);
+            if !inner.is_empty() {
+                *position = JoinedCollectionPosition {
+                    inner_position: Some(inner.prior(inner.end())),
+                    outer_position,
+                };
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::{
+    io::{self, BufRead},
+    iter::FusedIterator,
+    rc::Rc,
+};
+
+use crate::collections::BufferedIteratorCollection;
+
+/// A single-pass input collection of lines of a reader, e.g., a file.
+///
+/// Lines don't include their terminating `"\n"` or `"\r\n"`. Like
+/// `std::io::Lines`, each line is an `io::Result`, so I/O errors are never
+/// mistaken for end of input; reading stops after yielding the first error.
+///
+/// Collection algorithms, e.g., `count_where` or `prefix_while`, can run on
+/// lines after making them multi-pass with `buffered`.
+pub struct Lines<R>
+where
+    R: BufRead,
+{
+    /// The base reader.
+    reader: R,
+
+    /// True if reading has stopped.
+    is_done: bool,
+}
+
+impl<R> Lines<R>
+where
+    R: BufRead,
+{
+    /// Returns a new instance of Lines presenting lines of `reader`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let lines = collections::Lines::new("a\r\nb\n\nc".as_bytes());
+    /// let all: Vec<String> = lines.collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(all, ["a", "b", "", "c"]);
+    /// ```
+    pub fn new(reader: R) -> Self {
+        Lines {
+            reader,
+            is_done: false,
+        }
+    }
+
+    /// Returns a multi-pass collection of remaining lines, reading lines
+    /// from reader only when a position reaches them.
+    ///
+    /// If reading fails, the error is the last element of collection. Errors
+    /// are shared through `Rc`, as elements are cloned on access.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let log = "INFO start\nWARN disk\nINFO done\n\nWARN ignored";
+    /// let lines = collections::Lines::new(log.as_bytes()).buffered();
+    /// let paragraph = lines.prefix_while(|line| {
+    ///     line.as_ref().is_ok_and(|line| !line.is_empty())
+    /// });
+    /// assert_eq!(paragraph.count(), 3);
+    /// let warnings = paragraph.count_where(|line| {
+    ///     line.as_ref().is_ok_and(|line| line.as_str().starts_with("WARN"))
+    /// });
+    /// assert_eq!(warnings, 1);
+    /// ```
+    pub fn buffered(
+        self,
+    ) -> BufferedIteratorCollection<
+        impl Iterator<Item = Result<String, Rc<io::Error>>>,
+    > {
+        BufferedIteratorCollection::new(self.map(|line| line.map_err(Rc::new)))
+    }
+}
+
+impl<R> Iterator for Lines<R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => {
+                self.is_done = true;
+                None
+            }
+            Ok(_) => {
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Some(Ok(line))
+            }
+            Err(e) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<R> FusedIterator for Lines<R> where R: BufRead {}
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use crate::iterators::LazyCollectionIntoIter;
 use crate::{
-    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    hash_elements, lexicographical_partial_cmp, value_ref::ValueRef,
+    BidirectionalCollection, Collection, CollectionExt, LazyCollection,
     RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
 };
 
@@ -124,6 +126,21 @@ where
     }
 }
 
+impl<Base, MapFn, MappedType> IntoIterator
+    for MappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> MappedType,
+{
+    type Item = MappedType;
+
+    type IntoIter = LazyCollectionIntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LazyCollectionIntoIter::new(self)
+    }
+}
+
 impl<Base, MapFn, MappedType> BidirectionalCollection
     for MappedCollection<Base, MapFn, MappedType>
 where
@@ -136,7 +153,7 @@ where
     }
 
     fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
-        self.base.form_next_n(position, n);
+        self.base.form_prior_n(position, n);
     }
 
     fn form_prior_n_limited_by(
@@ -145,7 +162,7 @@ where
         n: usize,
         limit: Self::Position,
     ) -> bool {
-        self.base.form_next_n_limited_by(position, n, limit)
+        self.base.form_prior_n_limited_by(position, n, limit)
     }
 
     fn prior(&self, position: Self::Position) -> Self::Position {
@@ -195,6 +212,268 @@ where
     }
 }
 
+/// Mapped collections are compared and hashed by their mapped elements.
+impl<Base, MapFn, MappedType> PartialEq
+    for MappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> MappedType,
+    MappedType: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.equals_by(other, |x, y| x == y)
+    }
+}
+
+impl<Base, MapFn, MappedType> Eq for MappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> MappedType,
+    MappedType: Eq,
+{
+}
+
+impl<Base, MapFn, MappedType> PartialOrd
+    for MappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> MappedType,
+    MappedType: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        lexicographical_partial_cmp(self, other)
+    }
+}
+
+impl<Base, MapFn, MappedType> Ord for MappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> MappedType,
+    MappedType: Ord,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        lexicographical_partial_cmp(self, other)
+            .expect("Ord elements should always be comparable")
+    }
+}
+
+impl<Base, MapFn, MappedType> std::hash::Hash
+    for MappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Element) -> MappedType,
+    MappedType: std::hash::Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        hash_elements(self, state)
+    }
+}
+
+/// A lazy collection whose elements are applying closure on position and
+/// element-ref of `base`.
+pub struct IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    /// The base collection.
+    pub base: Base,
+
+    /// The mapping function.
+    map_fn: MapFn,
+}
+
+impl<Base, MapFn, MappedType> IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    pub(crate) fn new(base: Base, map_fn: MapFn) -> Self {
+        IndexedMappedCollection { base, map_fn }
+    }
+}
+
+impl<Base, MapFn, MappedType> Collection
+    for IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    type Position = Base::Position;
+
+    type Element = MappedType;
+
+    type ElementRef<'a>
+        = ValueRef<MappedType>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.base.start()
+    }
+
+    fn end(&self) -> Self::Position {
+        self.base.end()
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(position);
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new((self.map_fn)(i, &self.base.at(i)))
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_next_n(position, n);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.base.form_next_n_limited_by(position, n, limit)
+    }
+
+    fn next(&self, position: Self::Position) -> Self::Position {
+        self.base.next(position)
+    }
+
+    fn next_n(&self, position: Self::Position, n: usize) -> Self::Position {
+        self.base.next_n(position, n)
+    }
+
+    fn next_n_limited_by(
+        &self,
+        position: Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> Option<Self::Position> {
+        self.base.next_n_limited_by(position, n, limit)
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.base.distance(from, to)
+    }
+
+    fn count(&self) -> usize {
+        self.base.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count()
+    }
+}
+
+impl<Base, MapFn, MappedType> LazyCollection
+    for IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        (self.map_fn)(i, &self.base.at(i))
+    }
+}
+
+impl<Base, MapFn, MappedType> IntoIterator
+    for IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: Collection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    type Item = MappedType;
+
+    type IntoIter = LazyCollectionIntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LazyCollectionIntoIter::new(self)
+    }
+}
+
+impl<Base, MapFn, MappedType> BidirectionalCollection
+    for IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: BidirectionalCollection,
+    Base::Whole: BidirectionalCollection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.base.form_prior(position);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_prior_n(position, n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.base.form_prior_n_limited_by(position, n, limit)
+    }
+
+    fn prior(&self, position: Self::Position) -> Self::Position {
+        self.base.prior(position)
+    }
+
+    fn prior_n(&self, position: Self::Position, n: usize) -> Self::Position {
+        self.base.prior_n(position, n)
+    }
+
+    fn prior_n_limited_by(
+        &self,
+        position: Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> Option<Self::Position> {
+        self.base.prior_n_limited_by(position, n, limit)
+    }
+}
+
+impl<Base, MapFn, MappedType> RandomAccessCollection
+    for IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: RandomAccessCollection,
+    Base::Whole: RandomAccessCollection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+}
+
+impl<Base, MapFn, MappedType> ReorderableCollection
+    for IndexedMappedCollection<Base, MapFn, MappedType>
+where
+    Base: ReorderableCollection,
+    Base::Whole: ReorderableCollection,
+    MapFn: Fn(&Base::Position, &Base::Element) -> MappedType,
+{
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.base.swap_at(i, j)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
 /// A lazy collection whose elements are applying closure on lazily computed values of `base`.
 pub struct LazyMappedCollection<Base, MapFn, MappedType>
 where
@@ -317,6 +596,22 @@ where
     }
 }
 
+impl<Base, MapFn, MappedType> IntoIterator
+    for LazyMappedCollection<Base, MapFn, MappedType>
+where
+    Base: LazyCollection,
+    Base::Whole: LazyCollection,
+    MapFn: Fn(Base::Element) -> MappedType,
+{
+    type Item = MappedType;
+
+    type IntoIter = LazyCollectionIntoIter<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LazyCollectionIntoIter::new(self)
+    }
+}
+
 impl<Base, MapFn, MappedType> BidirectionalCollection
     for LazyMappedCollection<Base, MapFn, MappedType>
 where
@@ -329,7 +624,7 @@ where
     }
 
     fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
-        self.base.form_next_n(position, n);
+        self.base.form_prior_n(position, n);
     }
 
     fn form_prior_n_limited_by(
@@ -338,7 +633,7 @@ where
         n: usize,
         limit: Self::Position,
     ) -> bool {
-        self.base.form_next_n_limited_by(position, n, limit)
+        self.base.form_prior_n_limited_by(position, n, limit)
     }
 
     fn prior(&self, position: Self::Position) -> Self::Position {
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    Slice,
+};
+
+/// A lazy collection presenting elements of sorted `first` and sorted
+/// `second` collections as a single sorted collection.
+///
+/// Equivalent elements of `first` are presented before those of `second`,
+/// i.e., merging is stable.
+pub struct MergedCollection<A, B, Compare>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+    Compare: Fn(&A::Element, &A::Element) -> bool,
+{
+    /// The first base collection.
+    pub first: A,
+
+    /// The second base collection.
+    pub second: B,
+
+    /// The ordering both base collections are sorted with.
+    are_in_increasing_order: Compare,
+}
+
+/// Position type of MergedCollection.
+///
+/// Elements of both base collections before respective positions are
+/// presented before the position.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct MergedCollectionPosition<P, Q> {
+    /// The position in first base collection.
+    pub first_position: P,
+
+    /// The position in second base collection.
+    pub second_position: Q,
+}
+
+impl<A, B, Compare> MergedCollection<A, B, Compare>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+    Compare: Fn(&A::Element, &A::Element) -> bool,
+{
+    /// Returns a new instance of MergedCollection presenting elements of
+    /// `first` and `second` sorted wrt `are_in_increasing_order`.
+    ///
+    /// # Precondition
+    ///   - `first` and `second` are sorted wrt `are_in_increasing_order`.
+    ///   - `are_in_increasing_order` should follow strict weak ordering.
+    pub fn new(first: A, second: B, are_in_increasing_order: Compare) -> Self {
+        MergedCollection {
+            first,
+            second,
+            are_in_increasing_order,
+        }
+    }
+
+    /// Returns true if element at `position` is in first base collection.
+    ///
+    /// # Precondition
+    ///   - `position` is not end position.
+    fn is_in_first(&self, position: &<Self as Collection>::Position) -> bool {
+        if position.second_position == self.second.end() {
+            true
+        } else if position.first_position == self.first.end() {
+            false
+        } else {
+            !(self.are_in_increasing_order)(
+                &self.second.at(&position.second_position),
+                &self.first.at(&position.first_position),
+            )
+        }
+    }
+}
+
+impl<A, B, Compare> Collection for MergedCollection<A, B, Compare>
+where
+    A: Collection,
+    A::Element: Clone,
+    B: Collection<Element = A::Element>,
+    Compare: Fn(&A::Element, &A::Element) -> bool,
+{
+    type Position = MergedCollectionPosition<A::Position, B::Position>;
+
+    type Element = A::Element;
+
+    type ElementRef<'a>
+        = ValueRef<A::Element>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        MergedCollectionPosition {
+            first_position: self.first.start(),
+            second_position: self.second.start(),
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        MergedCollectionPosition {
+            first_position: self.first.end(),
+            second_position: self.second.end(),
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        if self.is_in_first(position) {
+            self.first.form_next(&mut position.first_position);
+        } else {
+            self.second.form_next(&mut position.second_position);
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.first.count() + self.second.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.first.underestimated_count() + self.second.underestimated_count()
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        if self.is_in_first(i) {
+            ValueRef::new(self.first.at(&i.first_position).clone())
+        } else {
+            ValueRef::new(self.second.at(&i.second_position).clone())
+        }
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<A, B, Compare> BidirectionalCollection for MergedCollection<A, B, Compare>
+where
+    A: BidirectionalCollection,
+    A::Whole: BidirectionalCollection,
+    A::Element: Clone,
+    B: BidirectionalCollection<Element = A::Element>,
+    B::Whole: BidirectionalCollection,
+    Compare: Fn(&A::Element, &A::Element) -> bool,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        let is_first_exhausted = position.first_position == self.first.start();
+        let is_second_exhausted =
+            position.second_position == self.second.start();
+        let is_from_first = if is_second_exhausted {
+            true
+        } else if is_first_exhausted {
+            false
+        } else {
+            // Last presented element is from `first` only if element of
+            // `second` before it is strictly less, as ties are presented from
+            // `first` first.
+            let x = self.first.prior(position.first_position.clone());
+            let y = self.second.prior(position.second_position.clone());
+            (self.are_in_increasing_order)(
+                &self.second.at(&y),
+                &self.first.at(&x),
+            )
+        };
+        if is_from_first {
+            self.first.form_prior(&mut position.first_position);
+        } else {
+            self.second.form_prior(&mut position.second_position);
+        }
+    }
+}
+
+impl<A, B, Compare> LazyCollection for MergedCollection<A, B, Compare>
+where
+    A: LazyCollection,
+    A::Whole: LazyCollection,
+    A::Element: Clone,
+    B: LazyCollection<Element = A::Element>,
+    B::Whole: LazyCollection,
+    Compare: Fn(&A::Element, &A::Element) -> bool,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        if self.is_in_first(i) {
+            self.first.compute_at(&i.first_position)
+        } else {
+            self.second.compute_at(&i.second_position)
+        }
+    }
+}
@@ -15,7 +15,146 @@ pub mod reversed;
 #[doc(inline)]
 pub use reversed::ReversedCollection;
 
+#[doc(hidden)]
+pub mod rotated;
+#[doc(inline)]
+pub use rotated::RotatedCollection;
+
+#[doc(hidden)]
+pub mod cycle;
+#[doc(inline)]
+pub use cycle::CycleCollection;
+
+#[doc(hidden)]
+pub mod strided;
+#[doc(inline)]
+pub use strided::StridedCollection;
+
+#[doc(hidden)]
+pub mod joined;
+#[doc(inline)]
+pub use joined::JoinedCollection;
+
+#[doc(hidden)]
+pub mod flat_mapped;
+#[doc(inline)]
+pub use flat_mapped::FlatMappedCollection;
+
+#[doc(hidden)]
+pub mod merged;
+#[doc(inline)]
+pub use merged::MergedCollection;
+
+#[doc(hidden)]
+pub mod adjacent_pairs;
+#[doc(inline)]
+pub use adjacent_pairs::AdjacentPairsCollection;
+
+#[doc(hidden)]
+pub mod compacted;
+#[doc(inline)]
+pub use compacted::CompactedCollection;
+
+#[doc(hidden)]
+pub mod buffered_iterator;
+#[doc(inline)]
+pub use buffered_iterator::BufferedIteratorCollection;
+
+#[doc(hidden)]
+pub mod bounded_top_k;
+#[doc(inline)]
+pub use bounded_top_k::BoundedTopK;
+
 #[doc(hidden)]
 pub mod mapped;
 #[doc(inline)]
 pub use mapped::*;
+
+#[doc(hidden)]
+pub mod projected;
+#[doc(inline)]
+pub use projected::{
+    ProjectedCollection, ProjectedElementRef, ProjectedMutCollection,
+};
+
+#[doc(hidden)]
+pub mod binary_heap_adaptor;
+#[doc(inline)]
+pub use binary_heap_adaptor::BinaryHeapAdaptor;
+
+#[doc(hidden)]
+pub mod indexed_heap;
+#[doc(inline)]
+pub use indexed_heap::{HeapHandle, IndexedHeap};
+
+#[doc(hidden)]
+pub mod ring_buffer;
+#[doc(inline)]
+pub use ring_buffer::{RingBuffer, RingBufferPosition};
+
+#[doc(hidden)]
+pub mod inline_vec;
+#[doc(inline)]
+pub use inline_vec::InlineVec;
+
+#[doc(hidden)]
+pub mod unbounded;
+#[doc(inline)]
+pub use unbounded::{GeneratedCollection, IotaCollection, PrefixCollection};
+
+#[doc(hidden)]
+pub mod numeric;
+#[doc(inline)]
+pub use numeric::{
+    linspace, stepped_iota, IotaElement, LinspaceCollection,
+    SteppedIotaCollection,
+};
+
+#[doc(hidden)]
+pub mod repeat;
+#[doc(inline)]
+pub use repeat::{repeat, RepeatCollection};
+
+#[doc(hidden)]
+pub mod padded;
+#[doc(inline)]
+pub use padded::PaddedCollection;
+
+#[doc(hidden)]
+pub mod interleaved;
+#[doc(inline)]
+pub use interleaved::InterleavedCollection;
+
+#[doc(hidden)]
+pub mod cartesian_product;
+#[doc(inline)]
+pub use cartesian_product::{cartesian_product, CartesianProductCollection};
+
+#[doc(hidden)]
+pub mod combinatorics;
+#[doc(inline)]
+pub use combinatorics::{
+    combinations, permutations, CombinationsCollection, PermutationsCollection,
+};
+
+#[doc(hidden)]
+pub mod sentinel;
+#[doc(inline)]
+pub use sentinel::{SentinelCollection, SentinelCollectionPosition};
+
+#[doc(hidden)]
+pub mod lines;
+#[doc(inline)]
+pub use lines::Lines;
+
+#[doc(hidden)]
+pub mod output;
+#[doc(inline)]
+pub use output::{OverwritingOutput, UninitOutput};
+
+#[cfg(feature = "checked-positions")]
+#[doc(hidden)]
+pub mod checked;
+#[cfg(feature = "checked-positions")]
+#[doc(inline)]
+pub use checked::{checked, CheckedCollection};
@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::ops::{Add, Mul};
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// Numeric type usable as element of SteppedIotaCollection.
+///
+/// Implemented for all primitive integer and floating point types.
+pub trait IotaElement: Copy + Add<Output = Self> + Mul<Output = Self> {
+    /// Returns `offset` as `Self`, or None if it isn't representable.
+    fn from_offset(offset: usize) -> Option<Self>;
+}
+
+macro_rules! impl_iota_element_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl IotaElement for $t {
+                fn from_offset(offset: usize) -> Option<Self> {
+                    Self::try_from(offset).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_iota_element_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl IotaElement for $t {
+                fn from_offset(offset: usize) -> Option<Self> {
+                    Some(offset as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_iota_element_for_integers!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_iota_element_for_floats!(f32, f64);
+
+/// A lazy collection of `count` values `start`, `start + step`,
+/// `start + 2 * step`, ...
+pub struct SteppedIotaCollection<T> {
+    /// The first element.
+    start: T,
+
+    /// Difference between consecutive elements.
+    step: T,
+
+    /// Number of elements.
+    count: usize,
+}
+
+/// A lazy collection of `n` evenly spaced values from `a` to `b`, both
+/// inclusive.
+pub struct LinspaceCollection {
+    /// The first element.
+    a: f64,
+
+    /// The last element.
+    b: f64,
+
+    /// Number of elements.
+    n: usize,
+}
+
+/// Returns a lazy collection of `count` values `start`, `start + step`,
+/// `start + 2 * step`, ...
+///
+/// # Postcondition
+///   - Accessing an element not representable by `T` panics.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let odds = collections::stepped_iota(1, 2, 5);
+/// assert!(odds.equals(&[1, 3, 5, 7, 9]));
+/// assert!(collections::stepped_iota(10, -3, 3).equals(&[10, 7, 4]));
+/// let xs = collections::stepped_iota(0.5, 0.25, 3);
+/// assert_eq!(xs.to_vec(), [0.5, 0.75, 1.0]);
+/// ```
+pub fn stepped_iota<T>(
+    start: T,
+    step: T,
+    count: usize,
+) -> SteppedIotaCollection<T>
+where
+    T: IotaElement,
+{
+    SteppedIotaCollection { start, step, count }
+}
+
+/// Returns a lazy collection of `n` evenly spaced values from `a` to `b`,
+/// both inclusive.
+///
+/// # Postcondition
+///   - If `n == 1`, the only element is `a`.
+///   - If `n > 1`, first element is exactly `a` and last element is exactly
+///     `b`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let xs = collections::linspace(0.0, 1.0, 5);
+/// assert_eq!(xs.to_vec(), [0.0, 0.25, 0.5, 0.75, 1.0]);
+/// ```
+pub fn linspace(a: f64, b: f64, n: usize) -> LinspaceCollection {
+    LinspaceCollection { a, b, n }
+}
+
+impl<T> SteppedIotaCollection<T>
+where
+    T: IotaElement,
+{
+    /// Returns element at offset `i`.
+    fn element_at(&self, i: usize) -> T {
+        match T::from_offset(i) {
+            Some(i) => self.start + self.step * i,
+            None => panic!("element should be representable"),
+        }
+    }
+}
+
+impl LinspaceCollection {
+    /// Returns element at offset `i`.
+    fn element_at(&self, i: usize) -> f64 {
+        if i == 0 {
+            self.a
+        } else if i + 1 == self.n {
+            self.b
+        } else {
+            self.a + (self.b - self.a) * (i as f64) / ((self.n - 1) as f64)
+        }
+    }
+}
+
+macro_rules! impl_numeric_collection {
+    ($collection:ty, $element:ty, $count:ident $(, $t:ident : $bound:path)?) => {
+        impl$(<$t: $bound>)? Collection for $collection {
+            type Position = usize;
+
+            type Element = $element;
+
+            type ElementRef<'a>
+                = ValueRef<$element>
+            where
+                Self: 'a;
+
+            type Whole = Self;
+
+            fn start(&self) -> Self::Position {
+                0
+            }
+
+            fn end(&self) -> Self::Position {
+                self.$count
+            }
+
+            fn form_next(&self, position: &mut Self::Position) {
+                *position += 1
+            }
+
+            fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+                *position += n
+            }
+
+            fn form_next_n_limited_by(
+                &self,
+                position: &mut Self::Position,
+                n: usize,
+                limit: Self::Position,
+            ) -> bool {
+                if *position + n <= limit {
+                    *position += n;
+                    true
+                } else {
+                    *position = limit;
+                    false
+                }
+            }
+
+            fn distance(
+                &self,
+                from: Self::Position,
+                to: Self::Position,
+            ) -> usize {
+                to - from
+            }
+
+            fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+                ValueRef::new(self.element_at(*i))
+            }
+
+            fn slice(
+                &self,
+                from: Self::Position,
+                to: Self::Position,
+            ) -> Slice<'_, Self::Whole> {
+                Slice::new(self, from, to)
+            }
+        }
+
+        impl$(<$t: $bound>)? LazyCollection for $collection {
+            fn compute_at(&self, i: &Self::Position) -> Self::Element {
+                self.element_at(*i)
+            }
+        }
+
+        impl$(<$t: $bound>)? BidirectionalCollection for $collection {
+            fn form_prior(&self, position: &mut Self::Position) {
+                *position -= 1
+            }
+
+            fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+                *position -= n
+            }
+
+            fn form_prior_n_limited_by(
+                &self,
+                position: &mut Self::Position,
+                n: usize,
+                limit: Self::Position,
+            ) -> bool {
+                if *position >= limit + n {
+                    *position -= n;
+                    true
+                } else {
+                    *position = limit;
+                    false
+                }
+            }
+        }
+
+        impl$(<$t: $bound>)? RandomAccessCollection for $collection {}
+    };
+}
+
+impl_numeric_collection!(SteppedIotaCollection<T>, T, count, T: IotaElement);
+impl_numeric_collection!(LinspaceCollection, f64, n);
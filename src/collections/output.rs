@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::mem::MaybeUninit;
+
+use crate::{MutableCollection, OutputCollection};
+
+/// An output collection writing to an uninitialized buffer from its start.
+///
+/// Written elements are exposed with `into_written`; elements left in buffer
+/// when it is dropped otherwise are leaked, not dropped.
+pub struct UninitOutput<'a, T> {
+    /// The buffer.
+    buffer: &'a mut [MaybeUninit<T>],
+
+    /// Number of elements written to the start of buffer.
+    written: usize,
+}
+
+impl<'a, T> UninitOutput<'a, T> {
+    /// Returns a new instance of UninitOutput writing to `buffer`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut buffer = Vec::with_capacity(4);
+    /// let mut out = collections::UninitOutput::new(buffer.spare_capacity_mut());
+    /// (1..3).lazy_map(|x| x * 2).lazy_write_to(&mut out);
+    /// assert_eq!(out.written_count(), 2);
+    /// assert_eq!(out.into_written(), [2, 4]);
+    /// ```
+    pub fn new(buffer: &'a mut [MaybeUninit<T>]) -> Self {
+        UninitOutput { buffer, written: 0 }
+    }
+
+    /// Returns number of elements written.
+    pub fn written_count(&self) -> usize {
+        self.written
+    }
+
+    /// Returns the written elements, with ownership of them passed to caller
+    /// through the returned reference.
+    ///
+    /// # Postcondition
+    ///   - Returned elements are not dropped with the buffer, and should be
+    ///     dropped by caller, e.g., with `std::ptr::drop_in_place`, if needed.
+    pub fn into_written(self) -> &'a mut [T] {
+        let written = &mut self.buffer[..self.written];
+        // SAFETY: first `self.written` elements of buffer are written.
+        unsafe { &mut *(written as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
+impl<T> OutputCollection for UninitOutput<'_, T> {
+    type Element = T;
+
+    fn write_next(&mut self, value: T) -> Result<(), T> {
+        match self.buffer.get_mut(self.written) {
+            Some(slot) => {
+                slot.write(value);
+                self.written += 1;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+}
+
+/// An output collection overwriting elements of a mutable collection from
+/// its start.
+pub struct OverwritingOutput<'a, C>
+where
+    C: MutableCollection + ?Sized,
+    C::Whole: MutableCollection,
+{
+    /// The collection.
+    collection: &'a mut C,
+
+    /// Position of next element to overwrite.
+    position: C::Position,
+}
+
+impl<'a, C> OverwritingOutput<'a, C>
+where
+    C: MutableCollection + ?Sized,
+    C::Whole: MutableCollection,
+{
+    /// Returns a new instance of OverwritingOutput overwriting elements of
+    /// `collection`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [0; 4];
+    /// let mut out = collections::OverwritingOutput::new(&mut arr);
+    /// (1..4).lazy_map(|x| x * x).lazy_write_to(&mut out);
+    /// assert_eq!(out.position(), 3);
+    /// assert_eq!(arr, [1, 4, 9, 0]);
+    /// ```
+    pub fn new(collection: &'a mut C) -> Self {
+        let position = collection.start();
+        OverwritingOutput {
+            collection,
+            position,
+        }
+    }
+
+    /// Returns position of next element to overwrite, i.e., position just
+    /// after the last overwritten element.
+    pub fn position(&self) -> C::Position {
+        self.position.clone()
+    }
+}
+
+impl<C> OutputCollection for OverwritingOutput<'_, C>
+where
+    C: MutableCollection + ?Sized,
+    C::Whole: MutableCollection,
+{
+    type Element = C::Element;
+
+    fn write_next(&mut self, value: C::Element) -> Result<(), C::Element> {
+        if self.position == self.collection.end() {
+            return Err(value);
+        }
+        *self.collection.at_mut(&self.position) = value;
+        self.collection.form_next(&mut self.position);
+        Ok(())
+    }
+}
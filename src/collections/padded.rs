@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// A lazy collection presenting elements of base collection followed by
+/// copies of a padding value, so that it has at least a minimum length.
+pub struct PaddedCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// The value presented after elements of base collection.
+    value: C::Element,
+
+    /// Number of copies of `value` presented.
+    padding: usize,
+}
+
+/// Position type of PaddedCollection.
+///
+/// Positions of padding elements have `base_position` as end of base
+/// collection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct PaddedCollectionPosition<P> {
+    /// The position in base collection.
+    pub base_position: P,
+
+    /// Number of padding elements before this position.
+    pub padding_offset: usize,
+}
+
+impl<C> PaddedCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    /// Returns a new instance of PaddedCollection presenting `base` padded
+    /// with `value` to length `len`.
+    ///
+    /// # Complexity
+    ///   - O(1) for RandomAccessCollection; O(n) otherwise where
+    ///     `n == base.count()`.
+    pub fn new(base: C, len: usize, value: C::Element) -> Self {
+        let padding = len.saturating_sub(base.count());
+        PaddedCollection {
+            base,
+            value,
+            padding,
+        }
+    }
+}
+
+impl<C> Collection for PaddedCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    type Position = PaddedCollectionPosition<C::Position>;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = ValueRef<C::Element>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        PaddedCollectionPosition {
+            base_position: self.base.start(),
+            padding_offset: 0,
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        PaddedCollectionPosition {
+            base_position: self.base.end(),
+            padding_offset: self.padding,
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        if position.base_position == self.base.end() {
+            position.padding_offset += 1;
+        } else {
+            self.base.form_next(&mut position.base_position);
+        }
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, mut n: usize) {
+        let base_end = self.base.end();
+        if position.base_position != base_end {
+            let to_end =
+                self.base.distance(position.base_position.clone(), base_end);
+            if n <= to_end {
+                self.base.form_next_n(&mut position.base_position, n);
+                return;
+            }
+            n -= to_end;
+            position.base_position = self.base.end();
+        }
+        position.padding_offset += n;
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.base.distance(from.base_position, to.base_position)
+            + to.padding_offset
+            - from.padding_offset
+    }
+
+    fn count(&self) -> usize {
+        self.base.count() + self.padding
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count() + self.padding
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        if i.base_position == self.base.end() {
+            ValueRef::new(self.value.clone())
+        } else {
+            ValueRef::new(self.base.at(&i.base_position).clone())
+        }
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> LazyCollection for PaddedCollection<C>
+where
+    C: Collection,
+    C::Element: Clone,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.at(i).val
+    }
+}
+
+impl<C> BidirectionalCollection for PaddedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: Clone,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        if position.padding_offset > 0 {
+            position.padding_offset -= 1;
+        } else {
+            self.base.form_prior(&mut position.base_position);
+        }
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, mut n: usize) {
+        if n <= position.padding_offset {
+            position.padding_offset -= n;
+            return;
+        }
+        n -= position.padding_offset;
+        position.padding_offset = 0;
+        self.base.form_prior_n(&mut position.base_position, n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<C> RandomAccessCollection for PaddedCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+    C::Element: Clone,
+{
+}
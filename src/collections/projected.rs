@@ -0,0 +1,469 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::{
+    BidirectionalCollection, Collection, MutableCollection,
+    RandomAccessCollection, ReorderableCollection, ReorderableCollectionExt,
+    Slice, SliceMut,
+};
+
+/// Element-ref of a projected collection, i.e., element-ref of base
+/// collection dereferencing to projected field.
+pub struct ProjectedElementRef<'a, BaseRef, Proj, Field> {
+    /// Element-ref of base collection.
+    base_ref: BaseRef,
+
+    /// The projection.
+    proj: &'a Proj,
+
+    /// Type of projected field.
+    field: PhantomData<fn() -> &'a Field>,
+}
+
+impl<BaseRef, Proj, Field> Deref
+    for ProjectedElementRef<'_, BaseRef, Proj, Field>
+where
+    BaseRef: Deref,
+    Proj: Fn(&BaseRef::Target) -> &Field,
+{
+    type Target = Field;
+
+    fn deref(&self) -> &Field {
+        (self.proj)(&self.base_ref)
+    }
+}
+
+/// A collection presenting a field of each element of `base`.
+///
+/// Reordering it reorders whole elements of `base`, so that algorithms like
+/// sorting and partitioning can order elements by a field.
+pub struct ProjectedCollection<Base, Proj, Field>
+where
+    Base: Collection,
+    Proj: Fn(&Base::Element) -> &Field,
+{
+    /// The base collection.
+    pub base: Base,
+
+    /// The projection.
+    proj: Proj,
+}
+
+impl<Base, Proj, Field> ProjectedCollection<Base, Proj, Field>
+where
+    Base: Collection,
+    Proj: Fn(&Base::Element) -> &Field,
+{
+    pub(crate) fn new(base: Base, proj: Proj) -> Self {
+        ProjectedCollection { base, proj }
+    }
+}
+
+impl<Base, Proj, Field> Collection for ProjectedCollection<Base, Proj, Field>
+where
+    Base: Collection,
+    Proj: Fn(&Base::Element) -> &Field,
+{
+    type Position = Base::Position;
+
+    type Element = Field;
+
+    type ElementRef<'a>
+        = ProjectedElementRef<'a, Base::ElementRef<'a>, Proj, Field>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.base.start()
+    }
+
+    fn end(&self) -> Self::Position {
+        self.base.end()
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(position);
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ProjectedElementRef {
+            base_ref: self.base.at(i),
+            proj: &self.proj,
+            field: PhantomData,
+        }
+    }
+
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ProjectedElementRef {
+            base_ref: self.base.at_unchecked(i),
+            proj: &self.proj,
+            field: PhantomData,
+        }
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_next_n(position, n);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.base.form_next_n_limited_by(position, n, limit)
+    }
+
+    fn next(&self, position: Self::Position) -> Self::Position {
+        self.base.next(position)
+    }
+
+    fn next_n(&self, position: Self::Position, n: usize) -> Self::Position {
+        self.base.next_n(position, n)
+    }
+
+    fn next_n_limited_by(
+        &self,
+        position: Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> Option<Self::Position> {
+        self.base.next_n_limited_by(position, n, limit)
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.base.distance(from, to)
+    }
+
+    fn count(&self) -> usize {
+        self.base.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count()
+    }
+}
+
+impl<Base, Proj, Field> BidirectionalCollection
+    for ProjectedCollection<Base, Proj, Field>
+where
+    Base: BidirectionalCollection,
+    Base::Whole: BidirectionalCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.base.form_prior(position);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_prior_n(position, n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.base.form_prior_n_limited_by(position, n, limit)
+    }
+
+    fn prior(&self, position: Self::Position) -> Self::Position {
+        self.base.prior(position)
+    }
+
+    fn prior_n(&self, position: Self::Position, n: usize) -> Self::Position {
+        self.base.prior_n(position, n)
+    }
+
+    fn prior_n_limited_by(
+        &self,
+        position: Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> Option<Self::Position> {
+        self.base.prior_n_limited_by(position, n, limit)
+    }
+}
+
+impl<Base, Proj, Field> RandomAccessCollection
+    for ProjectedCollection<Base, Proj, Field>
+where
+    Base: RandomAccessCollection,
+    Base::Whole: RandomAccessCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+{
+}
+
+impl<Base, Proj, Field> ReorderableCollection
+    for ProjectedCollection<Base, Proj, Field>
+where
+    Base: ReorderableCollection,
+    Base::Whole: ReorderableCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+{
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.base.swap_at(i, j)
+    }
+
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        self.base.swap_at_unchecked(i, j)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
+/// A mutable collection presenting a field of each element of `base`.
+///
+/// Unlike `ProjectedCollection`, mutating or reordering it mutates or
+/// reorders only the projected fields of `base`, leaving rest of each element
+/// in place.
+pub struct ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection,
+    Base::Whole: MutableCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+    /// The base collection, projected for access.
+    base: ProjectedCollection<Base, Proj, Field>,
+
+    /// The projection for mutation.
+    proj_mut: ProjMut,
+}
+
+impl<Base, Proj, ProjMut, Field>
+    ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection,
+    Base::Whole: MutableCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+    pub(crate) fn new(base: Base, proj: Proj, proj_mut: ProjMut) -> Self {
+        ProjectedMutCollection {
+            base: ProjectedCollection::new(base, proj),
+            proj_mut,
+        }
+    }
+
+    /// Returns the base collection.
+    pub fn into_base(self) -> Base {
+        self.base.base
+    }
+}
+
+impl<Base, Proj, ProjMut, Field> Collection
+    for ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection,
+    Base::Whole: MutableCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+    type Position = Base::Position;
+
+    type Element = Field;
+
+    type ElementRef<'a>
+        = ProjectedElementRef<'a, Base::ElementRef<'a>, Proj, Field>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.base.start()
+    }
+
+    fn end(&self) -> Self::Position {
+        self.base.end()
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(position);
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base.at(i)
+    }
+
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base.at_unchecked(i)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_next_n(position, n);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.base.form_next_n_limited_by(position, n, limit)
+    }
+
+    fn next(&self, position: Self::Position) -> Self::Position {
+        self.base.next(position)
+    }
+
+    fn next_n(&self, position: Self::Position, n: usize) -> Self::Position {
+        self.base.next_n(position, n)
+    }
+
+    fn next_n_limited_by(
+        &self,
+        position: Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> Option<Self::Position> {
+        self.base.next_n_limited_by(position, n, limit)
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.base.distance(from, to)
+    }
+
+    fn count(&self) -> usize {
+        self.base.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count()
+    }
+}
+
+impl<Base, Proj, ProjMut, Field> BidirectionalCollection
+    for ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection + BidirectionalCollection,
+    Base::Whole: MutableCollection + BidirectionalCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.base.form_prior(position);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_prior_n(position, n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        self.base.form_prior_n_limited_by(position, n, limit)
+    }
+
+    fn prior(&self, position: Self::Position) -> Self::Position {
+        self.base.prior(position)
+    }
+
+    fn prior_n(&self, position: Self::Position, n: usize) -> Self::Position {
+        self.base.prior_n(position, n)
+    }
+
+    fn prior_n_limited_by(
+        &self,
+        position: Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> Option<Self::Position> {
+        self.base.prior_n_limited_by(position, n, limit)
+    }
+}
+
+impl<Base, Proj, ProjMut, Field> RandomAccessCollection
+    for ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection + RandomAccessCollection,
+    Base::Whole: MutableCollection + RandomAccessCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+}
+
+impl<Base, Proj, ProjMut, Field> ReorderableCollection
+    for ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection,
+    Base::Whole: MutableCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        if i == j {
+            return;
+        }
+        let (i, j) = if i < j { (i, j) } else { (j, i) };
+        let (mut left, mut right) = self.base.base.splitting_at_mut(j.clone());
+        std::mem::swap(
+            (self.proj_mut)(left.at_mut(i)),
+            (self.proj_mut)(right.at_mut(j)),
+        );
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
+impl<Base, Proj, ProjMut, Field> MutableCollection
+    for ProjectedMutCollection<Base, Proj, ProjMut, Field>
+where
+    Base: MutableCollection,
+    Base::Whole: MutableCollection,
+    Proj: Fn(&Base::Element) -> &Field,
+    ProjMut: Fn(&mut Base::Element) -> &mut Field,
+{
+    fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
+        (self.proj_mut)(self.base.base.at_mut(i))
+    }
+
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        (self.proj_mut)(self.base.base.at_unchecked_mut(i))
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+/// A lazy collection of `n` copies of a value.
+pub struct RepeatCollection<T> {
+    /// The repeated value.
+    value: T,
+
+    /// Number of copies.
+    n: usize,
+}
+
+/// Returns a lazy collection of `n` copies of `value`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let zeros = collections::repeat(0, 3);
+/// assert!(zeros.equals(&[0, 0, 0]));
+/// ```
+pub fn repeat<T>(value: T, n: usize) -> RepeatCollection<T> {
+    RepeatCollection { value, n }
+}
+
+impl<T> RepeatCollection<T> {
+    /// Returns the repeated value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Collection for RepeatCollection<T> {
+    type Position = usize;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        0
+    }
+
+    fn end(&self) -> Self::Position {
+        self.n
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        *position += 1
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        *position += n
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position + n <= limit {
+            *position += n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to - from
+    }
+
+    fn at(&self, _: &Self::Position) -> &T {
+        &self.value
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<T> LazyCollection for RepeatCollection<T>
+where
+    T: Clone,
+{
+    fn compute_at(&self, _: &Self::Position) -> T {
+        self.value.clone()
+    }
+}
+
+impl<T> BidirectionalCollection for RepeatCollection<T> {
+    fn form_prior(&self, position: &mut Self::Position) {
+        *position -= 1
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        *position -= n
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position >= limit + n {
+            *position -= n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<T> RandomAccessCollection for RepeatCollection<T> {}
@@ -2,7 +2,8 @@
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
 use crate::{
-    BidirectionalCollection, Collection, LazyCollection, MutableCollection,
+    hash_elements, lexicographical_partial_cmp, BidirectionalCollection,
+    Collection, CollectionExt, LazyCollection, MutableCollection,
     RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
 };
 
@@ -103,6 +104,11 @@ where
         self.base.at(&self.base.prior(i.base_position.clone()))
     }
 
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base
+            .at_unchecked(&self.base.prior(i.base_position.clone()))
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -244,6 +250,17 @@ where
         )
     }
 
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        self.base.swap_at_unchecked(
+            &self.base.prior(i.base_position.clone()),
+            &self.base.prior(j.base_position.clone()),
+        )
+    }
+
     fn slice_mut(
         &mut self,
         from: Self::Position,
@@ -261,4 +278,83 @@ where
     fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
         self.base.at_mut(&self.base.prior(i.base_position.clone()))
     }
+
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        self.base
+            .at_unchecked_mut(&self.base.prior(i.base_position.clone()))
+    }
+}
+
+/// Reversed collections are compared and hashed by their elements in
+/// reversed order.
+impl<C> PartialEq for ReversedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.equals_by(other, |x, y| x == y)
+    }
+}
+
+impl<C> Eq for ReversedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: Eq,
+{
+}
+
+impl<C> PartialOrd for ReversedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        lexicographical_partial_cmp(self, other)
+    }
+}
+
+impl<C> Ord for ReversedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: Ord,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        lexicographical_partial_cmp(self, other)
+            .expect("Ord elements should always be comparable")
+    }
+}
+
+impl<C> std::hash::Hash for ReversedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+    C::Element: std::hash::Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        hash_elements(self, state)
+    }
+}
+
+impl<C> IntoIterator for ReversedCollection<C>
+where
+    C: BidirectionalCollection + IntoIterator,
+    C::Whole: BidirectionalCollection,
+    C::IntoIter: DoubleEndedIterator,
+{
+    type Item = C::Item;
+
+    type IntoIter = std::iter::Rev<C::IntoIter>;
+
+    /// Moves elements of base collection out in reverse order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.base.into_iter().rev()
+    }
 }
@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    BidirectionalCollection, Collection, MutableCollection,
+    RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
+};
+
+/// A double ended queue of at most `N` elements, stored inline without any
+/// allocation.
+///
+/// Elements occupy a contiguous range of storage starting at `head`, wrapping
+/// around to start of storage once end of storage is reached.
+pub struct RingBuffer<T, const N: usize> {
+    /// Storage of elements, where only `len` slots starting from `head`
+    /// (wrapping around) are occupied.
+    storage: [Option<T>; N],
+
+    /// Index in storage of the first element.
+    head: usize,
+
+    /// Number of elements.
+    len: usize,
+}
+
+/// Position type of RingBuffer.
+///
+/// Positions of elements stored after `head` are ordered before positions of
+/// elements wrapped around to start of storage.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct RingBufferPosition {
+    /// True if position refers to storage before `head`.
+    is_wrapped: bool,
+
+    /// Index in storage.
+    index: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates an empty ring buffer.
+    pub fn new() -> Self {
+        Self {
+            storage: std::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns maximum number of elements ring buffer can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns true if ring buffer holds `capacity()` elements.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends `element` at back of ring buffer, or returns `element` back if
+    /// ring buffer is full.
+    pub fn push_back(&mut self, element: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(element);
+        }
+        let i = self.storage_index(self.len);
+        self.storage[i] = Some(element);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Prepends `element` at front of ring buffer, or returns `element` back
+    /// if ring buffer is full.
+    pub fn push_front(&mut self, element: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(element);
+        }
+        self.head = self.storage_index(N - 1);
+        self.storage[self.head] = Some(element);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends `element` at back of ring buffer, removing and returning the
+    /// front element if ring buffer is full.
+    ///
+    /// # Precondition
+    ///   - `N > 0`.
+    pub fn push_back_overwriting(&mut self, element: T) -> Option<T> {
+        let overwritten = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        let _ = self.push_back(element);
+        overwritten
+    }
+
+    /// Removes and returns the front element, or None if ring buffer is
+    /// empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let element = self.storage[self.head].take();
+        self.head = self.storage_index(1);
+        self.len -= 1;
+        element
+    }
+
+    /// Removes and returns the back element, or None if ring buffer is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let i = self.storage_index(self.len);
+        self.storage[i].take()
+    }
+
+    /// Removes all elements.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`.
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        self.head = 0;
+    }
+
+    /// Returns index in storage of element at `offset` from front, wrapping
+    /// around end of storage.
+    ///
+    /// # Precondition
+    ///   - `offset <= N`.
+    fn storage_index(&self, offset: usize) -> usize {
+        let i = self.head + offset;
+        if i >= N {
+            i - N
+        } else {
+            i
+        }
+    }
+
+    /// Returns position of element at `offset` from front.
+    ///
+    /// # Precondition
+    ///   - `offset <= self.len`.
+    fn position_at(&self, offset: usize) -> RingBufferPosition {
+        let i = self.head + offset;
+        if i >= N {
+            RingBufferPosition {
+                is_wrapped: true,
+                index: i - N,
+            }
+        } else {
+            RingBufferPosition {
+                is_wrapped: false,
+                index: i,
+            }
+        }
+    }
+
+    /// Returns offset from front of element at `position`.
+    fn offset_of(&self, position: &RingBufferPosition) -> usize {
+        if position.is_wrapped {
+            position.index + N - self.head
+        } else {
+            position.index - self.head
+        }
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Collection for RingBuffer<T, N> {
+    type Position = RingBufferPosition;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.position_at(0)
+    }
+
+    fn end(&self) -> Self::Position {
+        self.position_at(self.len)
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        *position = self.position_at(self.offset_of(position) + 1);
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        *position = self.position_at(self.offset_of(position) + n);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.offset_of(&to) - self.offset_of(&from)
+    }
+
+    fn count(&self) -> usize {
+        self.len
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.len
+    }
+
+    fn at(&self, i: &Self::Position) -> &T {
+        self.storage[i.index]
+            .as_ref()
+            .expect("position should be of an element")
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<T, const N: usize> BidirectionalCollection for RingBuffer<T, N> {
+    fn form_prior(&self, position: &mut Self::Position) {
+        *position = self.position_at(self.offset_of(position) - 1);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        *position = self.position_at(self.offset_of(position) - n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<T, const N: usize> RandomAccessCollection for RingBuffer<T, N> {}
+
+impl<T, const N: usize> ReorderableCollection for RingBuffer<T, N> {
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.storage.swap(i.index, j.index)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
+impl<T, const N: usize> MutableCollection for RingBuffer<T, N> {
+    fn at_mut(&mut self, i: &Self::Position) -> &mut T {
+        self.storage[i.index]
+            .as_mut()
+            .expect("position should be of an element")
+    }
+}
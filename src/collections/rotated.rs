@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    BidirectionalCollection, Collection, LazyCollection, MutableCollection,
+    RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
+};
+
+/// A collection that presents elements of base collection starting from
+/// `pivot`, wrapping around to elements before `pivot`.
+pub struct RotatedCollection<C>
+where
+    C: Collection,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Position in base collection of the first element of self.
+    pivot: C::Position,
+}
+
+/// Position type of RotatedCollection.
+///
+/// Positions of elements after `pivot` in base collection are ordered before
+/// positions of elements before `pivot`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct RotatedCollectionPosition<P> {
+    /// True if position refers to an element before pivot in base collection.
+    pub is_wrapped: bool,
+
+    /// The position in base collection.
+    pub base_position: P,
+}
+
+impl<C> RotatedCollection<C>
+where
+    C: Collection,
+{
+    /// Returns a new instance of RotatedCollection starting from `pivot` of
+    /// `base`.
+    ///
+    /// # Precondition
+    ///   - `pivot` is a valid position in `base`.
+    pub fn new(base: C, pivot: C::Position) -> Self {
+        RotatedCollection { base, pivot }
+    }
+
+    /// Returns the position in base collection of the first element of `self`.
+    pub fn pivot(&self) -> C::Position {
+        self.pivot.clone()
+    }
+
+    /// Wraps `position` to start of base collection, if it points to end of
+    /// base collection before wrapping.
+    fn normalize(&self, position: &mut RotatedCollectionPosition<C::Position>) {
+        if !position.is_wrapped && position.base_position == self.base.end() {
+            position.is_wrapped = true;
+            position.base_position = self.base.start();
+        }
+    }
+}
+
+impl<C> Collection for RotatedCollection<C>
+where
+    C: Collection,
+{
+    type Position = RotatedCollectionPosition<C::Position>;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = C::ElementRef<'a>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        let mut start = RotatedCollectionPosition {
+            is_wrapped: false,
+            base_position: self.pivot.clone(),
+        };
+        self.normalize(&mut start);
+        start
+    }
+
+    fn end(&self) -> Self::Position {
+        RotatedCollectionPosition {
+            is_wrapped: true,
+            base_position: self.pivot.clone(),
+        }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(&mut position.base_position);
+        self.normalize(position);
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, mut n: usize) {
+        if !position.is_wrapped {
+            let to_end = self
+                .base
+                .distance(position.base_position.clone(), self.base.end());
+            if n < to_end {
+                self.base.form_next_n(&mut position.base_position, n);
+                return;
+            }
+            n -= to_end;
+            position.is_wrapped = true;
+            position.base_position = self.base.start();
+        }
+        self.base.form_next_n(&mut position.base_position, n);
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        if from.is_wrapped == to.is_wrapped {
+            self.base.distance(from.base_position, to.base_position)
+        } else {
+            self.base.distance(from.base_position, self.base.end())
+                + self.base.distance(self.base.start(), to.base_position)
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.base.count()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count()
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base.at(&i.base_position)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> BidirectionalCollection for RotatedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        if position.is_wrapped && position.base_position == self.base.start() {
+            position.is_wrapped = false;
+            position.base_position = self.base.end();
+        }
+        self.base.form_prior(&mut position.base_position);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, mut n: usize) {
+        if position.is_wrapped {
+            let to_start = self
+                .base
+                .distance(self.base.start(), position.base_position.clone());
+            if n <= to_start {
+                self.base.form_prior_n(&mut position.base_position, n);
+                return;
+            }
+            n -= to_start;
+            position.is_wrapped = false;
+            position.base_position = self.base.end();
+        }
+        self.base.form_prior_n(&mut position.base_position, n);
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<C> RandomAccessCollection for RotatedCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+{
+}
+
+impl<C> LazyCollection for RotatedCollection<C>
+where
+    C: LazyCollection,
+    C::Whole: LazyCollection,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.base.compute_at(&i.base_position)
+    }
+}
+
+impl<C> ReorderableCollection for RotatedCollection<C>
+where
+    C: ReorderableCollection,
+    C::Whole: ReorderableCollection,
+{
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.base.swap_at(&i.base_position, &j.base_position)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
+impl<C> MutableCollection for RotatedCollection<C>
+where
+    C: MutableCollection,
+    C::Whole: MutableCollection,
+{
+    fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
+        self.base.at_mut(&i.base_position)
+    }
+}
+
+impl<C> IntoIterator for RotatedCollection<C>
+where
+    C: Collection + IntoIterator,
+{
+    type Item = C::Item;
+
+    type IntoIter = std::vec::IntoIter<C::Item>;
+
+    /// Moves elements of base collection out in rotated order.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == self.count()`, allocating a buffer of `n`
+    ///     elements.
+    fn into_iter(self) -> Self::IntoIter {
+        let k = self.base.distance(self.base.start(), self.pivot);
+        let mut elements: Vec<C::Item> = self.base.into_iter().collect();
+        elements.rotate_left(k);
+        elements.into_iter()
+    }
+}
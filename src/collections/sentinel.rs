@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{Collection, Slice};
+
+/// A collection presenting elements of a base collection upto, but not
+/// including, the first element satisfying a sentinel predicate, e.g., bytes
+/// of a null-terminated string.
+///
+/// End is detected while advancing positions, so constructing or iterating
+/// the collection never scans past the sentinel.
+pub struct SentinelCollection<C, F>
+where
+    C: Collection,
+    F: Fn(&C::Element) -> bool,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Returns true for the sentinel element.
+    is_end: F,
+}
+
+/// Position type of SentinelCollection.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum SentinelCollectionPosition<P> {
+    /// Position of an element before sentinel in base collection.
+    At(P),
+
+    /// End position, i.e., position of sentinel or end of base collection.
+    End,
+}
+
+impl<C, F> SentinelCollection<C, F>
+where
+    C: Collection,
+    F: Fn(&C::Element) -> bool,
+{
+    /// Returns a new instance of SentinelCollection presenting elements of
+    /// `base` before first element satisfying `is_end`.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let c_str = b"hello\0world";
+    /// let s = collections::SentinelCollection::new(c_str, |b| *b == 0);
+    /// assert!(s.equals(b"hello"));
+    /// assert!(s.starts_with(b"he"));
+    /// assert!(s.first_position_of(&b'w').is_none());
+    /// ```
+    pub fn new(base: C, is_end: F) -> Self {
+        Self { base, is_end }
+    }
+
+    /// Returns position for `position` of base collection.
+    fn position_of(
+        &self,
+        position: C::Position,
+    ) -> SentinelCollectionPosition<C::Position> {
+        if position == self.base.end()
+            || (self.is_end)(&self.base.at(&position))
+        {
+            SentinelCollectionPosition::End
+        } else {
+            SentinelCollectionPosition::At(position)
+        }
+    }
+}
+
+impl<C, F> Collection for SentinelCollection<C, F>
+where
+    C: Collection,
+    F: Fn(&C::Element) -> bool,
+{
+    type Position = SentinelCollectionPosition<C::Position>;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = C::ElementRef<'a>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.position_of(self.base.start())
+    }
+
+    fn end(&self) -> Self::Position {
+        SentinelCollectionPosition::End
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        if let SentinelCollectionPosition::At(p) = position {
+            *position = self.position_of(self.base.next(p.clone()));
+        }
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        match i {
+            SentinelCollectionPosition::At(p) => self.base.at(p),
+            SentinelCollectionPosition::End => {
+                panic!("Out of bounds read to sentinel collection.")
+            }
+        }
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{
+    BidirectionalCollection, Collection, LazyCollection, MutableCollection,
+    RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
+};
+
+/// A collection that presents every `step`-th element of base collection,
+/// starting from its first element.
+pub struct StridedCollection<C>
+where
+    C: Collection,
+{
+    /// The base collection.
+    pub base: C,
+
+    /// Distance in base collection between consecutive elements of self.
+    step: usize,
+}
+
+impl<C> StridedCollection<C>
+where
+    C: Collection,
+{
+    /// Returns a new instance of StridedCollection presenting every `step`-th
+    /// element of `base`.
+    ///
+    /// # Precondition
+    ///   - `step > 0`.
+    pub fn new(base: C, step: usize) -> Self {
+        assert!(step > 0, "stride step must be positive");
+        StridedCollection { base, step }
+    }
+
+    /// Returns distance in base collection between consecutive elements of
+    /// `self`.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+}
+
+impl<C> Collection for StridedCollection<C>
+where
+    C: Collection,
+{
+    type Position = C::Position;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = C::ElementRef<'a>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.base.start()
+    }
+
+    fn end(&self) -> Self::Position {
+        self.base.end()
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base
+            .form_next_n_limited_by(position, self.step, self.base.end());
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        // Saturating is fine as base position is clamped to end anyway.
+        self.base.form_next_n_limited_by(
+            position,
+            n.saturating_mul(self.step),
+            self.base.end(),
+        );
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.base.distance(from, to).div_ceil(self.step)
+    }
+
+    fn count(&self) -> usize {
+        self.base.count().div_ceil(self.step)
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.base.underestimated_count().div_ceil(self.step)
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base.at(i)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> BidirectionalCollection for StridedCollection<C>
+where
+    C: BidirectionalCollection,
+    C::Whole: BidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.form_prior_n(position, 1);
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        if n == 0 {
+            return;
+        }
+        if *position == self.base.end() {
+            // Last stride might be shorter than `step`.
+            let count = self.count();
+            *position = self
+                .base
+                .next_n(self.base.start(), count.saturating_sub(n) * self.step);
+        } else {
+            self.base.form_prior_n_limited_by(
+                position,
+                n.saturating_mul(self.step),
+                self.base.start(),
+            );
+        }
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<C> RandomAccessCollection for StridedCollection<C>
+where
+    C: RandomAccessCollection,
+    C::Whole: RandomAccessCollection,
+{
+}
+
+impl<C> LazyCollection for StridedCollection<C>
+where
+    C: LazyCollection,
+    C::Whole: LazyCollection,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        self.base.compute_at(i)
+    }
+}
+
+impl<C> ReorderableCollection for StridedCollection<C>
+where
+    C: ReorderableCollection,
+    C::Whole: ReorderableCollection,
+{
+    fn swap_at(&mut self, i: &Self::Position, j: &Self::Position) {
+        self.base.swap_at(i, j)
+    }
+
+    fn slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> SliceMut<'_, Self::Whole> {
+        SliceMut::new(self, from, to)
+    }
+}
+
+impl<C> MutableCollection for StridedCollection<C>
+where
+    C: MutableCollection,
+    C::Whole: MutableCollection,
+{
+    fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
+        self.base.at_mut(i)
+    }
+}
+
+impl<C> IntoIterator for StridedCollection<C>
+where
+    C: Collection + IntoIterator,
+{
+    type Item = C::Item;
+
+    type IntoIter = std::iter::StepBy<C::IntoIter>;
+
+    /// Moves every `step`th element of base collection out.
+    fn into_iter(self) -> Self::IntoIter {
+        self.base.into_iter().step_by(self.step)
+    }
+}
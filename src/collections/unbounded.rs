@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::ops::Add;
+
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice, UnboundedBidirectionalCollection,
+    UnboundedCollection, UnboundedRandomAccessCollection,
+};
+
+/// An unbounded collection whose element at offset `i` is computed as `f(i)`.
+pub struct GeneratedCollection<F> {
+    /// The generator of elements.
+    f: F,
+}
+
+impl<F> GeneratedCollection<F> {
+    /// Returns a new instance of GeneratedCollection generating elements by
+    /// `f`.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F, T> UnboundedCollection for GeneratedCollection<F>
+where
+    F: Fn(usize) -> T,
+{
+    type Position = usize;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = ValueRef<T>
+    where
+        Self: 'a;
+
+    fn start(&self) -> Self::Position {
+        0
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        *position += 1
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        *position += n
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to - from
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        ValueRef::new((self.f)(*i))
+    }
+}
+
+impl<F, T> UnboundedBidirectionalCollection for GeneratedCollection<F>
+where
+    F: Fn(usize) -> T,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        *position -= 1
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        *position -= n
+    }
+}
+
+impl<F, T> UnboundedRandomAccessCollection for GeneratedCollection<F> where
+    F: Fn(usize) -> T
+{
+}
+
+/// An unbounded collection of consecutive values `start`, `start + 1`, ...
+pub struct IotaCollection<T> {
+    /// The first element.
+    start: T,
+}
+
+impl<T> IotaCollection<T>
+where
+    T: Copy + Add<Output = T> + TryFrom<usize>,
+{
+    /// Returns a new instance of IotaCollection starting from `start`.
+    pub fn new(start: T) -> Self {
+        Self { start }
+    }
+}
+
+impl<T> UnboundedCollection for IotaCollection<T>
+where
+    T: Copy + Add<Output = T> + TryFrom<usize>,
+{
+    type Position = usize;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = ValueRef<T>
+    where
+        Self: 'a;
+
+    fn start(&self) -> Self::Position {
+        0
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        *position += 1
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        *position += n
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to - from
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        match T::try_from(*i) {
+            Ok(offset) => ValueRef::new(self.start + offset),
+            Err(_) => panic!("element should be representable"),
+        }
+    }
+}
+
+impl<T> UnboundedBidirectionalCollection for IotaCollection<T>
+where
+    T: Copy + Add<Output = T> + TryFrom<usize>,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        *position -= 1
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        *position -= n
+    }
+}
+
+impl<T> UnboundedRandomAccessCollection for IotaCollection<T> where
+    T: Copy + Add<Output = T> + TryFrom<usize>
+{
+}
+
+/// A collection of first `n` elements of an unbounded collection.
+pub struct PrefixCollection<C>
+where
+    C: UnboundedCollection,
+{
+    /// The base unbounded collection.
+    pub base: C,
+
+    /// Position in base collection just after last element of self.
+    end: C::Position,
+}
+
+impl<C> PrefixCollection<C>
+where
+    C: UnboundedCollection,
+{
+    /// Returns a new instance of PrefixCollection of first `n` elements of
+    /// `base`.
+    ///
+    /// # Complexity
+    ///   - O(1) for UnboundedRandomAccessCollection; O(n) otherwise.
+    pub fn new(base: C, n: usize) -> Self {
+        let end = base.next_n(base.start(), n);
+        Self { base, end }
+    }
+}
+
+impl<C> Collection for PrefixCollection<C>
+where
+    C: UnboundedCollection,
+{
+    type Position = C::Position;
+
+    type Element = C::Element;
+
+    type ElementRef<'a>
+        = C::ElementRef<'a>
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        self.base.start()
+    }
+
+    fn end(&self) -> Self::Position {
+        self.end.clone()
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        self.base.form_next(position)
+    }
+
+    fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_next_n(position, n)
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(position.clone(), limit.clone()) >= n {
+            self.form_next_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        self.base.distance(from, to)
+    }
+
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.base.at(i)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<C> BidirectionalCollection for PrefixCollection<C>
+where
+    C: UnboundedBidirectionalCollection,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        self.base.form_prior(position)
+    }
+
+    fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+        self.base.form_prior_n(position, n)
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if self.distance(limit.clone(), position.clone()) >= n {
+            self.form_prior_n(position, n);
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
+
+impl<C> RandomAccessCollection for PrefixCollection<C> where
+    C: UnboundedRandomAccessCollection
+{
+}
+
+impl<F, T> LazyCollection for PrefixCollection<GeneratedCollection<F>>
+where
+    F: Fn(usize) -> T,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        (self.base.f)(*i)
+    }
+}
+
+impl<T> LazyCollection for PrefixCollection<IotaCollection<T>>
+where
+    T: Copy + Add<Output = T> + TryFrom<usize>,
+{
+    fn compute_at(&self, i: &Self::Position) -> Self::Element {
+        *self.base.at(i)
+    }
+}
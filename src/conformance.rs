@@ -0,0 +1,298 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Checks that a collection conforms to laws of collection traits, and that
+//! algorithms of extension traits can be used with it.
+//!
+//! Every check takes the collection and elements it is expected to have, and
+//! panics on first violation. `collection_conformance_tests!` generates tests
+//! running these checks, which can be used by authors of new collections.
+
+use std::fmt::Debug;
+
+use crate::{
+    BidirectionalCollection, BidirectionalCollectionExt, Collection,
+    CollectionExt, MutableCollection, MutableCollectionExt,
+    RandomAccessCollection,
+};
+
+/// Returns true iff `c` has exactly elements of `expected` in order.
+fn has_elements<'e, C>(
+    c: &C,
+    expected: impl IntoIterator<Item = &'e C::Element>,
+) -> bool
+where
+    C: Collection + ?Sized,
+    C::Element: PartialEq + 'e,
+{
+    let mut rest = c.full();
+    expected
+        .into_iter()
+        .all(|e| rest.pop_first().is_some_and(|x| *x == *e))
+        && rest.is_empty()
+}
+
+/// Checks that `c` has exactly `expected` elements in order, positions are
+/// increasing, and algorithms of `CollectionExt` agree with `expected`.
+///
+/// # Complexity
+///   - O(n^2) where `n == expected.len()`.
+pub fn check_collection<C>(c: &C, expected: &[C::Element])
+where
+    C: Collection + ?Sized,
+    C::Element: PartialEq + Debug,
+{
+    let n = expected.len();
+
+    let mut positions = vec![];
+    let mut p = c.start();
+    while p != c.end() {
+        assert!(positions.len() < n, "collection has more elements");
+        assert_eq!(*c.at(&p), expected[positions.len()]);
+        positions.push(p.clone());
+        c.form_next(&mut p);
+    }
+    assert_eq!(positions.len(), n, "collection has less elements");
+    assert!(
+        positions.windows(2).all(|w| w[0] < w[1]),
+        "positions should be increasing"
+    );
+    positions.push(c.end());
+
+    assert_eq!(c.count(), n);
+    assert!(c.underestimated_count() <= n);
+    assert_eq!(c.is_empty(), n == 0);
+    for (i, p) in positions.iter().enumerate() {
+        assert!(c.next_n(c.start(), i) == *p);
+        assert_eq!(c.distance(c.start(), p.clone()), i);
+        assert!(c.next_n_limited_by(c.start(), i, c.end()) == Some(p.clone()));
+    }
+    assert!(c.next_n_limited_by(c.start(), n + 1, c.end()).is_none());
+
+    for i in 0..=n {
+        for j in i..=n {
+            let s = c.slice(positions[i].clone(), positions[j].clone());
+            assert_eq!(s.count(), j - i);
+            assert!(has_elements(&s, &expected[i..j]));
+        }
+        assert!(has_elements(&c.prefix(i), &expected[..i]));
+        assert!(has_elements(&c.dropping_prefix(i), &expected[i..]));
+    }
+
+    assert_eq!(c.first().as_deref(), expected.first());
+    for (i, e) in expected.iter().enumerate() {
+        let first = expected.iter().position(|x| x == e).unwrap();
+        assert!(
+            c.first_position_where(|x| x == e)
+                == Some(positions[first].clone())
+        );
+        assert_eq!(
+            c.count_where(|x| x == e),
+            expected.iter().filter(|x| *x == e).count()
+        );
+        assert!(c.any_satisfy(|x| x == e));
+        assert!(has_elements(
+            &c.suffix_from(positions[i].clone()),
+            &expected[i..]
+        ));
+    }
+    assert!(c.none_satisfy(|x| !expected.contains(x)));
+    assert_eq!(c.fold_left(0, |acc, _| acc + 1), n);
+
+    let mut visited = 0;
+    c.for_each(|e| {
+        assert_eq!(*e, expected[visited]);
+        visited += 1;
+    });
+    assert_eq!(visited, n);
+
+    for k in 1..=3 {
+        let parts: Vec<usize> =
+            c.splitting_evenly_in(k).map(|s| s.count()).collect();
+        assert_eq!(parts.iter().sum::<usize>(), n);
+    }
+}
+
+/// Checks that `c` conforms to `check_collection`, traversing backwards
+/// visits elements in reverse, and algorithms of
+/// `BidirectionalCollectionExt` agree with `expected`.
+///
+/// # Complexity
+///   - O(n^2) where `n == expected.len()`.
+pub fn check_bidirectional_collection<C>(c: &C, expected: &[C::Element])
+where
+    C: BidirectionalCollection + ?Sized,
+    C::Whole: BidirectionalCollection,
+    C::Element: PartialEq + Debug,
+{
+    check_collection(c, expected);
+    let n = expected.len();
+
+    let mut p = c.end();
+    for i in (0..n).rev() {
+        c.form_prior(&mut p);
+        assert_eq!(*c.at(&p), expected[i]);
+        assert!(c.next(p.clone()) == c.next_n(c.start(), i + 1));
+        assert!(c.prior_n(c.end(), n - i) == p);
+    }
+    assert!(p == c.start());
+
+    assert!(has_elements(&c.full().reversed(), expected.iter().rev()));
+    for e in expected {
+        let last = expected.iter().rposition(|x| x == e).unwrap();
+        assert!(
+            c.last_position_where(|x| x == e)
+                == Some(c.next_n(c.start(), last))
+        );
+    }
+}
+
+/// Checks that `c` conforms to `check_bidirectional_collection`, and that
+/// jumping and measuring distance between positions are consistent with
+/// traversal.
+///
+/// # Complexity
+///   - O(n^2) where `n == expected.len()`.
+pub fn check_random_access_collection<C>(c: &C, expected: &[C::Element])
+where
+    C: RandomAccessCollection + ?Sized,
+    C::Whole: RandomAccessCollection,
+    C::Element: PartialEq + Debug,
+{
+    check_bidirectional_collection(c, expected);
+    let n = expected.len();
+
+    let positions: Vec<_> =
+        Iterator::map(0..=n, |i| c.next_n(c.start(), i)).collect();
+    for i in 0..=n {
+        for j in i..=n {
+            assert_eq!(
+                c.distance(positions[i].clone(), positions[j].clone()),
+                j - i
+            );
+            assert!(c.next_n(positions[i].clone(), j - i) == positions[j]);
+            assert!(c.prior_n(positions[j].clone(), j - i) == positions[i]);
+        }
+    }
+}
+
+/// Checks that `c` conforms to `check_random_access_collection`, and that
+/// elements can be mutated and reordered in place.
+///
+/// # Postcondition
+///   - `c` has `expected` elements in order, once check is done.
+///
+/// # Complexity
+///   - O(n^2) where `n == expected.len()`.
+pub fn check_mutable_collection<C>(c: &mut C, expected: &[C::Element])
+where
+    C: MutableCollection + RandomAccessCollection + ?Sized,
+    C::Whole: MutableCollection + RandomAccessCollection,
+    C::Element: PartialEq + Debug + Clone,
+{
+    check_random_access_collection(c, expected);
+    let n = expected.len();
+    if n == 0 {
+        return;
+    }
+
+    let (first, last) = (c.start(), c.prior(c.end()));
+    c.swap_at(&first, &last);
+    assert_eq!(*c.at(&first), expected[n - 1]);
+    assert_eq!(*c.at(&last), expected[0]);
+    c.swap_at(&first, &last);
+
+    c.reverse();
+    assert!(has_elements(c, expected.iter().rev()));
+    c.reverse();
+
+    let mut i = 0;
+    c.for_each_mut(|e| {
+        *e = expected[n - 1 - i].clone();
+        i += 1;
+    });
+    assert!(has_elements(c, expected.iter().rev()));
+
+    let mut p = c.start();
+    for e in expected {
+        *c.at_mut(&p) = e.clone();
+        c.form_next(&mut p);
+    }
+    check_random_access_collection(c, expected);
+}
+
+/// Generates a module of tests checking that a collection conforms to laws
+/// of collection traits, using checks of `stl::conformance`.
+///
+/// Invoked as `collection_conformance_tests!(kind, name, collection,
+/// expected)` where
+///   - `kind` is one of `collection`, `bidirectional`, `random_access` and
+///     `mutable`, the most refined trait `collection` conforms to.
+///   - `name` is name of generated module.
+///   - `collection` is an expression creating the collection to check.
+///   - `expected` is an array of elements collection is expected to have.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// collection_conformance_tests!(random_access, squares, {
+///     (1..4).lazy_map(|x| x * x)
+/// }, [1, 4, 9]);
+/// ```
+#[macro_export]
+macro_rules! collection_conformance_tests {
+    (collection, $name:ident, $collection:expr, $expected:expr) => {
+        #[allow(unused_imports)]
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn conforms_to_collection() {
+                $crate::conformance::check_collection(&$collection, &$expected);
+            }
+        }
+    };
+    (bidirectional, $name:ident, $collection:expr, $expected:expr) => {
+        #[allow(unused_imports)]
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn conforms_to_bidirectional_collection() {
+                $crate::conformance::check_bidirectional_collection(
+                    &$collection,
+                    &$expected,
+                );
+            }
+        }
+    };
+    (random_access, $name:ident, $collection:expr, $expected:expr) => {
+        #[allow(unused_imports)]
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn conforms_to_random_access_collection() {
+                $crate::conformance::check_random_access_collection(
+                    &$collection,
+                    &$expected,
+                );
+            }
+        }
+    };
+    (mutable, $name:ident, $collection:expr, $expected:expr) => {
+        #[allow(unused_imports)]
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn conforms_to_mutable_collection() {
+                $crate::conformance::check_mutable_collection(
+                    &mut $collection,
+                    &$expected,
+                );
+            }
+        }
+    };
+}
@@ -20,8 +20,31 @@ impl<T> SemiRegular for T where T: Send + Eq {}
 pub trait Regular: SemiRegular + Clone {}
 impl<T> Regular for T where T: SemiRegular + Clone {}
 
+/// Models a sequence of slots which are written one after another, e.g., an
+/// uninitialized buffer or a growable sink.
+///
+/// Unlike `MutableCollection::at_mut`, writing doesn't require an existing
+/// element to be referred, so computed values, e.g., of a `LazyCollection`,
+/// can be written to destinations whose elements don't exist yet.
+pub trait OutputCollection {
+    /// Type of element in the sequence.
+    type Element;
+
+    /// Writes `value` to the next slot, or returns it back as error if no slot
+    /// is left.
+    ///
+    /// # Postcondition
+    ///   - Once an error is returned, every later call returns an error.
+    fn write_next(&mut self, value: Self::Element)
+        -> Result<(), Self::Element>;
+}
+
 /// Models a multi-pass linear sequence of elements.
 ///
+/// Positions can be revisited, so algorithms can traverse a collection any
+/// number of times. Single-pass sources, e.g., lines of a file, are modeled
+/// by `Iterator` instead (see `InputCollectionExt`).
+///
 /// Representation:
 /// ```text
 ///   _ _ _ _ _ _
@@ -29,6 +52,15 @@ impl<T> Regular for T where T: SemiRegular + Clone {}
 ///   ^            ^
 ///   |            |
 /// start   -->   end
+/// ```
+///
+/// # Complexity Requirements
+///   - `start`, `end`, `form_next` and `at` are O(1), and complexities
+///     stated by algorithms assume it.
+///   - Collections which can't meet it state their costs in their docs, and
+///     algorithms over them cost that much per such operation. Among stdlib
+///     implementations, these are `BTreeMap` and `BTreeSet`, with O(log n)
+///     `at`, `form_next` and `form_prior`, and `LinkedList`, with O(n) `at`.
 pub trait Collection {
     /// Type of positions in the collection.
     type Position: Regular + Ord;
@@ -171,8 +203,12 @@ pub trait Collection {
 
     /// Returns number of elements in collection.
     ///
+    /// Collections knowing their count without traversal, e.g., `BTreeMap`,
+    /// should override it to O(1); adaptors should forward it to their base
+    /// when possible.
+    ///
     /// # Complexity
-    ///   - O(1) if RandomAccessCollection; O(n) otherwise.
+    ///   - O(1) if RandomAccessCollection or overridden; O(n) otherwise.
     fn count(&self) -> usize {
         self.distance(self.start(), self.end())
     }
@@ -191,9 +227,25 @@ pub trait Collection {
     ///   - i is a valid position in self and i != end()
     ///
     /// # Complexity Requirement
-    ///   - O(1)
+    ///   - O(1), except for collections stating their cost (see
+    ///     `Collection`).
     fn at(&self, i: &Self::Position) -> Self::ElementRef<'_>;
 
+    /// Access element at position i, skipping checks of validity of i where
+    /// possible, e.g., bounds checks of slices.
+    ///
+    /// Algorithms use it after validating positions once, e.g., when they are
+    /// computed within `[start(), end())`. Defaults to `at`.
+    ///
+    /// # Safety
+    ///   - i is a valid position in self and i != end().
+    ///
+    /// # Complexity Requirement
+    ///   - O(1)
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.at(i)
+    }
+
     /// Returns slice of collection in positions `[from, to)`.
     ///
     /// # Precondition
@@ -203,6 +255,27 @@ pub trait Collection {
         from: Self::Position,
         to: Self::Position,
     ) -> Slice<'_, Self::Whole>;
+
+    /// Returns elements in positions `[from, to)` as a Rust slice if they are
+    /// stored contiguously in memory, otherwise None.
+    ///
+    /// This is the escape hatch to contiguous memory: algorithms like
+    /// `equals` and `copy_into` use it to dispatch to faster slice operations.
+    /// Collections storing elements contiguously, and adaptors over them like
+    /// `Slice`, should override it.
+    ///
+    /// # Precondition
+    ///   - `[from, to)` represents valid positions in collection.
+    ///
+    /// # Complexity Requirement
+    ///   - O(1)
+    fn contiguous_slice(
+        &self,
+        _from: Self::Position,
+        _to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        None
+    }
 }
 
 /// Models a collection whose elements are computed on element access.
@@ -357,6 +430,19 @@ where
     /// Swaps element at position i with element at position j.
     fn swap_at(&mut self, i: &Self::Position, j: &Self::Position);
 
+    /// Swaps element at position i with element at position j, skipping checks
+    /// of validity of positions where possible. Defaults to `swap_at`.
+    ///
+    /// # Safety
+    ///   - i and j are valid positions in self and not end().
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        self.swap_at(i, j)
+    }
+
     /// Returns mutable slice of collection in positions `[from, to)`.
     ///
     /// # Precondition
@@ -381,4 +467,41 @@ where
     /// # Complexity Requirement
     ///   - O(1)
     fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element;
+
+    /// Mutably access element at position i, skipping checks of validity of i
+    /// where possible. Defaults to `at_mut`.
+    ///
+    /// # Safety
+    ///   - i is a valid position in self and i != end().
+    ///
+    /// # Complexity Requirement
+    ///   - O(1)
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        self.at_mut(i)
+    }
+
+    /// Returns elements in positions `[from, to)` as a mutable Rust slice if
+    /// they are stored contiguously in memory, otherwise None.
+    ///
+    /// This is the mutable escape hatch to contiguous memory: algorithms like
+    /// `copy_into`, `fill`, `sort` and `parallel_sort_unstable_by` use it to
+    /// dispatch to faster slice operations.
+    /// Collections storing elements contiguously, and adaptors over them like
+    /// `SliceMut`, should override it.
+    ///
+    /// # Precondition
+    ///   - `[from, to)` represents valid positions in collection.
+    ///
+    /// # Complexity Requirement
+    ///   - O(1)
+    fn contiguous_slice_mut(
+        &mut self,
+        _from: Self::Position,
+        _to: Self::Position,
+    ) -> Option<&mut [Self::Element]> {
+        None
+    }
 }
@@ -2,7 +2,10 @@
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
 use crate::unwrap_option_vec;
-use std::sync::LazyLock;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    LazyLock, Mutex,
+};
 
 /// Returns the global thread pool to execute tasks on.
 fn global_thread_pool() -> &'static rayon_core::ThreadPool {
@@ -57,3 +60,199 @@ where
 
     unwrap_option_vec(task_results)
 }
+
+/// Token shared by tasks of `exec_par_until` and `exec_par_cancellable`,
+/// signalling that result has already been found and remaining work can be
+/// abandoned.
+///
+/// Every task holds its own copy of token knowing index of task, so that a
+/// task can cancel either all tasks or only the tasks after it.
+#[derive(Clone, Copy)]
+pub struct CancellationToken<'a> {
+    /// Index of first cancelled task, or usize::MAX if none is cancelled.
+    cancelled_from: &'a AtomicUsize,
+
+    /// Index of task holding the token.
+    task: usize,
+}
+
+impl CancellationToken<'_> {
+    /// Returns true if task holding the token has been cancelled and should
+    /// stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.task >= self.cancelled_from.load(Ordering::Relaxed)
+    }
+
+    /// Requests all tasks sharing the token to stop.
+    pub fn cancel(&self) {
+        self.cancelled_from.store(0, Ordering::Relaxed)
+    }
+
+    /// Requests tasks after the task holding the token to stop.
+    ///
+    /// Useful when result of an earlier task takes precedence over results
+    /// of later tasks, like finding first element satisfying a predicate.
+    pub fn cancel_later_tasks(&self) {
+        self.cancelled_from
+            .fetch_min(self.task + 1, Ordering::Relaxed);
+    }
+}
+
+/// Executes all task in `tasks` concurrently on global executor and returns
+/// the result of each task in order in a vector.
+///
+/// Each task receives a `CancellationToken` and is expected to poll it,
+/// returning early once it is cancelled.
+///
+/// # Postcondition
+///   - Tasks not yet started when they get cancelled are still executed, and
+///     are expected to return early.
+pub fn exec_par_cancellable<Task, TaskResult, Tasks>(
+    tasks: Tasks,
+) -> Vec<TaskResult>
+where
+    Task: FnOnce(CancellationToken) -> TaskResult + Send,
+    Tasks: ExactSizeIterator<Item = Task> + Send,
+    TaskResult: Send,
+{
+    let cancelled_from = AtomicUsize::new(usize::MAX);
+    let cancelled_from = &cancelled_from;
+    exec_par(tasks.enumerate().map(|(task_index, task)| {
+        move || {
+            task(CancellationToken {
+                cancelled_from,
+                task: task_index,
+            })
+        }
+    }))
+}
+
+/// Executes all task in `tasks` concurrently on global executor until one of
+/// them produces a result, and returns the first result produced. If no task
+/// produces a result, returns None.
+///
+/// Each task receives a `CancellationToken` and is expected to poll it,
+/// returning early once it is cancelled.
+///
+/// # Postcondition
+///   - Once a result is produced, token is cancelled and tasks not yet
+///     started are not executed.
+///   - If multiple tasks produce results, only the first one produced is
+///     returned, which need not be the one of first task.
+pub fn exec_par_until<Task, TaskResult, Tasks>(
+    tasks: Tasks,
+) -> Option<TaskResult>
+where
+    Task: FnOnce(CancellationToken) -> Option<TaskResult> + Send,
+    Tasks: Iterator<Item = Task> + Send,
+    TaskResult: Send,
+{
+    let cancelled_from = AtomicUsize::new(usize::MAX);
+    let token = CancellationToken {
+        cancelled_from: &cancelled_from,
+        task: 0,
+    };
+    let result = Mutex::new(None);
+    let result_ref = &result;
+
+    let tasks_storing_result = tasks
+        .take_while(|_| !token.is_cancelled())
+        .enumerate()
+        .map(|(task_index, task)| {
+            let token = CancellationToken {
+                task: task_index,
+                ..token
+            };
+            move || {
+                if token.is_cancelled() {
+                    return;
+                }
+                if let Some(task_result) = task(token) {
+                    let mut result = result_ref.lock().unwrap();
+                    if result.is_none() {
+                        *result = Some(task_result);
+                        token.cancel();
+                    }
+                }
+            }
+        });
+
+    exec_par_void(tasks_storing_result);
+
+    result.into_inner().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // First task always starts before the others, so other tasks can wait for
+    // it without deadlocking, whatever number of threads pool has.
+
+    #[test]
+    fn exec_par_until_returns_none_if_no_task_produces_result() {
+        let tasks = [1, 2, 3].map(|_| |_: CancellationToken| None::<i32>);
+        assert_eq!(exec_par_until(tasks.into_iter()), None);
+    }
+
+    #[test]
+    fn exec_par_until_cancels_other_tasks() {
+        let res = exec_par_until([0, 1, 2, 3].into_iter().map(|k| {
+            move |token: CancellationToken| {
+                if k == 0 {
+                    return Some(k);
+                }
+                while !token.is_cancelled() {
+                    std::thread::yield_now();
+                }
+                None
+            }
+        }));
+        assert_eq!(res, Some(0));
+    }
+
+    #[test]
+    fn exec_par_until_skips_tasks_after_result() {
+        let executed = AtomicUsize::new(0);
+        let executed = &executed;
+        let tasks = std::iter::repeat_with(|| {
+            move |_: CancellationToken| {
+                executed.fetch_add(1, Ordering::Relaxed);
+                Some(())
+            }
+        })
+        .take(10000);
+        assert_eq!(exec_par_until(tasks), Some(()));
+        assert!(executed.load(Ordering::Relaxed) < 10000);
+    }
+
+    #[test]
+    fn cancel_later_tasks() {
+        let res = exec_par_cancellable([0, 1, 2].into_iter().map(|k| {
+            move |token: CancellationToken| {
+                if k == 0 {
+                    token.cancel_later_tasks();
+                } else {
+                    while !token.is_cancelled() {
+                        std::thread::yield_now();
+                    }
+                }
+                token.is_cancelled()
+            }
+        }));
+        assert_eq!(res, vec![false, true, true]);
+    }
+
+    #[test]
+    fn cancel_cancels_all_tasks() {
+        let res = exec_par_cancellable([0, 1].into_iter().map(|k| {
+            move |token: CancellationToken| {
+                if k == 0 {
+                    token.cancel();
+                }
+                token.is_cancelled()
+            }
+        }));
+        assert_eq!(res, vec![true, true]);
+    }
+}
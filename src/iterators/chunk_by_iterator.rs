@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::iter::FusedIterator;
+
+use crate::{Collection, CollectionExt, Slice};
+
+/// An iterator of maximal slices of a collection where every pair of adjacent
+/// elements in a slice is related by a binary predicate.
+pub struct ChunkByIterator<'a, C, F>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element, &C::Element) -> bool,
+{
+    /// Rest of collection.
+    rest: Slice<'a, C::Whole>,
+
+    /// Predicate relating adjacent elements of a chunk.
+    bi_pred: F,
+}
+
+impl<'a, C, F> ChunkByIterator<'a, C, F>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element, &C::Element) -> bool,
+{
+    pub(crate) fn new(slice: Slice<'a, C::Whole>, bi_pred: F) -> Self {
+        ChunkByIterator {
+            rest: slice,
+            bi_pred,
+        }
+    }
+}
+
+impl<C, F> Clone for ChunkByIterator<'_, C, F>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element, &C::Element) -> bool + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            bi_pred: self.bi_pred.clone(),
+        }
+    }
+}
+
+impl<'a, C, F> Iterator for ChunkByIterator<'a, C, F>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element, &C::Element) -> bool,
+{
+    type Item = Slice<'a, C::Whole>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let end = self.rest.end();
+        let mut prev = self.rest.start();
+        let mut cur = self.rest.next(prev.clone());
+        while cur != end
+            && (self.bi_pred)(&self.rest.at(&prev), &self.rest.at(&cur))
+        {
+            prev = cur.clone();
+            self.rest.form_next(&mut cur);
+        }
+        Some(self.rest.pop_prefix_upto(cur))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.rest.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.rest.count()))
+        }
+    }
+}
+
+impl<C, F> FusedIterator for ChunkByIterator<'_, C, F>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element, &C::Element) -> bool,
+{
+}
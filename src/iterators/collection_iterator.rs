@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::iter::FusedIterator;
+
 use crate::{
     BidirectionalCollection, Collection, RandomAccessCollection, Slice,
 };
@@ -22,6 +24,22 @@ where
     pub(crate) fn new(slice: Slice<'a, C>) -> Self {
         Self { slice }
     }
+
+    /// Returns slice of remaining elements to iterate.
+    pub(crate) fn remaining(&self) -> Slice<'a, C> {
+        self.slice.clone()
+    }
+}
+
+impl<C> Clone for CollectionIter<'_, C>
+where
+    C: Collection<Whole = C>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+        }
+    }
 }
 
 impl<'a, C> Iterator for CollectionIter<'a, C>
@@ -34,6 +52,11 @@ where
         self.slice.pop_first()
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.slice.drop(n);
+        self.slice.pop_first()
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.slice.underestimated_count(), None)
     }
@@ -46,6 +69,14 @@ where
     fn next_back(&mut self) -> Option<Self::Item> {
         self.slice.pop_last()
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let mut to = self.slice.end();
+        self.slice
+            .form_prior_n_limited_by(&mut to, n, self.slice.start());
+        self.slice.drop_suffix_from(to);
+        self.slice.pop_last()
+    }
 }
 
 impl<'a, C> ExactSizeIterator for CollectionIter<'a, C>
@@ -56,3 +87,5 @@ where
         self.slice.count()
     }
 }
+
+impl<C> FusedIterator for CollectionIter<'_, C> where C: Collection<Whole = C> {}
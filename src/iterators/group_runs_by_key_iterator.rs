@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::iter::FusedIterator;
+
+use crate::{Collection, CollectionExt, Slice};
+
+/// An iterator of maximal runs of consecutive elements having equal key,
+/// yielding each run along with its key.
+pub struct GroupRunsByKeyIterator<'a, C, F, K>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element) -> K,
+    K: PartialEq,
+{
+    /// Rest of collection.
+    rest: Slice<'a, C::Whole>,
+
+    /// Function computing key of an element.
+    key_fn: F,
+}
+
+impl<'a, C, F, K> GroupRunsByKeyIterator<'a, C, F, K>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element) -> K,
+    K: PartialEq,
+{
+    pub(crate) fn new(slice: Slice<'a, C::Whole>, key_fn: F) -> Self {
+        GroupRunsByKeyIterator {
+            rest: slice,
+            key_fn,
+        }
+    }
+}
+
+impl<C, F, K> Clone for GroupRunsByKeyIterator<'_, C, F, K>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element) -> K + Clone,
+    K: PartialEq,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            key_fn: self.key_fn.clone(),
+        }
+    }
+}
+
+impl<'a, C, F, K> Iterator for GroupRunsByKeyIterator<'a, C, F, K>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Slice<'a, C::Whole>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let key = (self.key_fn)(&self.rest.at(&self.rest.start()));
+        let key_fn = &mut self.key_fn;
+        let p = self
+            .rest
+            .slice(self.rest.next(self.rest.start()), self.rest.end())
+            .first_position_where(|x| key_fn(x) != key)
+            .unwrap_or(self.rest.end());
+        Some((key, self.rest.pop_prefix_upto(p)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.rest.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, None)
+        }
+    }
+}
+
+impl<C, F, K> FusedIterator for GroupRunsByKeyIterator<'_, C, F, K>
+where
+    C: Collection<Whole = C>,
+    F: FnMut(&C::Element) -> K,
+    K: PartialEq,
+{
+}
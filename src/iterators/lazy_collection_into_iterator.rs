@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::{cell::Cell, iter::FusedIterator};
+
+use crate::{BidirectionalCollection, LazyCollection, RandomAccessCollection};
+
+/// An iterator owning a lazy collection, and yielding its computed elements.
+pub struct LazyCollectionIntoIter<C>
+where
+    C: LazyCollection<Whole = C>,
+{
+    /// The owned collection.
+    collection: C,
+
+    /// Position of next element to yield from front.
+    from: C::Position,
+
+    /// Position after next element to yield from back.
+    to: C::Position,
+
+    /// Number of elements left to yield, computed on first use as it is O(n)
+    /// for collections without random access.
+    remaining: Cell<Option<usize>>,
+}
+
+impl<C> LazyCollectionIntoIter<C>
+where
+    C: LazyCollection<Whole = C>,
+{
+    /// Creates a new instance of Self iterating over all elements of
+    /// `collection`.
+    pub(crate) fn new(collection: C) -> Self {
+        let from = collection.start();
+        let to = collection.end();
+        Self {
+            collection,
+            from,
+            to,
+            remaining: Cell::new(None),
+        }
+    }
+
+    /// Returns number of elements left to yield.
+    ///
+    /// # Complexity
+    ///   - O(1) if RandomAccessCollection or already computed; O(n)
+    ///     otherwise.
+    fn remaining(&self) -> usize {
+        match self.remaining.get() {
+            Some(n) => n,
+            None => {
+                let n = self
+                    .collection
+                    .distance(self.from.clone(), self.to.clone());
+                self.remaining.set(Some(n));
+                n
+            }
+        }
+    }
+
+    /// Records that `n` elements have been yielded or skipped.
+    fn consume(&mut self, n: usize) {
+        if let Some(remaining) = self.remaining.get_mut() {
+            *remaining -= n;
+        }
+    }
+}
+
+impl<C> Iterator for LazyCollectionIntoIter<C>
+where
+    C: LazyCollection<Whole = C>,
+{
+    type Item = C::Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.from == self.to {
+            return None;
+        }
+        let e = self.collection.compute_at(&self.from);
+        self.collection.form_next(&mut self.from);
+        self.consume(1);
+        Some(e)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.collection.form_next_n_limited_by(
+            &mut self.from,
+            n,
+            self.to.clone(),
+        ) {
+            self.consume(n);
+        } else {
+            self.remaining.set(Some(0));
+        }
+        self.next()
+    }
+
+    /// Exact number of elements left, computed once by traversal for
+    /// collections without random access, and then kept up to date.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.remaining();
+        (n, Some(n))
+    }
+}
+
+impl<C> DoubleEndedIterator for LazyCollectionIntoIter<C>
+where
+    C: BidirectionalCollection<Whole = C> + LazyCollection,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.from == self.to {
+            return None;
+        }
+        self.collection.form_prior(&mut self.to);
+        self.consume(1);
+        Some(self.collection.compute_at(&self.to))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if self.collection.form_prior_n_limited_by(
+            &mut self.to,
+            n,
+            self.from.clone(),
+        ) {
+            self.consume(n);
+        } else {
+            self.remaining.set(Some(0));
+        }
+        self.next_back()
+    }
+}
+
+impl<C> ExactSizeIterator for LazyCollectionIntoIter<C>
+where
+    C: RandomAccessCollection<Whole = C> + LazyCollection,
+{
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<C> FusedIterator for LazyCollectionIntoIter<C> where
+    C: LazyCollection<Whole = C>
+{
+}
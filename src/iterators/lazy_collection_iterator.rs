@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::iter::FusedIterator;
+
 use crate::{
     BidirectionalCollection, Collection, LazyCollection, LazyCollectionExt,
     RandomAccessCollection, Slice,
@@ -23,6 +25,22 @@ where
     pub(crate) fn new(slice: Slice<'a, C>) -> Self {
         Self { slice }
     }
+
+    /// Returns slice of remaining elements to iterate.
+    pub(crate) fn remaining(&self) -> Slice<'a, C> {
+        self.slice.clone()
+    }
+}
+
+impl<C> Clone for LazyCollectionIter<'_, C>
+where
+    C: LazyCollection<Whole = C>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+        }
+    }
 }
 
 impl<C> Iterator for LazyCollectionIter<'_, C>
@@ -37,6 +55,11 @@ where
         r
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.slice.drop(n);
+        self.next()
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.slice.underestimated_count(), None)
     }
@@ -51,6 +74,14 @@ where
         self.slice.drop_last();
         r
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let mut to = self.slice.end();
+        self.slice
+            .form_prior_n_limited_by(&mut to, n, self.slice.start());
+        self.slice.drop_suffix_from(to);
+        self.next_back()
+    }
 }
 
 impl<'a, C> ExactSizeIterator for LazyCollectionIter<'a, C>
@@ -61,3 +92,8 @@ where
         self.slice.count()
     }
 }
+
+impl<C> FusedIterator for LazyCollectionIter<'_, C> where
+    C: LazyCollection<Whole = C>
+{
+}
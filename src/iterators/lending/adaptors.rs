@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use super::{Item, LendingIterator, LendingIteratorItem};
+
+/// An iterator of results of applying a function to items of a lending
+/// iterator.
+pub struct Map<I, F> {
+    /// The base lending iterator.
+    iter: I,
+
+    /// The function to apply.
+    f: F,
+}
+
+impl<I, F> Map<I, F> {
+    /// Creates a new instance of Self with given iterator and function.
+    pub(super) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I, F, B> Iterator for Map<I, F>
+where
+    I: LendingIterator,
+    F: FnMut(Item<'_, I>) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        self.iter.next().map(&mut self.f)
+    }
+}
+
+/// A lending iterator of first `n` items of a lending iterator.
+pub struct Take<I> {
+    /// The base lending iterator.
+    iter: I,
+
+    /// Number of items left to take.
+    n: usize,
+}
+
+impl<I> Take<I> {
+    /// Creates a new instance of Self with given iterator and count.
+    pub(super) fn new(iter: I, n: usize) -> Self {
+        Self { iter, n }
+    }
+}
+
+impl<'a, I> LendingIteratorItem<'a> for Take<I>
+where
+    I: LendingIterator,
+{
+    type Item = Item<'a, I>;
+}
+
+impl<I> LendingIterator for Take<I>
+where
+    I: LendingIterator,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.n == 0 {
+            return None;
+        }
+        self.n -= 1;
+        self.iter.next()
+    }
+}
+
+/// A lending iterator of items of a lending iterator upto first item not
+/// satisfying a predicate.
+pub struct TakeWhile<I, Pred> {
+    /// The base lending iterator.
+    iter: I,
+
+    /// The predicate.
+    pred: Pred,
+
+    /// True if an item not satisfying predicate has been seen.
+    is_done: bool,
+}
+
+impl<I, Pred> TakeWhile<I, Pred> {
+    /// Creates a new instance of Self with given iterator and predicate.
+    pub(super) fn new(iter: I, pred: Pred) -> Self {
+        Self {
+            iter,
+            pred,
+            is_done: false,
+        }
+    }
+}
+
+impl<'a, I, Pred> LendingIteratorItem<'a> for TakeWhile<I, Pred>
+where
+    I: LendingIterator,
+    Pred: FnMut(&Item<'_, I>) -> bool,
+{
+    type Item = Item<'a, I>;
+}
+
+impl<I, Pred> LendingIterator for TakeWhile<I, Pred>
+where
+    I: LendingIterator,
+    Pred: FnMut(&Item<'_, I>) -> bool,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.is_done {
+            return None;
+        }
+        let e = self.iter.next()?;
+        if (self.pred)(&e) {
+            Some(e)
+        } else {
+            self.is_done = true;
+            None
+        }
+    }
+}
+
+/// A lending iterator of items of a lending iterator followed by items of
+/// another.
+pub struct Chain<A, B> {
+    /// The first lending iterator.
+    first: A,
+
+    /// The second lending iterator.
+    second: B,
+
+    /// True if first lending iterator is exhausted.
+    is_first_done: bool,
+}
+
+impl<A, B> Chain<A, B> {
+    /// Creates a new instance of Self with given iterators.
+    pub(super) fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            is_first_done: false,
+        }
+    }
+}
+
+impl<'a, A, B> LendingIteratorItem<'a> for Chain<A, B>
+where
+    A: LendingIterator,
+{
+    type Item = Item<'a, A>;
+}
+
+impl<A, B> LendingIterator for Chain<A, B>
+where
+    A: LendingIterator,
+    B: LendingIterator + for<'a> LendingIteratorItem<'a, Item = Item<'a, A>>,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if !self.is_first_done {
+            if let Some(e) = self.first.next() {
+                return Some(e);
+            }
+            self.is_first_done = true;
+        }
+        self.second.next()
+    }
+}
+
+/// A lending iterator of pairs of index and item of a lending iterator.
+pub struct Enumerate<I> {
+    /// The base lending iterator.
+    iter: I,
+
+    /// Index of next item.
+    index: usize,
+}
+
+impl<I> Enumerate<I> {
+    /// Creates a new instance of Self with given iterator.
+    pub(super) fn new(iter: I) -> Self {
+        Self { iter, index: 0 }
+    }
+}
+
+impl<'a, I> LendingIteratorItem<'a> for Enumerate<I>
+where
+    I: LendingIterator,
+{
+    type Item = (usize, Item<'a, I>);
+}
+
+impl<I> LendingIterator for Enumerate<I>
+where
+    I: LendingIterator,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let e = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some((index, e))
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use super::{Item, LendingIterator};
+use crate::MutableCollection;
+
+/// Collection algorithms driven directly by a `LendingIterator`, so that a
+/// lending stream needn't be materialized into a collection to use them.
+///
+/// Algorithms taking `&mut self` stop early and leave remaining items to be
+/// consumed later.
+pub trait LendingIteratorExt: LendingIterator {
+    /// Consumes the iterator and returns number of items.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIteratorExt};
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// assert_eq!(lending::windows_mut(&mut arr, 3).count(), 2);
+    /// ```
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.fold(0, |n, _| n + 1)
+    }
+
+    /// Consumes the iterator and returns number of items satisfying `pred`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIteratorExt};
+    ///
+    /// let iter = lending::from_collection(vec![1, 2, 3, 4]);
+    /// assert_eq!(iter.count_where(|x| *x % 2 == 0), 2);
+    /// ```
+    fn count_where<Pred>(self, mut pred: Pred) -> usize
+    where
+        Self: Sized,
+        Pred: FnMut(Item<'_, Self>) -> bool,
+    {
+        self.fold(0, |n, e| if pred(e) { n + 1 } else { n })
+    }
+
+    /// Returns true if all items satisfy `pred`.
+    ///
+    /// # Postcondition
+    ///   - Items after first item not satisfying `pred` are not consumed.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIteratorExt};
+    ///
+    /// let mut arr = [1, 2, 3, 2];
+    /// let mut windows = lending::windows_mut(&mut arr, 2);
+    /// assert!(!windows.all_satisfy(|w| w.sum() < 4));
+    /// assert!(windows.all_satisfy(|w| w.sum() == 5));
+    /// ```
+    fn all_satisfy<Pred>(&mut self, mut pred: Pred) -> bool
+    where
+        Pred: FnMut(Item<'_, Self>) -> bool,
+    {
+        while let Some(e) = self.next() {
+            if !pred(e) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns true if atleast one item satisfies `pred`.
+    ///
+    /// # Postcondition
+    ///   - Items after first item satisfying `pred` are not consumed.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIteratorExt};
+    ///
+    /// let mut iter = lending::from_collection((1..10).lazy_map(|x| x * x));
+    /// assert!(iter.any_satisfy(|x| *x > 10));
+    /// ```
+    fn any_satisfy<Pred>(&mut self, mut pred: Pred) -> bool
+    where
+        Pred: FnMut(Item<'_, Self>) -> bool,
+    {
+        while let Some(e) = self.next() {
+            if pred(e) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns true if no item satisfies `pred`.
+    ///
+    /// # Postcondition
+    ///   - Items after first item satisfying `pred` are not consumed.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIteratorExt};
+    ///
+    /// let mut iter = lending::from_collection(vec![1, 3, 5]);
+    /// assert!(iter.none_satisfy(|x| *x % 2 == 0));
+    /// ```
+    fn none_satisfy<Pred>(&mut self, pred: Pred) -> bool
+    where
+        Pred: FnMut(Item<'_, Self>) -> bool,
+    {
+        !self.any_satisfy(pred)
+    }
+
+    /// Writes results of applying `f` to items to the start of `dest` until
+    /// either `self` or `dest` is exhausted, and returns the position in
+    /// `dest` just after the last written element.
+    ///
+    /// As items may borrow from `self`, `f` turns an item into an owned
+    /// element, e.g., `|x| x.clone()` for element-refs.
+    ///
+    /// # Postcondition
+    ///   - Items not fitting in `dest` are not consumed.
+    ///
+    /// # Complexity
+    ///   - O(min(n, `dest.count()`)) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIteratorExt};
+    ///
+    /// let mut iter = lending::from_collection((1..10).lazy_map(|x| x * 2));
+    /// let mut dest = [0; 3];
+    /// assert_eq!(iter.copy_into(&mut dest, |x| *x), 3);
+    /// assert_eq!(dest, [2, 4, 6]);
+    /// ```
+    fn copy_into<D, F>(&mut self, dest: &mut D, mut f: F) -> D::Position
+    where
+        D: MutableCollection + ?Sized,
+        D::Whole: MutableCollection,
+        F: FnMut(Item<'_, Self>) -> D::Element,
+    {
+        let mut out = dest.start();
+        let end = dest.end();
+        while out != end {
+            match self.next() {
+                Some(e) => *dest.at_mut(&out) = f(e),
+                None => break,
+            }
+            dest.form_next(&mut out);
+        }
+        out
+    }
+}
+
+impl<I> LendingIteratorExt for I where I: LendingIterator + ?Sized {}
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Lending iterators, whose items may borrow from the iterator itself.
+//!
+//! Unlike `Iterator`, an item of a lending iterator must be dropped before
+//! the next one is requested. This allows streaming element-refs of a
+//! collection owned by the iterator, e.g., proxy references of a lazy
+//! collection, or overlapping mutable windows of a collection.
+//!
+//! Type of item with lifetime `'a` of lending iterator `I` is
+//! `Item<'a, I>`. It is defined through `LendingIteratorItem` rather than a
+//! generic associated type, so that closures over items borrowed for any
+//! lifetime don't require the iterator to be `'static`.
+
+mod adaptors;
+pub use adaptors::*;
+
+mod algorithms;
+pub use algorithms::*;
+
+mod sources;
+pub use sources::*;
+
+/// Defines type of item of a lending iterator borrowed for lifetime `'a`.
+///
+/// `Bound` should be left as default; it restricts `'a` to lifetimes for
+/// which `Self` is valid.
+pub trait LendingIteratorItem<'a, Bound = &'a Self> {
+    /// Type of item borrowed for lifetime `'a`.
+    type Item;
+}
+
+/// Type of item of lending iterator `I` borrowed for lifetime `'a`.
+pub type Item<'a, I> = <I as LendingIteratorItem<'a>>::Item;
+
+/// Models an iterator whose items may borrow from the iterator itself.
+pub trait LendingIterator: for<'a> LendingIteratorItem<'a> {
+    /// Advances the iterator and returns the next item, or None if iterator
+    /// is exhausted.
+    fn next(&mut self) -> Option<Item<'_, Self>>;
+
+    /// Returns an iterator of results of applying `f` to each item.
+    ///
+    /// As results can't borrow from lending iterator, returned iterator is a
+    /// regular `Iterator`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// let sums: Vec<i32> =
+    ///     lending::windows_mut(&mut arr, 2).map(|w| w.sum()).collect();
+    /// assert_eq!(sums, [3, 5]);
+    /// ```
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Item<'_, Self>) -> B,
+    {
+        Map::new(self, f)
+    }
+
+    /// Returns a lending iterator of first `n` items, or all items if there
+    /// are fewer than `n` items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// lending::windows_mut(&mut arr, 2)
+    ///     .take(2)
+    ///     .for_each(|mut w| w.reverse());
+    /// assert_eq!(arr, [2, 3, 1, 4]);
+    /// ```
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Returns a lending iterator of items upto first item not satisfying
+    /// `pred`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let iter = lending::from_collection(vec![1, 2, 5, 3]);
+    /// let small = iter.take_while(|x| **x < 4).fold(0, |acc, x| acc + x);
+    /// assert_eq!(small, 3);
+    /// ```
+    fn take_while<Pred>(self, pred: Pred) -> TakeWhile<Self, Pred>
+    where
+        Self: Sized,
+        Pred: FnMut(&Item<'_, Self>) -> bool,
+    {
+        TakeWhile::new(self, pred)
+    }
+
+    /// Returns a lending iterator of items of `self` followed by items of
+    /// `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let first = lending::from_collection(vec![1, 2]);
+    /// let second = lending::from_collection(vec![3]);
+    /// let all: Vec<i32> = first.chain(second).map(|x| *x).collect();
+    /// assert_eq!(all, [1, 2, 3]);
+    /// ```
+    fn chain<Other>(self, other: Other) -> Chain<Self, Other>
+    where
+        Self: Sized,
+        Other: LendingIterator
+            + for<'a> LendingIteratorItem<'a, Item = Item<'a, Self>>,
+    {
+        Chain::new(self, other)
+    }
+
+    /// Returns a lending iterator of pairs of index and item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let mut arr = [0; 3];
+    /// lending::windows_mut(&mut arr, 1)
+    ///     .enumerate()
+    ///     .for_each(|(i, mut w)| w.fill(i));
+    /// assert_eq!(arr, [0, 1, 2]);
+    /// ```
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        Enumerate::new(self)
+    }
+
+    /// Returns the result of left fold of items with `init` by `op`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let iter = lending::from_collection((1..4).lazy_map(|x| x * 10));
+    /// assert_eq!(iter.fold(0, |acc, x| acc + *x), 60);
+    /// ```
+    fn fold<R, F>(mut self, init: R, mut op: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(R, Item<'_, Self>) -> R,
+    {
+        let mut acc = init;
+        while let Some(e) = self.next() {
+            acc = op(acc, e);
+        }
+        acc
+    }
+
+    /// Applies `f` to each item.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of items.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::lending::{self, LendingIterator};
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// lending::windows_mut(&mut arr, 2).for_each(|mut w| {
+    ///     let prev = *w.at(&w.start());
+    ///     *w.at_mut(&w.next(w.start())) += prev;
+    /// });
+    /// assert_eq!(arr, [1, 3, 6, 10]);
+    /// ```
+    fn for_each<F>(mut self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Item<'_, Self>),
+    {
+        while let Some(e) = self.next() {
+            f(e);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use super::{Item, LendingIterator, LendingIteratorItem};
+use crate::{Collection, ReorderableCollection, SliceMut};
+
+/// A lending iterator of element-refs of a collection it owns.
+pub struct CollectionLendingIter<C>
+where
+    C: Collection,
+{
+    /// The owned collection.
+    collection: C,
+
+    /// Position of next element.
+    position: C::Position,
+}
+
+/// Returns a lending iterator of element-refs of `collection`, owning it.
+///
+/// Unlike `iter`, `collection` needn't outlive the iterator, e.g., a lazy
+/// collection can be returned with an iterator over it.
+///
+/// # Complexity
+///   - O(1).
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+/// use stl::iterators::lending::{self, LendingIterator};
+///
+/// fn squares(n: i32) -> impl LendingIterator {
+///     lending::from_collection((0..n).lazy_map(|x| x * x))
+/// }
+/// assert_eq!(squares(4).fold(0, |n, _| n + 1), 4);
+/// ```
+pub fn from_collection<C>(collection: C) -> CollectionLendingIter<C>
+where
+    C: Collection,
+{
+    let position = collection.start();
+    CollectionLendingIter {
+        collection,
+        position,
+    }
+}
+
+impl<'a, C> LendingIteratorItem<'a> for CollectionLendingIter<C>
+where
+    C: Collection,
+{
+    type Item = C::ElementRef<'a>;
+}
+
+impl<C> LendingIterator for CollectionLendingIter<C>
+where
+    C: Collection,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.position == self.collection.end() {
+            return None;
+        }
+        let position = self.position.clone();
+        self.collection.form_next(&mut self.position);
+        Some(self.collection.at(&position))
+    }
+}
+
+/// A lending iterator of overlapping mutable windows of a collection.
+pub struct WindowsMut<'s, C>
+where
+    C: ReorderableCollection + ?Sized,
+    C::Whole: ReorderableCollection,
+{
+    /// The collection.
+    collection: &'s mut C,
+
+    /// Start position of next window.
+    from: C::Position,
+
+    /// End position of next window.
+    to: C::Position,
+
+    /// True if no window is left.
+    is_done: bool,
+}
+
+/// Returns a lending iterator of every `n` consecutive elements of
+/// `collection` as mutable slices, from left to right.
+///
+/// Windows overlap, so each window must be dropped before next one is
+/// requested, which `Iterator` can't express.
+///
+/// # Precondition
+///   - `n > 0`.
+///
+/// # Complexity
+///   - O(1) for RandomAccessCollection; O(n) otherwise.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+/// use stl::iterators::lending::{self, LendingIterator};
+///
+/// let mut arr = [3, 1, 2];
+/// lending::windows_mut(&mut arr, 2).for_each(|mut w| w.sort_unstable());
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn windows_mut<C>(collection: &mut C, n: usize) -> WindowsMut<'_, C>
+where
+    C: ReorderableCollection + ?Sized,
+    C::Whole: ReorderableCollection,
+{
+    assert!(n > 0, "window size should be positive");
+    let from = collection.start();
+    let to = collection.next_n_limited_by(from.clone(), n, collection.end());
+    let is_done = to.is_none();
+    WindowsMut {
+        to: to.unwrap_or_else(|| from.clone()),
+        from,
+        collection,
+        is_done,
+    }
+}
+
+impl<'a, C> LendingIteratorItem<'a> for WindowsMut<'_, C>
+where
+    C: ReorderableCollection + ?Sized,
+    C::Whole: ReorderableCollection,
+{
+    type Item = SliceMut<'a, C::Whole>;
+}
+
+impl<C> LendingIterator for WindowsMut<'_, C>
+where
+    C: ReorderableCollection + ?Sized,
+    C::Whole: ReorderableCollection,
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.is_done {
+            return None;
+        }
+        let (from, to) = (self.from.clone(), self.to.clone());
+        if to == self.collection.end() {
+            self.is_done = true;
+        } else {
+            self.collection.form_next(&mut self.from);
+            self.collection.form_next(&mut self.to);
+        }
+        Some(self.collection.slice_mut(from, to))
+    }
+}
@@ -11,6 +11,11 @@ pub mod lazy_collection_iterator;
 #[doc(inline)]
 pub use lazy_collection_iterator::*;
 
+#[doc(hidden)]
+pub mod lazy_collection_into_iterator;
+#[doc(inline)]
+pub use lazy_collection_into_iterator::*;
+
 #[doc(hidden)]
 pub mod mutable_collection_iterator;
 #[doc(inline)]
@@ -21,7 +26,29 @@ pub mod split_where_iterator;
 #[doc(inline)]
 pub use split_where_iterator::*;
 
+#[doc(hidden)]
+pub mod split_with_separators_iterator;
+#[doc(inline)]
+pub use split_with_separators_iterator::*;
+
+#[doc(hidden)]
+pub mod group_runs_by_key_iterator;
+#[doc(inline)]
+pub use group_runs_by_key_iterator::*;
+
+#[doc(hidden)]
+pub mod chunk_by_iterator;
+#[doc(inline)]
+pub use chunk_by_iterator::*;
+
 #[doc(hidden)]
 pub mod split_evenly_iterator;
 #[doc(inline)]
 pub use split_evenly_iterator::*;
+
+#[doc(hidden)]
+pub mod peekable_iterator;
+#[doc(inline)]
+pub use peekable_iterator::*;
+
+pub mod lending;
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::iter::FusedIterator;
+
 use crate::{
     BidirectionalCollection, Collection, MutableCollection,
     RandomAccessCollection, SliceMut,
@@ -35,6 +37,11 @@ where
         self.slice.pop_first_mut()
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.slice.drop(n);
+        self.slice.pop_first_mut()
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.slice.underestimated_count(), None)
     }
@@ -47,6 +54,14 @@ where
     fn next_back(&mut self) -> Option<Self::Item> {
         self.slice.pop_last_mut()
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let mut to = self.slice.end();
+        self.slice
+            .form_prior_n_limited_by(&mut to, n, self.slice.start());
+        self.slice.drop_suffix_from(to);
+        self.slice.pop_last_mut()
+    }
 }
 
 impl<'a, C> ExactSizeIterator for MutableCollectionIter<'a, C>
@@ -57,3 +72,8 @@ where
         self.slice.count()
     }
 }
+
+impl<C> FusedIterator for MutableCollectionIter<'_, C> where
+    C: MutableCollection<Whole = C>
+{
+}
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::iter::{FusedIterator, Peekable};
+use std::ops::Deref;
+
+use crate::{
+    iterators::{CollectionIter, LazyCollectionIter},
+    value_ref::ValueRef,
+    Collection, LazyCollection, LazyCollectionExt,
+};
+
+/// Models an iterator which can look at its next item without consuming it.
+///
+/// Unlike `std::iter::Peekable`, peeking doesn't require buffering the next
+/// item when the iterator can compute it again, e.g., iterators of
+/// collections. Thus peeked item is given through a proxy reference, like
+/// `Collection::ElementRef`.
+pub trait PeekableIterator: Iterator {
+    /// Type that is like `&Self::Item`.
+    type PeekRef<'p>: Deref<Target = Self::Item>
+    where
+        Self: 'p;
+
+    /// Returns the next item without consuming it, or None if iterator is
+    /// exhausted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::PeekableIterator;
+    ///
+    /// let arr = [1, 2];
+    /// let mut it = arr.iter();
+    /// assert_eq!(**it.peek().unwrap(), 1);
+    /// assert_eq!(*it.next().unwrap(), 1);
+    /// ```
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>>;
+
+    /// Consumes and returns the next item if it satisfies `pred`, otherwise
+    /// returns None without consuming it.
+    ///
+    /// # Complexity
+    ///   - O(1) calls to `peek` and `next`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::PeekableIterator;
+    ///
+    /// let mut it = "-42".chars();
+    /// assert_eq!(it.next_if(|c| *c == '-'), Some('-'));
+    /// assert_eq!(it.next_if(|c| *c == '-'), None);
+    /// assert_eq!(it.next(), Some('4'));
+    /// ```
+    fn next_if<Pred>(&mut self, pred: Pred) -> Option<Self::Item>
+    where
+        Pred: FnOnce(&Self::Item) -> bool,
+    {
+        if pred(&*self.peek()?) {
+            self.next()
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator consuming items while they satisfy `pred`.
+    ///
+    /// Unlike `Iterator::take_while`, the first item not satisfying `pred` is
+    /// not consumed, so `self` can continue from it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::PeekableIterator;
+    ///
+    /// let mut it = "123+45".chars();
+    /// let lhs: String =
+    ///     it.take_while_peeking(|c| c.is_ascii_digit()).collect();
+    /// assert_eq!(lhs, "123");
+    /// assert_eq!(it.next(), Some('+'));
+    /// ```
+    fn take_while_peeking<Pred>(
+        &mut self,
+        pred: Pred,
+    ) -> TakeWhilePeeking<'_, Self, Pred>
+    where
+        Self: Sized,
+        Pred: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhilePeeking {
+            iter: Some(self),
+            pred,
+        }
+    }
+}
+
+/// Conversion into a PeekableIterator.
+///
+/// Implemented for every `IntoIterator` by buffering the next item with
+/// `std::iter::Peekable`. Iterators of collections implement
+/// PeekableIterator without buffering and don't need it.
+pub trait IntoPeekableIterator: IntoIterator {
+    /// Type of peekable iterator.
+    type IntoPeekableIter: PeekableIterator<Item = Self::Item>;
+
+    /// Returns peekable iterator over items of `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::{IntoPeekableIterator, PeekableIterator};
+    ///
+    /// let mut it = vec![1, 2, 3].into_peekable_iter();
+    /// assert_eq!(it.next_if(|x| *x == 1), Some(1));
+    /// assert_eq!(it.peek(), Some(&2));
+    /// ```
+    fn into_peekable_iter(self) -> Self::IntoPeekableIter;
+}
+
+impl<I> IntoPeekableIterator for I
+where
+    I: IntoIterator,
+{
+    type IntoPeekableIter = Peekable<I::IntoIter>;
+
+    fn into_peekable_iter(self) -> Self::IntoPeekableIter {
+        self.into_iter().peekable()
+    }
+}
+
+/// An iterator consuming items of a PeekableIterator while they satisfy a
+/// predicate, leaving first item not satisfying it.
+pub struct TakeWhilePeeking<'a, I, Pred>
+where
+    I: PeekableIterator,
+    Pred: FnMut(&I::Item) -> bool,
+{
+    /// The base iterator, None after first item not satisfying `pred`.
+    iter: Option<&'a mut I>,
+
+    /// The predicate.
+    pred: Pred,
+}
+
+impl<I, Pred> Iterator for TakeWhilePeeking<'_, I, Pred>
+where
+    I: PeekableIterator,
+    Pred: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.iter.as_mut()?.next_if(&mut self.pred);
+        if res.is_none() {
+            self.iter = None;
+        }
+        res
+    }
+}
+
+impl<I, Pred> FusedIterator for TakeWhilePeeking<'_, I, Pred>
+where
+    I: PeekableIterator,
+    Pred: FnMut(&I::Item) -> bool,
+{
+}
+
+impl<I> PeekableIterator for Peekable<I>
+where
+    I: Iterator,
+{
+    type PeekRef<'p>
+        = &'p I::Item
+    where
+        Self: 'p;
+
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>> {
+        Peekable::peek(self)
+    }
+}
+
+impl<'a, T> PeekableIterator for std::slice::Iter<'a, T> {
+    type PeekRef<'p>
+        = ValueRef<&'a T>
+    where
+        Self: 'p;
+
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>> {
+        self.as_slice().first().map(ValueRef::new)
+    }
+}
+
+impl PeekableIterator for std::str::Chars<'_> {
+    type PeekRef<'p>
+        = ValueRef<char>
+    where
+        Self: 'p;
+
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>> {
+        self.clone().next().map(ValueRef::new)
+    }
+}
+
+impl PeekableIterator for std::str::CharIndices<'_> {
+    type PeekRef<'p>
+        = ValueRef<(usize, char)>
+    where
+        Self: 'p;
+
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>> {
+        self.clone().next().map(ValueRef::new)
+    }
+}
+
+impl<'a, C> PeekableIterator for CollectionIter<'a, C>
+where
+    C: Collection<Whole = C>,
+{
+    type PeekRef<'p>
+        = ValueRef<C::ElementRef<'a>>
+    where
+        Self: 'p;
+
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>> {
+        self.remaining().pop_first().map(ValueRef::new)
+    }
+}
+
+impl<C> PeekableIterator for LazyCollectionIter<'_, C>
+where
+    C: LazyCollection<Whole = C>,
+{
+    type PeekRef<'p>
+        = ValueRef<C::Element>
+    where
+        Self: 'p;
+
+    fn peek(&mut self) -> Option<Self::PeekRef<'_>> {
+        self.remaining().lazy_first().map(ValueRef::new)
+    }
+}
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::iter::FusedIterator;
+
 use crate::{
-    Collection, CollectionExt, ReorderableCollection, Slice, SliceMut,
+    BidirectionalCollection, Collection, ReorderableCollection, Slice, SliceMut,
 };
 
 /// An iterator yielding evenly sized slices of collection.
@@ -43,6 +45,20 @@ where
     }
 }
 
+impl<C> Clone for SplitEvenlyIterator<'_, C>
+where
+    C: Collection<Whole = C>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            num_slices: self.num_slices,
+            slice_size: self.slice_size,
+            num_bigger_slices: self.num_bigger_slices,
+        }
+    }
+}
+
 impl<'a, C> Iterator for SplitEvenlyIterator<'a, C>
 where
     C: Collection<Whole = C>,
@@ -50,10 +66,11 @@ where
     type Item = Slice<'a, C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.rest.is_empty() {
+        if self.num_slices == 0 {
             return None;
         }
 
+        self.num_slices -= 1;
         let mut size = self.slice_size;
         if self.num_bigger_slices > 0 {
             size += 1;
@@ -77,6 +94,32 @@ where
     }
 }
 
+impl<C> DoubleEndedIterator for SplitEvenlyIterator<'_, C>
+where
+    C: BidirectionalCollection<Whole = C>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.num_slices == 0 {
+            return None;
+        }
+
+        let mut size = self.slice_size;
+        if self.num_bigger_slices == self.num_slices {
+            size += 1;
+            self.num_bigger_slices -= 1;
+        }
+        self.num_slices -= 1;
+
+        let from = self.rest.prior_n(self.rest.end(), size);
+        Some(self.rest.pop_suffix_from(from))
+    }
+}
+
+impl<C> FusedIterator for SplitEvenlyIterator<'_, C> where
+    C: Collection<Whole = C>
+{
+}
+
 /// An iterator yielding evenly sized mutable slices of collection.
 pub struct SplitEvenlyIteratorMut<'a, C>
 where
@@ -122,10 +165,11 @@ where
     type Item = SliceMut<'a, C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.rest.is_empty() {
+        if self.num_slices == 0 {
             return None;
         }
 
+        self.num_slices -= 1;
         let mut size = self.slice_size;
         if self.num_bigger_slices > 0 {
             size += 1;
@@ -148,3 +192,29 @@ where
         self.num_slices
     }
 }
+
+impl<C> FusedIterator for SplitEvenlyIteratorMut<'_, C> where
+    C: ReorderableCollection<Whole = C>
+{
+}
+
+impl<C> DoubleEndedIterator for SplitEvenlyIteratorMut<'_, C>
+where
+    C: BidirectionalCollection<Whole = C> + ReorderableCollection,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.num_slices == 0 {
+            return None;
+        }
+
+        let mut size = self.slice_size;
+        if self.num_bigger_slices == self.num_slices {
+            size += 1;
+            self.num_bigger_slices -= 1;
+        }
+        self.num_slices -= 1;
+
+        let from = self.rest.prior_n(self.rest.end(), size);
+        Some(self.rest.pop_suffix_from(from))
+    }
+}
@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::iter::FusedIterator;
+
 use crate::{
-    Collection, CollectionExt, ReorderableCollection, Slice, SliceMut,
+    BidirectionalCollection, Collection, CollectionExt, ReorderableCollection,
+    Slice, SliceMut,
 };
 
 /// An iterator of slices which are separated by elements that match `predicate`.
@@ -31,6 +34,19 @@ where
     }
 }
 
+impl<C, Pred> Clone for SplitWhereIterator<'_, C, Pred>
+where
+    C: Collection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
 impl<'a, C, Pred> Iterator for SplitWhereIterator<'a, C, Pred>
 where
     C: Collection<Whole = C>,
@@ -52,6 +68,13 @@ where
     }
 }
 
+impl<C, Pred> FusedIterator for SplitWhereIterator<'_, C, Pred>
+where
+    C: Collection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool + Clone,
+{
+}
+
 /// An iterator of mutable slices which are separated by elements that match `predicate`.
 pub struct SplitWhereIteratorMut<'a, C, Pred>
 where
@@ -98,3 +121,143 @@ where
         Some(res)
     }
 }
+
+impl<C, Pred> FusedIterator for SplitWhereIteratorMut<'_, C, Pred>
+where
+    C: ReorderableCollection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool + Clone,
+{
+}
+
+/// An iterator of slices which are separated by elements that match
+/// `predicate`, yielding slices from the back of collection.
+pub struct RSplitWhereIterator<'a, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    /// Rest of collection.
+    rest: Slice<'a, C::Whole>,
+
+    /// Predicate upon which splitting would be done.
+    predicate: Pred,
+}
+
+impl<'a, C, Pred> RSplitWhereIterator<'a, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    pub(crate) fn new(slice: Slice<'a, C::Whole>, predicate: Pred) -> Self {
+        RSplitWhereIterator {
+            rest: slice,
+            predicate,
+        }
+    }
+}
+
+impl<C, Pred> Clone for RSplitWhereIterator<'_, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<'a, C, Pred> Iterator for RSplitWhereIterator<'a, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    type Item = Slice<'a, C::Whole>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let start = self.rest.start();
+        let mut p = self.rest.end();
+        while p != start {
+            let q = self.rest.prior(p.clone());
+            if (self.predicate)(&self.rest.at(&q)) {
+                let res = self.rest.pop_suffix_from(p);
+                self.rest.drop_last();
+                return Some(res);
+            }
+            p = q;
+        }
+        Some(self.rest.pop_suffix_from(start))
+    }
+}
+
+impl<C, Pred> FusedIterator for RSplitWhereIterator<'_, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+}
+
+/// An iterator of mutable slices which are separated by elements that match
+/// `predicate`, yielding slices from the back of collection.
+pub struct RSplitWhereIteratorMut<'a, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C> + ReorderableCollection,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    /// Rest of collection.
+    rest: SliceMut<'a, C::Whole>,
+
+    /// Predicate upon which splitting would be done.
+    predicate: Pred,
+}
+
+impl<'a, C, Pred> RSplitWhereIteratorMut<'a, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C> + ReorderableCollection,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    pub(crate) fn new(slice: SliceMut<'a, C::Whole>, predicate: Pred) -> Self {
+        RSplitWhereIteratorMut {
+            rest: slice,
+            predicate,
+        }
+    }
+}
+
+impl<'a, C, Pred> Iterator for RSplitWhereIteratorMut<'a, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C> + ReorderableCollection,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    type Item = SliceMut<'a, C::Whole>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let start = self.rest.start();
+        let mut p = self.rest.end();
+        while p != start {
+            let q = self.rest.prior(p.clone());
+            if (self.predicate)(&self.rest.at(&q)) {
+                let res = self.rest.pop_suffix_from(p);
+                self.rest.drop_last();
+                return Some(res);
+            }
+            p = q;
+        }
+        Some(self.rest.pop_suffix_from(start))
+    }
+}
+
+impl<C, Pred> FusedIterator for RSplitWhereIteratorMut<'_, C, Pred>
+where
+    C: BidirectionalCollection<Whole = C> + ReorderableCollection,
+    Pred: FnMut(&C::Element) -> bool,
+{
+}
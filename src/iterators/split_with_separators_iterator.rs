@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::iter::FusedIterator;
+
+use crate::{Collection, CollectionExt, Slice};
+
+/// An item yielded by `SplitWithSeparatorsIterator`.
+pub enum SplitItem<'a, C>
+where
+    C: Collection<Whole = C> + 'a,
+{
+    /// A maximal slice of elements not matching the predicate.
+    Segment(Slice<'a, C>),
+
+    /// An element matching the predicate along with its position.
+    Separator(C::Position, C::ElementRef<'a>),
+}
+
+/// An iterator of slices separated by elements that match `predicate`,
+/// yielding the separating elements as well.
+///
+/// Iterator always yields a segment first, and alternates between segments
+/// and separators, ending with a segment.
+pub struct SplitWithSeparatorsIterator<'a, C, Pred>
+where
+    C: Collection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    /// Rest of collection.
+    rest: Slice<'a, C::Whole>,
+
+    /// Predicate upon which splitting would be done.
+    predicate: Pred,
+
+    /// True if the next item is a separator.
+    is_at_separator: bool,
+
+    /// True if the last segment has been yielded.
+    is_done: bool,
+}
+
+impl<'a, C, Pred> SplitWithSeparatorsIterator<'a, C, Pred>
+where
+    C: Collection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    pub(crate) fn new(slice: Slice<'a, C::Whole>, predicate: Pred) -> Self {
+        SplitWithSeparatorsIterator {
+            rest: slice,
+            predicate,
+            is_at_separator: false,
+            is_done: false,
+        }
+    }
+}
+
+impl<'a, C, Pred> Iterator for SplitWithSeparatorsIterator<'a, C, Pred>
+where
+    C: Collection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+    type Item = SplitItem<'a, C::Whole>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        if self.is_at_separator {
+            self.is_at_separator = false;
+            let p = self.rest.start();
+            let e = self.rest.pop_first()?;
+            return Some(SplitItem::Separator(p, e));
+        }
+
+        match self.rest.first_position_where(&mut self.predicate) {
+            Some(p) => {
+                self.is_at_separator = true;
+                Some(SplitItem::Segment(self.rest.pop_prefix_upto(p)))
+            }
+            None => {
+                self.is_done = true;
+                let end = self.rest.end();
+                Some(SplitItem::Segment(self.rest.pop_prefix_upto(end)))
+            }
+        }
+    }
+}
+
+impl<C, Pred> FusedIterator for SplitWithSeparatorsIterator<'_, C, Pred>
+where
+    C: Collection<Whole = C>,
+    Pred: FnMut(&C::Element) -> bool,
+{
+}
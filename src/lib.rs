@@ -17,6 +17,9 @@
 //! - `RangeInclusive<T>` (a..=b) where `T` is a signed/unsigned integer type.
 //! - `&str` (string slice).
 //! - `String` (Owned String).
+//! - `LinkedList<T>` (LinkedList, with O(n) element access)
+//! - `BTreeMap<K, V>` (values keyed by positions) and `BTreeSet<T>`.
+//! - `&C`, `Box<C>`, `Rc<C>`, `Arc<C>` (read-only) where `C` is a collection.
 
 mod core;
 #[doc(inline)]
@@ -30,22 +33,50 @@ mod slice_mut;
 #[doc(inline)]
 pub use slice_mut::*;
 
+mod sliding_view;
+#[doc(inline)]
+pub use sliding_view::*;
+
+mod unbounded;
+#[doc(inline)]
+pub use unbounded::*;
+
 mod algo;
 #[doc(inline)]
 pub use algo::*;
 
+mod pipeline;
+#[doc(inline)]
+pub use pipeline::*;
+
+pub mod cmp;
+
 /// All the collections exposed from library.
 pub mod collections;
 
 /// All the iterators exposed from library.
 pub mod iterators;
 
+/// Collections guarded by locks, to be shared across threads.
+pub mod locking;
+
+/// Checks of collection trait laws, for testing collection implementations.
+pub mod conformance;
+
+/// Searching lines of text for a pattern.
+pub mod textsearch;
+
 #[doc(hidden)]
 pub(crate) mod std_impl;
+#[doc(inline)]
+pub use std_impl::btree_impl::BTreePosition;
 
 /// Proxy Reference to temporary value.
 pub mod value_ref;
 
+/// Temporary buffers for implementing algorithms without unsafe code.
+pub mod buffer;
+
 mod util;
 pub(crate) use util::*;
 
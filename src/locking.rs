@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::sync::RwLock;
+
+use crate::{
+    Collection, CollectionExt, ReorderableCollection, ReorderableCollectionExt,
+    Slice, SliceMut,
+};
+
+/// A collection guarded by a `RwLock`, to be shared across threads.
+///
+/// Algorithms run on the collection inside a scope, while holding a read
+/// guard (`with_read`) or a write guard (`with_write`) for the whole scope.
+/// So every traversal sees a consistent state of the collection, and
+/// concurrent writers are blocked only for duration of the scope. To run long
+/// algorithms without blocking writers, take a `snapshot` instead.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use stl::locking::RwLockCollection;
+/// use stl::*;
+///
+/// let shared = Arc::new(RwLockCollection::new(vec![3, 1, 2]));
+/// let writer = shared.clone();
+/// std::thread::spawn(move || writer.with_write(|mut s| s.sort_unstable()))
+///     .join()
+///     .unwrap();
+/// assert!(shared.with_read(|s| s.equals(&[1, 2, 3])));
+/// ```
+#[derive(Default, Debug)]
+pub struct RwLockCollection<C> {
+    lock: RwLock<C>,
+}
+
+impl<C> RwLockCollection<C>
+where
+    C: Collection<Whole = C>,
+{
+    /// Returns a new instance of RwLockCollection guarding `collection`.
+    pub fn new(collection: C) -> Self {
+        RwLockCollection {
+            lock: RwLock::new(collection),
+        }
+    }
+
+    /// Returns result of calling `f` with slice of full collection, while
+    /// holding a read guard.
+    ///
+    /// # Precondition
+    ///   - Lock is not poisoned.
+    pub fn with_read<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(Slice<'_, C>) -> R,
+    {
+        let guard = self.lock.read().expect("RwLockCollection is poisoned");
+        f(guard.full())
+    }
+
+    /// Returns result of calling `f` with mutable slice of full collection,
+    /// while holding a write guard.
+    ///
+    /// # Precondition
+    ///   - Lock is not poisoned.
+    pub fn with_write<R, F>(&self, f: F) -> R
+    where
+        C: ReorderableCollection,
+        F: FnOnce(SliceMut<'_, C>) -> R,
+    {
+        let mut guard =
+            self.lock.write().expect("RwLockCollection is poisoned");
+        f(guard.full_mut())
+    }
+
+    /// Returns a copy of collection, holding a read guard only while copying.
+    ///
+    /// # Precondition
+    ///   - Lock is not poisoned.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of elements in collection.
+    pub fn snapshot(&self) -> C
+    where
+        C: Clone,
+    {
+        self.lock
+            .read()
+            .expect("RwLockCollection is poisoned")
+            .clone()
+    }
+
+    /// Consumes self and returns the guarded collection.
+    ///
+    /// # Precondition
+    ///   - Lock is not poisoned.
+    pub fn into_inner(self) -> C {
+        self.lock
+            .into_inner()
+            .expect("RwLockCollection is poisoned")
+    }
+}
+
+impl<C> From<C> for RwLockCollection<C>
+where
+    C: Collection<Whole = C>,
+{
+    fn from(collection: C) -> Self {
+        RwLockCollection::new(collection)
+    }
+}
@@ -0,0 +1,337 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::ops::ControlFlow;
+
+use crate::{exec_par, trace_span, Collection, CollectionExt, Slice};
+
+/// Result of applying stages of pipeline to an element.
+///
+///   - `Continue(Some(x))`: `x` is passed to terminal operation.
+///   - `Continue(None)`: element is skipped.
+///   - `Break(())`: traversal stops.
+pub type StageResult<T> = ControlFlow<(), Option<T>>;
+
+/// Models a stage of pipeline transforming elements of type `In`.
+pub trait PipelineStage<In> {
+    /// Type of elements produced by stage.
+    type Output;
+
+    /// Applies stage to `element`.
+    fn apply(&mut self, element: In) -> StageResult<Self::Output>;
+}
+
+/// Models a stage whose result on an element doesn't depend on elements
+/// before it, so that disjoint parts of source can be processed by
+/// independent clones of stage.
+///
+/// `parallel_fold` requires it, so pipelines with a `take` stage are
+/// rejected at compile time:
+/// ```rust,compile_fail
+/// use stl::*;
+///
+/// let arr = [1, 2, 3];
+/// pipeline(&arr).take(2).parallel_fold(0, |x, y| x + y, |x, y| x + y);
+/// ```
+pub trait SplittableStage {}
+
+/// Stage passing elements as it is.
+#[derive(Clone)]
+pub struct IdentityStage;
+
+impl<In> PipelineStage<In> for IdentityStage {
+    type Output = In;
+
+    fn apply(&mut self, element: In) -> StageResult<In> {
+        ControlFlow::Continue(Some(element))
+    }
+}
+
+impl SplittableStage for IdentityStage {}
+
+/// Stage mapping elements produced by `stage` with `map_fn`.
+#[derive(Clone)]
+pub struct MapStage<S, MapFn> {
+    stage: S,
+    map_fn: MapFn,
+}
+
+impl<In, S, MapFn, U> PipelineStage<In> for MapStage<S, MapFn>
+where
+    S: PipelineStage<In>,
+    MapFn: FnMut(S::Output) -> U,
+{
+    type Output = U;
+
+    fn apply(&mut self, element: In) -> StageResult<U> {
+        match self.stage.apply(element) {
+            ControlFlow::Continue(x) => {
+                ControlFlow::Continue(x.map(&mut self.map_fn))
+            }
+            ControlFlow::Break(()) => ControlFlow::Break(()),
+        }
+    }
+}
+
+impl<S: SplittableStage, MapFn> SplittableStage for MapStage<S, MapFn> {}
+
+/// Stage skipping elements produced by `stage` not satisfying `pred`.
+#[derive(Clone)]
+pub struct FilterStage<S, Pred> {
+    stage: S,
+    pred: Pred,
+}
+
+impl<In, S, Pred> PipelineStage<In> for FilterStage<S, Pred>
+where
+    S: PipelineStage<In>,
+    Pred: FnMut(&S::Output) -> bool,
+{
+    type Output = S::Output;
+
+    fn apply(&mut self, element: In) -> StageResult<S::Output> {
+        match self.stage.apply(element) {
+            ControlFlow::Continue(x) => {
+                ControlFlow::Continue(x.filter(&mut self.pred))
+            }
+            ControlFlow::Break(()) => ControlFlow::Break(()),
+        }
+    }
+}
+
+impl<S: SplittableStage, Pred> SplittableStage for FilterStage<S, Pred> {}
+
+/// Stage stopping traversal after `n` elements produced by `stage`.
+#[derive(Clone)]
+pub struct TakeStage<S> {
+    stage: S,
+    n: usize,
+}
+
+impl<In, S> PipelineStage<In> for TakeStage<S>
+where
+    S: PipelineStage<In>,
+{
+    type Output = S::Output;
+
+    fn apply(&mut self, element: In) -> StageResult<S::Output> {
+        if self.n == 0 {
+            return ControlFlow::Break(());
+        }
+        let res = self.stage.apply(element);
+        if let ControlFlow::Continue(Some(_)) = res {
+            self.n -= 1;
+        }
+        res
+    }
+}
+
+/// A deferred chain of stages over elements of a collection, executed in a
+/// single traversal by a terminal operation.
+///
+/// Stages recorded by `map`, `filter` and `take` are fused into one stage,
+/// so no intermediate collection or adaptor is created per stage. Nothing is
+/// computed until a terminal operation (`fold`, `for_each`, `count`, `to_vec`
+/// or `parallel_fold`) is called.
+pub struct Pipeline<'a, W, S>
+where
+    W: Collection<Whole = W>,
+    S: PipelineStage<W::ElementRef<'a>>,
+{
+    /// The source elements.
+    source: Slice<'a, W>,
+
+    /// The fused stages.
+    stage: S,
+}
+
+/// Returns a pipeline with no stages over elements of `collection`.
+///
+/// # Complexity
+///   - O(1).
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let arr = [1, 2, 3, 4, 5, 6];
+/// let sum = pipeline(&arr)
+///     .map(|x| x * x)
+///     .filter(|x| x % 2 == 0)
+///     .take(2)
+///     .fold(0, |acc, x| acc + x);
+/// assert_eq!(sum, 20);
+/// ```
+pub fn pipeline<C>(collection: &C) -> Pipeline<'_, C::Whole, IdentityStage>
+where
+    C: Collection + ?Sized,
+{
+    Pipeline {
+        source: collection.full(),
+        stage: IdentityStage,
+    }
+}
+
+impl<'a, W, S> Pipeline<'a, W, S>
+where
+    W: Collection<Whole = W>,
+    S: PipelineStage<W::ElementRef<'a>>,
+{
+    /// Appends a stage mapping each element with `map_fn`.
+    pub fn map<U, MapFn>(
+        self,
+        map_fn: MapFn,
+    ) -> Pipeline<'a, W, MapStage<S, MapFn>>
+    where
+        MapFn: FnMut(S::Output) -> U,
+    {
+        Pipeline {
+            source: self.source,
+            stage: MapStage {
+                stage: self.stage,
+                map_fn,
+            },
+        }
+    }
+
+    /// Appends a stage skipping elements not satisfying `pred`.
+    pub fn filter<Pred>(
+        self,
+        pred: Pred,
+    ) -> Pipeline<'a, W, FilterStage<S, Pred>>
+    where
+        Pred: FnMut(&S::Output) -> bool,
+    {
+        Pipeline {
+            source: self.source,
+            stage: FilterStage {
+                stage: self.stage,
+                pred,
+            },
+        }
+    }
+
+    /// Appends a stage stopping traversal after `n` elements have passed
+    /// through it.
+    pub fn take(self, n: usize) -> Pipeline<'a, W, TakeStage<S>> {
+        Pipeline {
+            source: self.source,
+            stage: TakeStage {
+                stage: self.stage,
+                n,
+            },
+        }
+    }
+
+    /// Executes pipeline and returns result of folding resultant elements with
+    /// `op` starting from `init`.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of source elements traversed.
+    pub fn fold<R, Op>(self, init: R, mut op: Op) -> R
+    where
+        Op: FnMut(R, S::Output) -> R,
+    {
+        let mut res = Some(init);
+        self.for_each(|x| res = Some(op(res.take().unwrap(), x)));
+        res.unwrap()
+    }
+
+    /// Executes pipeline and calls `f` on every resultant element.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of source elements traversed.
+    pub fn for_each<F>(mut self, mut f: F)
+    where
+        F: FnMut(S::Output),
+    {
+        while let Some(e) = self.source.pop_first() {
+            match self.stage.apply(e) {
+                ControlFlow::Continue(Some(x)) => f(x),
+                ControlFlow::Continue(None) => {}
+                ControlFlow::Break(()) => break,
+            }
+        }
+    }
+
+    /// Executes pipeline and returns number of resultant elements.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of source elements traversed.
+    pub fn count(self) -> usize {
+        self.fold(0, |n, _| n + 1)
+    }
+
+    /// Executes pipeline and returns resultant elements in a Vec.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n` is number of source elements traversed.
+    pub fn to_vec(self) -> Vec<S::Output> {
+        let mut res = Vec::with_capacity(self.source.underestimated_count());
+        self.for_each(|x| res.push(x));
+        res
+    }
+
+    /// Executes pipeline parallely on disjoint parts of source and returns
+    /// result of folding resultant elements.
+    ///
+    /// Every part is folded with `op` starting from a clone of `init`, and
+    /// results of parts are combined in order with `combine`.
+    ///
+    /// Pipeline can't have a `take` stage, as its stages are required to be
+    /// `SplittableStage`.
+    ///
+    /// # Precondition
+    ///   - `init` is identity of `combine`, and `op`, `combine` together are
+    ///     associative, i.e., splitting source anywhere gives same result.
+    ///
+    /// # Complexity
+    ///   - O(n) where `n == number of source elements`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let sum = pipeline(&(1..=1000_i64))
+    ///     .map(|x| *x * 2)
+    ///     .parallel_fold(0, |acc, x| acc + x, |x, y| x + y);
+    /// assert_eq!(sum, 1001000);
+    /// ```
+    pub fn parallel_fold<R, Op, Combine>(
+        self,
+        init: R,
+        op: Op,
+        combine: Combine,
+    ) -> R
+    where
+        W: Send,
+        S: SplittableStage + Clone + Send,
+        R: Clone + Send,
+        Op: FnMut(R, S::Output) -> R + Clone + Send,
+        Combine: FnMut(R, R) -> R,
+    {
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let min_elements_per_core = 512;
+        trace_span!("parallel_fold", threads = hardware_concurrency);
+        let stage = self.stage;
+        let tasks: Vec<_> = self
+            .source
+            .split_evenly_in_with_min_size(
+                hardware_concurrency,
+                min_elements_per_core,
+            )
+            .map(|source| {
+                let part = Pipeline {
+                    source,
+                    stage: stage.clone(),
+                };
+                let init = init.clone();
+                let op = op.clone();
+                move || part.fold(init, op)
+            })
+            .collect();
+        exec_par(tasks.into_iter()).into_iter().fold(init, combine)
+    }
+}
@@ -1,14 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
+use std::ops::{Index, Range};
+
 use crate::{
-    iterators::{SplitEvenlyIterator, SplitWhereIterator},
-    BidirectionalCollection, Collection, CollectionExt, LazyCollection,
-    RandomAccessCollection,
+    collections::InlineVec,
+    hash_elements,
+    iterators::{
+        RSplitWhereIterator, SplitEvenlyIterator, SplitWhereIterator,
+        SplitWithSeparatorsIterator,
+    },
+    lexicographical_partial_cmp, BidirectionalCollection, Collection,
+    CollectionExt, LazyCollection, RandomAccessCollection,
 };
 
 /// A contiguous sub-collection of a collection.
-#[derive(PartialEq, Eq)]
+///
+/// Slices are compared and hashed by their elements, irrespective of the
+/// collection or positions they span.
 pub struct Slice<'a, Whole>
 where
     Whole: Collection<Whole = Whole>,
@@ -36,6 +45,55 @@ where
     }
 }
 
+// Comparison and hashing by elements.
+impl<Whole> PartialEq for Slice<'_, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+    Whole::Element: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.equals_by(other, |x, y| x == y)
+    }
+}
+
+impl<Whole> Eq for Slice<'_, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+    Whole::Element: Eq,
+{
+}
+
+impl<Whole> PartialOrd for Slice<'_, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+    Whole::Element: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        lexicographical_partial_cmp(self, other)
+    }
+}
+
+impl<Whole> Ord for Slice<'_, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+    Whole::Element: Ord,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        lexicographical_partial_cmp(self, other)
+            .expect("Ord elements should always be comparable")
+    }
+}
+
+impl<Whole> std::hash::Hash for Slice<'_, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+    Whole::Element: std::hash::Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        hash_elements(self, state)
+    }
+}
+
 // Base accessor algorithms.
 impl<'a, Whole> Slice<'a, Whole>
 where
@@ -54,6 +112,19 @@ where
         }
     }
 
+    /// Returns reference to the whole collection.
+    pub(crate) fn whole(&self) -> &'a Whole {
+        self._whole
+    }
+
+    /// Returns true if slice spans the whole collection.
+    ///
+    /// Used to count elements with `Whole::count`, which is O(1) for many
+    /// non random access collections too, e.g., `BTreeMap`.
+    fn is_whole(&self) -> bool {
+        self.from == self._whole.start() && self.to == self._whole.end()
+    }
+
     /// Panics if position is out of bounds of slice for reading element.
     ///
     /// # Complexity
@@ -342,6 +413,76 @@ where
         SplitWhereIterator::new(self, pred)
     }
 
+    /// Returns an iterator over subsequences of `self`, split at elements
+    /// where `p` returns `true`, also yielding the elements where split
+    /// happened.
+    ///
+    /// # Postcondition
+    ///   - Yielded items alternate between segments and separators, starting
+    ///     and ending with a segment. Thus, concatenating all yielded items in
+    ///     order gives back all elements of `self`.
+    ///
+    /// # Complexity
+    ///   - O(`self.count()`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    /// use stl::iterators::SplitItem;
+    ///
+    /// let arr = [1, 2, 3, 4];
+    /// let v: Vec<_> = arr
+    ///     .full()
+    ///     .split_with_separators(|x| x % 2 == 0)
+    ///     .map(|item| match item {
+    ///         SplitItem::Segment(s) => s.to_vec(),
+    ///         SplitItem::Separator(_, e) => vec![-e],
+    ///     })
+    ///     .collect();
+    /// assert_eq!(v, vec![vec![1], vec![-2], vec![3], vec![-4], vec![]]);
+    /// ```
+    pub fn split_with_separators<Pred>(
+        self,
+        pred: Pred,
+    ) -> SplitWithSeparatorsIterator<'a, Whole, Pred>
+    where
+        Pred: FnMut(&Whole::Element) -> bool,
+    {
+        SplitWithSeparatorsIterator::new(self, pred)
+    }
+
+    /// Returns an iterator over subsequences of `self`, split at elements
+    /// where `p` returns `true`, starting from the back of `self`.
+    ///
+    /// # Note
+    ///   - Consecutive elements for which `p` returns `true` produce empty subsequences.
+    ///
+    /// # Complexity
+    ///   - O(`self.count()`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let arr = [1, 3, 5, 2, 2, 3, 4, 5, 7];
+    /// let v: Vec<_> =
+    ///   arr.full()
+    ///      .rsplit_where(|x| x % 2 == 0)
+    ///      .map(|s| s.to_vec())
+    ///      .collect();
+    /// assert_eq!(v, vec![vec![5, 7], vec![3], vec![], vec![1, 3, 5]]);
+    /// ```
+    pub fn rsplit_where<Pred>(
+        self,
+        pred: Pred,
+    ) -> RSplitWhereIterator<'a, Whole, Pred>
+    where
+        Whole: BidirectionalCollection,
+        Pred: FnMut(&Whole::Element) -> bool,
+    {
+        RSplitWhereIterator::new(self, pred)
+    }
+
     /// Returns an iterator over at most `n` subsequences of `self`, each of size
     /// at least `min_size`, splitting as evenly as possible.
     ///
@@ -376,7 +517,7 @@ where
             return SplitEvenlyIterator::new(self, 0, 0, 0);
         }
         let num_slices = match min_size == 0 {
-            true => n,
+            true => usize::min(n, c),
             false => usize::min(usize::max(c / min_size, 1), n),
         };
 
@@ -475,11 +616,34 @@ where
         self._whole.distance(from, to)
     }
 
+    fn count(&self) -> usize {
+        if self.is_whole() {
+            self._whole.count()
+        } else {
+            self.distance(self.from.clone(), self.to.clone())
+        }
+    }
+
+    fn underestimated_count(&self) -> usize {
+        if self.is_whole() {
+            self._whole.underestimated_count()
+        } else {
+            self.count()
+        }
+    }
+
     fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
         self.assert_bounds_check_read(i);
         self._whole.at(i)
     }
 
+    /// Skips only check of `i` against bounds of slice. Slice can be created
+    /// with positions outside whole collection, so whole collection still
+    /// checks `i`.
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self._whole.at(i)
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -489,6 +653,14 @@ where
         self.assert_bounds_check_slice(&to);
         Slice::new(self._whole, from, to)
     }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        self._whole.contiguous_slice(from, to)
+    }
 }
 
 impl<Whole> LazyCollection for Slice<'_, Whole>
@@ -535,3 +707,36 @@ impl<Whole> RandomAccessCollection for Slice<'_, Whole> where
     Whole: RandomAccessCollection<Whole = Whole>
 {
 }
+
+/// Implements `Index<Range<usize>>` for slices of contiguous collection
+/// `$whole`, indexing elements by offsets from start of slice.
+macro_rules! impl_index_by_offsets_for_slice {
+    ([$($generics:tt)*] $whole:ty) => {
+        impl<$($generics)*> Index<Range<usize>> for Slice<'_, $whole> {
+            type Output = [T];
+
+            /// Returns elements of slice with offsets in `range`.
+            ///
+            /// # Precondition
+            ///   - `range.start <= range.end <= self.count()`.
+            ///
+            /// # Complexity
+            ///   - O(1).
+            fn index(&self, range: Range<usize>) -> &[T] {
+                assert!(
+                    range.start <= range.end && range.end <= self.count(),
+                    "range should be within slice"
+                );
+                let from = self.from + range.start;
+                let to = self.from + range.end;
+                self._whole.contiguous_slice(from, to).unwrap()
+            }
+        }
+    };
+}
+
+impl_index_by_offsets_for_slice!([T] Vec<T>);
+impl_index_by_offsets_for_slice!([T, const N: usize] [T; N]);
+impl_index_by_offsets_for_slice!([T] & [T]);
+impl_index_by_offsets_for_slice!([T] &mut [T]);
+impl_index_by_offsets_for_slice!([T, const N: usize] InlineVec<T, N>);
@@ -1,10 +1,16 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
-use std::marker::PhantomData;
+use std::{
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range},
+};
 
 use crate::{
-    iterators::{SplitEvenlyIteratorMut, SplitWhereIteratorMut},
+    collections::InlineVec,
+    iterators::{
+        RSplitWhereIteratorMut, SplitEvenlyIteratorMut, SplitWhereIteratorMut,
+    },
     BidirectionalCollection, Collection, CollectionExt, LazyCollection,
     MutableCollection, RandomAccessCollection, ReorderableCollection, Slice,
 };
@@ -63,6 +69,14 @@ where
         unsafe { &mut *self._whole }
     }
 
+    /// Returns true if slice spans the whole collection.
+    ///
+    /// Used to count elements with `Whole::count`, which is O(1) for many
+    /// non random access collections too, e.g., `BTreeMap`.
+    fn is_whole(&self) -> bool {
+        self.from == self.whole().start() && self.to == self.whole().end()
+    }
+
     /// Panics if position is out of bounds of slice for reading element.
     ///
     /// # Complexity
@@ -239,8 +253,8 @@ where
     where
         Whole: BidirectionalCollection + MutableCollection,
     {
-        let t = self.prior(self.to.clone());
         if self.drop_last() {
+            let t = self.to.clone();
             Some(unsafe { &mut *self._whole }.at_mut(&t))
         } else {
             None
@@ -381,6 +395,38 @@ where
         SplitWhereIteratorMut::new(self, p)
     }
 
+    /// Returns an iterator over subsequences of `self`, split at elements
+    /// where `p` returns `true`, starting from the back of `self`.
+    ///
+    /// # Note
+    ///   - Consecutive elements for which `p` returns `true` produce empty subsequences.
+    ///
+    /// # Complexity
+    ///   - O(`self.count()`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let mut arr = [1, 3, 5, 2, 2, 3, 4, 5, 7];
+    /// let v: Vec<_> =
+    ///   arr.full_mut()
+    ///      .rsplit_where(|x| x % 2 == 0)
+    ///      .map(|s| s.to_vec())
+    ///      .collect();
+    /// assert_eq!(v, vec![vec![5, 7], vec![3], vec![], vec![1, 3, 5]]);
+    /// ```
+    pub fn rsplit_where<Predicate>(
+        self,
+        p: Predicate,
+    ) -> RSplitWhereIteratorMut<'a, Whole, Predicate>
+    where
+        Whole: BidirectionalCollection,
+        Predicate: FnMut(&Whole::Element) -> bool,
+    {
+        RSplitWhereIteratorMut::new(self, p)
+    }
+
     /// Returns an iterator over at most `n` subsequences of `self`, each of size
     /// at least `min_size`, splitting as evenly as possible.
     ///
@@ -415,7 +461,7 @@ where
             return SplitEvenlyIteratorMut::new(self, 0, 0, 0);
         }
         let num_slices = match min_size == 0 {
-            true => n,
+            true => usize::min(n, c),
             false => usize::min(usize::max(c / min_size, 1), n),
         };
 
@@ -517,11 +563,34 @@ where
         self.whole().distance(from, to)
     }
 
+    fn count(&self) -> usize {
+        if self.is_whole() {
+            self.whole().count()
+        } else {
+            self.distance(self.from.clone(), self.to.clone())
+        }
+    }
+
+    fn underestimated_count(&self) -> usize {
+        if self.is_whole() {
+            self.whole().underestimated_count()
+        } else {
+            self.count()
+        }
+    }
+
     fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
         self.assert_bounds_check_read(i);
         self.whole().at(i)
     }
 
+    /// Skips only check of `i` against bounds of slice. Slice can be created
+    /// with positions outside whole collection, so whole collection still
+    /// checks `i`.
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.whole().at(i)
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -531,6 +600,14 @@ where
         self.assert_bounds_check_slice(&to);
         Slice::new(self.whole(), from, to)
     }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        self.whole().contiguous_slice(from, to)
+    }
 }
 
 impl<Whole> LazyCollection for SliceMut<'_, Whole>
@@ -588,6 +665,16 @@ where
         self.whole_mut().swap_at(i, j);
     }
 
+    /// Skips only checks of `i` and `j` against bounds of slice, as in
+    /// `at_unchecked`.
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        self.whole_mut().swap_at(i, j);
+    }
+
     fn slice_mut(
         &mut self,
         from: Self::Position,
@@ -607,4 +694,73 @@ where
         self.assert_bounds_check_read(i);
         self.whole_mut().at_mut(i)
     }
+
+    /// Skips only check of `i` against bounds of slice, as in `at_unchecked`.
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        self.whole_mut().at_mut(i)
+    }
+
+    fn contiguous_slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&mut [Self::Element]> {
+        self.whole_mut().contiguous_slice_mut(from, to)
+    }
 }
+
+/// Implements `Index<Range<usize>>` and `IndexMut<Range<usize>>` for mutable
+/// slices of contiguous collection `$whole`, indexing elements by offsets
+/// from start of slice.
+macro_rules! impl_index_by_offsets_for_slice_mut {
+    ([$($generics:tt)*] $whole:ty) => {
+        impl<$($generics)*> SliceMut<'_, $whole> {
+            /// Returns positions of elements of slice with offsets in `range`.
+            fn positions_at_offsets(&self, range: Range<usize>) -> Range<usize> {
+                assert!(
+                    range.start <= range.end && range.end <= self.count(),
+                    "range should be within slice"
+                );
+                self.from + range.start..self.from + range.end
+            }
+        }
+
+        impl<$($generics)*> Index<Range<usize>> for SliceMut<'_, $whole> {
+            type Output = [T];
+
+            /// Returns elements of slice with offsets in `range`.
+            ///
+            /// # Precondition
+            ///   - `range.start <= range.end <= self.count()`.
+            ///
+            /// # Complexity
+            ///   - O(1).
+            fn index(&self, range: Range<usize>) -> &[T] {
+                let Range { start, end } = self.positions_at_offsets(range);
+                self.whole().contiguous_slice(start, end).unwrap()
+            }
+        }
+
+        impl<$($generics)*> IndexMut<Range<usize>> for SliceMut<'_, $whole> {
+            /// Returns mutable elements of slice with offsets in `range`.
+            ///
+            /// # Precondition
+            ///   - `range.start <= range.end <= self.count()`.
+            ///
+            /// # Complexity
+            ///   - O(1).
+            fn index_mut(&mut self, range: Range<usize>) -> &mut [T] {
+                let Range { start, end } = self.positions_at_offsets(range);
+                self.whole_mut().contiguous_slice_mut(start, end).unwrap()
+            }
+        }
+    };
+}
+
+impl_index_by_offsets_for_slice_mut!([T] Vec<T>);
+impl_index_by_offsets_for_slice_mut!([T, const N: usize] [T; N]);
+impl_index_by_offsets_for_slice_mut!([T] &mut [T]);
+impl_index_by_offsets_for_slice_mut!([T, const N: usize] InlineVec<T, N>);
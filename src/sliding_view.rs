@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use crate::{Collection, Slice};
+
+/// A window of contiguous elements sliding forward over a collection.
+///
+/// Window grows at back with `advance_back` and shrinks at front with
+/// `advance_front`, both in O(1). This is the backbone of streaming window
+/// algorithms like rolling folds and two-pointer techniques.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// // Length of longest subarray with sum at most 7.
+/// let arr = [2, 1, 5, 1, 3, 2];
+/// let mut view = SlidingView::new(arr.full());
+/// let (mut sum, mut longest) = (0, 0);
+/// while view.advance_back() {
+///     sum += *view.back().unwrap();
+///     while sum > 7 {
+///         sum -= *view.front().unwrap();
+///         view.advance_front();
+///     }
+///     longest = longest.max(view.len());
+/// }
+/// assert_eq!(longest, 3);
+/// ```
+pub struct SlidingView<'a, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+{
+    /// Reference to the whole collection.
+    whole: &'a Whole,
+
+    /// Start position of window.
+    from: Whole::Position,
+
+    /// End position of window.
+    to: Whole::Position,
+
+    /// Position of last element of window, `None` if window is empty.
+    last: Option<Whole::Position>,
+
+    /// Position beyond which window can't grow.
+    limit: Whole::Position,
+
+    /// Number of elements in window.
+    len: usize,
+}
+
+impl<'a, Whole> SlidingView<'a, Whole>
+where
+    Whole: Collection<Whole = Whole>,
+{
+    /// Returns a new instance of SlidingView with an empty window at start
+    /// of `bounds`, which can slide till end of `bounds`.
+    pub fn new(bounds: Slice<'a, Whole>) -> Self {
+        SlidingView {
+            whole: bounds.whole(),
+            from: bounds.start(),
+            to: bounds.start(),
+            last: None,
+            limit: bounds.end(),
+            len: 0,
+        }
+    }
+
+    /// Returns slice of elements in window.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn window(&self) -> Slice<'a, Whole> {
+        Slice::new(self.whole, self.from.clone(), self.to.clone())
+    }
+
+    /// Returns number of elements in window.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if window has no elements.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if window can't grow any further.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn is_at_limit(&self) -> bool {
+        self.to == self.limit
+    }
+
+    /// Returns the first element of window, or `None` if window is empty.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn front(&self) -> Option<Whole::ElementRef<'a>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.whole.at(&self.from))
+        }
+    }
+
+    /// Returns the last element of window, or `None` if window is empty.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn back(&self) -> Option<Whole::ElementRef<'a>> {
+        self.last.as_ref().map(|p| self.whole.at(p))
+    }
+
+    /// Grows window to include next element and returns true; returns false
+    /// if window can't grow any further.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn advance_back(&mut self) -> bool {
+        if self.is_at_limit() {
+            return false;
+        }
+        self.last = Some(self.to.clone());
+        self.whole.form_next(&mut self.to);
+        self.len += 1;
+        true
+    }
+
+    /// Shrinks window by excluding its first element and returns true;
+    /// returns false if window is empty.
+    ///
+    /// # Complexity
+    ///   - O(1).
+    pub fn advance_front(&mut self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        self.whole.form_next(&mut self.from);
+        self.len -= 1;
+        if self.len == 0 {
+            self.last = None;
+        }
+        true
+    }
+}
@@ -75,6 +75,10 @@ impl<T, const N: usize> Collection for [T; N] {
         &self[*i]
     }
 
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> &Self::Element {
+        self.get_unchecked(*i)
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -82,6 +86,14 @@ impl<T, const N: usize> Collection for [T; N] {
     ) -> Slice<'_, Self::Whole> {
         Slice::new(self, from, to)
     }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        Some(&self[from..to])
+    }
 }
 
 impl<T, const N: usize> BidirectionalCollection for [T; N] {
@@ -116,6 +128,15 @@ impl<T, const N: usize> ReorderableCollection for [T; N] {
         self.swap(*i, *j)
     }
 
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        let p = self.as_mut_ptr();
+        std::ptr::swap(p.add(*i), p.add(*j))
+    }
+
     fn slice_mut(
         &mut self,
         from: Self::Position,
@@ -129,4 +150,19 @@ impl<T, const N: usize> MutableCollection for [T; N] {
     fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
         &mut self[*i]
     }
+
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        self.get_unchecked_mut(*i)
+    }
+
+    fn contiguous_slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&mut [Self::Element]> {
+        Some(&mut self[from..to])
+    }
 }
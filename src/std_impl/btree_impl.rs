@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Collection implementations of `BTreeMap` and `BTreeSet`.
+//!
+//! Stable std doesn't expose nodes of B-trees, so a position can only be a
+//! key, and `at`, `form_next` and `form_prior` look the key up. They are
+//! thus O(log n) instead of O(1), as allowed by complexity requirements of
+//! `Collection` for collections stating their cost. Every algorithm over
+//! these collections costs an extra log(n) factor per element access or
+//! position step, e.g., traversal is O(n * log(n)).
+//!
+//! Prefer iterating `BTreeMap` and `BTreeSet` directly when that matters.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound::{Excluded, Unbounded};
+
+use crate::{BidirectionalCollection, Collection, Regular, Slice};
+
+/// Position type of `BTreeMap` and `BTreeSet`.
+///
+/// Position refers to an element by its key, so it stays valid as long as the
+/// key is present. Positions are ordered by keys, and end position is ordered
+/// after all keys.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BTreePosition<K> {
+    /// Key of element, or `None` for end position.
+    key: Option<K>,
+}
+
+impl<K> BTreePosition<K> {
+    /// Returns key of element at position, or `None` for end position.
+    pub fn key(&self) -> Option<&K> {
+        self.key.as_ref()
+    }
+}
+
+impl<K: Ord> PartialOrd for BTreePosition<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord> Ord for BTreePosition<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.key, &other.key) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// BTreeMap is a collection of its values, with positions being keys.
+impl<K, V> Collection for BTreeMap<K, V>
+where
+    K: Regular + Ord,
+{
+    type Position = BTreePosition<K>;
+
+    type Element = V;
+
+    type ElementRef<'a>
+        = &'a V
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        BTreePosition {
+            key: self.keys().next().cloned(),
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        BTreePosition { key: None }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        let key = position.key.take().unwrap();
+        position.key = self
+            .range((Excluded(key), Unbounded))
+            .next()
+            .map(|(k, _)| k.clone());
+    }
+
+    fn count(&self) -> usize {
+        self.len()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.len()
+    }
+
+    /// O(log n), see module docs.
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        &self[i.key.as_ref().unwrap()]
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<K, V> BidirectionalCollection for BTreeMap<K, V>
+where
+    K: Regular + Ord,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        let prior = match position.key.take() {
+            Some(key) => self.range(..key).next_back(),
+            None => self.last_key_value(),
+        };
+        position.key = prior.map(|(k, _)| k.clone());
+    }
+}
+
+impl<T> Collection for BTreeSet<T>
+where
+    T: Regular + Ord,
+{
+    type Position = BTreePosition<T>;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        BTreePosition {
+            key: self.first().cloned(),
+        }
+    }
+
+    fn end(&self) -> Self::Position {
+        BTreePosition { key: None }
+    }
+
+    fn form_next(&self, position: &mut Self::Position) {
+        let key = position.key.take().unwrap();
+        position.key = self.range((Excluded(key), Unbounded)).next().cloned();
+    }
+
+    fn count(&self) -> usize {
+        self.len()
+    }
+
+    fn underestimated_count(&self) -> usize {
+        self.len()
+    }
+
+    /// O(log n), see module docs.
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        self.get(i.key.as_ref().unwrap()).unwrap()
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<T> BidirectionalCollection for BTreeSet<T>
+where
+    T: Regular + Ord,
+{
+    fn form_prior(&self, position: &mut Self::Position) {
+        position.key = match position.key.take() {
+            Some(key) => self.range(..key).next_back().cloned(),
+            None => self.last().cloned(),
+        };
+    }
+}
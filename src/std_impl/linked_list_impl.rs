@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Collection implementation of `LinkedList`.
+//!
+//! Stable std doesn't expose nodes of LinkedList, so a position is index of
+//! element. Moving positions is O(1), but `at` walks the list from nearer
+//! end, and is O(n) instead of O(1), as allowed by complexity requirements
+//! of `Collection` for collections stating their cost. Every algorithm over
+//! LinkedList thus costs an extra O(n) factor per element access, e.g.,
+//! traversal is O(n^2).
+//!
+//! LinkedList is useful to exercise algorithms on non-random-access
+//! collections; prefer iterating it directly when performance matters.
+
+use std::collections::LinkedList;
+
+use crate::{BidirectionalCollection, Collection, Slice};
+
+/// Returns element at index `i` of `list`, walking from nearer end.
+fn node_at<T>(list: &LinkedList<T>, i: usize) -> &T {
+    let n = list.len();
+    if i < n / 2 {
+        list.iter().nth(i).unwrap()
+    } else {
+        list.iter().nth_back(n - 1 - i).unwrap()
+    }
+}
+
+impl<T> Collection for LinkedList<T> {
+    type Position = usize;
+
+    type Element = T;
+
+    type ElementRef<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    type Whole = Self;
+
+    fn start(&self) -> Self::Position {
+        0
+    }
+
+    fn end(&self) -> Self::Position {
+        self.len()
+    }
+
+    fn form_next(&self, i: &mut Self::Position) {
+        *i += 1
+    }
+
+    fn form_next_n(&self, i: &mut Self::Position, n: usize) {
+        *i += n
+    }
+
+    fn form_next_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position + n <= limit {
+            *position += n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+
+    fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+        to - from
+    }
+
+    /// O(n), see module docs.
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+        node_at(self, *i)
+    }
+
+    fn slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Slice<'_, Self::Whole> {
+        Slice::new(self, from, to)
+    }
+}
+
+impl<T> BidirectionalCollection for LinkedList<T> {
+    fn form_prior(&self, i: &mut Self::Position) {
+        *i -= 1
+    }
+
+    fn form_prior_n(&self, i: &mut Self::Position, n: usize) {
+        *i -= n
+    }
+
+    fn form_prior_n_limited_by(
+        &self,
+        position: &mut Self::Position,
+        n: usize,
+        limit: Self::Position,
+    ) -> bool {
+        if *position >= limit + n {
+            *position -= n;
+            true
+        } else {
+            *position = limit;
+            false
+        }
+    }
+}
@@ -2,7 +2,10 @@
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
 pub mod array_impl;
+pub mod btree_impl;
+pub mod linked_list_impl;
 pub mod option;
+pub mod pointer_impl;
 pub mod range;
 pub mod slice_impl;
 pub mod string;
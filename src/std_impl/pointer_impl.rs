@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{
+    BidirectionalCollection, Collection, LazyCollection,
+    RandomAccessCollection, Slice,
+};
+
+// Pointers to a collection are read-only views of the pointee, forwarding
+// every operation to it. Whole of pointer is whole of pointee, so slicing a
+// pointer gives the same slice as slicing the pointee.
+macro_rules! impl_collection_for_pointer {
+($($t:ty),*) => {
+  $(impl<C> Collection for $t
+  where
+      C: Collection + ?Sized,
+  {
+      type Position = C::Position;
+
+      type Element = C::Element;
+
+      type ElementRef<'a>
+          = C::ElementRef<'a>
+      where
+          Self: 'a;
+
+      type Whole = C::Whole;
+
+      fn start(&self) -> Self::Position {
+          (**self).start()
+      }
+
+      fn end(&self) -> Self::Position {
+          (**self).end()
+      }
+
+      fn form_next(&self, position: &mut Self::Position) {
+          (**self).form_next(position)
+      }
+
+      fn form_next_n(&self, position: &mut Self::Position, n: usize) {
+          (**self).form_next_n(position, n)
+      }
+
+      fn form_next_n_limited_by(
+          &self,
+          position: &mut Self::Position,
+          n: usize,
+          limit: Self::Position,
+      ) -> bool {
+          (**self).form_next_n_limited_by(position, n, limit)
+      }
+
+      fn distance(&self, from: Self::Position, to: Self::Position) -> usize {
+          (**self).distance(from, to)
+      }
+
+      fn count(&self) -> usize {
+          (**self).count()
+      }
+
+      fn underestimated_count(&self) -> usize {
+          (**self).underestimated_count()
+      }
+
+      fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
+          (**self).at(i)
+      }
+
+      unsafe fn at_unchecked(
+          &self,
+          i: &Self::Position,
+      ) -> Self::ElementRef<'_> {
+          (**self).at_unchecked(i)
+      }
+
+      fn slice(
+          &self,
+          from: Self::Position,
+          to: Self::Position,
+      ) -> Slice<'_, Self::Whole> {
+          (**self).slice(from, to)
+      }
+
+      fn contiguous_slice(
+          &self,
+          from: Self::Position,
+          to: Self::Position,
+      ) -> Option<&[Self::Element]> {
+          (**self).contiguous_slice(from, to)
+      }
+  }
+
+  impl<C> BidirectionalCollection for $t
+  where
+      C: BidirectionalCollection + ?Sized,
+      C::Whole: BidirectionalCollection,
+  {
+      fn form_prior(&self, position: &mut Self::Position) {
+          (**self).form_prior(position)
+      }
+
+      fn form_prior_n(&self, position: &mut Self::Position, n: usize) {
+          (**self).form_prior_n(position, n)
+      }
+
+      fn form_prior_n_limited_by(
+          &self,
+          position: &mut Self::Position,
+          n: usize,
+          limit: Self::Position,
+      ) -> bool {
+          (**self).form_prior_n_limited_by(position, n, limit)
+      }
+  }
+
+  impl<C> RandomAccessCollection for $t
+  where
+      C: RandomAccessCollection + ?Sized,
+      C::Whole: RandomAccessCollection,
+  {
+  }
+
+  impl<C> LazyCollection for $t
+  where
+      C: LazyCollection + ?Sized,
+      C::Whole: LazyCollection,
+  {
+      fn compute_at(&self, i: &Self::Position) -> Self::Element {
+          (**self).compute_at(i)
+      }
+  })*
+};
+}
+
+impl_collection_for_pointer!(&C, Box<C>, Rc<C>, Arc<C>);
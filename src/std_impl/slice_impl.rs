@@ -57,6 +57,10 @@ impl<T> Collection for &[T] {
         &self[*i]
     }
 
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> &Self::Element {
+        self.get_unchecked(*i)
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -64,6 +68,14 @@ impl<T> Collection for &[T] {
     ) -> Slice<'_, Self::Whole> {
         Slice::new(self, from, to)
     }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        Some(&self[from..to])
+    }
 }
 
 impl<T> BidirectionalCollection for &[T] {
@@ -144,6 +156,10 @@ impl<T> Collection for &mut [T] {
         &self[*i]
     }
 
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> &Self::Element {
+        self.get_unchecked(*i)
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -151,6 +167,14 @@ impl<T> Collection for &mut [T] {
     ) -> Slice<'_, Self::Whole> {
         Slice::new(self, from, to)
     }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        Some(&self[from..to])
+    }
 }
 
 impl<T> BidirectionalCollection for &mut [T] {
@@ -185,6 +209,15 @@ impl<T> ReorderableCollection for &mut [T] {
         self.swap(*i, *j)
     }
 
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        let p = self.as_mut_ptr();
+        std::ptr::swap(p.add(*i), p.add(*j))
+    }
+
     fn slice_mut(
         &mut self,
         from: Self::Position,
@@ -198,4 +231,19 @@ impl<T> MutableCollection for &mut [T] {
     fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
         &mut self[*i]
     }
+
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        self.get_unchecked_mut(*i)
+    }
+
+    fn contiguous_slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&mut [Self::Element]> {
+        Some(&mut self[from..to])
+    }
 }
@@ -1,10 +1,17 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
-use crate::{value_ref::ValueRef, Collection, LazyCollection, Slice};
+use crate::{
+    value_ref::ValueRef, BidirectionalCollection, Collection, LazyCollection,
+    Slice,
+};
 
-// Returns the length of `i`th char in terms of bytes.
+// Positions of String and &str are byte offsets of chars, so they always lie
+// on char boundaries.
+
+// Returns the length of char starting at byte offset `i` in terms of bytes.
 fn char_len_at(s: &str, i: usize) -> usize {
+    debug_assert!(s.is_char_boundary(i), "position is not a char boundary");
     match s.as_bytes()[i] {
         0x00..=0x7F => 1,
         0xC0..=0xDF => 2,
@@ -14,6 +21,27 @@ fn char_len_at(s: &str, i: usize) -> usize {
     }
 }
 
+// Returns the byte offset of char just before byte offset `i`.
+fn char_start_before(s: &str, i: usize) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = i - 1;
+    // Skip continuation bytes of form 0b10xxxxxx.
+    while bytes[i] & 0xC0 == 0x80 {
+        i -= 1;
+    }
+    i
+}
+
+// Returns the char starting at byte offset `i`.
+fn char_at(s: &str, i: usize) -> char {
+    let len = char_len_at(s, i);
+    let bytes = s.as_bytes();
+    unsafe { std::str::from_utf8_unchecked(&bytes[i..i + len]) }
+        .chars()
+        .next()
+        .unwrap()
+}
+
 impl Collection for String {
     type Position = usize;
 
@@ -40,13 +68,9 @@ impl Collection for String {
     }
 
     fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
-        let len = char_len_at(self, *i);
-        let bytes = self.as_bytes();
-        let val = unsafe { std::str::from_utf8_unchecked(&bytes[*i..i + len]) }
-            .chars()
-            .next()
-            .unwrap();
-        ValueRef { val }
+        ValueRef {
+            val: char_at(self, *i),
+        }
     }
 
     fn slice(
@@ -64,6 +88,12 @@ impl LazyCollection for String {
     }
 }
 
+impl BidirectionalCollection for String {
+    fn form_prior(&self, position: &mut Self::Position) {
+        *position = char_start_before(self, *position)
+    }
+}
+
 impl Collection for &str {
     type Position = usize;
 
@@ -90,13 +120,9 @@ impl Collection for &str {
     }
 
     fn at(&self, i: &Self::Position) -> Self::ElementRef<'_> {
-        let len = char_len_at(self, *i);
-        let bytes = self.as_bytes();
-        let val = unsafe { std::str::from_utf8_unchecked(&bytes[*i..i + len]) }
-            .chars()
-            .next()
-            .unwrap();
-        ValueRef { val }
+        ValueRef {
+            val: char_at(self, *i),
+        }
     }
 
     fn slice(
@@ -113,3 +139,9 @@ impl LazyCollection for &str {
         self.at(i).val
     }
 }
+
+impl BidirectionalCollection for &str {
+    fn form_prior(&self, position: &mut Self::Position) {
+        *position = char_start_before(self, *position)
+    }
+}
@@ -2,7 +2,7 @@
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
 use crate::{
-    BidirectionalCollection, Collection, MutableCollection,
+    BidirectionalCollection, Collection, MutableCollection, OutputCollection,
     RandomAccessCollection, ReorderableCollection, Slice, SliceMut,
 };
 
@@ -65,6 +65,10 @@ impl<T> Collection for Vec<T> {
         &self[*i]
     }
 
+    unsafe fn at_unchecked(&self, i: &Self::Position) -> &Self::Element {
+        self.get_unchecked(*i)
+    }
+
     fn slice(
         &self,
         from: Self::Position,
@@ -72,6 +76,14 @@ impl<T> Collection for Vec<T> {
     ) -> Slice<'_, Self::Whole> {
         Slice::new(self, from, to)
     }
+
+    fn contiguous_slice(
+        &self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&[Self::Element]> {
+        Some(&self[from..to])
+    }
 }
 
 impl<T> BidirectionalCollection for Vec<T> {
@@ -106,6 +118,15 @@ impl<T> ReorderableCollection for Vec<T> {
         self.swap(*i, *j)
     }
 
+    unsafe fn swap_at_unchecked(
+        &mut self,
+        i: &Self::Position,
+        j: &Self::Position,
+    ) {
+        let p = self.as_mut_ptr();
+        std::ptr::swap(p.add(*i), p.add(*j))
+    }
+
     fn slice_mut(
         &mut self,
         from: Self::Position,
@@ -119,4 +140,29 @@ impl<T> MutableCollection for Vec<T> {
     fn at_mut(&mut self, i: &Self::Position) -> &mut Self::Element {
         &mut self[*i]
     }
+
+    unsafe fn at_unchecked_mut(
+        &mut self,
+        i: &Self::Position,
+    ) -> &mut Self::Element {
+        self.get_unchecked_mut(*i)
+    }
+
+    fn contiguous_slice_mut(
+        &mut self,
+        from: Self::Position,
+        to: Self::Position,
+    ) -> Option<&mut [Self::Element]> {
+        Some(&mut self[from..to])
+    }
+}
+
+/// Writing to a Vec appends to it, so it never runs out of slots.
+impl<T> OutputCollection for Vec<T> {
+    type Element = T;
+
+    fn write_next(&mut self, value: T) -> Result<(), T> {
+        self.push(value);
+        Ok(())
+    }
 }
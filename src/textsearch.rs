@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+//! Searching lines of text for a pattern, as done by `grep`.
+//!
+//! Text is any collection of bytes, e.g., `Vec<u8>` read from a file or
+//! `&[u8]` over a memory mapped file. Lines are separated by `b'\n'`, and are
+//! searched in parallel. `examples/stlgrep.rs` assembles these into a small
+//! command line utility.
+
+use crate::{
+    exec_par, Collection, CollectionExt, ParallelCollectionExt, Slice,
+};
+
+/// A line of text having a match of searched pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineMatch<Position> {
+    /// Number of the line, starting from 1.
+    pub line_number: usize,
+
+    /// Position of first byte of the line in text.
+    pub line_start: Position,
+
+    /// Position of first byte of first match of pattern in the line.
+    pub match_start: Position,
+}
+
+/// Returns an iterator over lines of `text`, excluding the separating
+/// `b'\n'`.
+///
+/// # Postcondition
+///   - A `b'\n'` ending `text` doesn't start a new line, and empty `text`
+///     has no lines.
+///
+/// # Complexity
+///   - O(n) for complete iteration where `n == text.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let text = b"ab\ncd";
+/// let lengths: Vec<usize> =
+///     textsearch::lines(text).map(|line| line.count()).collect();
+/// assert_eq!(lengths, [2, 2]);
+/// ```
+pub fn lines<C>(text: &C) -> impl Iterator<Item = Slice<'_, C::Whole>>
+where
+    C: Collection<Element = u8> + ?Sized,
+{
+    text.full().split_where(|b| *b == b'\n')
+}
+
+/// Returns position of first occurrence of `pattern` in `haystack`, or
+/// `None` if `pattern` doesn't occur in `haystack`.
+///
+/// # Postcondition
+///   - Empty `pattern` occurs at `haystack.start()`.
+///
+/// # Complexity
+///   - O(n * m) where `n == haystack.count()` and `m == pattern.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let haystack = b"hello world";
+/// let pattern = b"world";
+/// assert_eq!(textsearch::first_match_position(haystack, pattern), Some(6));
+/// assert_eq!(textsearch::first_match_position(haystack, b"x"), None);
+/// ```
+pub fn first_match_position<C, P>(
+    haystack: &C,
+    pattern: &P,
+) -> Option<C::Position>
+where
+    C: Collection<Element = u8> + ?Sized,
+    P: Collection<Element = u8> + ?Sized,
+{
+    let mut rest = haystack.full();
+    loop {
+        if rest.starts_with(pattern) {
+            return Some(rest.start());
+        }
+        if !rest.drop_first() {
+            return None;
+        }
+    }
+}
+
+/// Returns all lines of `text` having `pattern`, in order of lines, searching
+/// lines in parallel.
+///
+/// # Complexity
+///   - O(n * m) where `n == text.count()` and `m == pattern.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let text = b"apple\nbanana\ncherry\npineapple";
+/// let matches = textsearch::parallel_grep(text, b"apple");
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches[1].line_number, 4);
+/// assert_eq!(matches[1].line_start, 20);
+/// assert_eq!(matches[1].match_start, 24);
+/// ```
+pub fn parallel_grep<C, P>(text: &C, pattern: &P) -> Vec<LineMatch<C::Position>>
+where
+    C: Collection<Element = u8> + ?Sized,
+    C::Whole: Send,
+    C::Position: Send,
+    P: Collection<Element = u8> + Sync + ?Sized,
+{
+    let lines: Vec<_> = lines(text)
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .collect();
+
+    let hardware_concurrency = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let min_lines_per_core = 512;
+    let parallel_tasks: Vec<_> = lines
+        .splitting_evenly_in_with_min_size(
+            hardware_concurrency,
+            min_lines_per_core,
+        )
+        .map(|part| {
+            move || {
+                part.iter()
+                    .filter_map(|(line_number, line)| {
+                        let match_start = first_match_position(line, pattern)?;
+                        Some(LineMatch {
+                            line_number: *line_number,
+                            line_start: line.start(),
+                            match_start,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            }
+        })
+        .collect();
+
+    exec_par(parallel_tasks.into_iter())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Returns number of lines of `text` having `pattern`, searching lines in
+/// parallel.
+///
+/// # Complexity
+///   - O(n * m) where `n == text.count()` and `m == pattern.count()`.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let text = b"apple\nbanana\ncherry\npineapple";
+/// assert_eq!(textsearch::parallel_count_matching_lines(text, b"an"), 1);
+/// ```
+pub fn parallel_count_matching_lines<C, P>(text: &C, pattern: &P) -> usize
+where
+    C: Collection<Element = u8> + ?Sized,
+    C::Whole: Send,
+    P: Collection<Element = u8> + Sync + ?Sized,
+{
+    let lines: Vec<_> = lines(text).collect();
+    lines.parallel_count_where(|line| {
+        first_match_position(line, pattern).is_some()
+    })
+}
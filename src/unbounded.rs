@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+use std::ops::{Add, Deref};
+
+use crate::{
+    collections::{GeneratedCollection, IotaCollection, PrefixCollection},
+    Regular,
+};
+
+/// Models a multi-pass linear sequence of elements which never ends, e.g.,
+/// elements produced by a generator.
+///
+/// Representation:
+/// ```text
+///   _ _ _ _ _ _ ...
+///
+///   ^
+///   |
+/// start   -->
+/// ```
+///
+/// As there is no end, an UnboundedCollection is not a `Collection`. Its
+/// `prefix(n)` is a `Collection`, on which all algorithms can be used.
+pub trait UnboundedCollection {
+    /// Type of positions in the collection.
+    type Position: Regular + Ord;
+
+    /// Type of element in the collection.
+    type Element;
+
+    /// Type that is like `&Element`.
+    type ElementRef<'a>: Deref<Target = Self::Element>
+    where
+        Self: 'a;
+
+    /// Returns the position of first element in self.
+    fn start(&self) -> Self::Position;
+
+    /// Mutates the given position to position just after `position`.
+    fn form_next(&self, position: &mut Self::Position);
+
+    /// Mutates the given position to nth position after `position`.
+    ///
+    /// # Complexity
+    ///   - O(1) for UnboundedRandomAccessCollection; O(n) otherwise.
+    fn form_next_n(&self, position: &mut Self::Position, mut n: usize) {
+        while n > 0 {
+            self.form_next(position);
+            n -= 1;
+        }
+    }
+
+    /// Returns position immediately after `position`.
+    fn next(&self, mut position: Self::Position) -> Self::Position {
+        self.form_next(&mut position);
+        position
+    }
+
+    /// Returns nth position after `position`.
+    ///
+    /// # Complexity
+    ///   - O(1) for UnboundedRandomAccessCollection; O(n) otherwise.
+    fn next_n(&self, mut position: Self::Position, n: usize) -> Self::Position {
+        self.form_next_n(&mut position, n);
+        position
+    }
+
+    /// Returns number of elements in `[from, to)`.
+    ///
+    /// # Precondition
+    ///   - `to` is reachable from `from`.
+    ///
+    /// # Complexity
+    ///   - O(1) for UnboundedRandomAccessCollection; O(n) otherwise where `n`
+    ///     is the returned distance.
+    fn distance(&self, mut from: Self::Position, to: Self::Position) -> usize {
+        let mut n = 0;
+        while from != to {
+            self.form_next(&mut from);
+            n += 1;
+        }
+        n
+    }
+
+    /// Access element at position i.
+    ///
+    /// # Complexity Requirement
+    ///   - O(1)
+    fn at(&self, i: &Self::Position) -> Self::ElementRef<'_>;
+
+    /// Returns collection of first `n` elements of self.
+    ///
+    /// # Complexity
+    ///   - O(1) for UnboundedRandomAccessCollection; O(n) otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use stl::*;
+    ///
+    /// let squares = generate(|i| i * i).prefix(4);
+    /// assert!(squares.equals(&[0, 1, 4, 9]));
+    /// ```
+    fn prefix(self, n: usize) -> PrefixCollection<Self>
+    where
+        Self: Sized,
+    {
+        PrefixCollection::new(self, n)
+    }
+}
+
+/// Models an unbounded collection which can be traversed backward as well.
+pub trait UnboundedBidirectionalCollection: UnboundedCollection {
+    /// Mutates the given position to position just before `position`.
+    ///
+    /// # Precondition
+    ///   - `position != start()`
+    fn form_prior(&self, position: &mut Self::Position);
+
+    /// Mutates the given position to nth position before `position`.
+    ///
+    /// # Precondition
+    ///   - There are n valid positions in self before `position`.
+    ///
+    /// # Complexity
+    ///   - O(1) for UnboundedRandomAccessCollection; O(n) otherwise.
+    fn form_prior_n(&self, position: &mut Self::Position, mut n: usize) {
+        while n > 0 {
+            self.form_prior(position);
+            n -= 1;
+        }
+    }
+}
+
+/// Models an unbounded collection where jumping to any position from any
+/// other position is O(1) operation.
+///
+/// # Complexity Requirements
+///   - `self.distance(from, to)` -> O(1).
+///   - `self.form_next_n(i)` -> O(1).
+///   - `self.form_prior_n(i)` -> O(1).
+pub trait UnboundedRandomAccessCollection:
+    UnboundedBidirectionalCollection
+{
+}
+
+/// Returns an unbounded collection whose element at offset `i` is `f(i)`.
+///
+/// Elements are computed on every access.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let evens = generate(|i| 2 * i);
+/// assert_eq!(*evens.at(&3), 6);
+/// assert_eq!(evens.prefix(3).to_vec(), [0, 2, 4]);
+/// ```
+pub fn generate<F, T>(f: F) -> GeneratedCollection<F>
+where
+    F: Fn(usize) -> T,
+{
+    GeneratedCollection::new(f)
+}
+
+/// Returns an unbounded collection of `start`, `start + 1`, `start + 2`, ...
+///
+/// # Postcondition
+///   - Accessing an element not representable by `T` panics.
+///
+/// # Example
+/// ```rust
+/// use stl::*;
+///
+/// let naturals = iota(1u64);
+/// assert_eq!(naturals.prefix(100).fold_left(0, |x, y| x + y), 5050);
+/// ```
+pub fn iota<T>(start: T) -> IotaCollection<T>
+where
+    T: Copy + Add<Output = T> + TryFrom<usize>,
+{
+    IotaCollection::new(start)
+}
@@ -1,14 +1,69 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
 
-/// Unwraps given `Vec<Option<T>>` as `Vec<T>` without any allocation.
+/// Unwraps given `Vec<Option<T>>` as `Vec<T>`, reusing allocation of `v`
+/// where layouts permit.
 ///
 /// # Precondition
 ///   - All elements of `v` have values inside it.
-pub fn unwrap_option_vec<T>(mut v: Vec<Option<T>>) -> Vec<T> {
-    let len = v.len();
-    let capacity = v.capacity();
-    let ptr = v.as_mut_ptr();
-    std::mem::forget(v);
-    unsafe { Vec::from_raw_parts(ptr as *mut T, len, capacity) }
+pub fn unwrap_option_vec<T>(v: Vec<Option<T>>) -> Vec<T> {
+    v.into_iter()
+        .map(|x| x.expect("all elements should have values"))
+        .collect()
+}
+
+/// Returns the lexicographical comparison of elements of `a` and `b`, or None
+/// if any compared pair of elements is not comparable.
+///
+/// # Complexity
+///   - O(n) where `n == min(a.count(), b.count())`.
+pub fn lexicographical_partial_cmp<A, B>(
+    a: &A,
+    b: &B,
+) -> Option<std::cmp::Ordering>
+where
+    A: crate::Collection + ?Sized,
+    B: crate::Collection<Element = A::Element> + ?Sized,
+    A::Element: PartialOrd,
+{
+    let (mut i, mut j) = (a.start(), b.start());
+    let (a_end, b_end) = (a.end(), b.end());
+    loop {
+        match (i == a_end, j == b_end) {
+            (true, true) => return Some(std::cmp::Ordering::Equal),
+            (true, false) => return Some(std::cmp::Ordering::Less),
+            (false, true) => return Some(std::cmp::Ordering::Greater),
+            (false, false) => {}
+        }
+        match (*a.at(&i)).partial_cmp(&*b.at(&j))? {
+            std::cmp::Ordering::Equal => {}
+            non_eq => return Some(non_eq),
+        }
+        a.form_next(&mut i);
+        b.form_next(&mut j);
+    }
+}
+
+/// Feeds elements of `c` followed by their count into `state`, so that
+/// collections with equal elements hash equally.
+///
+/// # Complexity
+///   - O(n) where `n == c.count()`.
+pub fn hash_elements<C, H>(c: &C, state: &mut H)
+where
+    C: crate::Collection + ?Sized,
+    C::Element: std::hash::Hash,
+    H: std::hash::Hasher,
+{
+    use std::hash::Hash;
+
+    let mut n: usize = 0;
+    let mut i = c.start();
+    let end = c.end();
+    while i != end {
+        (*c.at(&i)).hash(state);
+        n += 1;
+        c.form_next(&mut i);
+    }
+    n.hash(state);
 }
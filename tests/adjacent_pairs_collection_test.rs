@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn adjacent_pairs() {
+        let arr = [1, 2, 4, 7].adjacent_pairs();
+        assert_eq!(arr.count(), 3);
+        assert!(arr.equals(&[(1, 2), (2, 4), (4, 7)]));
+    }
+
+    #[test]
+    fn adjacent_pairs_of_short_collections() {
+        let empty: [i32; 0] = [];
+        assert!(empty.adjacent_pairs().is_empty());
+        assert_eq!(empty.adjacent_pairs().count(), 0);
+        assert!([1].adjacent_pairs().is_empty());
+        assert!([1, 2].adjacent_pairs().equals(&[(1, 2)]));
+    }
+
+    #[test]
+    fn finite_differences() {
+        let arr = [1, 4, 9, 16, 25];
+        let deltas = arr.adjacent_pairs().map(|(x, y)| y - x);
+        assert!(deltas.equals(&[3, 5, 7, 9]));
+        assert!(arr.adjacent_pairs().all_satisfy(|(x, y)| x < y));
+    }
+
+    #[test]
+    fn backward_traversal() {
+        let arr = [1, 2, 3, 4].adjacent_pairs();
+        assert_eq!(*arr.at(&arr.prior(arr.end())), (3, 4));
+        assert!(arr.reversed().equals(&[(3, 4), (2, 3), (1, 2)]));
+    }
+
+    #[test]
+    fn lazy_adjacent_pairs() {
+        let arr = (1..5).lazy_map(|x| x * x).adjacent_pairs();
+        assert_eq!(arr.lazy_first(), Some((1, 4)));
+        assert_eq!(arr.lazy_last(), Some((9, 16)));
+    }
+
+    #[test]
+    fn adjacent_pairs_of_forward_collection() {
+        let arr = "abcd".adjacent_pairs();
+        assert!(arr.equals(&[('a', 'b'), ('b', 'c'), ('c', 'd')]));
+    }
+}
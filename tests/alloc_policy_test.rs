@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    fn policies() -> [AllocPolicy; 4] {
+        [
+            AllocPolicy::Unlimited,
+            AllocPolicy::Bounded(1024),
+            AllocPolicy::Bounded(0),
+            AllocPolicy::Forbidden,
+        ]
+    }
+
+    #[test]
+    fn allows() {
+        assert!(AllocPolicy::default().allows::<u8>(usize::MAX));
+        assert!(AllocPolicy::Bounded(16).allows::<u32>(4));
+        assert!(!AllocPolicy::Bounded(16).allows::<u32>(5));
+        assert!(AllocPolicy::Bounded(0).allows::<()>(100));
+        assert!(!AllocPolicy::Forbidden.allows::<u8>(1));
+    }
+
+    #[test]
+    fn split_among() {
+        assert_eq!(
+            AllocPolicy::Bounded(100).split_among(3),
+            AllocPolicy::Bounded(33)
+        );
+        assert_eq!(
+            AllocPolicy::Bounded(100).split_among(1),
+            AllocPolicy::Bounded(100)
+        );
+        assert_eq!(
+            AllocPolicy::Unlimited.split_among(8),
+            AllocPolicy::Unlimited
+        );
+        assert_eq!(
+            AllocPolicy::Forbidden.split_among(8),
+            AllocPolicy::Forbidden
+        );
+    }
+
+    #[test]
+    fn stable_sort() {
+        for policy in policies() {
+            let mut arr: [i32; 0] = [];
+            arr.stable_sort(policy);
+            assert_eq!(arr, []);
+
+            let mut v: Vec<i32> =
+                (0..1000).lazy_map(|x| (x * 7919) % 1000).to_vec();
+            v.stable_sort(policy);
+            assert!(v.equals(&(0..1000)));
+
+            let mut v: Vec<i32> = (0..100).lazy_map(|x| 100 - x).to_vec();
+            v.stable_sort(policy);
+            assert!(v.equals(&(1..=100)));
+        }
+    }
+
+    #[test]
+    fn stable_sort_by_preserves_order_of_equivalent_elements() {
+        for policy in policies() {
+            let mut v: Vec<(i32, usize)> = (0..500_usize)
+                .lazy_map(|i| ((i * 31 % 7) as i32, i))
+                .to_vec();
+            v.stable_sort_by(policy, |x, y| x.0 < y.0);
+            assert!(v.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    #[test]
+    fn parallel_stable_sort() {
+        for policy in policies() {
+            let mut arr: [i32; 0] = [];
+            arr.parallel_stable_sort(policy);
+            assert_eq!(arr, []);
+
+            let mut v: Vec<i32> =
+                (0..20000).lazy_map(|x| (x * 7919) % 20000).to_vec();
+            v.parallel_stable_sort(policy);
+            assert!(v.equals(&(0..20000)));
+
+            let mut v: Vec<i32> = (0..100).lazy_map(|x| 100 - x).to_vec();
+            v.parallel_stable_sort(policy);
+            assert!(v.equals(&(1..=100)));
+        }
+    }
+
+    #[test]
+    fn parallel_stable_sort_by_preserves_order_of_equivalent_elements() {
+        for policy in policies() {
+            let mut v: Vec<(i32, usize)> = (0..20000_usize)
+                .lazy_map(|i| ((i * 31 % 7) as i32, i))
+                .to_vec();
+            v.parallel_stable_sort_by(policy, |x, y| x.0 < y.0);
+            assert!(v.windows(2).all(|w| w[0] < w[1]));
+
+            let mut v: Vec<(i32, usize)> = (0..5000_usize)
+                .lazy_map(|i| ((i * 13 % 3) as i32, i))
+                .to_vec();
+            v.slice_mut(1000, 4000)
+                .parallel_stable_sort_by(policy, |x, y| x.0 < y.0);
+            assert!(v[..1000].iter().enumerate().all(|(i, e)| e.1 == i));
+            assert!(v[1000..4000].windows(2).all(|w| w[0] < w[1]));
+            assert!(v[4000..].iter().enumerate().all(|(i, e)| e.1 == i + 4000));
+        }
+    }
+
+    #[test]
+    fn merge_inplace() {
+        for policy in policies() {
+            let mut arr = [1, 3, 5, 7, 2, 4, 6];
+            arr.merge_inplace(4, policy);
+            assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7]);
+
+            let mut arr = [1, 2, 3];
+            arr.merge_inplace(0, policy);
+            assert_eq!(arr, [1, 2, 3]);
+
+            let mut arr = [1, 2, 3];
+            arr.merge_inplace(3, policy);
+            assert_eq!(arr, [1, 2, 3]);
+
+            let mut arr = [(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd')];
+            arr.merge_inplace_by(2, policy, |x, y| x.0 < y.0);
+            assert_eq!(arr, [(1, 'a'), (1, 'c'), (2, 'b'), (2, 'd')]);
+        }
+    }
+
+    #[test]
+    fn stable_partition_with() {
+        for policy in policies() {
+            let mut arr = [1, 2, 3, 4, 5, 6];
+            let i = arr.stable_partition_with(policy, |x| x % 2 == 1);
+            assert_eq!(i, 3);
+            assert_eq!(arr, [2, 4, 6, 1, 3, 5]);
+
+            let mut arr: [i32; 0] = [];
+            let i = arr.stable_partition_with(policy, |x| x % 2 == 1);
+            assert_eq!(i, 0);
+
+            let mut arr = [2, 4];
+            let i = arr.stable_partition_with(policy, |x| x % 2 == 1);
+            assert_eq!(i, 2);
+            assert_eq!(arr, [2, 4]);
+        }
+    }
+
+    #[test]
+    fn scratch_estimates() {
+        assert_eq!(estimate_stable_sort_scratch_bytes(0, 8), 0);
+        assert_eq!(estimate_stable_sort_scratch_bytes(7, 4), 12);
+        assert_eq!(estimate_merge_inplace_scratch_bytes(5, 4), 20);
+        assert_eq!(estimate_stable_partition_scratch_bytes(5, 4), 20);
+        assert_eq!(estimate_partitioned_scratch_bytes(5, 4), 40);
+        assert_eq!(
+            estimate_stable_sort_scratch_bytes(usize::MAX, 8),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn choosing_policy_from_estimate() {
+        let mut v: Vec<u64> =
+            (0..1000_u64).lazy_map(|x| (x * 7919) % 1000).to_vec();
+        let bytes = estimate_stable_sort_scratch_bytes(v.len(), 8);
+        let budget = 4096;
+        let policy = if AllocPolicy::Bounded(budget).allows_bytes(bytes) {
+            AllocPolicy::Bounded(budget)
+        } else {
+            AllocPolicy::Forbidden
+        };
+        assert_eq!(policy, AllocPolicy::Bounded(budget));
+        v.stable_sort(policy);
+        assert!(v.equals(&(0..1000_u64)));
+
+        assert!(AllocPolicy::Bounded(20).allows_bytes(20));
+        assert!(!AllocPolicy::Bounded(20).allows_bytes(21));
+        assert!(!AllocPolicy::Forbidden.allows_bytes(0));
+    }
+}
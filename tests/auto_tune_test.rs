@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn should_parallelize() {
+        let policy = AutoTune::sequential();
+        assert!(!policy.should_parallelize::<u8>(usize::MAX));
+        assert_eq!(policy.available_threads(), 1);
+
+        let policy = AutoTune {
+            max_threads: 0,
+            min_parallel_bytes: 1024,
+            ..AutoTune::default()
+        };
+        let can_parallelize = policy.available_threads() > 1;
+        assert!(!policy.should_parallelize::<u64>(127));
+        assert_eq!(policy.should_parallelize::<u64>(128), can_parallelize);
+        assert!(!policy.should_parallelize::<u8>(1023));
+    }
+
+    #[test]
+    fn sort() {
+        let mut arr = [5, 3, 1, 4, 2];
+        stl::sort(&mut arr);
+        assert_eq!(arr, [1, 2, 3, 4, 5]);
+
+        let mut v: Vec<i32> = (0..10000).rev().collect();
+        stl::sort(&mut v);
+        assert!(v.is_sorted());
+
+        let mut arr = [3, 2, 1, 0];
+        stl::sort(&mut arr.prefix_mut(3));
+        assert_eq!(arr, [1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn sort_strategy() {
+        let policy = AutoTune {
+            alloc_policy: AllocPolicy::Forbidden,
+            ..AutoTune::sequential()
+        };
+        assert_eq!(
+            policy.sort_strategy::<u64>(1 << 20),
+            SortStrategy::SequentialInPlace
+        );
+
+        let policy = AutoTune {
+            alloc_policy: AllocPolicy::Bounded(400),
+            ..AutoTune::sequential()
+        };
+        assert_eq!(
+            policy.sort_strategy::<u64>(100),
+            SortStrategy::SequentialBuffered
+        );
+        assert_eq!(
+            policy.sort_strategy::<u64>(101),
+            SortStrategy::SequentialBuffered
+        );
+        assert_eq!(
+            policy.sort_strategy::<u64>(102),
+            SortStrategy::SequentialInPlace
+        );
+
+        let policy = AutoTune {
+            max_threads: 0,
+            min_parallel_bytes: 8000,
+            alloc_policy: AllocPolicy::Forbidden,
+        };
+        if policy.available_threads() > 1 {
+            assert_eq!(
+                policy.sort_strategy::<u64>(999),
+                SortStrategy::SequentialInPlace
+            );
+            assert_eq!(
+                policy.sort_strategy::<u64>(1000),
+                SortStrategy::ParallelInPlace
+            );
+            let policy = AutoTune {
+                alloc_policy: AllocPolicy::Unlimited,
+                ..policy
+            };
+            assert_eq!(
+                policy.sort_strategy::<u64>(999),
+                SortStrategy::SequentialBuffered
+            );
+            assert_eq!(
+                policy.sort_strategy::<u64>(1000),
+                SortStrategy::ParallelBuffered
+            );
+        }
+    }
+
+    #[test]
+    fn sort_with_each_strategy() {
+        let policies = [
+            AutoTune {
+                alloc_policy: AllocPolicy::Forbidden,
+                ..AutoTune::sequential()
+            },
+            AutoTune {
+                alloc_policy: AllocPolicy::Unlimited,
+                ..AutoTune::sequential()
+            },
+            AutoTune {
+                max_threads: 0,
+                min_parallel_bytes: 0,
+                alloc_policy: AllocPolicy::Forbidden,
+            },
+            AutoTune {
+                max_threads: 0,
+                min_parallel_bytes: 0,
+                alloc_policy: AllocPolicy::Unlimited,
+            },
+        ];
+        for policy in policies {
+            let mut v: Vec<i32> =
+                (0..5000).lazy_map(|x| (x * 7919) % 5000).to_vec();
+            stl::sort_with(&policy, &mut v);
+            assert!(v.iter().copied().eq(0..5000));
+
+            let mut v: Vec<i32> =
+                (0..5000).lazy_map(|x| (x * 7919) % 5000).to_vec();
+            stl::sort_buffered_with(&policy, &mut v);
+            assert!(v.iter().copied().eq(0..5000));
+        }
+    }
+
+    #[test]
+    fn default_sort_is_in_place() {
+        let policy = AutoTune::default();
+        assert_eq!(policy.alloc_policy, AllocPolicy::Forbidden);
+        assert!(matches!(
+            policy.sort_strategy::<u64>(1 << 20),
+            SortStrategy::SequentialInPlace | SortStrategy::ParallelInPlace
+        ));
+    }
+
+    #[test]
+    fn sort_elements_without_clone() {
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+        struct Id(u32);
+
+        let mut v: Vec<Id> =
+            std::iter::successors(Some(0_u32), |x| Some((x + 7919) % 5000))
+                .take(5000)
+                .map(Id)
+                .collect();
+        stl::sort(&mut v);
+        assert!(v.iter().map(|x| x.0).eq(0..5000));
+
+        let policy = AutoTune {
+            min_parallel_bytes: 0,
+            ..AutoTune::default()
+        };
+        let mut v: Vec<Id> =
+            std::iter::successors(Some(0_u32), |x| Some((x + 7919) % 5000))
+                .take(5000)
+                .map(Id)
+                .collect();
+        stl::sort_with(&policy, &mut v);
+        assert!(v.iter().map(|x| x.0).eq(0..5000));
+    }
+
+    #[test]
+    fn find() {
+        let arr = [1, 2, 3, 2];
+        assert_eq!(stl::find(&arr, &2), Some(1));
+        assert_eq!(stl::find(&arr, &7), None);
+
+        let v: Vec<u64> = (0..100000).collect();
+        assert_eq!(stl::find(&v, &99999), Some(99999));
+        let policy = AutoTune {
+            max_threads: 0,
+            min_parallel_bytes: 0,
+            ..AutoTune::default()
+        };
+        assert_eq!(find_with(&policy, &v, &4242), Some(4242));
+        assert_eq!(find_with(&AutoTune::sequential(), &v, &7), Some(7));
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::{collections::BinaryHeapAdaptor, *};
+
+    #[test]
+    fn pops_in_decreasing_order() {
+        let mut heap = BinaryHeapAdaptor::new([3, 1, 4, 1, 5]);
+        assert_eq!(heap.len(), 5);
+        assert_eq!(heap.peek().copied(), Some(5));
+
+        let mut res = vec![];
+        while let Some(e) = heap.pop() {
+            res.push(*e);
+        }
+        assert_eq!(res, vec![5, 4, 3, 1, 1]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.into_base(), [1, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn push_into_spare_capacity() {
+        let mut heap = BinaryHeapAdaptor::empty_by(vec![0; 4], |x, y| x > y);
+        assert!(heap.peek().is_none());
+        assert_eq!(heap.capacity(), 4);
+
+        heap.push(7);
+        heap.push(2);
+        heap.push(9);
+        assert_eq!(heap.peek().copied(), Some(2));
+        assert!(heap.as_slice().is_heap_by(|x, y| x > y));
+
+        assert_eq!(heap.pop().copied(), Some(2));
+        heap.push(1);
+        heap.push(8);
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.pop().copied(), Some(1));
+        assert_eq!(heap.pop().copied(), Some(7));
+        assert_eq!(heap.pop().copied(), Some(8));
+        assert_eq!(heap.pop().copied(), Some(9));
+        assert_eq!(heap.pop().copied(), None);
+    }
+
+    #[test]
+    fn heap_over_mutable_slice() {
+        let mut arr = [0, 2, 6, 4, 0];
+        {
+            let mut heap = BinaryHeapAdaptor::new(arr.slice_mut(1, 4));
+            assert_eq!(heap.pop().copied(), Some(6));
+            heap.push(5);
+            assert_eq!(heap.peek().copied(), Some(5));
+        }
+        assert_eq!(arr[0], 0);
+        assert_eq!(arr[4], 0);
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::LinkedList;
+    use stl::*;
+
+    #[test]
+    fn binary_search_position_agrees_with_std() {
+        let arr = [1, 2, 2, 2, 5, 7, 7, 9];
+        for x in 0..11 {
+            let res = arr.binary_search_position(&x);
+            let insertion_point = arr.partition_point(|e| *e >= x);
+            if arr.contains(&x) {
+                assert_eq!(res, Ok(insertion_point));
+                assert!(arr.binary_search(&x).is_ok());
+            } else {
+                assert_eq!(res, Err(insertion_point));
+                assert_eq!(arr.binary_search(&x), Err(insertion_point));
+            }
+        }
+    }
+
+    #[test]
+    fn binary_search_position_in_empty_collection() {
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.binary_search_position(&1), Err(0));
+    }
+
+    #[test]
+    fn binary_search_position_by_on_linked_list() {
+        let list: LinkedList<(i32, char)> =
+            [(1, 'a'), (3, 'b'), (3, 'c'), (5, 'd')].into();
+        let cmp = |x: &(i32, char), y: &(i32, char)| x.0 < y.0;
+        let p = list.binary_search_position_by(&(3, 'z'), cmp).unwrap();
+        assert_eq!(*list.at(&p), (3, 'b'));
+        let p = list.binary_search_position_by(&(6, 'z'), cmp).unwrap_err();
+        assert!(p == list.end());
+    }
+
+    #[test]
+    fn insertion_point_keeps_collection_sorted() {
+        let mut vec = vec![];
+        for x in [5, 1, 4, 1, 3, 9, 2, 6] {
+            let p = vec
+                .binary_search_position(&x)
+                .unwrap_or_else(|insertion_point| insertion_point);
+            vec.insert(p, x);
+        }
+        assert_eq!(vec, [1, 1, 2, 3, 4, 5, 6, 9]);
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::collections::BoundedTopK;
+    use stl::*;
+
+    #[test]
+    fn insert() {
+        let mut top = BoundedTopK::new(3);
+        for e in [4, 1, 7, 3, 9, 2, 7] {
+            top.insert(e);
+        }
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.peek(), Some(&7));
+        assert_eq!(top.into_sorted_vec(), vec![9, 7, 7]);
+    }
+
+    #[test]
+    fn less_than_k_elements() {
+        let mut top = BoundedTopK::new(5);
+        top.extend([3, 1, 2]);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.into_sorted_vec(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn zero_k() {
+        let mut top = BoundedTopK::new(0);
+        top.insert(1);
+        assert!(top.is_empty());
+        assert_eq!(top.peek(), None);
+        assert_eq!(top.into_sorted_vec(), vec![]);
+    }
+
+    #[test]
+    fn insert_by() {
+        let mut top = BoundedTopK::new_by(2, |x: &i32, y: &i32| x > y);
+        top.extend([4, 1, 7, 3]);
+        assert_eq!(top.into_sorted_vec(), vec![1, 3]);
+    }
+
+    #[test]
+    fn top_k_accumulate_in_chunks() {
+        let data = [8, 3, 5, 1, 9, 2, 6, 4, 7];
+        let mut chunks = data.splitting_evenly_in(3);
+        let mut top = chunks.next().unwrap().top_k_accumulate(4);
+        for chunk in chunks {
+            top.extend(chunk.iter().copied());
+        }
+        assert_eq!(top.k(), 4);
+        assert_eq!(top.into_sorted_vec(), vec![9, 8, 7, 6]);
+    }
+
+    #[test]
+    fn smallest_k() {
+        let arr = [5, 1, 4, 2, 3, 1];
+        assert_eq!(arr.smallest_k(3), [1, 1, 2]);
+        assert_eq!(arr.smallest_k(0), Vec::<i32>::new());
+        assert_eq!(arr.smallest_k(10), [1, 1, 2, 3, 4, 5]);
+
+        let squares = (0..100).lazy_map(|x| (x - 50) * (x - 50));
+        assert_eq!(squares.smallest_k(3), [0, 1, 1]);
+    }
+
+    #[test]
+    fn smallest_k_by() {
+        let arr = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')];
+        let res = arr.smallest_k_by(3, |x, y| x.0 < y.0);
+        let keys: Vec<i32> = res.iter().map(|e| e.0).collect();
+        assert_eq!(keys, [1, 1, 2]);
+        assert!(res.contains(&(1, 'b')) && res.contains(&(1, 'd')));
+    }
+
+    #[test]
+    fn largest_k() {
+        let arr = [5, 1, 4, 2, 3, 5];
+        assert_eq!(arr.largest_k(3), [5, 5, 4]);
+        assert_eq!(arr.largest_k(0), Vec::<i32>::new());
+        assert_eq!(arr.largest_k(10), [5, 5, 4, 3, 2, 1]);
+
+        let arr = ["ccc", "a", "dddd", "bb", "eeee"];
+        assert_eq!(arr.largest_k_by(1, |x, y| x.len() < y.len()), ["dddd"]);
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+    use stl::*;
+
+    #[test]
+    fn btree_set() {
+        let set = BTreeSet::from([5, 1, 3, 9]);
+        assert_eq!(set.count(), 4);
+        assert!(set.equals(&[1, 3, 5, 9]));
+        assert_eq!(set.count_where(|x| *x > 2), 3);
+        assert_eq!(set.fold_left(0, |acc, x| acc + x), 18);
+
+        let p = set.first_position_of(&5).unwrap();
+        assert_eq!(p.key(), Some(&5));
+        assert_eq!(set.next(p.clone()).key(), Some(&9));
+        assert_eq!(set.prior(p).key(), Some(&3));
+        assert_eq!(set.next(set.prior(set.end())), set.end());
+        assert_eq!(set.end().key(), None);
+        assert!(set.reversed().equals(&[9, 5, 3, 1]));
+    }
+
+    #[test]
+    fn empty_btree_set() {
+        let set: BTreeSet<i32> = BTreeSet::new();
+        assert_eq!(set.start(), set.end());
+        assert!(set.is_empty());
+        assert_eq!(set.count(), 0);
+    }
+
+    #[test]
+    fn btree_map() {
+        let map = BTreeMap::from([(3, "c"), (1, "a"), (2, "b")]);
+        assert_eq!(map.count(), 3);
+        assert!(map.equals(&["a", "b", "c"]));
+        assert!(map.equals_by(&[1, 1, 1], |x, y| x.len() == *y));
+
+        let p = map.first_position_where(|v| *v == "b").unwrap();
+        assert_eq!(p.key(), Some(&2));
+        assert_eq!(*map.at(&p), "b");
+        assert!(map.suffix_from(p).equals(&["b", "c"]));
+
+        let last = map.prior(map.end());
+        assert_eq!(last.key(), Some(&3));
+        assert!(map.start() < last && last < map.end());
+    }
+
+    #[test]
+    fn btree_map_slices() {
+        let map: BTreeMap<i32, i32> =
+            Iterator::map(0..10, |x| (x * 10, x)).collect();
+        let from = map.next_n(map.start(), 2);
+        let to = map.prior_n(map.end(), 2);
+        let s = map.slice(from, to);
+        assert_eq!(s.count(), 6);
+        assert!(s.equals(&[2, 3, 4, 5, 6, 7]));
+        assert_eq!(s.start().key(), Some(&20));
+    }
+}
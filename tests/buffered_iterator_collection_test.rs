@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::collections::BufferedIteratorCollection;
+    use stl::*;
+
+    #[test]
+    fn multi_pass() {
+        let c = BufferedIteratorCollection::new([1, 2, 3, 4].into_iter());
+        assert_eq!(c.count(), 4);
+        assert!(c.equals(&[1, 2, 3, 4]));
+        assert!(c.equals(&[1, 2, 3, 4]));
+        assert_eq!(c.fold_left(0, |acc, x| acc + x), 10);
+    }
+
+    #[test]
+    fn empty() {
+        let c = BufferedIteratorCollection::new(std::iter::empty::<i32>());
+        assert_eq!(c.start(), c.end());
+        assert!(c.is_empty());
+        assert_eq!(c.count(), 0);
+    }
+
+    #[test]
+    fn elements_are_consumed_lazily() {
+        let c = BufferedIteratorCollection::new(1..);
+        assert_eq!(c.first_position_where(|x| x * x > 50), Some(7));
+        // Forming position after an element looks one element ahead.
+        assert_eq!(c.buffered_count(), 9);
+        assert_eq!(*c.at(&2), 3);
+
+        let prefix = c.prefix(3);
+        assert!(prefix.equals(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn positions_requiring_search() {
+        let c = BufferedIteratorCollection::new(
+            [1, 3, 5, 2, 4].into_iter().map(|x| x * 10),
+        );
+        assert_eq!(c.partition_point(|x| x % 20 == 0), 3);
+        assert_eq!(c.last_position_of(&30), Some(1));
+        assert!(c.suffix_from(3).equals(&[20, 40]));
+        assert_eq!(c.prior(c.end()), 4);
+        assert!(c.reversed().equals(&[40, 20, 50, 30, 10]));
+    }
+
+    #[test]
+    fn form_next_n_limited_by() {
+        let c = BufferedIteratorCollection::new(0..5);
+
+        let mut i = c.start();
+        assert!(c.form_next_n_limited_by(&mut i, 3, c.end()));
+        assert_eq!(i, 3);
+
+        assert!(c.form_next_n_limited_by(&mut i, 2, c.end()));
+        assert_eq!(i, c.end());
+
+        let mut i = c.start();
+        assert!(!c.form_next_n_limited_by(&mut i, 6, c.end()));
+        assert_eq!(i, c.end());
+
+        let mut i = c.start();
+        assert!(!c.form_next_n_limited_by(&mut i, 3, 2));
+        assert_eq!(i, 2);
+    }
+}
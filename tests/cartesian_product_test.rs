@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::LinkedList;
+    use stl::*;
+
+    #[test]
+    fn cartesian_product() {
+        let grid = collections::cartesian_product([1, 2], ["a", "b", "c"]);
+        assert_eq!(grid.count(), 6);
+        assert_eq!(
+            grid.to_vec(),
+            [(1, "a"), (1, "b"), (1, "c"), (2, "a"), (2, "b"), (2, "c")]
+        );
+
+        let grid = collections::cartesian_product([] as [i32; 0], [1, 2]);
+        assert!(grid.is_empty());
+        let grid = collections::cartesian_product([1, 2], [] as [i32; 0]);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn random_access() {
+        let grid = collections::cartesian_product(0usize..4, 0usize..3);
+        let n = grid.count();
+        assert_eq!(n, 12);
+        for i in 0..=n {
+            let p = grid.next_n(grid.start(), i);
+            assert_eq!(grid.distance(grid.start(), p.clone()), i);
+            assert!(p == grid.prior_n(grid.end(), n - i));
+            if i < n {
+                assert_eq!(*grid.at(&p), (i / 3, i % 3));
+            }
+            for j in 0..=i {
+                let q = grid.prior_n(p.clone(), j);
+                assert_eq!(grid.distance(q, p.clone()), j);
+            }
+        }
+    }
+
+    #[test]
+    fn non_random_access() {
+        let a: LinkedList<_> = [1, 2].into_iter().collect();
+        let b: LinkedList<_> = ['x', 'y'].into_iter().collect();
+        let grid = collections::cartesian_product(&a, &b);
+        assert!(grid.equals(&[(1, 'x'), (1, 'y'), (2, 'x'), (2, 'y')]));
+        assert!(grid.reversed().equals(&[
+            (2, 'y'),
+            (2, 'x'),
+            (1, 'y'),
+            (1, 'x')
+        ]));
+    }
+
+    #[test]
+    fn with_algorithms() {
+        let grid = collections::cartesian_product(1..=3, 1..=3);
+        assert_eq!(grid.count_where(|(x, y)| x < y), 3);
+        let best = grid
+            .lazy_map(|(x, y)| x * 10 - y * y)
+            .fold_left(i32::MIN, |m, v| m.max(*v));
+        assert_eq!(best, 29);
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(all(test, feature = "checked-positions"))]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn presents_base_elements() {
+        let mut c = collections::checked(vec![3, 1, 2]);
+        assert!(c.equals(&[3, 1, 2]));
+        assert_eq!(c.count(), 3);
+        c.sort_unstable();
+        assert_eq!(c.base, [1, 2, 3]);
+        let i = c.next_n(c.start(), 2);
+        assert_eq!(*c.at(&i), 3);
+        assert_eq!(c.distance(c.start(), i.clone()), 2);
+        *c.at_mut(&i) = 4;
+        assert!(c.slice(c.start(), i).equals(&[1, 2]));
+        assert_eq!(c.base, [1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Position of another collection")]
+    fn read_with_position_of_another_collection() {
+        let a = collections::checked(vec![1, 2, 3]);
+        let b = collections::checked(vec![4, 5, 6]);
+        b.at(&a.start());
+    }
+
+    #[test]
+    #[should_panic(expected = "Position of another collection")]
+    fn write_with_position_of_another_collection() {
+        let a = collections::checked(vec![1, 2, 3]);
+        let mut b = collections::checked(vec![4, 5, 6]);
+        *b.at_mut(&a.start()) = 0;
+    }
+
+    // Positions of different collections are ordered by their owner, so
+    // they lie outside bounds of any slice of another collection.
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn slice_with_position_of_another_collection() {
+        let a = collections::checked(vec![1, 2, 3]);
+        let b = collections::checked(vec![4, 5, 6]);
+        let s = b.full();
+        s.at(&a.start());
+    }
+
+    #[test]
+    #[should_panic(expected = "Position of another collection")]
+    fn distance_with_position_of_another_collection() {
+        let a = collections::checked([1, 2, 3]);
+        let b = collections::checked([4, 5, 6]);
+        b.distance(b.start(), a.end());
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn chunk_by() {
+        let arr = [1, 1, 2, 3, 3, 3];
+        let v: Vec<_> = arr
+            .chunk_by(|x, y| x == y)
+            .map(|s| (s.start(), s.end()))
+            .collect();
+        assert_eq!(v, vec![(0, 2), (2, 3), (3, 6)]);
+    }
+
+    #[test]
+    fn chunk_by_relates_adjacent_elements() {
+        let arr = [1, 2, 3, 5, 6, 8];
+        let v: Vec<_> = arr
+            .chunk_by(|x, y| x + 1 == *y)
+            .map(|s| s.to_vec())
+            .collect();
+        assert_eq!(v, vec![vec![1, 2, 3], vec![5, 6], vec![8]]);
+    }
+
+    #[test]
+    fn single_chunk() {
+        let arr = vec![2, 4, 6];
+        let mut it = arr.chunk_by(|x, y| x < y);
+        assert!(it.next().unwrap().equals(&[2, 4, 6]));
+        assert!(it.next().is_none());
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn empty_collection() {
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.chunk_by(|x, y| x == y).count(), 0);
+    }
+
+    #[test]
+    fn non_random_access_collection() {
+        let list: std::collections::LinkedList<_> =
+            ["a", "ab", "b", "cd", "ce"].into_iter().collect();
+        let v: Vec<_> = list
+            .chunk_by(|x, y| x.len() == y.len())
+            .map(|s| s.to_vec())
+            .collect();
+        assert_eq!(v, vec![vec!["a"], vec!["ab"], vec!["b"], vec!["cd", "ce"]]);
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::cmp::ComparatorExt;
+    use stl::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Person {
+        name: &'static str,
+        age: u32,
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person {
+                name: "dan",
+                age: 30,
+            },
+            Person {
+                name: "amy",
+                age: 25,
+            },
+            Person {
+                name: "bob",
+                age: 30,
+            },
+            Person {
+                name: "cal",
+                age: 25,
+            },
+            Person {
+                name: "eve",
+                age: 40,
+            },
+        ]
+    }
+
+    fn names(arr: &[Person]) -> Vec<&'static str> {
+        arr.iter().map(|p| p.name).collect()
+    }
+
+    #[test]
+    fn by_key() {
+        let mut arr = people();
+        arr.stable_sort_by(
+            AllocPolicy::Unlimited,
+            cmp::by_key(|p: &Person| p.age),
+        );
+        assert_eq!(names(&arr), ["amy", "cal", "dan", "bob", "eve"]);
+    }
+
+    #[test]
+    fn then_by_key() {
+        let mut arr = people();
+        arr.sort_unstable_by(
+            cmp::by_key(|p: &Person| p.age).then_by_key(|p| p.name),
+        );
+        assert_eq!(names(&arr), ["amy", "cal", "bob", "dan", "eve"]);
+    }
+
+    #[test]
+    fn then_by() {
+        let mut arr = people();
+        let by_age = |x: &Person, y: &Person| x.age < y.age;
+        arr.sort_unstable_by(by_age.then_by(|x, y| x.name > y.name));
+        assert_eq!(names(&arr), ["cal", "amy", "dan", "bob", "eve"]);
+    }
+
+    #[test]
+    fn reversed() {
+        let mut arr = people();
+        arr.stable_sort_by(
+            AllocPolicy::Unlimited,
+            cmp::by_key(|p: &Person| p.age).reversed(),
+        );
+        assert_eq!(names(&arr), ["eve", "dan", "bob", "amy", "cal"]);
+
+        let mut arr = people();
+        arr.sort_unstable_by(
+            cmp::by_key(|p: &Person| p.age)
+                .reversed()
+                .then_by_key(|p| p.name),
+        );
+        assert_eq!(names(&arr), ["eve", "bob", "dan", "amy", "cal"]);
+    }
+
+    #[test]
+    fn with_other_algorithms() {
+        let arr = people();
+        let cmp = cmp::by_key(|p: &Person| p.age).then_by_key(|p| p.name);
+        let mut sorted = arr.clone();
+        sorted.sort_unstable_by(cmp.clone());
+        assert!(sorted.is_sorted_by(cmp.clone()));
+
+        let bob = Person {
+            name: "bob",
+            age: 30,
+        };
+        assert_eq!(sorted.binary_search_position_by(&bob, cmp.clone()), Ok(2));
+        let ann = Person {
+            name: "ann",
+            age: 30,
+        };
+        assert_eq!(sorted.binary_search_position_by(&ann, cmp), Err(2));
+    }
+}
@@ -129,4 +129,38 @@ pub mod tests {
         let i = arr.distance(arr.start(), arr.end());
         assert_eq!(i, 5);
     }
+
+    #[test]
+    fn suffix_and_dropping_suffix() {
+        let arr = NonJumpableCollection {
+            data: [1, 2, 3, 4, 5],
+        };
+        assert!(arr.suffix(2).equals(&[4, 5]));
+        assert!(arr.dropping_suffix(2).equals(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn offset_of_position() {
+        let arr = [1, 2, 3, 4, 5];
+        let s = arr.suffix(3);
+        assert_eq!(s.offset_of(&s.start()), 0);
+        assert_eq!(s.offset_of(&s.end()), 3);
+        assert_eq!(s.offset_of(&s.position_at(2)), 2);
+        let r = arr.reversed();
+        let i = r.first_position_of(&2).unwrap();
+        assert_eq!(r.offset_of(&i), 3);
+        let list: std::collections::LinkedList<_> = arr.iter().collect();
+        let j = list.first_position_of(&&3).unwrap();
+        assert_eq!(list.offset_of(&j), 2);
+    }
+
+    #[test]
+    fn offsets() {
+        let arr = [5, 6, 7];
+        assert!(arr.offsets().equals(&[0, 1, 2]));
+        assert!(arr.suffix(1).offsets().equals(&[0]));
+        assert!(arr.prefix(0).offsets().equals(&[]));
+        let zipped: Vec<_> = arr.offsets().zip(arr.iter()).collect();
+        assert_eq!(zipped, [(0, &5), (1, &6), (2, &7)]);
+    }
 }
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn combinations() {
+        let c = collections::combinations([1, 2, 3, 4], 2);
+        assert_eq!(
+            c.to_vec(),
+            [
+                vec![1, 2],
+                vec![1, 3],
+                vec![1, 4],
+                vec![2, 3],
+                vec![2, 4],
+                vec![3, 4]
+            ]
+        );
+
+        let c = collections::combinations([1, 2, 3], 3);
+        assert_eq!(c.to_vec(), [vec![1, 2, 3]]);
+        let c = collections::combinations([1, 2, 3], 0);
+        assert_eq!(c.to_vec(), [Vec::<i32>::new()]);
+        let c = collections::combinations([1, 2, 3], 4);
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn combinations_are_distinct_and_sorted() {
+        let base: Vec<usize> = (0..10).collect();
+        let c = collections::combinations(&base, 4);
+        assert_eq!(c.count(), 210);
+        let all = c.to_vec();
+        assert!(all.is_sorted());
+        assert!(all.windows(2).all(|w| w[0] < w[1]));
+        assert!(all.iter().all(|x| x.windows(2).all(|w| w[0] < w[1])));
+        assert_eq!(*c.at(&209), vec![6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn permutations() {
+        let p = collections::permutations([1, 2, 3], 3);
+        assert_eq!(
+            p.to_vec(),
+            [
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1]
+            ]
+        );
+
+        let p = collections::permutations(['a', 'b', 'c'], 1);
+        assert_eq!(p.to_vec(), [vec!['a'], vec!['b'], vec!['c']]);
+        let p = collections::permutations([1, 2], 0);
+        assert_eq!(p.count(), 1);
+        let p = collections::permutations([1, 2], 3);
+        assert!(p.is_empty());
+    }
+
+    #[test]
+    fn permutations_are_distinct_and_sorted() {
+        let base: Vec<usize> = (0..6).collect();
+        let p = collections::permutations(&base, 3);
+        assert_eq!(p.count(), 120);
+        let all = p.to_vec();
+        assert!(all.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(*p.at(&119), vec![5, 4, 3]);
+    }
+
+    #[test]
+    fn with_algorithms() {
+        let weights = [3, 5, 7, 11];
+        let best = collections::combinations(weights, 2)
+            .lazy_map(|c| c.iter().sum::<i32>())
+            .fold_left(0, |m, s| if *s <= 15 && *s > m { *s } else { m });
+        assert_eq!(best, 14);
+
+        let p = collections::permutations([1, 2, 3, 4], 4);
+        let i = p.first_position_where(|x| x == &[3, 1, 4, 2]).unwrap();
+        assert_eq!(i, 13);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unrepresentable_count() {
+        let base: Vec<usize> = (0..100).collect();
+        collections::permutations(&base, 50);
+    }
+}
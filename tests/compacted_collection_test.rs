@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn compacted() {
+        let arr = [None, Some(1), None, None, Some(4), Some(5), None];
+        let values = arr.compacted();
+        assert!(values.equals(&[1, 4, 5]));
+        assert_eq!(values.count(), 3);
+        assert_eq!(values.start(), 1);
+        assert_eq!(values.next(1), 4);
+        assert_eq!(values.compute_at(&5), 5);
+    }
+
+    #[test]
+    fn compacted_when_all_are_none_or_empty() {
+        let arr: [Option<i32>; 3] = [None, None, None];
+        assert!(arr.compacted().is_empty());
+
+        let arr: [Option<i32>; 0] = [];
+        assert!(arr.compacted().is_empty());
+    }
+
+    #[test]
+    fn compacted_when_all_are_some() {
+        let arr = [Some(1), Some(2), Some(3)];
+        assert!(arr.compacted().equals(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn compacted_is_bidirectional() {
+        let arr = [None, Some(1), None, Some(3), None];
+        let values = arr.compacted();
+        assert_eq!(values.prior(values.end()), 3);
+        assert_eq!(values.prior(3), 1);
+        assert!(values.reversed().equals(&[3, 1]));
+    }
+
+    #[test]
+    fn compacted_of_lazy_collection() {
+        let multiples =
+            (0..10).lazy_map(|x| if x % 3 == 0 { Some(x * x) } else { None });
+        let values = multiples.compacted();
+        assert!(values.equals(&[0, 9, 36, 81]));
+        let values: Vec<i32> = values.into_iter().collect();
+        assert_eq!(values, [0, 9, 36, 81]);
+    }
+
+    #[test]
+    fn first_none_position() {
+        let arr = [Some(1), Some(2), None, None];
+        assert_eq!(arr.first_none_position(), Some(2));
+
+        let arr = [Some(1), Some(2)];
+        assert_eq!(arr.first_none_position(), None);
+
+        let list: std::collections::LinkedList<Option<i32>> =
+            [Some(1), None].into_iter().collect();
+        assert!(list.first_none_position().is_some());
+    }
+}
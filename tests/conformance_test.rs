@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::{BTreeMap, BTreeSet, LinkedList};
+    use stl::collections::*;
+    use stl::*;
+
+    static ARR: [i32; 6] = [1, 2, 3, 4, 5, 6];
+
+    collection_conformance_tests!(mutable, array, [3, 1, 2], [3, 1, 2]);
+    collection_conformance_tests!(mutable, empty_array, [0; 0], []);
+    collection_conformance_tests!(mutable, vec, vec![1, 1, 2], [1, 1, 2]);
+    collection_conformance_tests!(random_access, range, 2..6, [2, 3, 4, 5]);
+    collection_conformance_tests!(random_access, empty_range, 2..2, []);
+    collection_conformance_tests!(
+        random_access,
+        range_inclusive,
+        -2..=1,
+        [-2, -1, 0, 1]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        linked_list,
+        LinkedList::from([1, 2, 3]),
+        [1, 2, 3]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        btree_set,
+        BTreeSet::from([3, 1, 2]),
+        [1, 2, 3]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        btree_map,
+        BTreeMap::from([(2, 'b'), (1, 'a')]),
+        ['a', 'b']
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        string,
+        String::from("héllo"),
+        ['h', 'é', 'l', 'l', 'o']
+    );
+    collection_conformance_tests!(bidirectional, option, Some(4), [4]);
+    collection_conformance_tests!(
+        random_access,
+        slice,
+        ARR.slice(1, 5),
+        [2, 3, 4, 5]
+    );
+    collection_conformance_tests!(
+        random_access,
+        slice_of_slice,
+        ARR.slice(1, 5).slice(2, 4),
+        [3, 4]
+    );
+    collection_conformance_tests!(
+        mutable,
+        slice_mut,
+        [1, 2, 3, 4].slice_mut(1, 3),
+        [2, 3]
+    );
+    collection_conformance_tests!(
+        random_access,
+        reference,
+        &ARR,
+        [1, 2, 3, 4, 5, 6]
+    );
+    collection_conformance_tests!(
+        random_access,
+        boxed,
+        Box::new(vec![1, 2]),
+        [1, 2]
+    );
+    collection_conformance_tests!(
+        random_access,
+        lazy_mapped,
+        (1..4).lazy_map(|x| x * x),
+        [1, 4, 9]
+    );
+    collection_conformance_tests!(
+        random_access,
+        mapped,
+        CollectionExt::map([1, 2, 3], |x| x * 2),
+        [2, 4, 6]
+    );
+    collection_conformance_tests!(
+        mutable,
+        reversed,
+        [1, 2, 3].reversed(),
+        [3, 2, 1]
+    );
+    collection_conformance_tests!(
+        random_access,
+        reversed_range,
+        (0..4).reversed(),
+        [3, 2, 1, 0]
+    );
+    collection_conformance_tests!(
+        mutable,
+        rotated,
+        [1, 2, 3, 4].rotated(1),
+        [2, 3, 4, 1]
+    );
+    collection_conformance_tests!(
+        mutable,
+        strided,
+        [1, 2, 3, 4, 5].stride(2),
+        [1, 3, 5]
+    );
+    collection_conformance_tests!(
+        random_access,
+        cycled,
+        [1, 2].cycled(2),
+        [1, 2, 1, 2]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        joined,
+        [vec![1], vec![], vec![2, 3]].joined(),
+        [1, 2, 3]
+    );
+    collection_conformance_tests!(
+        collection,
+        flat_mapped,
+        [1, 2].flat_map(|x| vec![*x; *x as usize]),
+        [1, 2, 2]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        merged,
+        [1, 3, 5].merged([2, 3]),
+        [1, 2, 3, 3, 5]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        adjacent_pairs,
+        [1, 2, 3].adjacent_pairs(),
+        [(1, 2), (2, 3)]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        compacted,
+        [None, Some(1), None, Some(2)].compacted(),
+        [1, 2]
+    );
+    collection_conformance_tests!(
+        mutable,
+        singleton,
+        SingletonCollection::new(7),
+        [7]
+    );
+    collection_conformance_tests!(
+        mutable,
+        empty,
+        EmptyCollection::<i32>::new(),
+        []
+    );
+    collection_conformance_tests!(
+        mutable,
+        ring_buffer,
+        {
+            let mut rb = RingBuffer::<i32, 4>::new();
+            rb.push_back(3).unwrap();
+            rb.push_back(4).unwrap();
+            rb.push_front(2).unwrap();
+            rb.push_front(1).unwrap();
+            rb
+        },
+        [1, 2, 3, 4]
+    );
+    collection_conformance_tests!(
+        mutable,
+        inline_vec,
+        {
+            let mut v = InlineVec::<i32, 4>::new();
+            v.push(1).unwrap();
+            v.push(2).unwrap();
+            v.push(3).unwrap();
+            v
+        },
+        [1, 2, 3]
+    );
+    collection_conformance_tests!(
+        random_access,
+        interleaved,
+        [1, 3, 5, 6].interleaved_with([2, 4]),
+        [1, 2, 3, 4, 5, 6]
+    );
+    collection_conformance_tests!(
+        random_access,
+        interleaved_with_longer,
+        [1].interleaved_with([2, 3, 4]),
+        [1, 2, 3, 4]
+    );
+    collection_conformance_tests!(
+        random_access,
+        cartesian_product,
+        collections::cartesian_product([1, 2, 3], [true, false]),
+        [
+            (1, true),
+            (1, false),
+            (2, true),
+            (2, false),
+            (3, true),
+            (3, false)
+        ]
+    );
+    collection_conformance_tests!(
+        random_access,
+        cartesian_product_with_empty,
+        collections::cartesian_product([1, 2, 3], [] as [i32; 0]),
+        []
+    );
+    collection_conformance_tests!(
+        random_access,
+        combinations,
+        collections::combinations([1, 2, 3], 2),
+        [vec![1, 2], vec![1, 3], vec![2, 3]]
+    );
+    collection_conformance_tests!(
+        random_access,
+        permutations,
+        collections::permutations([1, 2], 2),
+        [vec![1, 2], vec![2, 1]]
+    );
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::collections::InlineVec;
+    use stl::*;
+
+    fn as_slice<C>(c: &C) -> &[i32]
+    where
+        C: Collection<Element = i32> + ?Sized,
+    {
+        c.contiguous_slice(c.start(), c.end()).unwrap()
+    }
+
+    fn sum<C>(c: &C) -> i32
+    where
+        C: Collection<Element = i32> + ?Sized,
+    {
+        as_slice(c).iter().sum()
+    }
+
+    fn double<C>(c: &mut C)
+    where
+        C: MutableCollection<Element = i32> + ?Sized,
+        C::Whole: MutableCollection,
+    {
+        let (start, end) = (c.start(), c.end());
+        let s = c.contiguous_slice_mut(start, end).unwrap();
+        s.iter_mut().for_each(|x| *x *= 2);
+    }
+
+    #[test]
+    fn std_collections() {
+        let mut arr = [1, 2, 3];
+        assert_eq!(sum(&arr), 6);
+        double(&mut arr);
+        assert_eq!(arr, [2, 4, 6]);
+
+        let mut v = vec![1, 2, 3, 4];
+        assert_eq!(sum(&v), 10);
+        double(&mut v);
+        assert_eq!(v, [2, 4, 6, 8]);
+
+        let s: &[i32] = &v[1..3];
+        assert_eq!(sum(&s), 10);
+
+        let mut s: &mut [i32] = &mut v[2..];
+        double(&mut s);
+        assert_eq!(v, [2, 4, 12, 16]);
+    }
+
+    #[test]
+    fn inline_vec() {
+        let mut v = InlineVec::<i32, 4>::new();
+        v.push(1).unwrap();
+        v.push(2).unwrap();
+        assert_eq!(sum(&v), 3);
+        double(&mut v);
+        assert_eq!(as_slice(&v), [2, 4]);
+    }
+
+    #[test]
+    fn slices() {
+        let arr = [1, 2, 3, 4, 5];
+        let s = arr.slice(1, 4);
+        assert_eq!(as_slice(&s), [2, 3, 4]);
+        let s = s.slice(2, 4);
+        assert_eq!(as_slice(&s), [3, 4]);
+        assert_eq!(sum(&arr.slice(5, 5)), 0);
+
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut s = arr.slice_mut(1, 4);
+        assert_eq!(sum(&s), 9);
+        let mut t = s.slice_mut(2, 4);
+        double(&mut t);
+        assert_eq!(arr, [1, 2, 6, 8, 5]);
+    }
+
+    #[test]
+    fn contiguous_slice() {
+        let arr = [1, 2, 3, 4];
+        assert_eq!(arr.contiguous_slice(1, 3), Some(&[2, 3][..]));
+        assert_eq!(arr.slice(1, 4).contiguous_slice(2, 4), Some(&[3, 4][..]));
+
+        let list: std::collections::LinkedList<i32> = [1, 2].into();
+        assert!(list.contiguous_slice(list.start(), list.end()).is_none());
+
+        let shared = std::rc::Rc::new(vec![1, 2, 3]);
+        assert_eq!(shared.contiguous_slice(0, 2), Some(&[1, 2][..]));
+        assert!(shared.equals(&[1, 2, 3]));
+        let r = arr.reversed();
+        assert!(r.contiguous_slice(r.start(), r.end()).is_none());
+        assert!(r.equals(&[4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn sort() {
+        let mut v = vec![5, 3, 4, 1, 2];
+        stl::sort(&mut v.slice_mut(1, 5));
+        assert_eq!(v, [5, 1, 2, 3, 4]);
+
+        let mut v: Vec<u64> =
+            (0..100_000_u64).lazy_map(|x| (x * 7919) % 100_000).to_vec();
+        v.parallel_sort_unstable_by(|x, y| x > y);
+        assert!(v.iter().copied().eq((0..100_000).rev()));
+
+        let mut arr = [1, 4, 2, 3];
+        let mut r = arr.slice_mut(0, 4).reversed();
+        stl::sort(&mut r);
+        assert_eq!(arr, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn fill() {
+        let mut arr = [1, 2, 3, 4];
+        arr.slice_mut(1, 3).fill(0);
+        assert_eq!(arr, [1, 0, 0, 4]);
+
+        let mut v = vec![String::from("a"); 2];
+        v.fill(String::from("b"));
+        assert_eq!(v, ["b", "b"]);
+
+        let mut ring: collections::RingBuffer<i32, 2> =
+            collections::RingBuffer::new();
+        ring.push_back_overwriting(1);
+        ring.push_back_overwriting(2);
+        ring.push_back_overwriting(3);
+        ring.fill(7);
+        assert!(ring.equals(&[7, 7]));
+    }
+
+    #[test]
+    fn copy_into() {
+        let mut dest = [0; 5];
+        let i = [1, 2, 3].slice(1, 3).copy_into(&mut dest.slice_mut(1, 5));
+        assert_eq!(i, 3);
+        assert_eq!(dest, [0, 2, 3, 0, 0]);
+
+        let list: std::collections::LinkedList<i32> = [4, 5].into();
+        let i = list.copy_into(&mut dest);
+        assert_eq!(i, 2);
+        assert_eq!(dest, [4, 5, 3, 0, 0]);
+
+        let mut ring: collections::RingBuffer<i32, 3> =
+            collections::RingBuffer::new();
+        for x in [1, 0, 0, 0] {
+            ring.push_back_overwriting(x);
+        }
+        let i = vec![8, 9].copy_into(&mut ring);
+        assert!(ring.slice(ring.start(), i).equals(&[8, 9]));
+        assert!(ring.equals(&[8, 9, 0]));
+
+        let mut empty: [i32; 0] = [];
+        [0; 0].copy_into(&mut empty);
+    }
+}
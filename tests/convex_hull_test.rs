@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[derive(Clone, Copy)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn square_with_inner_points() {
+        let points = [(1, 1), (0, 0), (4, 0), (2, 3), (4, 4), (0, 4), (2, 0)];
+        let hull = points.convex_hull_by(|p| p.0, |p| p.1);
+        assert_eq!(hull, vec![1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        let empty: [(i32, i32); 0] = [];
+        assert!(empty.convex_hull_by(|p| p.0, |p| p.1).is_empty());
+        assert_eq!([(1, 1)].convex_hull_by(|p| p.0, |p| p.1), vec![0]);
+        assert_eq!([(1, 1), (1, 1)].convex_hull_by(|p| p.0, |p| p.1).len(), 1);
+        assert_eq!(
+            [(2, 2), (0, 0)].convex_hull_by(|p| p.0, |p| p.1),
+            vec![1, 0]
+        );
+    }
+
+    #[test]
+    fn collinear_points() {
+        let points = [(0, 0), (1, 1), (2, 2), (3, 3)];
+        assert_eq!(points.convex_hull_by(|p| p.0, |p| p.1), vec![0, 3]);
+    }
+
+    #[test]
+    fn floating_point_struct() {
+        let points: Vec<Point> = Iterator::map(0..12, |i| {
+            let angle = i as f64 * std::f64::consts::PI / 6.0;
+            let r = if i % 2 == 0 { 2.0 } else { 1.0 };
+            Point {
+                x: r * angle.cos(),
+                y: r * angle.sin(),
+            }
+        })
+        .collect();
+        let hull = points.convex_hull_by(|p| p.x, |p| p.y);
+        assert_eq!(hull, vec![6, 8, 10, 0, 2, 4]);
+    }
+
+    #[test]
+    fn hull_is_counter_clockwise_and_contains_all_points() {
+        let points: Vec<(i64, i64)> =
+            Iterator::map(0..200_i64, |i| ((i * 37) % 101, (i * 53) % 97))
+                .collect();
+        let hull = points.convex_hull_by(|p| p.0, |p| p.1);
+        let n = hull.len();
+        assert!(n >= 3);
+        for k in 0..n {
+            let o = points[hull[k]];
+            let a = points[hull[(k + 1) % n]];
+            for p in &points {
+                let cross =
+                    (a.0 - o.0) * (p.1 - o.1) - (a.1 - o.1) * (p.0 - o.0);
+                assert!(cross >= 0);
+            }
+        }
+    }
+}
@@ -34,4 +34,81 @@ pub mod tests {
         let arr = 1..2;
         assert!(!CollectionExt::is_empty(&arr));
     }
+
+    #[test]
+    fn count_of_by() {
+        let arr = [1, -1, 2, 1, -2];
+        let eq = |x: &i32, y: &i32| x.abs() == y.abs();
+        assert_eq!(arr.count_of_by(&-1, eq), 3);
+        assert_eq!(arr.count_of_by(&2, eq), 2);
+        assert_eq!(arr.count_of_by(&3, eq), 0);
+    }
+
+    #[test]
+    fn parallel_count_where() {
+        let arr: Vec<u64> = (0..50_000).collect();
+        assert_eq!(arr.parallel_count_where(|x| x % 5 == 0), 10_000);
+        assert_eq!(arr.parallel_count_where(|_| false), 0);
+        let empty: [u64; 0] = [];
+        assert_eq!(empty.parallel_count_where(|_| true), 0);
+    }
+
+    /// Forward collection knowing its count, recording traversal steps.
+    struct Counted {
+        elements: Vec<i32>,
+        steps: std::cell::Cell<usize>,
+    }
+
+    impl Collection for Counted {
+        type Position = usize;
+
+        type Element = i32;
+
+        type ElementRef<'a>
+            = &'a i32
+        where
+            Self: 'a;
+
+        type Whole = Self;
+
+        fn start(&self) -> usize {
+            0
+        }
+
+        fn end(&self) -> usize {
+            self.elements.len()
+        }
+
+        fn form_next(&self, i: &mut usize) {
+            self.steps.set(self.steps.get() + 1);
+            *i += 1
+        }
+
+        fn count(&self) -> usize {
+            self.elements.len()
+        }
+
+        fn at(&self, i: &usize) -> &i32 {
+            &self.elements[*i]
+        }
+
+        fn slice(&self, from: usize, to: usize) -> Slice<'_, Self> {
+            Slice::new(self, from, to)
+        }
+    }
+
+    #[test]
+    fn count_of_whole_slice_uses_whole_count() {
+        let c = Counted {
+            elements: (0..100).collect(),
+            steps: std::cell::Cell::new(0),
+        };
+        assert_eq!(c.full().count(), 100);
+        assert_eq!(c.full().underestimated_count(), 100);
+        assert_eq!(c.full().map(|x| x + 1).count(), 100);
+        assert_eq!(c.steps.get(), 0);
+
+        assert_eq!(c.slice(1, 100).count(), 99);
+        assert_eq!(c.steps.get(), 99);
+    }
 }
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn test_the_equality() {
+        let arr = [1, 2, 3];
+        assert!(arr.cycled(2).equals(&[1, 2, 3, 1, 2, 3]));
+        assert!(arr.cycled(1).equals(&[1, 2, 3]));
+        assert!(arr.cycled(0).equals(&[]));
+        let arr: [i32; 0] = [];
+        assert!(arr.cycled(3).equals(&[]));
+        assert_eq!(arr.cycled(3).count(), 0);
+    }
+
+    #[test]
+    fn count_and_distance() {
+        let arr = [1, 2, 3].cycled(3);
+        assert_eq!(arr.count(), 9);
+        assert_eq!(arr.distance(arr.start(), arr.end()), 9);
+        let i = arr.next_n(arr.start(), 4);
+        assert_eq!(*arr.at(&i), 2);
+        assert_eq!(arr.distance(arr.start(), i.clone()), 4);
+        assert_eq!(arr.distance(i, arr.end()), 5);
+    }
+
+    #[test]
+    fn backward_traversal() {
+        assert!([1, 2, 3].cycled(2).reversed().equals(&[3, 2, 1, 3, 2, 1]));
+        let arr = [1, 2, 3].cycled(2);
+        let i = arr.prior_n(arr.end(), 4);
+        assert_eq!(*arr.at(&i), 3);
+    }
+
+    #[test]
+    fn slicing() {
+        let arr = [1, 2, 3].cycled(3);
+        assert!(arr.prefix(4).equals(&[1, 2, 3, 1]));
+        assert!(arr.suffix(5).equals(&[2, 3, 1, 2, 3]));
+        assert!(arr.prefix(12).equals(&[1, 2, 3, 1, 2, 3, 1, 2, 3]));
+    }
+
+    #[test]
+    fn position_ordering() {
+        let arr = [1, 2, 3].cycled(2);
+        assert!(arr.start() < arr.end());
+        assert!(arr.next_n(arr.start(), 3) > arr.next_n(arr.start(), 2));
+    }
+
+    #[test]
+    fn lazy_collection() {
+        let arr = (1..=3).lazy_map(|x| x * 2).cycled(2);
+        assert!(arr.lazy_iter().eq([2, 4, 6, 2, 4, 6]));
+    }
+}
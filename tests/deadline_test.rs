@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::Duration;
+    use stl::*;
+
+    const LONG: Duration = Duration::from_secs(3600);
+
+    fn shuffled(n: i32) -> Vec<i32> {
+        (0..n).lazy_map(|x| (x * 7919) % n).to_vec()
+    }
+
+    #[test]
+    fn sort_within_deadline() {
+        let mut v = shuffled(1000);
+        assert_eq!(v.sort_unstable_with_deadline(LONG), Ok(()));
+        assert!(v.equals(&(0..1000)));
+
+        let mut v = shuffled(100);
+        assert_eq!(
+            v.sort_unstable_by_with_deadline(|x, y| x > y, LONG),
+            Ok(())
+        );
+        assert!(v.equals(&(0..100).reversed()));
+
+        let mut arr: [i32; 0] = [];
+        assert_eq!(arr.sort_unstable_with_deadline(Duration::ZERO), Ok(()));
+    }
+
+    #[test]
+    fn sort_timing_out() {
+        let mut v = shuffled(1000);
+        assert_eq!(
+            v.sort_unstable_with_deadline(Duration::ZERO),
+            Err(TimedOut)
+        );
+
+        // Elements are only permuted.
+        let mut sorted = v.clone();
+        sorted.sort_unstable();
+        assert!(sorted.equals(&(0..1000)));
+    }
+
+    #[test]
+    fn parallel_find_within_deadline() {
+        let v = shuffled(10000);
+        let res = v.parallel_first_position_of_with_deadline(&17, LONG);
+        assert_eq!(res, v.first_position_of(&17).map(Some).ok_or(TimedOut));
+
+        let res =
+            v.parallel_first_position_where_with_deadline(|x| *x < 0, LONG);
+        assert_eq!(res, Ok(None));
+    }
+
+    #[test]
+    fn parallel_find_timing_out() {
+        let v = vec![0; 10000];
+        let res = v.parallel_first_position_where_with_deadline(
+            |x| *x == 1,
+            Duration::ZERO,
+        );
+        assert_eq!(res, Err(TimedOut));
+
+        // Matches found before checking deadline are still reported.
+        let res =
+            v.parallel_first_position_of_with_deadline(&0, Duration::ZERO);
+        assert_eq!(res, Ok(Some(0)));
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn distinct_preserving_order() {
+        let arr = [5, 1, 5, 2, 1, 5, 3];
+        assert_eq!(arr.distinct_preserving_order(), vec![5, 1, 2, 3]);
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.distinct_preserving_order(), vec![]);
+    }
+
+    #[test]
+    fn distinct_of_strings() {
+        let words = vec![
+            String::from("b"),
+            String::from("a"),
+            String::from("b"),
+            String::from("c"),
+            String::from("a"),
+        ];
+        assert_eq!(words.distinct_preserving_order(), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn distinct_differs_from_dedup() {
+        let arr = [1, 1, 2, 1];
+        let mut deduped = arr.to_vec();
+        deduped.dedup();
+        assert_eq!(deduped, vec![1, 2, 1]);
+        assert_eq!(arr.distinct_preserving_order(), vec![1, 2]);
+    }
+
+    #[test]
+    fn distinct_by_key_keeps_first_occurrence() {
+        let arr = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e')];
+        assert_eq!(
+            arr.distinct_preserving_order_by_key(|x| x.0),
+            vec![(1, 'a'), (2, 'b'), (3, 'd')]
+        );
+    }
+
+    #[test]
+    fn distinct_of_lazy_collection() {
+        let arr = (0..10).lazy_map(|x| x % 3);
+        assert_eq!(arr.distinct_preserving_order(), vec![0, 1, 2]);
+        assert_eq!(
+            "hello world".distinct_preserving_order_by_key(|c| *c),
+            vec!['h', 'e', 'l', 'o', ' ', 'w', 'r', 'd']
+        );
+    }
+}
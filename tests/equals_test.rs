@@ -35,4 +35,33 @@ pub mod tests {
     fn equals_by() {
         assert!([1, 2, 3].equals_by(&[2, 3, 4], |x, y| *y == *x + 1));
     }
+
+    #[test]
+    fn starts_with() {
+        assert!([1, 2, 3].starts_with(&[1, 2]));
+        assert!([1, 2, 3].starts_with(&[1, 2, 3]));
+        assert!([1, 2, 3].starts_with(&[]));
+        assert!(![1, 2, 3].starts_with(&[1, 2, 3, 4]));
+        assert!(![1, 2, 3].starts_with(&[2]));
+        assert!("hello".starts_with(&"he".to_string()));
+    }
+
+    #[test]
+    fn starts_with_by() {
+        assert!([1, 2, 3].starts_with_by(&[2, 3], |x, y| *y == *x + 1));
+        assert!(!Vec::<i32>::new().starts_with_by(&[1], |x, y| x == y));
+        assert!(["Ab", "c"]
+            .starts_with_by(&["aB"], |x, y| x.eq_ignore_ascii_case(y)));
+    }
+
+    #[test]
+    fn equals_mixing_contiguous_and_non_contiguous() {
+        let list: std::collections::LinkedList<i32> = [1, 2, 3].into();
+        assert!([1, 2, 3].equals(&list));
+        assert!(list.equals(&vec![1, 2, 3]));
+        assert!(!list.equals(&[1, 2]));
+        let arr = [0, 1, 2, 3];
+        assert!(arr.slice(1, 4).equals(&list));
+        assert!(arr.slice(1, 4).equals(&[1, 2, 3]));
+    }
 }
@@ -124,4 +124,83 @@ pub mod tests {
         let i = arr.parallel_last_position_of(&5);
         assert_eq!(i, None);
     }
+
+    #[test]
+    fn position_of_by() {
+        let arr = ["x", "Ab", "aB", "ab"];
+        let eq = |x: &&str, y: &&str| x.eq_ignore_ascii_case(y);
+        assert_eq!(arr.first_position_of_by(&"ab", eq), Some(1));
+        assert_eq!(arr.last_position_of_by(&"AB", eq), Some(3));
+        assert_eq!(arr.first_position_of_by(&"y", eq), None);
+        assert_eq!(arr.last_position_of_by(&"y", eq), None);
+    }
+
+    #[test]
+    fn parallel_position_of_by() {
+        let arr: Vec<i32> = (0..5000).collect();
+        let eq = |x: &i32, y: &i32| x % 1000 == y % 1000;
+        assert_eq!(arr.parallel_first_position_of_by(&2007, eq), Some(7));
+        assert_eq!(arr.parallel_last_position_of_by(&7, eq), Some(4007));
+        assert_eq!(arr.parallel_first_position_of_by(&-1, eq), None);
+    }
+
+    #[test]
+    fn parallel_position_where_exits_early() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let arr: Vec<usize> = (0..100000).collect();
+
+        let calls = AtomicUsize::new(0);
+        let i = arr.parallel_first_position_where(|_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            true
+        });
+        assert_eq!(i, Some(0));
+        assert!(calls.load(Ordering::Relaxed) <= hardware_concurrency);
+
+        let calls = AtomicUsize::new(0);
+        let i = arr.parallel_last_position_where(|_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            true
+        });
+        assert_eq!(i, Some(99999));
+        assert!(calls.load(Ordering::Relaxed) <= arr.len());
+
+        let i = arr.parallel_first_position_where(|x| x % 1000 == 999);
+        assert_eq!(i, Some(999));
+        let i = arr.parallel_last_position_where(|x| x % 1000 == 1);
+        assert_eq!(i, Some(99001));
+        let i = arr.parallel_first_position_where(|x| *x > 100000);
+        assert_eq!(i, None);
+    }
+
+    #[test]
+    fn first_where() {
+        let arr = [1, 2, 3, 4];
+        assert_eq!(arr.first_where(|x| x % 2 == 0), Some(&2));
+        assert_eq!(arr.first_where(|x| *x > 4), None);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.first_where(|_| true), None);
+
+        let squares = collections::stepped_iota(1, 1, 10).lazy_map(|x| x * x);
+        assert_eq!(squares.first_where(|x| *x > 20).map(|x| *x), Some(25));
+    }
+
+    #[test]
+    fn last_where() {
+        let arr = [1, 2, 3, 4];
+        assert_eq!(arr.last_where(|x| x % 2 == 1), Some(&3));
+        assert_eq!(arr.last_where(|x| *x > 4), None);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.last_where(|_| true), None);
+
+        let mut arr = [(1, 'a'), (2, 'b'), (1, 'c')];
+        assert_eq!(arr.last_where(|x| x.0 == 1), Some(&(1, 'c')));
+        let s = arr.slice_mut(0, 2);
+        assert_eq!(s.last_where(|x| x.0 == 1), Some(&(1, 'a')));
+    }
 }
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn test_the_equality() {
+        let arr = [0, 1, 2, 3];
+        assert!(arr.flat_map(|x| 0..*x).equals(&[0, 0, 1, 0, 1, 2]));
+        assert!(arr
+            .flat_map(|x| vec![*x; 2])
+            .equals(&[0, 0, 1, 1, 2, 2, 3, 3]));
+        assert!(arr.flat_map(|_| 0..0).equals(&[]));
+    }
+
+    #[test]
+    fn count() {
+        let arr = [2, 0, 3];
+        let c = arr.flat_map(|x| 0..*x);
+        assert_eq!(c.count(), 5);
+        assert_eq!(c.distance(c.start(), c.end()), 5);
+        assert_eq!(*c.at(&c.next_n(c.start(), 3)), 1);
+    }
+
+    #[test]
+    fn backward_traversal() {
+        let arr = [2, 0, 3, 0];
+        assert!(arr.flat_map(|x| 0..*x).reversed().equals(&[2, 1, 0, 1, 0]));
+    }
+
+    #[test]
+    fn slicing() {
+        let arr = ["ab", "", "cde"];
+        let c = arr.flat_map(|s| s.to_string());
+        assert!(c.prefix(3).equals(&['a', 'b', 'c']));
+        assert!(c.dropping_prefix(4).equals(&['e']));
+    }
+
+    #[test]
+    fn lazy_access() {
+        let c = [1, 2].flat_map(|x| vec![*x * 10, *x * 10 + 1]);
+        assert_eq!(c.compute_at(&c.next(c.start())), 11);
+        assert_eq!(c.lazy_iter().collect::<Vec<_>>(), vec![10, 11, 20, 21]);
+    }
+}
@@ -3,6 +3,7 @@
 
 #[cfg(test)]
 pub mod tests {
+    use std::ops::ControlFlow;
     use stl::*;
 
     #[test]
@@ -44,4 +45,104 @@ pub mod tests {
         let arr = 1..2;
         assert_eq!(arr.lazy_fold_right(0, |x, y| x - y), 1);
     }
+
+    #[test]
+    fn windowed_fold() {
+        let arr = [1, 2, 3, 4, 5];
+        let sums = arr.windowed_fold(2, 0, |x, y| x + y);
+        assert_eq!(sums, vec![3, 5, 7, 9]);
+        assert_eq!(arr.windowed_fold(5, 0, |x, y| x + y), vec![15]);
+        assert_eq!(arr.windowed_fold(6, 0, |x, y| x + y), vec![]);
+        assert_eq!(arr.windowed_fold(1, 0, |x, y| x + y), arr.to_vec());
+    }
+
+    #[test]
+    fn windowed_fold_non_invertible() {
+        let arr = [4, 2, 12, 3, 8];
+        let mins = arr.windowed_fold(3, i32::MAX, |x, y| x.min(*y));
+        assert_eq!(mins, vec![2, 2, 3]);
+        let concat = "abcd".windowed_fold(2, String::new(), |mut s, c| {
+            s.push(*c);
+            s
+        });
+        assert_eq!(concat, vec!["ab", "bc", "cd"]);
+    }
+
+    #[test]
+    fn windowed_fold_with_inverse() {
+        let arr = [3, 1, 4, 1, 5, 9, 2, 6];
+        for w in 1..=9 {
+            assert_eq!(
+                arr.windowed_fold_with_inverse(
+                    w,
+                    0,
+                    |x, y| x + y,
+                    |x, y| x - y
+                ),
+                arr.windowed_fold(w, 0, |x, y| x + y)
+            );
+        }
+        let xors =
+            arr.windowed_fold_with_inverse(3, 0, |x, y| x ^ y, |x, y| x ^ y);
+        assert_eq!(xors, vec![6, 4, 0, 13, 14, 13]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windowed_fold_of_empty_window() {
+        [1, 2].windowed_fold(0, 0, |x, y| x + y);
+    }
+
+    #[test]
+    fn parallel_reduce() {
+        let arr: Vec<u64> = (1..=100_000).collect();
+        assert_eq!(arr.parallel_reduce(0, |x, y| x + y), 5_000_050_000);
+        assert_eq!(arr.parallel_reduce(0, |x, y| *x.max(y)), 100_000);
+
+        let empty: Vec<u64> = vec![];
+        assert_eq!(empty.parallel_reduce(1, |x, y| x * y), 1);
+    }
+
+    #[test]
+    fn parallel_reduce_with_non_commutative_op() {
+        let words: Vec<String> =
+            Iterator::map(0..5000, |i| (i % 10).to_string()).collect();
+        let joined = words.parallel_reduce(String::new(), |x, y| x.clone() + y);
+        assert_eq!(joined, words.concat());
+    }
+
+    #[test]
+    fn try_fold_left() {
+        let arr = [1, 2, 3];
+        let res: Result<i32, ()> = arr.try_fold_left(0, |x, y| Ok(x + y));
+        assert_eq!(res, Ok(6));
+
+        let mut visited = 0;
+        let res = arr.try_fold_left(0, |x, y| {
+            visited += 1;
+            if *y == 2 {
+                Err(x)
+            } else {
+                Ok(x + y)
+            }
+        });
+        assert_eq!(res, Err(1));
+        assert_eq!(visited, 2);
+
+        let arr = [u8::MAX, 1];
+        assert_eq!(arr.try_fold_left(0u8, |x, y| x.checked_add(*y)), None);
+
+        let arr = [1, 5, 2];
+        let res = arr.try_fold_left(String::new(), |mut s, x| {
+            if *x > 3 {
+                return ControlFlow::Break(s);
+            }
+            s.push_str(&x.to_string());
+            ControlFlow::Continue(s)
+        });
+        assert_eq!(res, ControlFlow::Break("1".to_string()));
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.try_fold_left(7, |_, _| None), Some(7));
+    }
 }
@@ -3,6 +3,7 @@
 
 #[cfg(test)]
 pub mod tests {
+    use std::ops::ControlFlow;
     use stl::*;
 
     #[test]
@@ -27,4 +28,104 @@ pub mod tests {
         arr.lazy_for_each(|e| sum += e);
         assert_eq!(sum, 6);
     }
+
+    #[test]
+    fn parallel_for_each_mut() {
+        let mut arr = [1, 2, 3];
+        arr.parallel_for_each_mut(|e| *e += 1);
+        assert_eq!(arr, [2, 3, 4]);
+
+        let mut arr: [i32; 0] = [];
+        arr.parallel_for_each_mut(|e| *e += 1);
+
+        let mut arr: Vec<usize> = (0..100000).collect();
+        arr.parallel_for_each_mut(|e| *e *= 3);
+        assert!(arr.iter().copied().eq((0..300000).step_by(3)));
+
+        let mut arr: Vec<usize> = (0..10000).collect();
+        arr.slice_mut(10, 9990).parallel_for_each_mut(|e| *e = 0);
+        assert!(arr[..10].iter().copied().eq(0..10));
+        assert!(arr[10..9990].iter().all(|e| *e == 0));
+        assert!(arr[9990..].iter().copied().eq(9990..10000));
+    }
+
+    #[test]
+    fn try_for_each() {
+        let arr = [1, 2, 3, 4];
+        let mut seen = vec![];
+        let res: Result<(), i32> = arr.try_for_each(|x| {
+            seen.push(*x);
+            Ok(())
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(seen, [1, 2, 3, 4]);
+
+        let mut seen = vec![];
+        let res = arr.try_for_each(|x| {
+            if *x % 2 == 0 {
+                return ControlFlow::Break(*x);
+            }
+            seen.push(*x);
+            ControlFlow::Continue(())
+        });
+        assert_eq!(res, ControlFlow::Break(2));
+        assert_eq!(seen, [1]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.try_for_each(|_| None), Some(()));
+    }
+
+    #[test]
+    fn try_for_each_mut() {
+        let mut arr = [1, 2, 3, 4];
+        let res = arr.try_for_each_mut(|e| {
+            if *e > 2 {
+                return Err(*e);
+            }
+            *e *= 10;
+            Ok(())
+        });
+        assert_eq!(res, Err(3));
+        assert_eq!(arr, [10, 20, 3, 4]);
+
+        let res: Result<(), ()> = arr.try_for_each_mut(|e| {
+            *e += 1;
+            Ok(())
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(arr, [11, 21, 4, 5]);
+    }
+
+    #[test]
+    fn for_each_zipped_mut() {
+        let mut a = [1, 2, 3];
+        let mut b = vec![10, 20, 30];
+        a.for_each_zipped_mut(&mut b, |x, y| *x += *y);
+        assert_eq!(a, [11, 22, 33]);
+
+        b.for_each_zipped_mut(&mut a, std::mem::swap);
+        assert_eq!(a, [10, 20, 30]);
+        assert_eq!(b, [11, 22, 33]);
+
+        let mut short = [0; 2];
+        let mut s = b.slice_mut(1, 3);
+        s.for_each_zipped_mut(&mut short, |x, y| *y = *x);
+        assert_eq!(short, [22, 33]);
+
+        let mut empty: [i32; 0] = [];
+        a.for_each_zipped_mut(&mut empty, |_, _| panic!());
+        assert_eq!(a, [10, 20, 30]);
+    }
+
+    #[test]
+    fn for_each_zipped_mut_different_element_types() {
+        let mut names = vec![String::from("a"), String::from("b")];
+        let mut lens = [0usize; 2];
+        names.for_each_zipped_mut(&mut lens, |name, len| {
+            name.push('!');
+            *len = name.len();
+        });
+        assert_eq!(names, ["a!", "b!"]);
+        assert_eq!(lens, [2, 2]);
+    }
 }
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn group_runs_by_key() {
+        let arr = [1, 1, 2, 3, 3, 3];
+        let v: Vec<_> = arr
+            .group_runs_by_key(|x| *x)
+            .map(|(k, s)| (k, s.count()))
+            .collect();
+        assert_eq!(v, vec![(1, 2), (2, 1), (3, 3)]);
+    }
+
+    #[test]
+    fn non_adjacent_equal_keys_form_separate_runs() {
+        let arr = ["a", "ab", "b", "cd", "e"];
+        let v: Vec<_> = arr
+            .group_runs_by_key(|x| x.len())
+            .map(|(k, s)| (k, s.to_vec()))
+            .collect();
+        assert_eq!(
+            v,
+            vec![
+                (1, vec!["a"]),
+                (2, vec!["ab"]),
+                (1, vec!["b"]),
+                (2, vec!["cd"]),
+                (1, vec!["e"])
+            ]
+        );
+    }
+
+    #[test]
+    fn single_run() {
+        let arr = [2, 4, 6];
+        let mut it = arr.group_runs_by_key(|x| x % 2);
+        let (k, s) = it.next().unwrap();
+        assert_eq!(k, 0);
+        assert!(s.equals(&[2, 4, 6]));
+        assert!(it.next().is_none());
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn empty_collection() {
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.group_runs_by_key(|x| *x).count(), 0);
+    }
+
+    #[test]
+    fn slice_positions_refer_to_whole() {
+        let arr = [1, 1, 2, 2, 2];
+        let v: Vec<_> = arr
+            .group_runs_by_key(|x| *x)
+            .map(|(_, s)| (s.start(), s.end()))
+            .collect();
+        assert_eq!(v, vec![(0, 2), (2, 5)]);
+    }
+}
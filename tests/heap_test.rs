@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn make_heap() {
+        let mut arr = [3, 1, 4, 1, 5, 9, 2, 6];
+        arr.make_heap();
+        assert!(arr.is_heap());
+        assert_eq!(arr[0], 9);
+
+        let mut arr: [i32; 0] = [];
+        arr.make_heap();
+        assert!(arr.is_heap());
+    }
+
+    #[test]
+    fn push_and_pop_heap() {
+        let mut v: Vec<i32> = vec![];
+        for e in [5, 1, 8, 3, 9, 2] {
+            v.push(e);
+            v.push_heap();
+            assert!(v.is_heap());
+        }
+
+        let mut popped = vec![];
+        while !v.is_empty() {
+            v.pop_heap();
+            popped.push(v.pop().unwrap());
+            assert!(v.is_heap());
+        }
+        assert_eq!(popped, vec![9, 8, 5, 3, 2, 1]);
+    }
+
+    #[test]
+    fn sort_heap() {
+        let mut arr = [3, 1, 4, 1, 5, 9, 2, 6];
+        arr.make_heap();
+        arr.sort_heap();
+        assert_eq!(arr, [1, 1, 2, 3, 4, 5, 6, 9]);
+    }
+
+    #[test]
+    fn heap_by() {
+        let mut arr = [3, 1, 4, 1, 5, 9, 2, 6];
+        arr.make_heap_by(|x, y| x > y);
+        assert!(arr.is_heap_by(|x, y| x > y));
+        arr.sort_heap_by(|x, y| x > y);
+        assert_eq!(arr, [9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn is_heap_until() {
+        let arr = [9, 5, 4, 1, 1, 3, 2, 6];
+        assert_eq!(arr.is_heap_until(), 7);
+        assert!(!arr.is_heap());
+        assert!(arr.prefix(7).is_heap());
+    }
+
+    #[test]
+    fn heap_on_slice() {
+        let mut arr = [0, 3, 1, 4, 1, 5, 0];
+        let mut s = arr.slice_mut(1, 6);
+        s.make_heap();
+        s.sort_heap();
+        assert_eq!(arr, [0, 1, 1, 3, 4, 5, 0]);
+    }
+
+    #[test]
+    fn make_heap_on_unordered_elements() {
+        let mut arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+        arr.make_heap();
+        assert!(arr.is_heap());
+        arr.sort_heap();
+        assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+    }
+
+    #[test]
+    fn make_heap_on_random_elements() {
+        let mut seed: u64 = 0x9e3779b97f4a7c15;
+        let mut next_random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        for _ in 0..500 {
+            let n = (next_random() % 64) as usize;
+            let mut v: Vec<u64> = std::iter::repeat_with(|| next_random() % 32)
+                .take(n)
+                .collect();
+            v.make_heap();
+            for i in 1..n {
+                assert!(v[(i - 1) / 2] >= v[i], "not a heap: {v:?}");
+            }
+            let mut sorted = v.clone();
+            sorted.sort();
+            v.sort_heap();
+            assert_eq!(v, sorted);
+        }
+    }
+}
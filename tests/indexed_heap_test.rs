@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::collections::IndexedHeap;
+    use stl::*;
+
+    #[test]
+    fn push_pop() {
+        let mut heap = IndexedHeap::new();
+        for x in [3, 1, 4, 1, 5, 9, 2, 6] {
+            heap.push(x);
+        }
+        assert_eq!(heap.len(), 8);
+        assert_eq!(heap.peek().map(|(_, x)| *x), Some(9));
+        let mut popped = vec![];
+        while let Some((_, x)) = heap.pop() {
+            popped.push(x);
+        }
+        assert_eq!(popped, vec![9, 6, 5, 4, 3, 2, 1, 1]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn handles_are_stable() {
+        let mut heap = IndexedHeap::new();
+        let handles: Vec<_> =
+            [5, 8, 2, 7].into_iter().map(|x| heap.push(x)).collect();
+        heap.push(10);
+        assert_eq!(heap.get(handles[0]), Some(&5));
+        assert_eq!(heap.get(handles[3]), Some(&7));
+
+        let (h, x) = heap.pop().unwrap();
+        assert_eq!(x, 10);
+        assert!(!heap.contains(h));
+        assert_eq!(heap.get(h), None);
+        assert_eq!(heap.get(handles[1]), Some(&8));
+    }
+
+    #[test]
+    fn update() {
+        let mut heap = IndexedHeap::new();
+        let a = heap.push(5);
+        let b = heap.push(8);
+        let c = heap.push(2);
+
+        assert_eq!(heap.update(c, 10), Some(2));
+        assert_eq!(heap.peek(), Some((c, &10)));
+
+        assert_eq!(heap.update(c, 1), Some(10));
+        assert_eq!(heap.peek(), Some((b, &8)));
+
+        assert_eq!(heap.pop(), Some((b, 8)));
+        assert_eq!(heap.pop(), Some((a, 5)));
+        assert_eq!(heap.update(a, 0), None);
+        assert_eq!(heap.pop(), Some((c, 1)));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn remove() {
+        let mut heap = IndexedHeap::new();
+        let handles: Vec<_> = Iterator::map(0..10, |x| heap.push(x)).collect();
+        assert_eq!(heap.remove(handles[9]), Some(9));
+        assert_eq!(heap.remove(handles[3]), Some(3));
+        assert_eq!(heap.remove(handles[3]), None);
+        let mut popped = vec![];
+        while let Some((_, x)) = heap.pop() {
+            popped.push(x);
+        }
+        assert_eq!(popped, vec![8, 7, 6, 5, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn traversal() {
+        let mut heap = IndexedHeap::new();
+        for x in [3, 1, 4, 1, 5] {
+            heap.push(x);
+        }
+        assert_eq!(heap.count(), 5);
+        assert_eq!(heap.first(), Some(&5));
+        assert_eq!(heap.fold_left(0, |acc, x| acc + x), 14);
+        assert!(heap.is_heap());
+    }
+
+    #[test]
+    fn dijkstra() {
+        let edges: Vec<Vec<(usize, u32)>> = vec![
+            vec![(1, 7), (2, 9), (5, 14)],
+            vec![(0, 7), (2, 10), (3, 15)],
+            vec![(0, 9), (1, 10), (3, 11), (5, 2)],
+            vec![(1, 15), (2, 11), (4, 6)],
+            vec![(3, 6), (5, 9)],
+            vec![(0, 14), (2, 2), (4, 9)],
+        ];
+        let mut dist = vec![u32::MAX; edges.len()];
+        let mut handles = vec![None; edges.len()];
+        let mut heap = IndexedHeap::new_by(|x: &(u32, usize), y| x > y);
+        dist[0] = 0;
+        handles[0] = Some(heap.push((0, 0)));
+        while let Some((_, (d, u))) = heap.pop() {
+            handles[u] = None;
+            for &(v, w) in &edges[u] {
+                if d + w < dist[v] {
+                    dist[v] = d + w;
+                    match handles[v] {
+                        Some(h) => {
+                            heap.update(h, (dist[v], v));
+                        }
+                        None => handles[v] = Some(heap.push((dist[v], v))),
+                    }
+                }
+            }
+        }
+        assert_eq!(dist, vec![0, 7, 9, 20, 20, 11]);
+    }
+}
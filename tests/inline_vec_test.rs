@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::rc::Rc;
+    use stl::collections::InlineVec;
+    use stl::*;
+
+    #[test]
+    fn push_and_pop() {
+        let mut v: InlineVec<i32, 3> = InlineVec::new();
+        assert!(v.is_empty());
+        assert_eq!(v.capacity(), 3);
+        assert_eq!(v.push(1), Ok(()));
+        assert_eq!(v.push(2), Ok(()));
+        assert_eq!(v.push(3), Ok(()));
+        assert!(v.is_full());
+        assert_eq!(v.push(4), Err(4));
+        assert_eq!(v.as_slice(), [1, 2, 3]);
+
+        assert_eq!(v.pop(), Some(3));
+        v.as_mut_slice()[0] = 10;
+        assert_eq!(v.as_slice(), [10, 2]);
+        v.clear();
+        assert_eq!(v.pop(), None);
+    }
+
+    #[test]
+    fn elements_are_dropped_exactly_once() {
+        let e = Rc::new(0);
+        {
+            let mut v: InlineVec<Rc<i32>, 4> = InlineVec::default();
+            for _ in 0..3 {
+                v.push(e.clone()).unwrap();
+            }
+            assert_eq!(Rc::strong_count(&e), 4);
+            let c = v.clone();
+            assert_eq!(Rc::strong_count(&e), 7);
+            assert_eq!(c, v);
+            drop(v.pop());
+            v.truncate(1);
+            assert_eq!(Rc::strong_count(&e), 5);
+        }
+        assert_eq!(Rc::strong_count(&e), 1);
+    }
+
+    #[test]
+    fn algorithms_on_inline_vec() {
+        let mut v: InlineVec<i32, 8> = InlineVec::new();
+        for x in [5, 3, 8, 1, 9, 2] {
+            v.push(x).unwrap();
+        }
+        assert_eq!(v.count(), 6);
+        v.sort_unstable();
+        assert_eq!(v.as_slice(), [1, 2, 3, 5, 8, 9]);
+        assert_eq!(v.binary_search_position(&5), Ok(3));
+        v.stable_sort_by(AllocPolicy::Forbidden, |x, y| x % 2 < y % 2);
+        assert_eq!(v.as_slice(), [2, 8, 1, 3, 5, 9]);
+        assert_eq!(format!("{v:?}"), "[2, 8, 1, 3, 5, 9]");
+    }
+}
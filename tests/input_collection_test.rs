@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    /// An input collection of numbers counting down to zero, which can't be
+    /// rewound.
+    struct Countdown {
+        remaining: u32,
+    }
+
+    impl Iterator for Countdown {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            if self.remaining == 0 {
+                return None;
+            }
+            self.remaining -= 1;
+            Some(self.remaining)
+        }
+    }
+
+    #[test]
+    fn read_into() {
+        let mut input = Countdown { remaining: 5 };
+        let mut dest = vec![9; 2];
+        assert_eq!(input.read_into(&mut dest), 2);
+        assert_eq!(dest, [4, 3]);
+
+        let mut dest = [9; 5];
+        assert_eq!(input.read_into(&mut dest), 3);
+        assert_eq!(dest, [2, 1, 0, 9, 9]);
+
+        let mut empty: [u32; 0] = [];
+        let mut input = Countdown { remaining: 1 };
+        assert_eq!(input.read_into(&mut empty), 0);
+        assert_eq!(input.next(), Some(0));
+    }
+
+    #[test]
+    fn read_into_slice() {
+        let mut input = Countdown { remaining: 4 };
+        let mut arr = [0; 4];
+        let i = input.read_into(&mut arr.slice_mut(1, 3));
+        assert_eq!(i, 3);
+        assert_eq!(arr, [0, 3, 2, 0]);
+        assert_eq!(input.sum::<u32>(), 1);
+    }
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::LinkedList;
+    use stl::*;
+
+    #[test]
+    fn interleaved_with() {
+        let arr = [1, 3, 5].interleaved_with([2, 4, 6]);
+        assert!(arr.equals(&[1, 2, 3, 4, 5, 6]));
+
+        let arr = [1, 3].interleaved_with([2, 4, 5, 6]);
+        assert!(arr.equals(&[1, 2, 3, 4, 5, 6]));
+
+        let arr = [1, 3, 4, 5].interleaved_with([2]);
+        assert!(arr.equals(&[1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn empty_sides() {
+        let arr = [1, 2].interleaved_with([]);
+        assert!(arr.equals(&[1, 2]));
+
+        let arr = [].interleaved_with([1, 2]);
+        assert!(arr.equals(&[1, 2]));
+
+        let arr = ([] as [i32; 0]).interleaved_with([]);
+        assert_eq!(arr.count(), 0);
+    }
+
+    #[test]
+    fn random_access() {
+        let a: Vec<i32> = (0..10).collect();
+        let b: Vec<i32> = (100..104).collect();
+        let arr = a.interleaved_with(b);
+        let expected = [0, 100, 1, 101, 2, 102, 3, 103, 4, 5, 6, 7, 8, 9];
+        assert_eq!(arr.count(), expected.len());
+        for i in 0..=expected.len() {
+            let p = arr.next_n(arr.start(), i);
+            assert_eq!(arr.distance(arr.start(), p.clone()), i);
+            assert!(p == arr.prior_n(arr.end(), expected.len() - i));
+            if i < expected.len() {
+                assert_eq!(*arr.at(&p), expected[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn non_random_access() {
+        let a: LinkedList<_> = [1, 3, 5, 7].into_iter().collect();
+        let b: LinkedList<_> = [2, 4].into_iter().collect();
+        let arr = (&a).interleaved_with(&b);
+        assert!(arr.equals(&[1, 2, 3, 4, 5, 7]));
+        assert!(arr.reversed().equals(&[7, 5, 4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn round_robin() {
+        let arr = ["a1", "a2", "a3"].interleaved_with(["b1", "b2"]);
+        let v = arr.lazy_map(|x| x.to_uppercase()).to_vec();
+        assert_eq!(v, ["A1", "B1", "A2", "B2", "A3"]);
+    }
+}
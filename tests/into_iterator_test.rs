@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::{BTreeSet, VecDeque};
+    use stl::*;
+
+    fn strings(arr: &[&str]) -> Vec<String> {
+        arr.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn mapped() {
+        let v: Vec<i32> = vec![1, 2, 3].map(|x| x * 2).into_iter().collect();
+        assert_eq!(v, [2, 4, 6]);
+
+        let it = [1, 2, 3, 4].map(|x| x + 1).into_iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.rev().collect::<Vec<_>>(), [5, 4, 3, 2]);
+
+        let mut it = (1..=5).lazy_map(|x| x * x).into_iter();
+        assert_eq!(it.nth(1), Some(4));
+        assert_eq!(it.nth_back(1), Some(16));
+        assert_eq!(it.collect::<Vec<_>>(), [9]);
+    }
+
+    #[test]
+    fn size_hint_agrees_with_len() {
+        let mut it = [1, 2, 3, 4, 5].map(|x| x * 2).into_iter();
+        assert_eq!(it.size_hint(), (5, Some(5)));
+        it.next();
+        it.next_back();
+        assert_eq!(it.size_hint(), (it.len(), Some(it.len())));
+        assert_eq!(it.len(), 3);
+        it.nth(5);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+        assert_eq!(it.len(), 0);
+
+        let it = (1..=4).lazy_map(|x| x * x).into_iter().skip(1);
+        assert_eq!(it.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn reversed() {
+        let v = strings(&["a", "b", "c"]);
+        let r: Vec<String> = v.reversed().into_iter().collect();
+        assert_eq!(r, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn rotated() {
+        let v = strings(&["a", "b", "c", "d"]);
+        let r: Vec<String> = v.rotated(2).into_iter().collect();
+        assert_eq!(r, ["c", "d", "a", "b"]);
+
+        let r: Vec<i32> = vec![1, 2].rotated(2).into_iter().collect();
+        assert_eq!(r, [1, 2]);
+    }
+
+    #[test]
+    fn strided() {
+        let r: Vec<i32> = vec![1, 2, 3, 4, 5].stride(2).into_iter().collect();
+        assert_eq!(r, [1, 3, 5]);
+    }
+
+    #[test]
+    fn from_collection() {
+        let d = VecDeque::from_collection(strings(&["x", "y"]).reversed());
+        assert_eq!(d, ["y", "x"]);
+
+        let s = BTreeSet::from_collection([3, 1, 2].map(|x| x % 2));
+        assert!(s.equals(&[0, 1]));
+    }
+}
@@ -16,4 +16,70 @@ pub mod tests {
         let arr = 1..=3;
         assert_eq!(arr.lazy_iter().sum::<i32>(), 6);
     }
+
+    #[test]
+    fn nth_by_iteration() {
+        let arr = [1, 2, 3, 4, 5];
+        let mut it = arr.iter();
+        assert_eq!(it.nth(1).copied(), Some(2));
+        assert_eq!(it.nth(1).copied(), Some(4));
+        assert_eq!(it.nth(1).copied(), None);
+        assert_eq!(it.next().copied(), None);
+    }
+
+    #[test]
+    fn nth_back_by_iteration() {
+        let arr = [1, 2, 3, 4, 5];
+        let mut it = arr.iter();
+        assert_eq!(it.nth_back(1).copied(), Some(4));
+        assert_eq!(it.nth_back(2).copied(), Some(1));
+        assert_eq!(it.nth_back(0).copied(), None);
+    }
+
+    #[test]
+    fn skip_by_iteration() {
+        let arr = [1, 2, 3, 4, 5];
+        let v: Vec<_> = arr.iter().skip(3).copied().collect();
+        assert_eq!(v, vec![4, 5]);
+    }
+
+    #[test]
+    fn nth_by_lazy_iteration() {
+        let arr = 1..=5;
+        let mut it = arr.lazy_iter();
+        assert_eq!(it.nth(2), Some(3));
+        assert_eq!(it.nth_back(0), Some(5));
+        assert_eq!(it.nth(5), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn nth_by_mutable_iteration() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut it = arr.iter_mut();
+        *it.nth(1).unwrap() = 0;
+        *it.nth_back(1).unwrap() = 0;
+        assert!(it.nth_back(5).is_none());
+        assert_eq!(arr, [1, 0, 3, 0, 5]);
+    }
+
+    #[test]
+    fn fused_iteration() {
+        let arr = [1, 2];
+        let mut it = arr.iter().fuse();
+        assert_eq!(it.next().copied(), Some(1));
+        assert_eq!(it.next().copied(), Some(2));
+        assert_eq!(it.next().copied(), None);
+        assert_eq!(it.next().copied(), None);
+    }
+
+    #[test]
+    fn cloned_iterator_is_independent() {
+        let arr = 1..=3;
+        let mut it = arr.lazy_iter();
+        it.next();
+        let copy = it.clone();
+        assert_eq!(it.sum::<i32>(), 5);
+        assert_eq!(copy.sum::<i32>(), 5);
+    }
 }
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn test_the_equality() {
+        let v = vec![vec![1, 2], vec![3], vec![4, 5, 6]];
+        assert!(v.joined().equals(&[1, 2, 3, 4, 5, 6]));
+        let v = vec![vec![], vec![1], vec![], vec![], vec![2, 3], vec![]];
+        assert!(v.joined().equals(&[1, 2, 3]));
+        let v: Vec<Vec<i32>> = vec![vec![], vec![]];
+        assert!(v.joined().equals(&[]));
+        let v: Vec<Vec<i32>> = vec![];
+        assert!(v.joined().equals(&[]));
+    }
+
+    #[test]
+    fn count() {
+        let v = vec![vec![1, 2], vec![], vec![4, 5, 6]];
+        let j = v.joined();
+        assert_eq!(j.count(), 5);
+        assert_eq!(j.distance(j.start(), j.end()), 5);
+        assert_eq!(*j.at(&j.next_n(j.start(), 3)), 5);
+    }
+
+    #[test]
+    fn backward_traversal() {
+        let v = vec![vec![1, 2], vec![], vec![3], vec![]];
+        let j = v.joined();
+        assert_eq!(*j.at(&j.prior(j.end())), 3);
+        assert!(j.reversed().equals(&[3, 2, 1]));
+    }
+
+    #[test]
+    fn slicing() {
+        let v = [vec![1, 2], vec![3], vec![4, 5]];
+        let j = v.joined();
+        assert!(j.prefix(3).equals(&[1, 2, 3]));
+        assert!(j.suffix(3).equals(&[3, 4, 5]));
+        assert!(j.start() < j.next(j.start()));
+    }
+
+    #[test]
+    fn joined_slice() {
+        let v = vec![vec![1, 2], vec![3], vec![4, 5]];
+        assert!(v.suffix(2).joined().equals(&[3, 4, 5]));
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::iterators::lending::{self, LendingIterator, LendingIteratorExt};
+    use stl::*;
+
+    #[test]
+    fn from_collection() {
+        let mut iter = lending::from_collection(vec![1, 2]);
+        assert_eq!(iter.next().copied(), Some(1));
+        assert_eq!(iter.next().copied(), Some(2));
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn from_lazy_collection() {
+        let iter =
+            lending::from_collection((1..4).lazy_map(|x: i32| x.to_string()));
+        let all: Vec<String> = iter.map(|s| s.val).collect();
+        assert_eq!(all, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn windows_mut() {
+        let mut arr = [1, 2, 3, 4];
+        let windows: Vec<Vec<i32>> = lending::windows_mut(&mut arr, 3)
+            .map(|w| w.to_vec())
+            .collect();
+        assert_eq!(windows, [[1, 2, 3], [2, 3, 4]]);
+
+        let mut arr = [1, 2];
+        assert_eq!(lending::windows_mut(&mut arr, 3).fold(0, |n, _| n + 1), 0);
+        assert_eq!(lending::windows_mut(&mut arr, 2).fold(0, |n, _| n + 1), 1);
+
+        let mut ring: collections::RingBuffer<i32, 3> =
+            collections::RingBuffer::new();
+        for x in [0, 1, 2, 3] {
+            ring.push_back_overwriting(x);
+        }
+        let mut windows = lending::windows_mut(&mut ring, 2);
+        assert!(windows.next().unwrap().equals(&[1, 2]));
+        assert!(windows.next().unwrap().equals(&[2, 3]));
+        assert!(windows.next().is_none());
+    }
+
+    #[test]
+    fn take() {
+        let iter = lending::from_collection(vec![1, 2, 3]);
+        assert_eq!(iter.take(2).fold(0, |acc, x| acc + x), 3);
+        let iter = lending::from_collection(vec![1, 2, 3]);
+        assert_eq!(iter.take(5).fold(0, |acc, x| acc + x), 6);
+        let iter = lending::from_collection(vec![1, 2, 3]);
+        assert_eq!(iter.take(0).fold(0, |acc, x| acc + x), 0);
+    }
+
+    #[test]
+    fn take_while() {
+        let mut arr = [1, 2, 3, 4];
+        lending::windows_mut(&mut arr, 2)
+            .take_while(|w| *w.at(&w.next(w.start())) < 3)
+            .for_each(|mut w| w.fill(0));
+        assert_eq!(arr, [0, 0, 3, 4]);
+
+        let mut iter =
+            lending::from_collection(vec![1, 5, 2]).take_while(|x| **x < 3);
+        assert_eq!(iter.next().copied(), Some(1));
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn chain() {
+        let mut a = [1, 2];
+        let mut b = [3, 4];
+        let mut seen = vec![];
+        lending::windows_mut(&mut a, 1)
+            .chain(lending::windows_mut(&mut b, 1))
+            .enumerate()
+            .for_each(|(i, mut w)| {
+                seen.push(w.to_vec()[0]);
+                w.fill(i as i32);
+            });
+        assert_eq!(seen, [1, 2, 3, 4]);
+        assert_eq!(a, [0, 1]);
+        assert_eq!(b, [2, 3]);
+
+        let empty = lending::from_collection(Vec::<i32>::new());
+        let all: Vec<i32> = empty
+            .chain(lending::from_collection(vec![7]))
+            .map(|x| *x)
+            .collect();
+        assert_eq!(all, [7]);
+    }
+
+    #[test]
+    fn enumerate() {
+        let iter = lending::from_collection(vec!['a', 'b']).enumerate();
+        let all: Vec<(usize, char)> = iter.map(|(i, c)| (i, *c)).collect();
+        assert_eq!(all, [(0, 'a'), (1, 'b')]);
+    }
+
+    #[test]
+    fn count_and_count_where() {
+        let mut arr = [1, 2, 3, 4, 5];
+        assert_eq!(lending::windows_mut(&mut arr, 2).count(), 4);
+        let iter = lending::windows_mut(&mut arr, 2);
+        assert_eq!(
+            iter.count_where(|w| w.fold_left(0, |acc, x| acc + x) > 4),
+            3
+        );
+        let empty = lending::from_collection(Vec::<i32>::new());
+        assert_eq!(empty.count(), 0);
+    }
+
+    #[test]
+    fn all_any_none_satisfy() {
+        let mut iter = lending::from_collection(vec![2, 4, 5, 6]);
+        assert!(!iter.all_satisfy(|x| *x % 2 == 0));
+        assert_eq!(iter.next().copied(), Some(6));
+
+        let mut iter = lending::from_collection(vec![1, 2, 3]);
+        assert!(iter.any_satisfy(|x| *x == 2));
+        assert_eq!(iter.next().copied(), Some(3));
+        assert!(!iter.any_satisfy(|_| true));
+        assert!(iter.all_satisfy(|_| false));
+
+        let mut iter = lending::from_collection(vec![1, 3]);
+        assert!(iter.none_satisfy(|x| *x % 2 == 0));
+    }
+
+    #[test]
+    fn copy_into() {
+        let mut iter = lending::from_collection(vec![1, 2, 3]);
+        let mut dest = [0; 2];
+        assert_eq!(iter.copy_into(&mut dest, |x| *x), 2);
+        assert_eq!(dest, [1, 2]);
+        let mut dest = [0; 2];
+        assert_eq!(iter.copy_into(&mut dest, |x| *x), 1);
+        assert_eq!(dest, [3, 0]);
+
+        let mut iter = lending::from_collection(vec![
+            String::from("a"),
+            String::from("b"),
+        ]);
+        let mut dest = vec![String::new(); 3];
+        assert_eq!(iter.copy_into(&mut dest, |x| x.clone()), 2);
+        assert_eq!(dest, ["a", "b", ""]);
+
+        let mut arr = [1, 2, 3];
+        let mut sums = [0; 2];
+        let mut windows = lending::windows_mut(&mut arr, 2);
+        windows.copy_into(&mut sums, |w| w.sum());
+        assert_eq!(sums, [3, 5]);
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::io::{self, BufReader, Read, Write};
+    use stl::*;
+
+    /// A reader failing after giving `data`.
+    struct Failing<'a> {
+        data: &'a [u8],
+    }
+
+    impl Read for Failing<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.data.is_empty() {
+                return Err(io::Error::other("disconnected"));
+            }
+            let n = self.data.read(buf)?;
+            Ok(n)
+        }
+    }
+
+    /// Returns lines of `text`, which must be readable without errors.
+    fn read_all(text: &str) -> Vec<String> {
+        collections::Lines::new(text.as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_lines_without_terminators() {
+        assert_eq!(read_all("x\ny\r\n"), ["x", "y"]);
+        assert_eq!(read_all("x\n\nz"), ["x", "", "z"]);
+        let mut lines = collections::Lines::new("x\n".as_bytes());
+        assert_eq!(lines.next().unwrap().unwrap(), "x");
+        assert!(lines.next().is_none());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn empty_reader() {
+        let mut lines = collections::Lines::new("".as_bytes());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn yields_error_and_stops() {
+        let reader = BufReader::new(Failing {
+            data: b"ok\npartial",
+        });
+        let mut lines = collections::Lines::new(reader);
+        assert_eq!(lines.next().unwrap().unwrap(), "ok");
+        let e = lines.next().unwrap().unwrap_err();
+        assert_eq!(e.to_string(), "disconnected");
+        assert!(lines.next().is_none());
+
+        let reader = BufReader::new(Failing { data: b"a\nb\nc" });
+        let res: io::Result<Vec<String>> =
+            collections::Lines::new(reader).collect();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn buffered_lines_keep_error() {
+        let reader = BufReader::new(Failing {
+            data: b"a\nb\npartial",
+        });
+        let lines = collections::Lines::new(reader).buffered();
+        assert_eq!(lines.count(), 3);
+        assert_eq!(lines.count_where(|line| line.is_ok()), 2);
+        let last = lines.at(&lines.prior(lines.end()));
+        assert_eq!(
+            last.as_ref().as_ref().unwrap_err().to_string(),
+            "disconnected"
+        );
+    }
+
+    #[test]
+    fn find_in_file() {
+        let path = std::env::temp_dir()
+            .join(format!("stl_lines_test_{}.txt", std::process::id()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        write!(file, "# header\nname=stl\nversion=1\n").unwrap();
+        drop(file);
+
+        let file = BufReader::new(std::fs::File::open(&path).unwrap());
+        let mut lines = collections::Lines::new(file);
+        let version = lines.find_map(|line| {
+            line.map(|line| {
+                line.as_str().starts_with("version=").then_some(line)
+            })
+            .transpose()
+        });
+        assert_eq!(version.unwrap().unwrap(), "version=1");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn buffered_lines_with_collection_algorithms() {
+        let text = "a,1\nb,2\n--\nc,3";
+        let lines = collections::Lines::new(text.as_bytes()).buffered();
+        let records = lines.prefix_while(|line| line.as_ref().unwrap() != "--");
+        assert_eq!(
+            records.count_where(|line| line
+                .as_ref()
+                .unwrap()
+                .as_str()
+                .ends_with('2')),
+            1
+        );
+        let fields: Vec<Vec<String>> = records
+            .iter()
+            .map(|line| {
+                line.as_ref()
+                    .as_ref()
+                    .unwrap()
+                    .split(',')
+                    .map(String::from)
+                    .collect()
+            })
+            .collect();
+        assert_eq!(fields, [["a", "1"], ["b", "2"]]);
+        let rest = lines.suffix_from(lines.next(records.end()));
+        assert_eq!(rest.count(), 1);
+        assert_eq!(rest.first().unwrap().as_ref().as_ref().unwrap(), "c,3");
+    }
+}
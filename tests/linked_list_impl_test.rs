@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::LinkedList;
+    use stl::*;
+
+    #[test]
+    fn traversal() {
+        let list = LinkedList::from([1, 2, 3, 4, 5]);
+        assert_eq!(list.count(), 5);
+        assert!(list.equals(&[1, 2, 3, 4, 5]));
+        assert_eq!(*list.at(&0), 1);
+        assert_eq!(*list.at(&3), 4);
+        assert_eq!(*list.at(&list.prior(list.end())), 5);
+        assert!(list.reversed().equals(&[5, 4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn empty() {
+        let list: LinkedList<i32> = LinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.first_position_of(&1), None);
+    }
+
+    #[test]
+    fn algorithms() {
+        let list = LinkedList::from([1, 3, 5, 2, 4]);
+        assert_eq!(list.first_position_where(|x| x % 2 == 0), Some(3));
+        assert_eq!(list.last_position_where(|x| x % 2 == 1), Some(2));
+        assert_eq!(list.partition_point(|x| x % 2 == 0), 3);
+        assert_eq!(list.fold_right(0, |x, acc| acc * 10 + x), 42531);
+        assert!(list.suffix_from(3).equals(&[2, 4]));
+        assert_eq!(list.count_where(|x| *x > 2), 3);
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Arc;
+    use stl::locking::RwLockCollection;
+    use stl::*;
+
+    #[test]
+    fn with_read() {
+        let c = RwLockCollection::new(vec![1, 2, 3, 4]);
+        assert_eq!(c.with_read(|s| s.count_where(|x| x % 2 == 0)), 2);
+        assert_eq!(c.with_read(|s| s.first_position_of(&3)), Some(2));
+    }
+
+    #[test]
+    fn with_write() {
+        let c = RwLockCollection::from(vec![1, 2, 3, 4, 5]);
+        let p = c.with_write(|mut s| s.stable_partition(|x| x % 2 == 1));
+        assert_eq!(p, 2);
+        assert_eq!(c.into_inner(), vec![2, 4, 1, 3, 5]);
+    }
+
+    #[test]
+    fn snapshot() {
+        let c = RwLockCollection::new(vec![3, 1, 2]);
+        let mut snapshot = c.snapshot();
+        snapshot.sort_unstable();
+        assert_eq!(snapshot, vec![1, 2, 3]);
+        assert!(c.with_read(|s| s.equals(&[3, 1, 2])));
+    }
+
+    #[test]
+    fn concurrent_readers_and_writers() {
+        let c = Arc::new(RwLockCollection::new(vec![0; 100]));
+        let handles: Vec<_> = Iterator::map(0..4, |_| {
+            let c = c.clone();
+            std::thread::spawn(move || {
+                for _ in 0..10 {
+                    c.with_write(|mut s| s.for_each_mut(|x| *x += 1));
+                    // Every traversal sees all elements equal.
+                    let consistent = c.with_read(|s| {
+                        let first = *s.at(&s.start());
+                        s.all_satisfy(|x| *x == first)
+                    });
+                    assert!(consistent);
+                }
+            })
+        })
+        .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert!(c.with_read(|s| s.all_satisfy(|x| *x == 40)));
+    }
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn merged() {
+        let arr = [1, 3, 5, 7].merged(vec![2, 3, 8]);
+        assert_eq!(arr.count(), 7);
+        assert!(arr.equals(&[1, 2, 3, 3, 5, 7, 8]));
+    }
+
+    #[test]
+    fn merged_with_empty() {
+        let a: [i32; 0] = [];
+        assert!(a.merged([1, 2]).equals(&[1, 2]));
+        assert!([1, 2].merged(a).equals(&[1, 2]));
+        assert!(a.merged(a).is_empty());
+    }
+
+    #[test]
+    fn merging_is_stable() {
+        let a = [(1, 'a'), (2, 'a'), (2, 'a')];
+        let b = [(0, 'b'), (2, 'b'), (3, 'b')];
+        let arr = a.merged_by(b, |x, y| x.0 < y.0);
+        assert!(arr.equals(&[
+            (0, 'b'),
+            (1, 'a'),
+            (2, 'a'),
+            (2, 'a'),
+            (2, 'b'),
+            (3, 'b')
+        ]));
+    }
+
+    #[test]
+    fn backward_traversal() {
+        let a = [(1, 'a'), (2, 'a'), (4, 'a')];
+        let b = [(2, 'b'), (3, 'b'), (4, 'b')];
+        let arr = a.merged_by(b, |x, y| x.0 < y.0);
+        let forward = arr.to_vec();
+        let mut backward = vec![];
+        let mut i = arr.end();
+        while i != arr.start() {
+            arr.form_prior(&mut i);
+            backward.push(*arr.at(&i));
+        }
+        backward.reverse();
+        assert_eq!(forward, backward);
+        assert!(arr.reversed().equals(&[
+            (4, 'b'),
+            (4, 'a'),
+            (3, 'b'),
+            (2, 'b'),
+            (2, 'a'),
+            (1, 'a')
+        ]));
+    }
+
+    #[test]
+    fn k_way_merge() {
+        let runs = [vec![1, 5, 9], vec![2, 6], vec![0, 3, 4, 7, 8]];
+        let arr = runs[0].full().merged(runs[1].full()).merged(runs[2].full());
+        assert!(arr.equals(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    }
+
+    #[test]
+    fn lazy_merge() {
+        let evens = (0..5).lazy_map(|x| x * 2);
+        let odds = (0..5).lazy_map(|x| x * 2 + 1);
+        let arr = evens.merged(odds);
+        assert_eq!(arr.lazy_first(), Some(0));
+        assert!(arr.equals(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    }
+}
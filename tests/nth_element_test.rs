@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn nth_element() {
+        let mut arr = [7, 2, 9, 2, 5, 7, 1];
+        arr.nth_element(0);
+        assert_eq!(arr[0], 1);
+
+        let mut arr = [7, 2, 9, 2, 5, 7, 1];
+        arr.nth_element(6);
+        assert_eq!(arr[6], 9);
+
+        let mut arr = [4];
+        arr.nth_element(0);
+        assert_eq!(arr, [4]);
+    }
+
+    #[test]
+    fn nth_element_matches_sorted() {
+        let v: Vec<i32> = (0..500).lazy_map(|x| (x * 7919) % 97).to_vec();
+        let mut sorted = v.clone();
+        sorted.sort();
+        for (k, e) in sorted.iter().enumerate() {
+            let mut arr = v.clone();
+            arr.nth_element(k);
+            assert_eq!(arr[k], *e);
+            assert!(arr[..k].iter().all(|x| x <= e));
+            assert!(arr[k + 1..].iter().all(|x| x >= e));
+        }
+    }
+
+    #[test]
+    fn nth_element_by() {
+        let mut arr = [(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd')];
+        arr.nth_element_by(0, |x, y| x.0 > y.0);
+        assert_eq!(arr[0].0, 3);
+
+        let mut arr = [1, 2, 3, 4, 5, 6];
+        arr.nth_element_by(2, |x, y| x > y);
+        assert_eq!(arr[2], 4);
+    }
+
+    #[test]
+    fn nth_element_of_slice() {
+        let mut arr = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+        arr.slice_mut(2, 7).nth_element(4);
+        assert_eq!(arr[4], 5);
+        assert_eq!(arr[..2], [9, 8]);
+        assert_eq!(arr[7..], [2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_element_at_end() {
+        let mut arr = [1, 2, 3];
+        arr.nth_element(3);
+    }
+
+    #[test]
+    fn select_nth_unstable() {
+        let mut arr = [5, 1, 4, 2, 3];
+        let (less, e, greater) = arr.select_nth_unstable(2);
+        assert_eq!(*e, 3);
+        assert_eq!(less.count(), 2);
+        assert_eq!(greater.count(), 2);
+        assert!(less.all_satisfy(|x| *x < 3));
+        assert!(greater.all_satisfy(|x| *x > 3));
+
+        let (mut less, e, _) = arr.select_nth_unstable(4);
+        assert_eq!(*e, 5);
+        less.sort_unstable();
+        assert_eq!(arr, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn select_nth_unstable_by() {
+        let mut arr = [5, 1, 4, 2, 3];
+        let (less, e, greater) = arr.select_nth_unstable_by(0, |x, y| x > y);
+        assert_eq!(*e, 5);
+        assert!(less.is_empty());
+        assert_eq!(greater.count(), 4);
+    }
+
+    #[test]
+    fn nth_element_deterministic_matches_sorted() {
+        let inputs: Vec<Vec<i32>> = vec![
+            (0..500).lazy_map(|x| (x * 7919) % 97).to_vec(),
+            (0..300).to_vec(),
+            (0..300).reversed().to_vec(),
+            vec![4; 100],
+            (0..200)
+                .lazy_map(|x| if x < 100 { x } else { 200 - x })
+                .to_vec(),
+        ];
+        for v in inputs {
+            let mut sorted = v.clone();
+            sorted.sort();
+            for (k, e) in sorted.iter().enumerate() {
+                let mut arr = v.clone();
+                arr.nth_element_deterministic(k);
+                assert_eq!(arr[k], *e);
+                assert!(arr[..k].iter().all(|x| x <= e));
+                assert!(arr[k + 1..].iter().all(|x| x >= e));
+            }
+        }
+    }
+
+    #[test]
+    fn nth_element_deterministic_by() {
+        let mut arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        arr.nth_element_deterministic_by(2, |x, y| x > y);
+        assert_eq!(arr[2], 10);
+
+        let mut arr = [9, 8, 7, 6, 5, 4, 3, 2, 1, 0, -1, -2];
+        arr.slice_mut(2, 12).nth_element_deterministic(9);
+        assert_eq!(arr[9], 5);
+        assert_eq!(arr[..2], [9, 8]);
+    }
+
+    #[test]
+    fn nth_element_deterministic_takes_linear_comparisons() {
+        use std::cell::Cell;
+        let n = 100000;
+        let comparisons = Cell::new(0);
+        let mut arr: Vec<usize> = (0..n).collect();
+        arr.nth_element_deterministic_by(n / 2, |x, y| {
+            comparisons.set(comparisons.get() + 1);
+            x < y
+        });
+        assert_eq!(arr[n / 2], n / 2);
+        assert!(comparisons.get() < 40 * n);
+    }
+}
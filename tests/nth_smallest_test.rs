@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn nth_smallest() {
+        let arr = [5, 1, 4, 2, 3];
+        assert_eq!(arr.nth_smallest(0), Some(1));
+        assert_eq!(arr.nth_smallest(2), Some(3));
+        assert_eq!(arr.nth_smallest(4), Some(5));
+        assert_eq!(arr.nth_smallest(5), None);
+        assert_eq!(arr, [5, 1, 4, 2, 3]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.nth_smallest(0), None);
+    }
+
+    #[test]
+    fn nth_smallest_with_repeated_elements() {
+        let arr = [2, 2, 1, 2, 1, 3, 3, 2];
+        let mut sorted = arr;
+        sorted.sort();
+        for (n, e) in sorted.iter().enumerate() {
+            assert_eq!(arr.nth_smallest(n), Some(*e));
+        }
+    }
+
+    #[test]
+    fn nth_smallest_matches_sorted() {
+        // Small and large `n` take heap and scratch buffer paths.
+        let arr: Vec<u64> =
+            Iterator::map(0..1000u64, |i| (i * 7919) % 331).collect();
+        let mut sorted = arr.clone();
+        sorted.sort();
+        for (n, e) in sorted.iter().enumerate() {
+            assert_eq!(arr.nth_smallest(n), Some(*e));
+        }
+        assert_eq!(arr.nth_smallest(arr.len()), None);
+    }
+
+    #[test]
+    fn nth_smallest_by() {
+        let arr = ["ccc", "a", "bb", "dddd"];
+        assert_eq!(arr.nth_smallest_by(0, |x, y| x.len() < y.len()), Some("a"));
+        assert_eq!(
+            arr.nth_smallest_by(1, |x, y| x.len() > y.len()),
+            Some("ccc")
+        );
+    }
+
+    #[test]
+    fn nth_smallest_of_lazy_collection() {
+        let squares = CollectionExt::map(0..100, |x| (x - 50) * (x - 50));
+        assert_eq!(squares.nth_smallest(0), Some(0));
+        assert_eq!(squares.nth_smallest(1), Some(1));
+        assert_eq!(squares.nth_smallest(2), Some(1));
+        assert_eq!(squares.nth_smallest(99), Some(2500));
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::collections::{linspace, stepped_iota};
+    use stl::*;
+
+    #[test]
+    fn stepped_iota_of_integers() {
+        assert_eq!(stepped_iota(0u32, 5, 4).to_vec(), [0, 5, 10, 15]);
+        assert_eq!(stepped_iota(3i64, -2, 4).to_vec(), [3, 1, -1, -3]);
+        assert_eq!(stepped_iota(7i32, 0, 3).to_vec(), [7, 7, 7]);
+        assert!(stepped_iota(1i32, 1, 0).is_empty());
+
+        let c = stepped_iota(100u64, 3, 1000);
+        assert_eq!(c.count(), 1000);
+        assert_eq!(*c.at(&999), 100 + 3 * 999);
+        assert_eq!(c.binary_search_position(&106), Ok(2));
+        assert_eq!(c.binary_search_position(&107), Err(3));
+    }
+
+    #[test]
+    fn stepped_iota_of_floats() {
+        let c = stepped_iota(1.0, 0.5, 4);
+        assert_eq!(c.to_vec(), [1.0, 1.5, 2.0, 2.5]);
+        assert_eq!(*c.at(&3), 2.5);
+        assert_eq!(stepped_iota(0.0f32, -2.0, 3).to_vec(), [0.0, -2.0, -4.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn stepped_iota_panics_for_unrepresentable_offset() {
+        let c = stepped_iota(0u8, 0, 300);
+        let _ = *c.at(&256);
+    }
+
+    #[test]
+    fn linspace_is_evenly_spaced() {
+        let xs = linspace(-1.0, 1.0, 9);
+        assert_eq!(xs.count(), 9);
+        assert_eq!(*xs.at(&0), -1.0);
+        assert_eq!(*xs.at(&8), 1.0);
+        assert_eq!(*xs.at(&4), 0.0);
+        assert_eq!(
+            xs.lazy_map(|x| x * 4.0).to_vec(),
+            [-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn linspace_ends_exactly_at_b() {
+        let xs = linspace(0.0, 0.3, 4);
+        assert_eq!(*xs.at(&3), 0.3);
+        assert_eq!(linspace(2.0, 5.0, 1).to_vec(), [2.0]);
+        assert!(linspace(2.0, 5.0, 0).is_empty());
+    }
+
+    collection_conformance_tests!(
+        random_access,
+        stepped_iota_conformance,
+        stepped_iota(1i32, 3, 4),
+        [1, 4, 7, 10]
+    );
+    collection_conformance_tests!(
+        random_access,
+        linspace_conformance,
+        linspace(0.0, 2.0, 5),
+        [0.0, 0.5, 1.0, 1.5, 2.0]
+    );
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn sum() {
+        assert_eq!([1, 2, 3, 4].sum(), 10);
+        assert_eq!(vec![0.5, 0.25].sum(), 0.75);
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(CollectionExt::sum(&(1..=100)), 5050);
+    }
+
+    #[test]
+    fn product() {
+        assert_eq!([1, 2, 3, 4].product(), 24);
+        let empty: [i64; 0] = [];
+        assert_eq!(empty.product(), 1);
+        assert_eq!((1..6).lazy_map(|x| x as u64).product(), 120);
+    }
+
+    #[test]
+    fn inclusive_scan_into() {
+        let arr = [3, 1, 4, 1, 5];
+        let mut dest = vec![0; 6];
+        let i = arr.inclusive_scan_into(&mut dest, |x, y| x + y);
+        assert_eq!(i, 5);
+        assert_eq!(dest, vec![3, 4, 8, 9, 14, 0]);
+
+        let mut dest = [0; 5];
+        arr.inclusive_scan_into(&mut dest, |x, y| *x.max(y));
+        assert_eq!(dest, [3, 3, 4, 4, 5]);
+    }
+
+    #[test]
+    fn inclusive_scan_into_empty() {
+        let empty: [i32; 0] = [];
+        let mut dest = [7; 2];
+        assert_eq!(empty.inclusive_scan_into(&mut dest, |x, y| x + y), 0);
+        assert_eq!(dest, [7, 7]);
+    }
+
+    #[test]
+    fn exclusive_scan_into() {
+        let arr = [3, 1, 4, 1, 5];
+        let mut dest = [0; 5];
+        let i = arr.exclusive_scan_into(10, &mut dest, |x, y| x + y);
+        assert_eq!(i, 5);
+        assert_eq!(dest, [10, 13, 14, 18, 19]);
+    }
+
+    #[test]
+    fn exclusive_scan_of_strings() {
+        let words = vec![String::from("a"), String::from("b")];
+        let mut dest = vec![String::new(); 2];
+        words.exclusive_scan_into(String::from(">"), &mut dest, |x, y| {
+            format!("{x}{y}")
+        });
+        assert_eq!(dest, vec![">", ">a"]);
+    }
+
+    #[test]
+    fn inclusive_scan_inplace() {
+        let mut arr = [3, 1, 4, 1, 5];
+        arr.inclusive_scan_inplace(|x, y| x + y);
+        assert_eq!(arr, [3, 4, 8, 9, 14]);
+
+        let mut arr = vec![2, 3, 4];
+        arr.slice_mut(1, 3).inclusive_scan_inplace(|x, y| x * y);
+        assert_eq!(arr, vec![2, 3, 12]);
+
+        let mut empty: [i32; 0] = [];
+        empty.inclusive_scan_inplace(|x, y| x + y);
+    }
+
+    #[test]
+    fn adjacent_difference_into() {
+        let arr = [2, 3, 5, 9];
+        let mut dest = vec![0; 5];
+        let i = arr.adjacent_difference_into(&mut dest, |x, y| x - y);
+        assert_eq!(i, 4);
+        assert_eq!(dest, vec![2, 1, 2, 4, 0]);
+
+        let mut dest = [0; 4];
+        arr.adjacent_difference_into(&mut dest, |x, y| x + y);
+        assert_eq!(dest, [2, 5, 8, 14]);
+    }
+
+    #[test]
+    fn adjacent_difference_inverts_inclusive_scan() {
+        let arr = [4, -1, 7, 0, 3];
+        let mut deltas = [0; 5];
+        arr.adjacent_difference_into(&mut deltas, |x, y| x - y);
+        deltas.inclusive_scan_inplace(|x, y| x + y);
+        assert_eq!(deltas, arr);
+
+        let empty: [i32; 0] = [];
+        let mut dest = [9];
+        assert_eq!(empty.adjacent_difference_into(&mut dest, |x, y| x - y), 0);
+        assert_eq!(dest, [9]);
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    fn respects(order: &[usize], edges: &[(usize, usize)]) -> bool {
+        let mut rank = vec![0; order.len()];
+        for (i, &u) in order.iter().enumerate() {
+            rank[u] = i;
+        }
+        edges.iter().all(|&(u, v)| rank[u] < rank[v])
+    }
+
+    #[test]
+    fn topological_sort_respects_edges() {
+        let edges = vec![(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)];
+        let order = topological_sort(6, &edges).unwrap();
+        assert_eq!(order.len(), 6);
+        assert!(respects(&order, &edges));
+    }
+
+    #[test]
+    fn topological_sort_without_edges() {
+        assert_eq!(topological_sort(3, &[]), Some(vec![0, 1, 2]));
+        assert_eq!(topological_sort(0, &[]), Some(vec![]));
+    }
+
+    #[test]
+    fn topological_sort_detects_cycle() {
+        assert_eq!(topological_sort(3, &[(0, 1), (1, 2), (2, 1)]), None);
+        assert_eq!(topological_sort(1, &[(0, 0)]), None);
+    }
+
+    #[test]
+    fn stable_topo_sort_is_smallest_order() {
+        let edges = [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)];
+        assert_eq!(stable_topo_sort(6, &edges), Some(vec![4, 5, 0, 2, 3, 1]));
+        assert_eq!(stable_topo_sort(4, &[]), Some(vec![0, 1, 2, 3]));
+        assert_eq!(stable_topo_sort(2, &[(0, 1), (1, 0)]), None);
+    }
+
+    #[test]
+    fn ordering_build_steps() {
+        let steps = ["link", "compile", "fetch", "test"];
+        let edges = [(2, 1), (1, 0), (0, 3)];
+        let order = stable_topo_sort(steps.count(), &edges).unwrap();
+        let ordered: Vec<_> = order.iter().map(|&i| steps[i]).collect();
+        assert_eq!(ordered, vec!["fetch", "compile", "link", "test"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn edge_out_of_bounds() {
+        topological_sort(2, &[(0, 2)]);
+    }
+}
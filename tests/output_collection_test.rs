@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::mem::MaybeUninit;
+    use stl::*;
+
+    #[test]
+    fn vec_appends() {
+        let mut out = vec![1];
+        assert!(out.write_next(2).is_ok());
+        let i = (3..5).lazy_write_to(&mut out);
+        assert_eq!(i, 5);
+        assert_eq!(out, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn uninit_output() {
+        let mut buffer = [const { MaybeUninit::<String>::uninit() }; 2];
+        let mut out = collections::UninitOutput::new(&mut buffer);
+        let strings = (1..4).lazy_map(|x: i32| x.to_string());
+        let i = strings.lazy_write_to(&mut out);
+        assert_eq!(i, 3);
+        assert_eq!(out.written_count(), 2);
+        assert_eq!(out.write_next(String::from("x")), Err(String::from("x")));
+        let written = out.into_written();
+        assert_eq!(written, ["1", "2"]);
+        unsafe { std::ptr::drop_in_place(written) };
+    }
+
+    #[test]
+    fn uninit_output_empty() {
+        let mut buffer: [MaybeUninit<i32>; 0] = [];
+        let mut out = collections::UninitOutput::new(&mut buffer);
+        assert_eq!((0..3).lazy_write_to(&mut out), 0);
+        assert!(out.into_written().is_empty());
+    }
+
+    #[test]
+    fn overwriting_output() {
+        let mut ring = collections::RingBuffer::<i32, 3>::new();
+        ring.push_back_overwriting(0);
+        ring.push_back_overwriting(0);
+        let mut out = collections::OverwritingOutput::new(&mut ring);
+        let i = (5..9).lazy_map(|x| x * 2).lazy_write_to(&mut out);
+        assert_eq!(i, 7);
+        assert!(out.position() == ring.end());
+        assert!(ring.iter().eq([10, 12].iter()));
+    }
+
+    #[test]
+    fn overwriting_output_partial() {
+        let mut arr = [0; 4];
+        let mut out = collections::OverwritingOutput::new(&mut arr);
+        assert_eq!((1..3).lazy_write_to(&mut out), 3);
+        assert_eq!(out.position(), 2);
+        assert_eq!(arr, [1, 2, 0, 0]);
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::LinkedList;
+    use stl::collections::*;
+    use stl::*;
+
+    #[test]
+    fn repeat_value() {
+        let c = repeat(String::from("ab"), 3);
+        assert_eq!(c.count(), 3);
+        assert_eq!(c.value(), "ab");
+        assert!(c.all_satisfy(|s| s == "ab"));
+        assert_eq!(c.compute_at(&1), "ab");
+        assert!(repeat(1, 0).is_empty());
+    }
+
+    #[test]
+    fn padded_to_extends_shorter_collection() {
+        let arr = [1, 2, 3];
+        let p = arr.padded_to(6, 9);
+        assert_eq!(p.count(), 6);
+        assert_eq!(p.to_vec(), [1, 2, 3, 9, 9, 9]);
+        assert_eq!(*p.at(&p.next_n(p.start(), 4)), 9);
+        assert_eq!(*p.at(&p.prior_n(p.end(), 4)), 3);
+        assert_eq!(p.reversed().to_vec(), [9, 9, 9, 3, 2, 1]);
+
+        let empty: Vec<i32> = vec![];
+        assert_eq!(empty.padded_to(2, 7).to_vec(), [7, 7]);
+        assert!([1, 2].padded_to(0, 0).equals(&[1, 2]));
+    }
+
+    #[test]
+    fn padding_aligns_sequences_for_zip() {
+        let a = [1, 2, 3, 4];
+        let b = [10, 20];
+        let sums: Vec<i32> = a
+            .iter()
+            .zip(b.padded_to(a.count(), 0).iter())
+            .map(|(x, y)| x + *y)
+            .collect();
+        assert_eq!(sums, [11, 22, 3, 4]);
+    }
+
+    #[test]
+    fn padded_linked_list() {
+        let list: LinkedList<i32> = [5, 6].into();
+        let p = (&list).padded_to(4, 0);
+        assert_eq!(p.to_vec(), [5, 6, 0, 0]);
+        assert_eq!(p.distance(p.start(), p.end()), 4);
+    }
+
+    collection_conformance_tests!(
+        random_access,
+        repeat_conformance,
+        repeat(4, 3),
+        [4, 4, 4]
+    );
+    collection_conformance_tests!(
+        random_access,
+        padded_conformance,
+        [1, 2].padded_to(4, 0),
+        [1, 2, 0, 0]
+    );
+    collection_conformance_tests!(
+        bidirectional,
+        padded_list_conformance,
+        LinkedList::from([1, 2]).padded_to(3, 0),
+        [1, 2, 0]
+    );
+}
@@ -155,4 +155,206 @@ pub mod tests {
         assert_eq!(evens, []);
         assert_eq!(odds, []);
     }
+
+    #[test]
+    fn parallel_partitioned() {
+        let arr: Vec<u32> = (0..100_000).collect();
+        let pred = |x: &u32| (x * 7919) % 3 == 1;
+        assert_eq!(arr.parallel_partitioned(pred), arr.partitioned(pred));
+
+        let (small, big) = arr.parallel_partitioned(|x| *x >= 10);
+        assert_eq!(small, (0..10).collect::<Vec<_>>());
+        assert_eq!(big.len(), 99_990);
+    }
+
+    #[test]
+    fn parallel_partitioned_drops_clones_on_panic() {
+        use std::sync::Arc;
+        let arr: Vec<Arc<u32>> = (0..10_000_u32).lazy_map(Arc::new).to_vec();
+        let res = std::panic::catch_unwind(|| {
+            arr.parallel_partitioned(|x| {
+                assert!(**x != 9_000);
+                **x % 2 == 0
+            })
+        });
+        assert!(res.is_err());
+        assert!(arr.iter().all(|x| Arc::strong_count(x) == 1));
+    }
+
+    #[test]
+    fn parallel_partitioned_small_collections() {
+        let empty: [i32; 0] = [];
+        let (f, s) = empty.parallel_partitioned(|x| x % 2 == 0);
+        assert!(f.is_empty() && s.is_empty());
+
+        let words =
+            vec![String::from("a"), String::from("bb"), String::from("c")];
+        let (short, long) = words.parallel_partitioned(|w| w.len() > 1);
+        assert_eq!(short, vec!["a", "c"]);
+        assert_eq!(long, vec!["bb"]);
+    }
+
+    fn check_three_way_partition(mut arr: Vec<i32>, pivot: usize) {
+        let v = arr[pivot];
+        let mut expected = arr.clone();
+        expected.sort();
+        let (p, q) = arr.partition_around_pivot(pivot);
+        assert!(p < q);
+        assert!(arr[..p].iter().all(|x| *x < v));
+        assert!(arr[p..q].iter().all(|x| *x == v));
+        assert!(arr[q..].iter().all(|x| *x > v));
+        arr.sort();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn partition_around_pivot() {
+        let mut seed = 17_u64;
+        for n in 1..60 {
+            for range in [1, 2, 3, 10, 1000] {
+                let arr: Vec<i32> = Iterator::map(0..n, |_| {
+                    seed = seed
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    ((seed >> 33) % range) as i32
+                })
+                .collect();
+                for pivot in [0, n / 2, n - 1] {
+                    check_three_way_partition(arr.clone(), pivot);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn partition_around_pivot_by() {
+        let mut arr = [(1, 'a'), (3, 'b'), (1, 'c'), (0, 'd'), (2, 'e')];
+        let (p, q) = arr.partition_around_pivot_by(2, |x, y| x.0 < y.0);
+        assert_eq!((p, q), (1, 3));
+        assert_eq!(arr[0], (0, 'd'));
+        assert!(arr[1..3].iter().all(|x| x.0 == 1));
+        assert!(arr[3..].iter().all(|x| x.0 > 1));
+    }
+
+    #[test]
+    fn partition_around_pivot_of_slice() {
+        let mut arr = [9, 4, 4, 1, 4, 0];
+        let (p, q) = arr.slice_mut(1, 5).partition_around_pivot(2);
+        assert_eq!((p, q), (2, 5));
+        assert_eq!(arr, [9, 1, 4, 4, 4, 0]);
+    }
+
+    #[test]
+    fn partition_positions() {
+        let arr = [1, 2, 3, 4, 5];
+        let (evens, odds) = arr.partition_positions(|x| x % 2 == 1);
+        assert_eq!(evens, [1, 3]);
+        assert_eq!(odds, [0, 2, 4]);
+
+        let arr: [i32; 0] = [];
+        let (evens, odds) = arr.partition_positions(|x| x % 2 == 1);
+        assert!(evens.is_empty());
+        assert!(odds.is_empty());
+    }
+
+    #[test]
+    fn partition_positions_of_non_clone_elements() {
+        struct Job {
+            done: bool,
+        }
+        let jobs = vec![
+            Job { done: true },
+            Job { done: false },
+            Job { done: false },
+            Job { done: true },
+        ];
+        let (pending, done) = jobs.partition_positions(|job| job.done);
+        assert_eq!(pending, [1, 2]);
+        assert_eq!(done, [0, 3]);
+        assert!(pending.iter().all(|i| !jobs[*i].done));
+    }
+
+    #[test]
+    fn partition_positions_of_linked_list() {
+        let list: std::collections::LinkedList<i32> = (1..=5).collect();
+        let (small, large) = list.partition_positions(|x| *x > 3);
+        assert_eq!(small.len(), 3);
+        assert_eq!(large.len(), 2);
+        assert_eq!(*list.at(&large[0]), 4);
+    }
+
+    #[test]
+    fn partition_views_mut() {
+        let mut arr = [1, 2, 3, 4, 5, 6, 7];
+        let (evens, odds) = arr.partition_views_mut(|x| x % 2 == 1);
+        assert_eq!(evens.count(), 3);
+        assert_eq!(odds.count(), 4);
+        assert!(evens.all_satisfy(|x| x % 2 == 0));
+        assert!(odds.all_satisfy(|x| x % 2 == 1));
+        assert_eq!(evens.end(), odds.start());
+
+        let (mut evens, mut odds) = arr.partition_views_mut(|x| x % 2 == 1);
+        evens.for_each_mut(|x| *x = 0);
+        odds.sort_unstable();
+        assert_eq!(arr, [0, 0, 0, 1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn partition_views_mut_when_a_part_is_empty() {
+        let mut arr = [1, 3, 5];
+        let (evens, odds) = arr.partition_views_mut(|x| x % 2 == 1);
+        assert!(evens.is_empty());
+        assert_eq!(odds.count(), 3);
+
+        let mut arr = [2, 4];
+        let (evens, odds) = arr.partition_views_mut(|x| x % 2 == 1);
+        assert_eq!(evens.count(), 2);
+        assert!(odds.is_empty());
+
+        let mut arr: [i32; 0] = [];
+        let (evens, odds) = arr.partition_views_mut(|x| x % 2 == 1);
+        assert!(evens.is_empty() && odds.is_empty());
+    }
+
+    #[test]
+    fn is_partitioned() {
+        let arr = [1, 3, 5, 2, 4];
+        assert!(arr.is_partitioned(|x| x % 2 == 0));
+        assert!(!arr.is_partitioned(|x| x % 2 == 1));
+        assert!(arr.is_partitioned(|x| *x > 10));
+        assert!(arr.is_partitioned(|x| *x > 0));
+
+        let arr: [i32; 0] = [];
+        assert!(arr.is_partitioned(|x| x % 2 == 0));
+
+        let list: std::collections::LinkedList<i32> = [2, 1, 4].into();
+        assert!(!list.is_partitioned(|x| x % 2 == 0));
+    }
+
+    #[test]
+    fn partition_point_hinted_agrees_with_partition_point() {
+        let arr: Vec<usize> = (0..100).collect();
+        for k in 0..=100 {
+            for hint in 0..=100 {
+                assert_eq!(arr.partition_point_hinted(hint, |x| *x >= k), k);
+            }
+        }
+
+        let list: std::collections::LinkedList<usize> = (0..20).collect();
+        let hint = list.next_n(list.start(), 3);
+        let p = list.partition_point_hinted(hint, |x| *x >= 11);
+        assert_eq!(*list.at(&p), 11);
+    }
+
+    #[test]
+    fn partition_point_hinted_probes_near_hint() {
+        let arr: Vec<usize> = (0..1_000_000).collect();
+        let mut calls = 0;
+        let p = arr.partition_point_hinted(500_000, |x| {
+            calls += 1;
+            *x >= 500_010
+        });
+        assert_eq!(p, 500_010);
+        assert!(calls <= 10);
+    }
 }
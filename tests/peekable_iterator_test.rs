@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::iterators::{IntoPeekableIterator, PeekableIterator};
+    use stl::*;
+
+    /// Parses a non-negative number from start of `it`.
+    fn parse_number<I>(it: &mut I) -> Option<u32>
+    where
+        I: PeekableIterator<Item = char>,
+    {
+        let digits: String =
+            it.take_while_peeking(|c| c.is_ascii_digit()).collect();
+        digits.parse().ok()
+    }
+
+    /// Evaluates sum and differences of non-negative numbers.
+    fn eval<I>(mut it: I) -> Option<u32>
+    where
+        I: PeekableIterator<Item = char>,
+    {
+        let mut res = parse_number(&mut it)?;
+        while let Some(op) = it.next_if(|c| *c == '+' || *c == '-') {
+            let rhs = parse_number(&mut it)?;
+            res = if op == '+' { res + rhs } else { res - rhs };
+        }
+        it.peek().is_none().then_some(res)
+    }
+
+    #[test]
+    fn parser_over_different_iterators() {
+        assert_eq!(eval("12+30-2".chars()), Some(40));
+        assert_eq!(eval("12+30-2".chars().into_peekable_iter()), Some(40));
+        assert_eq!(eval("12+".chars()), None);
+        assert_eq!(eval("12*2".chars()), None);
+
+        let chars = (0usize..3).lazy_map(|i| ['7', '+', '3'][i]);
+        assert_eq!(eval(chars.lazy_iter()), Some(10));
+    }
+
+    #[test]
+    fn collection_iter() {
+        let arr = [1, 2, 3];
+        let mut it = arr.iter();
+        assert_eq!(**it.peek().unwrap(), 1);
+        assert_eq!(**it.peek().unwrap(), 1);
+        assert_eq!(it.next_if(|x| **x == 1), Some(&1));
+        assert_eq!(it.next_if(|x| **x == 1), None);
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next(), Some(&3));
+        assert!(it.peek().is_none());
+        assert_eq!(it.next_if(|_| true), None);
+    }
+
+    #[test]
+    fn lazy_collection_iter() {
+        let c = (1..10).lazy_map(|x| x * x);
+        let mut it = c.lazy_iter();
+        let small: Vec<_> = it.take_while_peeking(|x| *x < 10).collect();
+        assert_eq!(small, [1, 4, 9]);
+        assert_eq!(*it.peek().unwrap(), 16);
+        assert_eq!(it.next(), Some(16));
+    }
+
+    #[test]
+    fn take_while_peeking_does_not_consume_rejected_item() {
+        let mut it = [1, 2, 5, 3].into_peekable_iter();
+        let mut prefix = it.take_while_peeking(|x| *x < 4);
+        assert_eq!(prefix.next(), Some(1));
+        assert_eq!(prefix.next(), Some(2));
+        assert_eq!(prefix.next(), None);
+        assert_eq!(prefix.next(), None);
+        assert_eq!(it.next(), Some(5));
+        assert_eq!(it.next(), Some(3));
+    }
+
+    #[test]
+    fn std_slice_iter_and_char_indices() {
+        let v = [1, 2];
+        let mut it = std::slice::Iter::clone(&v.as_slice().iter());
+        assert_eq!(**it.peek().unwrap(), 1);
+        assert_eq!(it.next(), Some(&1));
+
+        let mut it = "ab".char_indices();
+        assert_eq!(*it.peek().unwrap(), (0, 'a'));
+        assert_eq!(it.next_if(|(_, c)| *c == 'a'), Some((0, 'a')));
+        assert_eq!(*it.peek().unwrap(), (1, 'b'));
+    }
+}
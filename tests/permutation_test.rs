@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn next_permutation() {
+        let mut arr = [1, 2, 3];
+        let mut perms = vec![arr.to_vec()];
+        while arr.next_permutation() {
+            perms.push(arr.to_vec());
+        }
+        assert_eq!(
+            perms,
+            vec![
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1],
+            ]
+        );
+        assert_eq!(arr, [1, 2, 3]);
+    }
+
+    #[test]
+    fn next_permutation_with_duplicates() {
+        let mut arr = [1, 1, 2];
+        let mut count = 1;
+        while arr.next_permutation() {
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        assert_eq!(arr, [1, 1, 2]);
+    }
+
+    #[test]
+    fn next_permutation_of_trivial_collections() {
+        let mut arr: [i32; 0] = [];
+        assert!(!arr.next_permutation());
+
+        let mut arr = [1];
+        assert!(!arr.next_permutation());
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn prev_permutation() {
+        let mut arr = [3, 2, 1];
+        let mut count = 1;
+        while arr.prev_permutation() {
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        assert_eq!(arr, [3, 2, 1]);
+    }
+
+    #[test]
+    fn permutation_of_slice() {
+        let mut arr = [4, 1, 3, 2, 0];
+        assert!(arr.full_mut().dropping_suffix_mut(1).next_permutation());
+        assert_eq!(arr, [4, 2, 1, 3, 0]);
+        assert!(arr.prefix_mut(4).prev_permutation());
+        assert_eq!(arr, [4, 1, 3, 2, 0]);
+    }
+}
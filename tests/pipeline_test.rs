@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn map_filter() {
+        let arr = [1, 2, 3, 4, 5];
+        let v = pipeline(&arr).map(|x| x * 10).filter(|x| *x > 20).to_vec();
+        assert_eq!(v, vec![30, 40, 50]);
+        assert_eq!(pipeline(&arr).filter(|x| **x % 2 == 1).count(), 3);
+    }
+
+    #[test]
+    fn take_stops_traversal() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        let mut visited = 0;
+        let v = pipeline(&arr)
+            .map(|x| {
+                visited += 1;
+                *x
+            })
+            .filter(|x| x % 2 == 0)
+            .take(2)
+            .to_vec();
+        assert_eq!(v, vec![2, 4]);
+        assert_eq!(visited, 4);
+    }
+
+    #[test]
+    fn take_before_filter() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        let v = pipeline(&arr).take(3).filter(|x| **x % 2 == 1).to_vec();
+        assert_eq!(v, vec![&1, &3]);
+        assert_eq!(pipeline(&arr).take(0).count(), 0);
+    }
+
+    #[test]
+    fn fold_and_for_each() {
+        let arr = ["a", "bb", "ccc"];
+        let total = pipeline(&arr).map(|s| s.len()).fold(0, |x, y| x + y);
+        assert_eq!(total, 6);
+        let mut res = String::new();
+        pipeline(&arr).for_each(|s| res.push_str(s));
+        assert_eq!(res, "abbccc");
+    }
+
+    #[test]
+    fn lazy_and_sliced_sources() {
+        let v = pipeline(&(1..10_i32)).map(|x| *x).filter(|x| x % 3 == 0);
+        assert_eq!(v.to_vec(), vec![3, 6, 9]);
+        let arr = [1, 2, 3, 4];
+        assert_eq!(pipeline(&arr.suffix(2)).map(|x| *x).to_vec(), vec![3, 4]);
+    }
+
+    #[test]
+    fn parallel_fold() {
+        let v: Vec<u64> = (1..=10000).collect();
+        let sum = pipeline(&v)
+            .filter(|x| **x % 2 == 0)
+            .map(|x| *x)
+            .parallel_fold(0, |x, y| x + y, |x, y| x + y);
+        assert_eq!(sum, 25005000);
+        let concat = pipeline(&v).map(|x| vec![*x]).parallel_fold(
+            vec![],
+            |mut x, y| {
+                x.extend(y);
+                x
+            },
+            |mut x, y| {
+                x.extend(y);
+                x
+            },
+        );
+        assert_eq!(concat, v);
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use stl::*;
+
+    fn sum<C>(c: C) -> i32
+    where
+        C: Collection<Element = i32>,
+    {
+        c.fold_left(0, |acc, x| acc + x)
+    }
+
+    #[test]
+    fn reference() {
+        let v = vec![1, 2, 3, 4];
+        let r = &v;
+        assert_eq!(sum(r), 10);
+        assert_eq!(r.count(), 4);
+        assert_eq!(r.first_position_of(&3), Some(2));
+        assert_eq!(r.last_position_of(&1), Some(0));
+    }
+
+    #[test]
+    fn boxed() {
+        let b = Box::new(vec![1, 2, 3, 4]);
+        assert_eq!(sum(&b), 10);
+        assert!(b.slice(1, 3).equals(&[2, 3]));
+        assert_eq!(b.prior(b.end()), 3);
+    }
+
+    #[test]
+    fn rc() {
+        let rc = Rc::new([4, 3, 2, 1]);
+        let other = rc.clone();
+        assert_eq!(sum(other), 10);
+        assert_eq!(rc.partition_point(|x| *x < 3), 2);
+        assert!(rc.reversed().equals(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn arc_across_threads() {
+        let arc = Arc::new((1..=100).collect::<Vec<i32>>());
+        let handles: Vec<_> = Iterator::map(0..4, |i| {
+            let arc = arc.clone();
+            std::thread::spawn(move || arc.count_where(|x| x % 4 == i))
+        })
+        .collect();
+        let total: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(total, 100);
+    }
+
+    #[test]
+    fn lazy_pointee() {
+        let s: Box<String> = Box::new(String::from("héllo"));
+        assert_eq!(s.count(), 5);
+        assert_eq!(s.compute_at(&1), 'é');
+        assert_eq!(s.last_position_of(&'o'), Some(5));
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn normalize() {
+        let mut ranges = vec![(4, 6), (0, 2), (1, 3), (3, 3), (8, 9), (6, 7)];
+        normalize_ranges(&mut ranges);
+        assert_eq!(ranges, vec![(0, 3), (4, 7), (8, 9)]);
+
+        let mut ranges: Vec<(usize, usize)> = vec![(2, 2)];
+        normalize_ranges(&mut ranges);
+        assert_eq!(ranges, vec![]);
+    }
+
+    #[test]
+    fn union() {
+        let a = [(0, 2), (5, 7)];
+        let b = [(1, 3), (3, 4), (10, 11)];
+        assert_eq!(union_of_ranges(&a, &b), vec![(0, 4), (5, 7), (10, 11)]);
+        let empty: [(i32, i32); 0] = [];
+        assert_eq!(union_of_ranges(&a, &empty), vec![(0, 2), (5, 7)]);
+    }
+
+    #[test]
+    fn intersection() {
+        let a = [(0, 4), (6, 10)];
+        let b = [(2, 7), (8, 9), (12, 13)];
+        assert_eq!(
+            intersection_of_ranges(&a, &b),
+            vec![(2, 4), (6, 7), (8, 9)]
+        );
+        let c = [(4, 6)];
+        assert_eq!(intersection_of_ranges(&a, &c), vec![]);
+    }
+
+    #[test]
+    fn complement() {
+        let ranges = [(0, 2), (4, 5)];
+        assert_eq!(complement_of_ranges(&ranges, 0, 6), vec![(2, 4), (5, 6)]);
+        assert_eq!(complement_of_ranges(&ranges, 1, 3), vec![(2, 3)]);
+        let empty: [(i32, i32); 0] = [];
+        assert_eq!(complement_of_ranges(&empty, 0, 3), vec![(0, 3)]);
+    }
+
+    #[test]
+    fn combine_split_results() {
+        let arr = [1, 0, 2, 2, 0, 3];
+        let segments: Vec<_> = arr
+            .splitting_where(|x| *x == 0)
+            .map(|s| (s.start(), s.end()))
+            .collect();
+        let evens: Vec<_> = arr
+            .splitting_where(|x| x % 2 != 0)
+            .map(|s| (s.start(), s.end()))
+            .collect();
+        assert_eq!(
+            complement_of_ranges(&segments, arr.start(), arr.end()),
+            vec![(1, 2), (4, 5)]
+        );
+        let mut evens = evens;
+        normalize_ranges(&mut evens);
+        assert_eq!(intersection_of_ranges(&segments, &evens), vec![(2, 4)]);
+    }
+}
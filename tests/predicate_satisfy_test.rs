@@ -76,4 +76,38 @@ pub mod tests {
         let arr = [];
         assert!(arr.parallel_none_satisfy(|x| x % 2 == 1));
     }
+
+    #[test]
+    fn parallel_satisfy_exits_early() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let hardware_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let arr: Vec<usize> = (0..100000).collect();
+
+        let calls = AtomicUsize::new(0);
+        assert!(arr.parallel_any_satisfy(|_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            true
+        }));
+        assert!(calls.load(Ordering::Relaxed) <= hardware_concurrency);
+
+        let calls = AtomicUsize::new(0);
+        assert!(!arr.parallel_all_satisfy(|_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            false
+        }));
+        assert!(calls.load(Ordering::Relaxed) <= hardware_concurrency);
+
+        let calls = AtomicUsize::new(0);
+        assert!(!arr.parallel_none_satisfy(|_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            true
+        }));
+        assert!(calls.load(Ordering::Relaxed) <= hardware_concurrency);
+
+        assert!(arr.parallel_any_satisfy(|x| *x == 99999));
+        assert!(arr.parallel_all_satisfy(|x| *x < 100000));
+        assert!(arr.parallel_none_satisfy(|x| *x >= 100000));
+    }
 }
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(all(test, feature = "progress"))]
+pub mod tests {
+    use stl::*;
+
+    fn assert_valid_reports(reports: &[f32]) {
+        assert!(!reports.is_empty() && reports.len() <= 101);
+        assert!(reports.windows(2).all(|w| w[0] < w[1]));
+        assert!(reports.iter().all(|f| (0.0..=1.0).contains(f)));
+        assert_eq!(*reports.last().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn sort_unstable_with_progress() {
+        let mut v: Vec<i32> =
+            (0..10000).lazy_map(|x| (x * 7919) % 10000).to_vec();
+        let mut reports = Vec::new();
+        v.sort_unstable_with_progress(|f| reports.push(f));
+        assert!(v.equals(&(0..10000)));
+        assert_valid_reports(&reports);
+        assert!(reports.len() > 50);
+
+        let mut arr: [i32; 0] = [];
+        let mut reports = Vec::new();
+        arr.sort_unstable_with_progress(|f| reports.push(f));
+        assert_eq!(reports, [1.0]);
+    }
+
+    #[test]
+    fn sort_unstable_by_with_progress() {
+        let mut v: Vec<i32> = (0..1000).to_vec();
+        let mut reports = Vec::new();
+        v.sort_unstable_by_with_progress(|x, y| x > y, |f| reports.push(f));
+        assert!(v.equals(&(0..1000).reversed()));
+        assert_valid_reports(&reports);
+    }
+
+    #[test]
+    fn stable_partition_with_progress() {
+        let mut v: Vec<i32> = (0..1000).to_vec();
+        let mut reports = Vec::new();
+        let p = v.stable_partition_with_progress(
+            |x| x % 2 == 1,
+            |f| reports.push(f),
+        );
+        assert_eq!(p, 500);
+        assert!(v.prefix_upto(p).equals(&(0..500).lazy_map(|x| 2 * x)));
+        assert_valid_reports(&reports);
+    }
+}
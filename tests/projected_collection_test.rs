@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Task {
+        name: &'static str,
+        priority: u32,
+    }
+
+    fn tasks() -> Vec<Task> {
+        vec![
+            Task {
+                name: "a",
+                priority: 3,
+            },
+            Task {
+                name: "b",
+                priority: 1,
+            },
+            Task {
+                name: "c",
+                priority: 2,
+            },
+            Task {
+                name: "d",
+                priority: 1,
+            },
+        ]
+    }
+
+    fn names(tasks: &[Task]) -> String {
+        tasks.iter().map(|t| t.name).collect()
+    }
+
+    #[test]
+    fn projection_access() {
+        let tasks = tasks();
+        let priorities = tasks.full().project(|t| &t.priority);
+        assert!(priorities.equals(&[3, 1, 2, 1]));
+        assert_eq!(priorities.count(), 4);
+        assert_eq!(*priorities.at(&0), 3);
+        assert!(priorities.reversed().equals(&[1, 2, 1, 3]));
+    }
+
+    #[test]
+    fn sorting_by_projection_moves_elements() {
+        let mut tasks = tasks();
+        tasks.full_mut().project(|t| &t.priority).sort_unstable();
+        assert!(tasks.full().project(|t| &t.priority).equals(&[1, 1, 2, 3]));
+        assert_eq!(
+            tasks[3],
+            Task {
+                name: "a",
+                priority: 3
+            }
+        );
+    }
+
+    #[test]
+    fn partitioning_by_projection_moves_elements() {
+        let mut tasks = tasks();
+        let p = tasks
+            .full_mut()
+            .project(|t| &t.priority)
+            .stable_partition(|p| *p > 1);
+        assert_eq!(p, 2);
+        assert_eq!(names(&tasks), "bdac");
+    }
+
+    #[test]
+    fn mutable_projection_mutates_fields() {
+        let mut tasks = tasks();
+        let mut priorities = tasks
+            .full_mut()
+            .project_mut(|t| &t.priority, |t| &mut t.priority);
+        *priorities.at_mut(&0) = 7;
+        priorities.swap_at(&1, &3);
+        priorities.swap_at(&2, &2);
+        assert!(priorities.equals(&[7, 1, 2, 1]));
+        priorities.stable_sort(AllocPolicy::Unlimited);
+        assert!(priorities.equals(&[1, 1, 2, 7]));
+        assert_eq!(names(&tasks), "abcd");
+        assert!(tasks.full().project(|t| &t.priority).equals(&[1, 1, 2, 7]));
+    }
+
+    #[test]
+    fn mutable_projection_into_base() {
+        let mut projected =
+            tasks().project_mut(|t| &t.priority, |t| &mut t.priority);
+        projected.fill(0);
+        projected.swap_at(&3, &0);
+        let tasks = projected.into_base();
+        assert_eq!(names(&tasks), "abcd");
+        assert!(tasks.iter().all(|t| t.priority == 0));
+    }
+}
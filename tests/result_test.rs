@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    fn parse(s: &str) -> Result<i32, String> {
+        s.parse::<i32>().map_err(|_| format!("invalid: {s}"))
+    }
+
+    #[test]
+    fn first_error_position() {
+        let arr = ["1", "2", "x", "y"].map(parse);
+        assert_eq!(arr.first_error_position(), Some(2));
+
+        let arr = ["1", "2"].map(parse);
+        assert_eq!(arr.first_error_position(), None);
+
+        let arr: [Result<i32, String>; 0] = [];
+        assert_eq!(arr.first_error_position(), None);
+    }
+
+    #[test]
+    fn all_ok() {
+        assert!(["1", "2"].map(parse).all_ok());
+        assert!(!["1", "x"].map(parse).all_ok());
+        let arr: [Result<i32, String>; 0] = [];
+        assert!(arr.all_ok());
+    }
+
+    #[test]
+    fn collect_ok() {
+        assert_eq!(["1", "2", "3"].map(parse).collect_ok(), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            ["1", "x", "y"].map(parse).collect_ok(),
+            Err(String::from("invalid: x"))
+        );
+        let arr: [Result<i32, String>; 0] = [];
+        assert_eq!(arr.collect_ok(), Ok(vec![]));
+    }
+
+    #[test]
+    fn partitioned_results() {
+        let (values, errors) =
+            ["1", "x", "3", "y"].map(parse).partitioned_results();
+        assert_eq!(values, [1, 3]);
+        assert_eq!(errors, ["invalid: x", "invalid: y"]);
+
+        let (values, errors) = ["1", "2"].map(parse).partitioned_results();
+        assert_eq!(values, [1, 2]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn results_of_lazy_collection() {
+        let checked =
+            (1..=10).lazy_map(|x| if x % 4 == 0 { Err(x) } else { Ok(x * x) });
+        assert_eq!(checked.first_error_position(), Some(4));
+        assert!(!checked.all_ok());
+        assert_eq!(checked.collect_ok(), Err(4));
+        let (values, errors) = checked.partitioned_results();
+        assert_eq!(values, [1, 4, 9, 25, 36, 49, 81, 100]);
+        assert_eq!(errors, [4, 8]);
+    }
+}
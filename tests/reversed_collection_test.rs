@@ -50,4 +50,54 @@ pub mod tests {
         *arr.at_mut(&i) = 3;
         assert!(arr.equals(&[3, 4, 3, 2, 1]));
     }
+
+    #[test]
+    fn lazy_computation() {
+        let arr = (1..5).lazy_map(|x| x * 10).reversed();
+        assert_eq!(arr.lazy_first(), Some(40));
+        assert_eq!(arr.lazy_last(), Some(10));
+        assert_eq!(arr.lazy_fold_left(0, |acc, x| acc * 2 + x / 10), 49);
+    }
+
+    #[test]
+    fn sorting_reversed_view() {
+        let mut v = vec![3, 1, 5, 2, 4];
+        v.full_mut().reversed().sort_unstable();
+        assert_eq!(v, [5, 4, 3, 2, 1]);
+
+        let mut v = vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+        v.full_mut()
+            .reversed()
+            .stable_sort_by(AllocPolicy::Unlimited, |x, y| x.0 < y.0);
+        assert_eq!(v, [(1, 'a'), (1, 'c'), (0, 'b'), (0, 'd')]);
+    }
+
+    #[test]
+    fn reordering_algorithms_on_reversed_view() {
+        let mut v = vec![1, 2, 3, 4, 5, 6];
+        let mut r = v.full_mut().reversed();
+        let p = r.partition(|x| x % 2 == 0);
+        assert_eq!(r.distance(r.start(), p), 3);
+        assert!(v[..3].iter().all(|x| x % 2 == 0));
+
+        let mut v = vec![1, 2, 3, 4];
+        let mut r = v.full_mut().reversed();
+        let mid = r.next(r.start());
+        r.rotate(mid);
+        assert_eq!(v, [4, 1, 2, 3]);
+    }
+
+    #[test]
+    fn comparison_and_hashing_by_elements() {
+        use std::collections::HashSet;
+
+        assert!([1, 2, 3].reversed() == [1, 2, 3].reversed());
+        assert!([1, 2, 3].reversed() != [3, 2, 1].reversed());
+        assert!([1, 2, 3].reversed() > [3, 2, 1].reversed());
+        assert!((1..4).reversed() > (2..4).reversed());
+        let set: HashSet<_> = [vec![1, 2], vec![2, 1], vec![1, 2]]
+            .map(|v| v.reversed())
+            .into();
+        assert_eq!(set.len(), 2);
+    }
 }
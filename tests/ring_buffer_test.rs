@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::collections::RingBuffer;
+    use stl::*;
+
+    /// Returns ring buffer of capacity 5 holding `elements`, whose storage
+    /// wraps around after first 2 elements.
+    fn wrapped(elements: &[i32]) -> RingBuffer<i32, 5> {
+        let mut rb = RingBuffer::new();
+        for _ in 0..3 {
+            rb.push_back(0).unwrap();
+        }
+        for _ in 0..3 {
+            rb.pop_front();
+        }
+        for e in elements {
+            rb.push_back(*e).unwrap();
+        }
+        rb
+    }
+
+    fn elements<const N: usize>(rb: &RingBuffer<i32, N>) -> Vec<i32> {
+        rb.iter().copied().collect()
+    }
+
+    #[test]
+    fn push_and_pop_at_both_ends() {
+        let mut rb: RingBuffer<i32, 3> = RingBuffer::new();
+        assert!(rb.is_empty());
+        assert_eq!(rb.capacity(), 3);
+        assert_eq!(rb.push_back(2), Ok(()));
+        assert_eq!(rb.push_front(1), Ok(()));
+        assert_eq!(rb.push_back(3), Ok(()));
+        assert!(rb.is_full());
+        assert_eq!(rb.push_back(4), Err(4));
+        assert_eq!(rb.push_front(0), Err(0));
+        assert_eq!(elements(&rb), [1, 2, 3]);
+
+        assert_eq!(rb.pop_front(), Some(1));
+        assert_eq!(rb.pop_back(), Some(3));
+        assert_eq!(rb.pop_back(), Some(2));
+        assert_eq!(rb.pop_back(), None);
+        assert_eq!(rb.pop_front(), None);
+    }
+
+    #[test]
+    fn push_back_overwriting_drops_front() {
+        let mut rb: RingBuffer<i32, 3> = RingBuffer::default();
+        for i in 0..3 {
+            assert_eq!(rb.push_back_overwriting(i), None);
+        }
+        assert_eq!(rb.push_back_overwriting(3), Some(0));
+        assert_eq!(rb.push_back_overwriting(4), Some(1));
+        assert_eq!(elements(&rb), [2, 3, 4]);
+
+        rb.clear();
+        assert!(rb.is_empty());
+        rb.push_back(7).unwrap();
+        assert_eq!(elements(&rb), [7]);
+    }
+
+    #[test]
+    fn positions_are_ordered_across_wrap_around() {
+        let rb = wrapped(&[1, 2, 3, 4]);
+        let mut positions = vec![];
+        let mut p = rb.start();
+        while p != rb.end() {
+            positions.push(p.clone());
+            rb.form_next(&mut p);
+        }
+        positions.push(p);
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(rb.distance(rb.start(), rb.end()), 4);
+        assert_eq!(*rb.at(&rb.next_n(rb.start(), 3)), 4);
+        assert_eq!(*rb.at(&rb.prior_n(rb.end(), 4)), 1);
+    }
+
+    #[test]
+    fn algorithms_on_wrapped_ring_buffer() {
+        let mut rb = wrapped(&[4, 1, 5, 2, 3]);
+        assert!(rb.is_full());
+        rb.sort_unstable();
+        assert_eq!(elements(&rb), [1, 2, 3, 4, 5]);
+
+        rb.reverse();
+        assert_eq!(elements(&rb), [5, 4, 3, 2, 1]);
+
+        rb.for_each_mut(|x| *x *= 10);
+        assert_eq!(elements(&rb), [50, 40, 30, 20, 10]);
+
+        let p = rb.partition(|x| *x > 25);
+        assert_eq!(rb.distance(rb.start(), p), 2);
+    }
+
+    #[test]
+    fn zero_capacity_ring_buffer() {
+        let mut rb: RingBuffer<i32, 0> = RingBuffer::new();
+        assert!(rb.is_full() && rb.is_empty());
+        assert_eq!(rb.push_back(1), Err(1));
+        assert_eq!(rb.push_front(1), Err(1));
+        assert!(rb.start() == rb.end());
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn rle_encode() {
+        let arr = [1, 1, 2, 3, 3, 3, 1];
+        let rle: Vec<_> = arr.rle_encode().collect();
+        assert_eq!(rle, vec![(1, 2), (2, 1), (3, 3), (1, 1)]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.rle_encode().count(), 0);
+
+        let s = String::from("aaabccdd");
+        let rle: Vec<_> = s.rle_encode().collect();
+        assert_eq!(rle, vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)]);
+    }
+
+    #[test]
+    fn rle_decode_into() {
+        let rle = [(1, 2), (2, 0), (3, 3)];
+        let mut dest = [0; 5];
+        let i = rle.rle_decode_into(&mut dest);
+        assert_eq!(i, 5);
+        assert_eq!(dest, [1, 1, 3, 3, 3]);
+
+        let rle: [(i32, usize); 0] = [];
+        let mut dest = [7; 2];
+        assert_eq!(rle.rle_decode_into(&mut dest), 0);
+        assert_eq!(dest, [7, 7]);
+    }
+
+    #[test]
+    fn rle_roundtrip() {
+        let arr: Vec<u8> =
+            Iterator::map(0..1000, |i| (i / 7 % 3) as u8).collect();
+        let rle: Vec<_> = arr.rle_encode().collect();
+        assert_eq!(rle.iter().map(|x| x.1).sum::<usize>(), arr.len());
+        let mut decoded = vec![0; arr.len()];
+        let i = rle.rle_decode_into(&mut decoded);
+        assert_eq!(i, arr.len());
+        assert_eq!(decoded, arr);
+    }
+}
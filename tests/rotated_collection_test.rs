@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn test_the_equality() {
+        assert!([1, 2, 3, 4, 5].rotated(2).equals(&[3, 4, 5, 1, 2]));
+        assert!([1, 2, 3].rotated(0).equals(&[1, 2, 3]));
+        assert!([1, 2, 3].rotated(3).equals(&[1, 2, 3]));
+        assert!([1].rotated(0).equals(&[1]));
+        let arr: [i32; 0] = [];
+        assert!(arr.rotated(0).equals(&[]));
+    }
+
+    #[test]
+    fn count_and_distance() {
+        let arr = [1, 2, 3, 4, 5].rotated(3);
+        assert_eq!(arr.count(), 5);
+        assert_eq!(arr.distance(arr.start(), arr.end()), 5);
+        let i = arr.next_n(arr.start(), 3);
+        assert_eq!(*arr.at(&i), 2);
+        assert_eq!(arr.distance(i.clone(), arr.end()), 2);
+        assert_eq!(*arr.at(&arr.prior_n(i, 2)), 5);
+    }
+
+    #[test]
+    fn slicing() {
+        let arr = [1, 2, 3, 4, 5].rotated(2);
+        assert!(arr.prefix(4).equals(&[3, 4, 5, 1]));
+        assert!(arr.suffix(4).equals(&[4, 5, 1, 2]));
+        assert!(arr.dropping_prefix(7).equals(&[]));
+    }
+
+    #[test]
+    fn backward_iteration() {
+        let arr = [1, 2, 3, 4, 5].rotated(2);
+        let v: Vec<_> = arr.iter().rev().copied().collect();
+        assert_eq!(v, vec![2, 1, 5, 4, 3]);
+    }
+
+    #[test]
+    fn sorting_rotated_view() {
+        let mut arr = [5, 1, 4, 2, 3].rotated(1);
+        arr.sort_unstable();
+        assert!(arr.equals(&[1, 2, 3, 4, 5]));
+        assert_eq!(arr.base, [5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rotate_copy_into() {
+        let arr = [1, 2, 3, 4, 5];
+        let mut dest = vec![0; 5];
+        let i = arr.rotate_copy_into(3, &mut dest);
+        assert_eq!(i, 5);
+        assert_eq!(dest, vec![4, 5, 1, 2, 3]);
+
+        let i = arr.rotate_copy_into(0, &mut dest);
+        assert_eq!(i, 5);
+        assert_eq!(dest, vec![1, 2, 3, 4, 5]);
+    }
+}
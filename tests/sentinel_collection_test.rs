@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn stops_at_sentinel() {
+        let s = collections::SentinelCollection::new(b"ab\0cd\0", |b| *b == 0);
+        assert!(s.equals(b"ab"));
+        assert_eq!(s.count(), 2);
+    }
+
+    #[test]
+    fn stops_at_end_of_base_without_sentinel() {
+        let s = collections::SentinelCollection::new([1, 2, 3], |x| *x < 0);
+        assert!(s.equals(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn empty_if_first_element_is_sentinel() {
+        let s = collections::SentinelCollection::new([0, 1], |x| *x == 0);
+        assert!(s.start() == s.end());
+        assert!(s.is_empty());
+
+        let empty: [i32; 0] = [];
+        let s = collections::SentinelCollection::new(empty, |x| *x == 0);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn does_not_read_past_sentinel() {
+        let max_read = std::cell::Cell::new(0);
+        let frame = [3, 4, 5, 0, 7, 8, 9];
+        let s = collections::SentinelCollection::new(frame, |x| {
+            max_read.set(max_read.get().max(*x));
+            *x == 0
+        });
+        assert!(s.equals(&[3, 4, 5]));
+        assert_eq!(max_read.get(), 5);
+    }
+
+    #[test]
+    fn find_and_prefix() {
+        let s = collections::SentinelCollection::new(
+            "key=value;rest".as_bytes(),
+            |b| *b == b';',
+        );
+        let eq = s.first_position_of(&b'=').unwrap();
+        assert!(s.prefix_upto(eq.clone()).equals(b"key"));
+        assert!(s.suffix_from(s.next(eq)).equals(b"value"));
+        assert!(s.first_position_of(&b'r').is_none());
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    fn written<F>(op: F) -> Vec<i32>
+    where
+        F: FnOnce(&mut Vec<i32>) -> usize,
+    {
+        let mut dest = vec![0; 16];
+        let i = op(&mut dest);
+        dest.truncate(i);
+        dest
+    }
+
+    #[test]
+    fn set_union() {
+        let a = [1, 3, 3, 5, 7];
+        let b = [2, 3, 5, 5, 8];
+        assert_eq!(
+            written(|d| a.set_union_into(&b, d)),
+            vec![1, 2, 3, 3, 5, 5, 7, 8]
+        );
+        assert_eq!(written(|d| a.set_union_into(&[], d)), a.to_vec());
+        assert_eq!(written(|d| [].set_union_into(&b, d)), b.to_vec());
+    }
+
+    #[test]
+    fn set_intersection() {
+        let a = [1, 3, 3, 5, 7];
+        let b = [2, 3, 5, 5, 8];
+        assert_eq!(written(|d| a.set_intersection_into(&b, d)), vec![3, 5]);
+        assert_eq!(written(|d| a.set_intersection_into(&[], d)), vec![]);
+    }
+
+    #[test]
+    fn set_difference() {
+        let a = [1, 3, 3, 5, 7];
+        let b = [2, 3, 5, 5, 8];
+        assert_eq!(written(|d| a.set_difference_into(&b, d)), vec![1, 3, 7]);
+        assert_eq!(written(|d| b.set_difference_into(&a, d)), vec![2, 5, 8]);
+    }
+
+    #[test]
+    fn set_symmetric_difference() {
+        let a = [1, 3, 3, 5, 7];
+        let b = [2, 3, 5, 5, 8];
+        assert_eq!(
+            written(|d| a.set_symmetric_difference_into(&b, d)),
+            vec![1, 2, 3, 5, 7, 8]
+        );
+    }
+
+    #[test]
+    fn set_operations_by() {
+        let a = [7, 5, 3, 1];
+        let b = [6, 5, 4];
+        assert_eq!(
+            written(|d| a.set_union_into_by(&b, d, |x, y| x > y)),
+            vec![7, 6, 5, 4, 3, 1]
+        );
+        assert_eq!(
+            written(|d| a.set_intersection_into_by(&b, d, |x, y| x > y)),
+            vec![5]
+        );
+    }
+
+    #[test]
+    fn equivalent_elements_are_taken_from_self() {
+        let a = [(1, 'a'), (2, 'a')];
+        let b = [(2, 'b'), (3, 'b')];
+        let mut dest = [(0, ' '); 3];
+        let i = a.set_union_into_by(&b, &mut dest, |x, y| x.0 < y.0);
+        assert_eq!(i, 3);
+        assert_eq!(dest, [(1, 'a'), (2, 'a'), (3, 'b')]);
+    }
+
+    #[test]
+    fn merge_join_over_lazy_collections() {
+        let evens = (0..20).lazy_map(|x| x * 2);
+        let triples = (0..20).lazy_map(|x| x * 3);
+        let mut dest = vec![0; 20];
+        let i = evens.set_intersection_into(&triples, &mut dest);
+        assert_eq!(&dest[..i], &[0, 6, 12, 18, 24, 30, 36]);
+    }
+
+    #[test]
+    fn includes() {
+        let a = [1, 2, 2, 4, 6];
+        assert!(a.includes(&[]));
+        assert!(a.includes(&a));
+        assert!(a.includes(&[1, 4, 6]));
+        assert!(!a.includes(&[2, 2, 2]));
+        assert!(!a.includes(&[0]));
+        assert!(!a.includes(&[7]));
+        assert!(!Vec::<i32>::new().includes(&[1]));
+        assert!([6, 4, 2, 1].includes_by(&[4, 1], |x, y| x > y));
+    }
+}
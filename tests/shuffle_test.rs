@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    /// Returns xorshift generator seeded with `seed`.
+    fn xorshift(mut state: u64) -> impl FnMut() -> u64 {
+        move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        }
+    }
+
+    #[test]
+    fn parallel_shuffle_is_permutation() {
+        for n in [0, 1, 2, 511, 1024, 5000, 100003] {
+            let mut arr: Vec<usize> = (0..n).collect();
+            arr.parallel_shuffle(xorshift(n as u64 + 1));
+            let mut sorted = arr.clone();
+            sorted.sort();
+            assert!(sorted.iter().copied().eq(0..n));
+        }
+    }
+
+    #[test]
+    fn parallel_shuffle_reorders() {
+        let mut arr: Vec<usize> = (0..100000).collect();
+        arr.parallel_shuffle(xorshift(3));
+        let fixed_points = arr.iter().enumerate().filter(|(i, e)| i == *e);
+        assert!(fixed_points.count() < 100);
+    }
+
+    #[test]
+    fn parallel_shuffle_of_slice() {
+        let mut arr: Vec<usize> = (0..10000).collect();
+        arr.slice_mut(100, 9900).parallel_shuffle(xorshift(11));
+        assert!(arr[..100].iter().copied().eq(0..100));
+        assert!(arr[9900..].iter().copied().eq(9900..10000));
+        let mut middle = arr[100..9900].to_vec();
+        middle.sort();
+        assert!(middle.iter().copied().eq(100..9900));
+    }
+
+    #[test]
+    fn parallel_shuffle_is_uniform() {
+        // Each element should land in each half of collection about half of
+        // the times, which fails if parts are not mixed on merging.
+        let n = 4096;
+        let trials = 200;
+        let mut rng = xorshift(5);
+        let mut in_first_half = 0;
+        for _ in 0..trials {
+            let mut arr: Vec<usize> = (0..n).collect();
+            arr.parallel_shuffle(&mut rng);
+            in_first_half += arr[..n / 2].iter().filter(|e| **e < 16).count();
+        }
+        let expected = trials * 16 / 2;
+        assert!(in_first_half.abs_diff(expected) < expected / 5);
+    }
+
+    #[test]
+    fn random_permutation() {
+        let p = stl::random_permutation(0, xorshift(1));
+        assert!(p.is_empty());
+
+        let p = stl::random_permutation(20000, xorshift(9));
+        let mut sorted = p.clone();
+        sorted.sort();
+        assert!(sorted.iter().copied().eq(0..20000));
+        assert_ne!(p, sorted);
+    }
+
+    #[test]
+    fn random_permutation_of_small_n_is_uniform() {
+        let mut rng = xorshift(17);
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..6000 {
+            *counts
+                .entry(stl::random_permutation(3, &mut rng))
+                .or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 6);
+        assert!(counts.values().all(|c| (800..1200).contains(c)));
+    }
+}
@@ -296,4 +296,63 @@ pub mod tests {
         assert!(s1.equals(&[1, 2, 3]));
         assert!(s2.equals(&[4, 5]));
     }
+
+    #[test]
+    fn unchecked_access() {
+        let mut arr = [1, 2, 3, 4];
+        let mut s = arr.slice_mut(1, 4);
+        unsafe {
+            *s.at_unchecked_mut(&1) = 20;
+            s.swap_at_unchecked(&2, &3);
+            assert_eq!(*s.at_unchecked(&3), 3);
+        }
+        assert_eq!(arr, [1, 20, 4, 3]);
+
+        let mut v = vec![1, 2];
+        unsafe { v.swap_at_unchecked(&0, &1) };
+        assert_eq!(v, [2, 1]);
+    }
+
+    #[test]
+    fn index_by_offsets() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut s = arr.slice_mut(1, 4);
+        assert_eq!(s[0..2], [2, 3]);
+        s[1..3].copy_from_slice(&[8, 9]);
+        s[0..1][0] = 7;
+        assert_eq!(arr, [1, 7, 8, 9, 5]);
+
+        let mut v = vec![4, 3, 2, 1];
+        v.suffix_mut(2)[0..2].sort();
+        assert_eq!(v, [4, 3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range should be within slice")]
+    fn index_by_offsets_beyond_slice() {
+        let mut arr = [1, 2, 3];
+        let mut s = arr.slice_mut(0, 2);
+        s[1..3].fill(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn sort_unstable_beyond_whole_collection() {
+        let mut v = vec![3, 1, 2];
+        v.slice_mut(0, 10).sort_unstable();
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn make_heap_beyond_whole_collection() {
+        let mut v = vec![3, 1, 2];
+        v.slice_mut(0, 10).make_heap();
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn stable_sort_beyond_whole_collection() {
+        let mut v = vec![3, 1, 2];
+        v.slice_mut(0, 10).stable_sort(AllocPolicy::Unlimited);
+    }
 }
@@ -278,4 +278,94 @@ pub mod tests {
         assert!(s.equals(&[4, 5]));
         assert!(prefix.equals(&[1, 2, 3]));
     }
+
+    #[test]
+    fn position_at_offset() {
+        let arr = [1, 2, 3, 4, 5];
+        let s = arr.suffix(3);
+        assert_eq!(s.position_at(0), 2);
+        assert_eq!(s.position_at(3), 5);
+        assert_eq!(*s.element_at(2), 5);
+        let r = arr.reversed();
+        assert_eq!(*r.element_at(1), 4);
+    }
+
+    #[test]
+    fn index_by_offsets() {
+        let arr = [1, 2, 3, 4, 5];
+        let s = arr.suffix(3);
+        assert_eq!(s[0..2], [3, 4]);
+        assert_eq!(s[1..3], [4, 5]);
+        assert_eq!(s[3..3], []);
+
+        let v = vec![1, 2, 3, 4];
+        let s = v.slice(1, 3);
+        assert_eq!(s[0..2], [2, 3]);
+
+        let x: &[i32] = &[1, 2, 3];
+        assert_eq!(x.prefix(2)[1..2], [2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range should be within slice")]
+    fn index_by_offsets_beyond_slice() {
+        let arr = [1, 2, 3, 4, 5];
+        let s = arr.slice(1, 3);
+        let _ = &s[0..3];
+    }
+
+    #[test]
+    fn slice_at_offsets() {
+        let arr = [1, 2, 3, 4, 5];
+        let s = arr.suffix(4);
+        assert!(s.slice_at_offsets(1..3).equals(&[3, 4]));
+        assert!(s.slice_at_offsets(2..2).equals(&[]));
+        assert!(s.slice_at_offsets(0..4).equals(&[2, 3, 4, 5]));
+        let mut arr = [1, 2, 3, 4, 5];
+        arr.slice_at_offsets_mut(3..5).reverse();
+        assert_eq!(arr, [1, 2, 3, 5, 4]);
+    }
+
+    #[test]
+    fn at_unchecked() {
+        let arr = [1, 2, 3, 4];
+        let s = arr.slice(1, 3);
+        assert_eq!(unsafe { *s.at_unchecked(&2) }, 3);
+        assert_eq!(unsafe { *arr.at_unchecked(&0) }, 1);
+    }
+
+    #[test]
+    fn comparison_by_elements() {
+        let arr = [1, 2, 1, 2, 3];
+        assert!(arr.slice(0, 2) == arr.slice(2, 4));
+        assert!(arr.slice(0, 2) != arr.slice(1, 3));
+        assert!(arr.slice(0, 0) == arr.slice(5, 5));
+        assert!(arr.slice(0, 2) < arr.slice(2, 5));
+        assert!(arr.slice(1, 2) > arr.slice(2, 5));
+        assert_eq!(
+            arr.slice(0, 3).cmp(&arr.slice(2, 5)),
+            std::cmp::Ordering::Less
+        );
+        let floats = [1.0, f64::NAN];
+        assert!(floats.slice(1, 2) != floats.slice(1, 2));
+        assert_eq!(floats.slice(1, 2).partial_cmp(&floats.slice(0, 1)), None);
+    }
+
+    #[test]
+    fn hashing_by_elements() {
+        use std::collections::HashSet;
+
+        let arr = [1, 2, 1, 2, 3];
+        let mut set = HashSet::new();
+        assert!(set.insert(arr.slice(0, 2)));
+        assert!(!set.insert(arr.slice(2, 4)));
+        assert!(set.insert(arr.slice(2, 5)));
+        assert!(set.insert(arr.slice(0, 0)));
+        assert_eq!(set.len(), 3);
+
+        let words = ["b", "a", "b"];
+        let mut sorted = [words.full(), words.suffix(2), words.suffix(1)];
+        sorted.sort();
+        assert!(sorted[0].equals(&["a", "b"]));
+    }
 }
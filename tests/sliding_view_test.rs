@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn advancing() {
+        let arr = [1, 2, 3, 4];
+        let mut view = arr.sliding_view();
+        assert!(view.is_empty());
+        assert_eq!(view.front(), None);
+        assert_eq!(view.back(), None);
+        assert!(!view.advance_front());
+
+        assert!(view.advance_back());
+        assert!(view.advance_back());
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.front(), Some(&1));
+        assert_eq!(view.back(), Some(&2));
+        assert!(view.window().equals(&[1, 2]));
+
+        assert!(view.advance_front());
+        assert!(view.advance_front());
+        assert!(view.is_empty());
+        assert_eq!(view.back(), None);
+        assert!(view.window().is_empty());
+
+        assert!(view.advance_back());
+        assert!(view.advance_back());
+        assert!(view.is_at_limit());
+        assert!(!view.advance_back());
+        assert!(view.window().equals(&[3, 4]));
+    }
+
+    #[test]
+    fn bounded_by_slice() {
+        let arr = [1, 2, 3, 4, 5];
+        let mut view = SlidingView::new(arr.slice(1, 3));
+        while view.advance_back() {}
+        assert!(view.window().equals(&[2, 3]));
+        assert_eq!(view.window().start(), 1);
+        assert_eq!(view.window().end(), 3);
+    }
+
+    #[test]
+    fn rolling_sum_of_fixed_window() {
+        let arr = [1, 3, 2, 6, 4];
+        let mut view = arr.sliding_view();
+        let mut sum = 0;
+        let mut sums = vec![];
+        while view.advance_back() {
+            sum += *view.back().unwrap();
+            if view.len() > 3 {
+                sum -= *view.front().unwrap();
+                view.advance_front();
+            }
+            if view.len() == 3 {
+                sums.push(sum);
+                assert_eq!(view.window().fold_left(0, |x, y| x + y), sum);
+            }
+        }
+        assert_eq!(sums, vec![6, 11, 12]);
+    }
+
+    #[test]
+    fn sliding_over_forward_collection() {
+        let s = "abcabcbb";
+        let mut view = s.sliding_view();
+        let mut longest = 0;
+        while view.advance_back() {
+            let c = view.back().unwrap();
+            while view.window().count_of(&c) > 1 {
+                view.advance_front();
+            }
+            longest = longest.max(view.len());
+        }
+        assert_eq!(longest, 3);
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn insort() {
+        let mut v = vec![];
+        for e in [5, 1, 4, 1, 3, 9, 2] {
+            stl::insort(&mut v, e);
+        }
+        assert_eq!(v, vec![1, 1, 2, 3, 4, 5, 9]);
+        assert!(v.is_sorted());
+    }
+
+    #[test]
+    fn insort_returns_position() {
+        let mut v = vec![1, 3, 5];
+        assert_eq!(stl::insort(&mut v, 0), 0);
+        assert_eq!(stl::insort(&mut v, 6), 4);
+        assert_eq!(stl::insort(&mut v, 3), 3);
+        assert_eq!(v, vec![0, 1, 3, 3, 5, 6]);
+    }
+
+    #[test]
+    fn insert_sorted_by_is_stable() {
+        let mut v = vec![];
+        for e in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')] {
+            insert_sorted_by(&mut v, e, |x, y| x.0 < y.0);
+        }
+        assert_eq!(v, vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn insert_sorted_by_descending() {
+        let mut v = vec![5, 3, 1];
+        let i = insert_sorted_by(&mut v, 4, |x, y| x > y);
+        assert_eq!(i, 1);
+        assert_eq!(v, vec![5, 4, 3, 1]);
+    }
+}
@@ -19,4 +19,152 @@ pub mod tests {
         arr.sort_unstable();
         assert_eq!(arr, []);
     }
+
+    #[test]
+    fn sort_unstable_beyond_quick_sort_depth() {
+        let mut v: Vec<i32> = (0..1000).rev().collect();
+        v.sort_unstable();
+        assert!(v.iter().copied().eq(0..1000));
+
+        let mut v: Vec<i32> =
+            (0..1000).lazy_map(|x| (x * 7919) % 1000).to_vec();
+        v.sort_unstable();
+        assert!(v.iter().copied().eq(0..1000));
+    }
+
+    #[test]
+    fn parallel_sort_unstable() {
+        let mut v: Vec<i32> =
+            (0..100_000).lazy_map(|x| (x * 7919) % 1000).to_vec();
+        let mut expected = v.clone();
+        expected.sort();
+        v.parallel_sort_unstable();
+        assert_eq!(v, expected);
+
+        let mut v: Vec<i32> = (0..10_000).collect();
+        v.slice_mut(1000, 9000)
+            .parallel_sort_unstable_by(|x, y| x > y);
+        assert!(v.prefix(1000).equals(&(0..1000)));
+        assert!(v.slice(1000, 9000).equals(&(1000..9000).reversed()));
+
+        let mut empty: [i32; 0] = [];
+        empty.parallel_sort_unstable();
+    }
+
+    #[test]
+    fn parallel_is_sorted() {
+        let arr: [i32; 0] = [];
+        assert!(arr.parallel_is_sorted());
+        assert!([1].parallel_is_sorted());
+        assert!([1, 1, 1].parallel_is_sorted());
+        assert!(![2, 1].parallel_is_sorted());
+
+        let arr: Vec<usize> = Iterator::map(0..100000, |x| x / 3).collect();
+        assert!(arr.parallel_is_sorted());
+        assert!(!arr.parallel_is_sorted_by(|x, y| x > y));
+
+        // Inversions are detected anywhere, including boundaries of parts.
+        for i in [1, 511, 512, 513, 50000, 99999] {
+            let mut arr: Vec<usize> = (0..100000).collect();
+            arr.swap(i - 1, i);
+            assert!(!arr.parallel_is_sorted());
+        }
+    }
+
+    #[test]
+    fn parallel_is_sorted_of_lazy_collection() {
+        assert!((0..10000).parallel_is_sorted());
+        assert!((0..10000).reversed().parallel_is_sorted_by(|x, y| x > y));
+        assert!(
+            !CollectionExt::map(0..10000, |x| x % 5000).parallel_is_sorted()
+        );
+    }
+
+    #[test]
+    fn sort_unstable_by_key() {
+        let mut arr = [5, -1, 3, -4, 2];
+        arr.sort_unstable_by_key(|x: &i32| x.abs());
+        assert_eq!(arr, [-1, 2, 3, -4, 5]);
+
+        let mut arr: [i32; 0] = [];
+        arr.sort_unstable_by_key(|x: &i32| *x);
+    }
+
+    #[test]
+    fn sort_by_cached_key_calls_key_once_per_element() {
+        let mut arr: Vec<i32> =
+            Iterator::map(0..500, |i| (i * 37) % 101).collect();
+        let mut expected = arr.clone();
+        expected.as_mut_slice().sort_by_key(|x| x % 10);
+
+        let mut calls = 0;
+        arr.sort_by_cached_key(|x| {
+            calls += 1;
+            x % 10
+        });
+        assert_eq!(calls, 500);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn sort_by_cached_key_is_stable() {
+        let mut arr = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+        arr.sort_by_cached_key(|x| x.0);
+        assert_eq!(arr, [(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+
+        let mut arr = [3, 1, 2];
+        let mut s = arr.slice_mut(1, 3);
+        s.sort_by_cached_key(|x| std::cmp::Reverse(*x));
+        assert_eq!(arr, [3, 2, 1]);
+    }
+
+    #[test]
+    fn sort_index_by() {
+        let arr = [3, 1, 2, 1, 0];
+        assert_eq!(arr.sort_index_by(|x, y| x < y), [4, 1, 3, 2, 0]);
+        assert_eq!(arr.sort_index_by(|x, y| x > y), [0, 2, 1, 3, 4]);
+        assert_eq!(arr, [3, 1, 2, 1, 0]);
+
+        let arr: [i32; 0] = [];
+        assert!(arr.sort_index().is_empty());
+    }
+
+    #[test]
+    fn apply_permutation() {
+        let mut arr = [5, 3, 9, 1, 7, 3];
+        let perm = arr.sort_index();
+        arr.apply_permutation(&perm);
+        assert_eq!(arr, [1, 3, 3, 5, 7, 9]);
+
+        let mut arr = ['a', 'b', 'c', 'd', 'e'];
+        arr.apply_permutation(&[1, 0, 4, 2, 3]);
+        assert_eq!(arr, ['b', 'a', 'e', 'c', 'd']);
+
+        let mut arr = [1, 2, 3];
+        arr.apply_permutation(&[0, 1, 2]);
+        assert_eq!(arr, [1, 2, 3]);
+    }
+
+    #[test]
+    fn apply_permutation_parallel_arrays() {
+        let keys = [30, 10, 20, 10];
+        let mut values = ["c", "a", "b", "d"];
+        let perm = keys.sort_index();
+        values.apply_permutation(&perm);
+        assert_eq!(values, ["a", "d", "b", "c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "perm should be a permutation")]
+    fn apply_permutation_with_repeated_offsets() {
+        let mut arr = [1, 2];
+        arr.apply_permutation(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "perm should be a permutation")]
+    fn apply_permutation_with_missing_cycle_start() {
+        let mut arr = [1, 2, 3];
+        arr.apply_permutation(&[1, 2, 1]);
+    }
 }
@@ -64,4 +64,51 @@ pub mod tests {
             .collect();
         assert_eq!(splits, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
     }
+
+    #[test]
+    fn len_decreases_while_iterating() {
+        let arr = [1, 2, 3, 4, 5, 6, 7];
+        let mut it = arr.splitting_evenly_in(3);
+        assert_eq!(it.len(), 3);
+        it.next();
+        assert_eq!(it.len(), 2);
+    }
+
+    #[test]
+    fn clone_restarts_iteration() {
+        let arr = [1, 2, 3, 4, 5, 6, 7];
+        let it = arr.splitting_evenly_in(3);
+        let first: Vec<usize> = it.clone().map(|s| s.count()).collect();
+        let second: Vec<usize> = it.map(|s| s.count()).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn split_evenly_from_back() {
+        let arr = [1, 2, 3, 4, 5, 6, 7];
+        let splits: Vec<Vec<_>> = arr
+            .splitting_evenly_in(3)
+            .rev()
+            .map(|s| s.iter().copied().collect())
+            .collect();
+        assert_eq!(splits, vec![vec![6, 7], vec![4, 5], vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn split_evenly_from_both_ends() {
+        let mut arr = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut it = arr.splitting_evenly_in_mut(3);
+        assert!(it.next_back().unwrap().equals(&[7, 8]));
+        assert!(it.next().unwrap().equals(&[1, 2, 3]));
+        assert!(it.next_back().unwrap().equals(&[4, 5, 6]));
+        assert!(it.next().is_none());
+        assert!(it.next_back().is_none());
+    }
+
+    #[test]
+    fn split_evenly_in_more_slices_than_elements() {
+        let arr = [1, 2];
+        assert_eq!(arr.splitting_evenly_in(3).len(), 2);
+        assert_eq!(arr.splitting_evenly_in(3).rev().count(), 2);
+    }
 }
@@ -31,4 +31,44 @@ pub mod tests {
             assert_eq!(arr, [5, 3, 1, 2, 2, 3, 4, 7, 5]);
         }
     }
+
+    #[test]
+    fn rsplit() {
+        let arr = [1, 3, 5, 2, 2, 3, 4, 5, 5];
+        let mut res = vec![];
+        arr.rsplitting_where(|x| x % 2 == 0)
+            .for_each(|s| res.push(s.iter().sum::<i32>()));
+        assert_eq!(res, vec![10, 3, 0, 9]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.rsplitting_where(|x| x % 2 == 0).count(), 0);
+    }
+
+    #[test]
+    fn rsplit_mut() {
+        let mut arr = [1, 3, 5, 2, 2, 3, 4, 5, 7];
+        arr.rsplitting_where_mut(|x| x % 2 == 0)
+            .for_each(|mut s| s.reverse());
+        assert_eq!(arr, [5, 3, 1, 2, 2, 3, 4, 7, 5]);
+    }
+
+    #[test]
+    fn split_on() {
+        let arr = [1, 0, 2, 3, 0, 0, 4];
+        let splits: Vec<_> = arr.splitting_on(&0).map(|s| s.to_vec()).collect();
+        assert_eq!(splits, vec![vec![1], vec![2, 3], vec![], vec![4]]);
+
+        let arr = [1, 2];
+        assert_eq!(arr.splitting_on(&0).count(), 1);
+    }
+
+    #[test]
+    fn split_on_by() {
+        let words = ["a", "AND", "b", "and", "c"];
+        let splits: Vec<_> = words
+            .splitting_on_by(&"and", |x, y| x.eq_ignore_ascii_case(y))
+            .map(|s| s.to_vec())
+            .collect();
+        assert_eq!(splits, vec![vec!["a"], vec!["b"], vec!["c"]]);
+    }
 }
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::{iterators::SplitItem, *};
+
+    fn rebuild(arr: &[i32]) -> (Vec<i32>, usize, usize) {
+        let mut res = vec![];
+        let mut num_segments = 0;
+        let mut num_separators = 0;
+        for item in arr.splitting_with_separators(|x| x % 2 == 0) {
+            match item {
+                SplitItem::Segment(s) => {
+                    num_segments += 1;
+                    res.extend(s.iter().copied());
+                }
+                SplitItem::Separator(p, e) => {
+                    num_separators += 1;
+                    assert_eq!(arr[p], *e);
+                    res.push(*e);
+                }
+            }
+        }
+        (res, num_segments, num_separators)
+    }
+
+    #[test]
+    fn round_trip() {
+        let arr = [1, 3, 2, 2, 5, 4];
+        assert_eq!(rebuild(&arr), (arr.to_vec(), 4, 3));
+
+        let arr = [2, 1];
+        assert_eq!(rebuild(&arr), (arr.to_vec(), 2, 1));
+
+        let arr = [1, 3];
+        assert_eq!(rebuild(&arr), (arr.to_vec(), 1, 0));
+    }
+
+    #[test]
+    fn empty_collection_yields_one_empty_segment() {
+        let arr: [i32; 0] = [];
+        assert_eq!(rebuild(&arr), (vec![], 1, 0));
+    }
+}
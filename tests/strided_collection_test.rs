@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn test_the_equality() {
+        let arr = [1, 2, 3, 4, 5];
+        assert!(arr.stride(1).equals(&[1, 2, 3, 4, 5]));
+        assert!(arr.stride(2).equals(&[1, 3, 5]));
+        assert!(arr.stride(3).equals(&[1, 4]));
+        assert!(arr.stride(5).equals(&[1]));
+        assert!(arr.stride(7).equals(&[1]));
+        let arr: [i32; 0] = [];
+        assert!(arr.stride(2).equals(&[]));
+    }
+
+    #[test]
+    fn count_and_distance() {
+        let arr = [1, 2, 3, 4, 5, 6, 7].stride(3);
+        assert_eq!(arr.count(), 3);
+        assert_eq!(arr.distance(arr.start(), arr.end()), 3);
+        assert_eq!(arr.distance(3, arr.end()), 2);
+        assert_eq!(arr.next_n(arr.start(), 2), 6);
+        assert_eq!(arr.next_n(arr.start(), 3), arr.end());
+    }
+
+    #[test]
+    fn advance_by_huge_offsets() {
+        let arr = [1, 2, 3, 4, 5, 6, 7].stride(3);
+        assert_eq!(arr.next_n(arr.start(), usize::MAX), arr.end());
+        let mut pos = arr.start();
+        assert!(!arr.form_next_n_limited_by(&mut pos, usize::MAX, arr.end()));
+        assert_eq!(pos, arr.end());
+        let arr = [1, 2, 3].stride(usize::MAX);
+        assert_eq!(arr.next_n(arr.start(), 2), arr.end());
+        assert_eq!(arr.prior_n(arr.end(), 1), 0);
+    }
+
+    #[test]
+    fn backward_traversal() {
+        assert!([1, 2, 3, 4, 5, 6].stride(4).reversed().equals(&[5, 1]));
+        assert!([1, 2, 3, 4, 5].stride(2).reversed().equals(&[5, 3, 1]));
+        let arr = [1, 2, 3, 4, 5, 6, 7].stride(3);
+        assert_eq!(arr.prior(arr.end()), 6);
+        assert_eq!(arr.prior_n(arr.end(), 2), 3);
+        assert_eq!(arr.prior(3), 0);
+    }
+
+    #[test]
+    fn slicing() {
+        let arr = [1, 2, 3, 4, 5, 6, 7].stride(2);
+        assert!(arr.prefix(2).equals(&[1, 3]));
+        assert!(arr.suffix(3).equals(&[3, 5, 7]));
+        assert!(arr.suffix(7).equals(&[1, 3, 5, 7]));
+    }
+
+    #[test]
+    fn element_mutation() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut s = arr.full_mut().stride(2);
+        s.reverse();
+        assert_eq!(arr, [5, 2, 3, 4, 1]);
+    }
+}
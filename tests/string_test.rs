@@ -28,4 +28,44 @@ pub mod tests {
         let str = String::from("h你e好o");
         assert_eq!(str.count(), 5);
     }
+
+    #[test]
+    fn positions_are_byte_offsets() {
+        let str = "h你e好o";
+        assert_eq!(str.first_position_of(&'e'), Some(4));
+        assert_eq!(str.first_position_of(&'好'), Some(5));
+        assert_eq!(str.first_position_of(&'x'), None);
+        assert_eq!(str.at(&1).val, '你');
+        assert_eq!(str.next(1), 4);
+    }
+
+    #[test]
+    fn text_algorithms() {
+        let str = String::from("héllo wörld");
+        let word = str.prefix_while(|c| *c != ' ');
+        assert!(word.equals(&"héllo"));
+
+        let words: Vec<String> = str
+            .splitting_where(|c| *c == ' ')
+            .map(|s| s.iter().map(|c| c.val).collect())
+            .collect();
+        assert_eq!(words, vec!["héllo", "wörld"]);
+    }
+
+    #[test]
+    fn backward_traversal() {
+        let str = "a你b好";
+        assert_eq!(str.prior(str.end()), 5);
+        assert_eq!(str.prior(5), 4);
+        assert_eq!(str.prior(4), 1);
+        assert_eq!(str.prior(1), 0);
+        assert_eq!(str.last_position_of(&'b'), Some(4));
+
+        let rev: String = String::from("a你b好")
+            .reversed()
+            .iter()
+            .map(|c| c.val)
+            .collect();
+        assert_eq!(rev, "好b你a");
+    }
 }
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::rc::Rc;
+    use stl::buffer::TempBuffer;
+    use stl::*;
+
+    #[test]
+    fn parts_keep_push_order() {
+        let mut buffer = TempBuffer::with_capacity(5);
+        assert!(buffer.is_empty());
+        buffer.push_second(10);
+        buffer.push(1);
+        buffer.push_second(20);
+        buffer.push(2);
+        assert_eq!(buffer.len(), 4);
+        assert!(!buffer.is_full());
+        assert_eq!(buffer.capacity(), 5);
+        assert_eq!(buffer.into_parts(), (vec![1, 2], vec![10, 20]));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut buffer = TempBuffer::with_capacity(3);
+        buffer.push_second('c');
+        buffer.push('a');
+        buffer.push('b');
+        assert!(buffer.is_full());
+        let all: String = buffer.into_iter().collect();
+        assert_eq!(all, "abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "TempBuffer is full")]
+    fn push_to_full_buffer() {
+        let mut buffer = TempBuffer::with_capacity(1);
+        buffer.push_second(1);
+        buffer.push(2);
+    }
+
+    #[test]
+    fn drops_remaining_elements() {
+        let e = Rc::new(0);
+        {
+            let mut buffer = TempBuffer::with_capacity(4);
+            buffer.push(e.clone());
+            buffer.push_second(e.clone());
+            buffer.push_second(e.clone());
+            assert_eq!(Rc::strong_count(&e), 4);
+        }
+        assert_eq!(Rc::strong_count(&e), 1);
+    }
+
+    #[test]
+    fn write_into() {
+        let mut ring = collections::RingBuffer::<i32, 4>::new();
+        for _ in 0..4 {
+            ring.push_back_overwriting(0);
+        }
+        let mut buffer = TempBuffer::with_capacity(3);
+        buffer.push(1);
+        buffer.push_second(3);
+        buffer.push(2);
+        let start = ring.start();
+        let i = buffer.write_into(&mut ring, start);
+        assert!(i == ring.next_n(ring.start(), 3));
+        assert!(ring.iter().eq([1, 2, 3, 0].iter()));
+    }
+
+    #[test]
+    fn output_collection() {
+        let mut buffer = TempBuffer::with_capacity(2);
+        assert_eq!((1..5).lazy_write_to(&mut buffer), 3);
+        assert_eq!(buffer.write_next(7), Err(7));
+        assert_eq!(buffer.into_parts(), (vec![1, 2], vec![]));
+    }
+
+    #[test]
+    fn zero_sized_elements() {
+        let mut buffer = TempBuffer::with_capacity(3);
+        buffer.push(());
+        buffer.push_second(());
+        assert_eq!(buffer.into_parts(), (vec![()], vec![()]));
+    }
+}
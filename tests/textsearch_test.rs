@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::LinkedList;
+    use stl::textsearch::*;
+    use stl::*;
+
+    #[test]
+    fn lines_of_text() {
+        let text = b"ab\n\ncd\n";
+        let res: Vec<Vec<u8>> =
+            lines(text).map(|l| l.iter().copied().collect()).collect();
+        assert_eq!(res, [b"ab".to_vec(), vec![], b"cd".to_vec()]);
+
+        let text: [u8; 0] = [];
+        assert_eq!(lines(&text).count(), 0);
+    }
+
+    #[test]
+    fn first_match_position_of_pattern() {
+        let haystack = b"abababc";
+        assert_eq!(first_match_position(haystack, b"abc"), Some(4));
+        assert_eq!(first_match_position(haystack, b"ab"), Some(0));
+        assert_eq!(first_match_position(haystack, b"abcd"), None);
+        assert_eq!(first_match_position(haystack, b""), Some(0));
+
+        let empty: [u8; 0] = [];
+        assert_eq!(first_match_position(&empty, b""), Some(0));
+        assert_eq!(first_match_position(&empty, b"a"), None);
+
+        let list: LinkedList<u8> = b"xyz".iter().copied().collect();
+        let p = first_match_position(&list, b"yz").unwrap();
+        assert_eq!(*list.at(&p), b'y');
+    }
+
+    #[test]
+    fn parallel_grep_reports_lines_in_order() {
+        let text: &[u8] = b"one\ntwo\nthree\nfour two two";
+        let matches = parallel_grep(&text, b"two");
+        assert_eq!(
+            matches,
+            [
+                LineMatch {
+                    line_number: 2,
+                    line_start: 4,
+                    match_start: 4,
+                },
+                LineMatch {
+                    line_number: 4,
+                    line_start: 14,
+                    match_start: 19,
+                },
+            ]
+        );
+        assert!(parallel_grep(&text, b"six").is_empty());
+    }
+
+    #[test]
+    fn parallel_grep_in_large_text() {
+        let text: Vec<u8> = Iterator::flat_map(0..10000, |i| {
+            format!("line {i}\n").into_bytes()
+        })
+        .collect();
+        let matches = parallel_grep(&text, b"99");
+        let expected: Vec<usize> = (0..10000)
+            .filter(|i| i.to_string().contains("99"))
+            .map(|i| i + 1)
+            .collect();
+        assert_eq!(
+            matches.iter().map(|m| m.line_number).collect::<Vec<_>>(),
+            expected
+        );
+        assert!(matches
+            .iter()
+            .all(|m| text.suffix_from(m.line_start).starts_with(b"line ")
+                && text.suffix_from(m.match_start).starts_with(b"99")));
+        assert_eq!(parallel_count_matching_lines(&text, b"99"), expected.len());
+    }
+}
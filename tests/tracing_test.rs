@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(all(test, feature = "tracing"))]
+pub mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use stl::*;
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    /// Span name with its recorded fields, formatted as `field=value`.
+    type RecordedSpan = (String, Vec<String>);
+
+    /// Subscriber recording every span created.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        spans: Arc<Mutex<Vec<RecordedSpan>>>,
+    }
+
+    struct FieldsVisitor<'a>(&'a mut Vec<String>);
+
+    impl Visit for FieldsVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push(format!("{}={:?}", field.name(), value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push(format!("{}={}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = vec![];
+            span.record(&mut FieldsVisitor(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((span.metadata().name().to_string(), fields));
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn recorded_spans(f: impl FnOnce()) -> Vec<RecordedSpan> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), f);
+        let spans = recorder.spans.lock().unwrap().clone();
+        spans
+    }
+
+    #[test]
+    fn sort_unstable_records_count_and_strategy() {
+        let spans = recorded_spans(|| {
+            let mut arr = [3, 1, 2];
+            arr.sort_unstable();
+            let mut arr: Vec<i32> = (0..100).rev().collect();
+            arr.sort_unstable();
+        });
+        let sort_spans: Vec<_> = spans
+            .into_iter()
+            .filter(|(name, _)| name == "sort_unstable")
+            .collect();
+        assert_eq!(
+            sort_spans,
+            [
+                (
+                    "sort_unstable".to_string(),
+                    vec![
+                        "count=3".to_string(),
+                        "strategy=insertion_sort".into()
+                    ]
+                ),
+                (
+                    "sort_unstable".to_string(),
+                    vec!["count=100".to_string(), "strategy=introsort".into()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn buffered_algorithms_record_chosen_strategy() {
+        let spans = recorded_spans(|| {
+            let mut arr = [3, 1, 2, 4];
+            arr.stable_sort(AllocPolicy::Unlimited);
+            arr.stable_sort(AllocPolicy::Forbidden);
+            arr.stable_partition_with(AllocPolicy::Unlimited, |x| x % 2 == 0);
+        });
+        let strategies: Vec<_> = spans
+            .iter()
+            .filter(|(name, _)| str::starts_with(name, "stable"))
+            .map(|(name, fields)| (name.as_str(), fields[1].as_str()))
+            .collect();
+        assert_eq!(
+            strategies,
+            [
+                ("stable_sort", "strategy=buffered"),
+                ("stable_sort", "strategy=in_place"),
+                ("stable_partition_with", "strategy=buffered"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parallel_algorithms_record_count() {
+        let spans = recorded_spans(|| {
+            let arr: Vec<i32> = (0..1000).collect();
+            assert!(arr.parallel_any_satisfy(|x| *x == 999));
+        });
+        assert_eq!(spans[0].0, "parallel_any_satisfy");
+        assert_eq!(spans[0].1[0], "count=1000");
+    }
+}
@@ -31,4 +31,118 @@ pub mod tests {
         arr.reverse();
         assert!(arr.equals(&[9, 7, 5, 3, 1]));
     }
+
+    #[test]
+    fn lazy_map_chain() {
+        let calls = std::cell::Cell::new(0);
+        let arr = (1..1000)
+            .lazy_map(|x| {
+                calls.set(calls.get() + 1);
+                x * 2
+            })
+            .lazy_map(|x| x + 1);
+        let i = arr.next_n(arr.start(), 10);
+        assert_eq!(arr.compute_at(&i), 23);
+        assert_eq!(*arr.at(&i), 23);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(arr.count(), 999);
+    }
+
+    #[test]
+    fn backward_traversal_of_mapped() {
+        let arr = (1..=5).lazy_map(|x| x * 2).lazy_map(|x| x + 1);
+        let i = arr.prior_n(arr.end(), 2);
+        assert_eq!(arr.compute_at(&i), 9);
+        assert!(arr.suffix(2).equals(&[9, 11]));
+        let mut j = arr.end();
+        assert!(!arr.form_prior_n_limited_by(&mut j, 7, arr.start()));
+        assert_eq!(j, arr.start());
+
+        let arr = CollectionExt::map([1, 2, 3], |x| x * 2);
+        assert_eq!(*arr.at(&arr.prior_n(arr.end(), 3)), 2);
+    }
+
+    #[test]
+    fn zip_with_into() {
+        let a = [1, 2, 3];
+        let b = vec![4, 5, 6];
+        let mut dest = vec![0; 3];
+        let i = a.zip_with_into(&b, &mut dest, |x, y| x * y);
+        assert_eq!(i, 3);
+        assert_eq!(dest, [4, 10, 18]);
+
+        // Shorter of inputs decides number of written elements.
+        let mut dest = [""; 3];
+        let i = [1, 2, 3].zip_with_into(&[true], &mut dest, |x, y| {
+            if *y && x % 2 == 1 {
+                "odd"
+            } else {
+                "even"
+            }
+        });
+        assert_eq!(i, 1);
+        assert_eq!(dest, ["odd", "", ""]);
+
+        let mut dest = [0; 2];
+        let empty: [i32; 0] = [];
+        let i = empty.zip_with_into(&[1, 2], &mut dest, |x, y| x + y);
+        assert_eq!(i, 0);
+    }
+
+    #[test]
+    fn zip_with_into_different_element_types() {
+        let names = ["a", "b", "c"];
+        let counts = 1..=3_usize;
+        let mut dest = vec![String::new(); 3];
+        names.zip_with_into(&counts, &mut dest, |s, n| s.repeat(*n));
+        assert_eq!(dest, ["a", "bb", "ccc"]);
+
+        let mut dest = [0; 3];
+        let mut rev = dest.as_mut_slice().reversed();
+        let i = (1..4).lazy_map(|x| x * 10).zip_with_into(
+            &[1, 2, 3],
+            &mut rev,
+            |x, y| x + y,
+        );
+        assert!(i == rev.end());
+        assert_eq!(dest, [33, 22, 11]);
+    }
+
+    #[test]
+    fn indexed_mapping() {
+        let arr = [5, 6, 7].map_indexed(|i, x| (*i, x * 2));
+        assert!(arr.equals(&[(0, 10), (1, 12), (2, 14)]));
+        assert_eq!(arr.compute_at(&2), (2, 14));
+        assert!(arr.suffix(1).equals(&[(2, 14)]));
+
+        let arr = (10..13).map_indexed(|i, x| i + x);
+        assert!(arr.equals(&[20, 22, 24]));
+    }
+
+    #[test]
+    fn indexed_mapping_reordering() {
+        let mut arr = vec![1, 2, 3].map_indexed(|i, x| i * 10 + x);
+        arr.reverse();
+        assert!(arr.equals(&[3, 12, 21]));
+        assert_eq!(arr.base, [3, 2, 1]);
+    }
+
+    #[test]
+    fn mapped_comparison_and_hashing_by_elements() {
+        use std::collections::HashSet;
+
+        fn parity(x: &i32) -> i32 {
+            x % 2
+        }
+
+        let a = CollectionExt::map([1, 2, 3], parity);
+        let b = CollectionExt::map([3, 4, 5], parity);
+        assert!(a == b);
+        let c = CollectionExt::map([2, 2, 2], parity);
+        assert!(c < a);
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(!set.insert(b));
+    }
 }
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use stl::*;
+
+    #[test]
+    fn find_pair_summing_to() {
+        let arr = [-3, 0, 1, 4, 4, 9];
+        assert_eq!(arr.find_pair_summing_to(&8), Some((3, 4)));
+        assert_eq!(arr.find_pair_summing_to(&-3), Some((0, 1)));
+        assert_eq!(arr.find_pair_summing_to(&6), Some((0, 5)));
+        assert_eq!(arr.find_pair_summing_to(&18), None);
+        assert_eq!(arr.find_pair_summing_to(&2), None);
+    }
+
+    #[test]
+    fn find_pair_summing_to_short_collections() {
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.find_pair_summing_to(&0), None);
+        assert_eq!([3].find_pair_summing_to(&6), None);
+        assert_eq!([3, 3].find_pair_summing_to(&6), Some((0, 1)));
+    }
+
+    #[test]
+    fn find_pair_summing_to_in_slice() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        assert_eq!(arr.slice(2, 5).find_pair_summing_to(&8), Some((2, 4)));
+        assert_eq!(arr.slice(2, 5).find_pair_summing_to(&11), None);
+    }
+
+    #[test]
+    fn count_pairs_where() {
+        let arr = [1, 1, 2, 3, 5, 8, 13];
+        for k in 0..30 {
+            let mut expected = 0;
+            for i in 0..arr.len() {
+                for j in i + 1..arr.len() {
+                    if arr[i] + arr[j] < k {
+                        expected += 1;
+                    }
+                }
+            }
+            assert_eq!(arr.count_pairs_where(|x, y| x + y < k), expected);
+        }
+    }
+
+    #[test]
+    fn count_pairs_where_short_collections() {
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.count_pairs_where(|_, _| true), 0);
+        assert_eq!([1].count_pairs_where(|_, _| true), 0);
+        assert_eq!([1, 2, 3, 4].count_pairs_where(|_, _| true), 6);
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Rishabh Dwivedi (rishabhdwivedi17@gmail.com)
+
+#[cfg(test)]
+pub mod tests {
+    use std::cell::Cell;
+    use stl::*;
+
+    #[test]
+    fn generate_computes_elements_by_offset() {
+        let squares = generate(|i| i * i);
+        assert_eq!(*squares.at(&squares.start()), 0);
+        assert_eq!(*squares.at(&squares.next_n(squares.start(), 7)), 49);
+        assert_eq!(squares.distance(2, 9), 7);
+        assert_eq!(squares.prefix(5).to_vec(), [0, 1, 4, 9, 16]);
+    }
+
+    #[test]
+    fn generate_is_lazy() {
+        let calls = Cell::new(0);
+        let g = generate(|i| {
+            calls.set(calls.get() + 1);
+            i
+        });
+        let p = g.prefix(1_000_000);
+        assert_eq!(calls.get(), 0);
+        assert_eq!(p.count(), 1_000_000);
+        assert_eq!(*p.at(&999_999), 999_999);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn iota_counts_from_start() {
+        assert_eq!(iota(5i32).prefix(4).to_vec(), [5, 6, 7, 8]);
+        assert_eq!(iota(-2i64).prefix(3).to_vec(), [-2, -1, 0]);
+        assert!(iota(0u8).prefix(0).is_empty());
+        assert_eq!(*iota(250u8).at(&5), 255);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iota_panics_for_unrepresentable_element() {
+        let _ = *iota(0u8).at(&256);
+    }
+
+    #[test]
+    fn algorithms_on_prefix() {
+        let p = iota(1u64).prefix(100);
+        assert_eq!(p.fold_left(0, |x, y| x + y), 5050);
+        assert_eq!(*p.at(&p.prior(p.end())), 100);
+        assert_eq!(p.binary_search_position(&42), Ok(41));
+        assert!(p.reversed().starts_with(&[100, 99, 98]));
+        let p = iota(1u64).prefix(100);
+        assert_eq!(p.lazy_map(|x| x % 10).count_where(|x| *x == 0), 10);
+    }
+
+    collection_conformance_tests!(
+        random_access,
+        generated_prefix,
+        generate(|i| 3 * i).prefix(4),
+        [0, 3, 6, 9]
+    );
+    collection_conformance_tests!(
+        random_access,
+        iota_prefix,
+        iota(10i32).prefix(3),
+        [10, 11, 12]
+    );
+}